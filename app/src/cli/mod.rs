@@ -6,6 +6,7 @@ pub mod assets;
 pub mod join;
 pub mod login;
 pub mod package;
+pub mod replay;
 
 mod package_path;
 pub use package_path::*;
@@ -16,6 +17,7 @@ use self::{
     package::{
         build::Build, deploy::Deploy, new::New, run::Run, serve::Serve, Package, PackageArgs,
     },
+    replay::Replay,
 };
 
 #[derive(Parser, Clone)]
@@ -45,6 +47,9 @@ pub enum Commands {
     },
     /// Log into Ambient and save your API token to settings
     Login,
+    /// Replay a physics recording made with `--record-physics` in a headless scene, and check that
+    /// it simulates the same way
+    Replay(Replay),
 }
 
 #[derive(Subcommand, Clone, Copy, Debug)]
@@ -127,6 +132,7 @@ impl Cli {
             Commands::Join(Join { .. }) => None,
             Commands::Assets { .. } => None,
             Commands::Login => None,
+            Commands::Replay(_) => None,
         }
     }
     pub fn use_release_build(&self) -> bool {
@@ -139,7 +145,12 @@ impl Cli {
             C::Run(Run { package, .. }) | C::Build(Build { package, .. }) => {
                 package.is_release().unwrap_or(false)
             }
-            C::New(_) | C::Join(_) | C::Assets { .. } | C::Package { .. } | C::Login => false,
+            C::New(_)
+            | C::Join(_)
+            | C::Assets { .. }
+            | C::Package { .. }
+            | C::Login
+            | C::Replay(_) => false,
         }
     }
 }
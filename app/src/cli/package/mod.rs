@@ -131,6 +131,26 @@ pub struct HostCli {
     /// Shutdown the server after the specified number of seconds of inactivity
     #[arg(long)]
     pub shutdown_after_inactivity_seconds: Option<u64>,
+
+    /// Directory the server world is persisted to and restored from
+    ///
+    /// Defaults to the `world` directory of the package
+    #[arg(long)]
+    pub world_path: Option<PathBuf>,
+
+    /// Don't persist the server world, and start with an empty world every time
+    #[arg(long, conflicts_with = "world_path")]
+    pub no_world_persistence: bool,
+
+    /// How often a full snapshot of the server world is saved; changes in between are appended to a log
+    ///
+    /// Defaults to 60
+    #[arg(long)]
+    pub world_snapshot_interval_seconds: Option<u64>,
+
+    /// Record the inputs applied to the physics scene to this file, so that they can be replayed with `ambient replay`
+    #[arg(long)]
+    pub record_physics: Option<PathBuf>,
 }
 
 pub fn handle(
@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use ambient_native_std::asset_cache::{AssetCache, SyncAssetKeyExt};
+use ambient_physics::{physx::PhysicsKey, recording};
+use clap::Parser;
+
+#[derive(Parser, Clone, Debug)]
+/// Replay a physics recording made with `--record-physics` in a headless scene
+pub struct Replay {
+    /// The recording to replay
+    pub path: PathBuf,
+    /// Where to write the final state of the actors as JSON; printed if not specified
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+pub fn handle(args: &Replay, assets: &AssetCache) -> anyhow::Result<()> {
+    let steps = recording::read_recording(&args.path)?;
+    let report = recording::replay(&PhysicsKey.get(assets), &steps)?;
+
+    let json = serde_json::to_string_pretty(&report)?;
+    match &args.output {
+        Some(output) => std::fs::write(output, json)?,
+        None => println!("{json}"),
+    }
+
+    if let Some(step) = report.first_divergent_step {
+        anyhow::bail!(
+            "The replay diverged from the recording at step {step} of {}",
+            report.steps
+        );
+    }
+    tracing::info!("Replayed {} physics steps", report.steps);
+    Ok(())
+}
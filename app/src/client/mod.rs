@@ -195,6 +195,7 @@ fn MainApp(
         ambient_client_shared::player::PlayerRawInputHandler.el(),
         WindowSized::el([ClientView {
             server_addr,
+            loopback: None,
             user_id,
             fail_on_version_mismatch,
             // NOTE: client.game_state is **locked** and accesible through game_state.
@@ -403,6 +404,7 @@ fn systems() -> SystemGroup {
     SystemGroup::new(
         "client",
         vec![
+            Box::new(ambient_network::interpolation::systems()),
             Box::new(ambient_prefab::systems()),
             Box::new(ambient_decals::client_systems()),
             Box::new(ambient_primitives::systems()),
@@ -104,6 +104,7 @@ fn main() -> anyhow::Result<()> {
         Commands::Assets { assets: command } => rt.block_on(cli::assets::handle(command, &assets)),
         Commands::Login => rt.block_on(cli::login::handle(&assets)),
         Commands::Join(join) => cli::join::handle(join, &rt, assets),
+        Commands::Replay(replay) => cli::replay::handle(replay, &assets),
     }
 }
 
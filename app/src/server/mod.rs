@@ -9,8 +9,9 @@ use std::{
 
 use ambient_core::{asset_cache, main_package_name, name, FIXED_SERVER_TICK_TIME};
 use ambient_ecs::{
-    dont_store, generated::network::components::no_sync, world_events, ComponentDesc, Entity,
-    Networked, SystemGroup, World, WorldContext, WorldEventsSystem, WorldStreamCompEvent,
+    dont_store, generated::network::components::no_sync, persistent, world_events, ComponentDesc,
+    DynSystem, Entity, Networked, SystemGroup, World, WorldContext, WorldEventsSystem,
+    WorldPersistence, WorldStreamCompEvent,
 };
 use ambient_native_std::{
     ambient_version,
@@ -42,6 +43,7 @@ use tower_http::{cors::CorsLayer, services::ServeDir};
 
 use crate::{cli::package::HostCli, shared};
 
+pub mod persistence;
 pub mod wasm;
 
 pub struct ServerHandle {
@@ -69,6 +71,13 @@ pub async fn start(
     crypto: Crypto,
 ) -> ServerHandle {
     let quic_interface_port = host_cli.quic_interface_port;
+    let world_persistence_disabled = host_cli.no_world_persistence;
+    let world_path = host_cli.world_path.clone();
+    let world_snapshot_interval = host_cli
+        .world_snapshot_interval_seconds
+        .map(Duration::from_secs)
+        .unwrap_or(persistence::DEFAULT_SNAPSHOT_INTERVAL);
+    let record_physics = host_cli.record_physics.clone();
 
     let proxy_settings = (!host_cli.no_proxy).then(|| ProxySettings {
         // default to getting a proxy from the dims-web Google App Engine app
@@ -191,11 +200,26 @@ pub async fn start(
                 Default::default(),
             )
             .spawn(&mut server_world);
-        // Note: this should not be reset every time the server is created. Remove this when it becomes possible to load/save worlds.
-        Entity::new()
-            .with(ambient_core::name(), "Persistent resources".to_string())
-            .with(is_persistent_resources(), ())
-            .spawn(&mut server_world);
+
+        let world_path = (!world_persistence_disabled)
+            .then(|| world_path.unwrap_or_else(|| working_directory.join("world")));
+        let restored_persistent_resources = match &world_path {
+            Some(world_path) => persistence::restore(&mut server_world, world_path)
+                .unwrap_or_else(|err| {
+                    tracing::error!(
+                        "Failed to restore the world from {world_path:?}, starting with a fresh world: {err:?}"
+                    );
+                    false
+                }),
+            None => false,
+        };
+        if !restored_persistent_resources {
+            Entity::new()
+                .with(ambient_core::name(), "Persistent resources".to_string())
+                .with(is_persistent_resources(), ())
+                .with(persistent(), ())
+                .spawn(&mut server_world);
+        }
 
         wasm::initialize(&mut server_world, &assets, working_directory.join("data"))
             .await
@@ -209,11 +233,30 @@ pub async fn start(
         .await
         .unwrap();
 
+        if let Some(path) = &record_physics {
+            ambient_physics::recording::start_recording(&mut server_world, path)
+                .with_context(|| format!("Failed to record physics to {path:?}"))
+                .unwrap();
+        }
+
+        // Only the main instance persists its world; forked instances get `None`
+        let persistence = Mutex::new(world_path.and_then(|world_path| {
+            persistence::start(&server_world, &world_path)
+                .map_err(|err| {
+                    tracing::error!(
+                        "Failed to persist the world to {world_path:?}, running without persistence: {err:?}"
+                    );
+                })
+                .ok()
+        }));
+
         tracing::debug!("Starting server");
         server
             .run(
                 server_world,
-                Arc::new(systems),
+                Arc::new(move |world| {
+                    systems(world, persistence.lock().take(), world_snapshot_interval)
+                }),
                 Arc::new(on_forking_systems),
                 Arc::new(on_shutdown_systems),
                 Arc::new(is_sync_component),
@@ -225,29 +268,36 @@ pub async fn start(
     ServerHandle { addr, join_handle }
 }
 
-fn systems(_world: &mut World) -> SystemGroup {
-    SystemGroup::new(
-        "server",
-        vec![
-            ambient_physics::run_simulation_system(),
-            // Can happen *during* the physics step
-            Box::new(ambient_core::async_ecs::async_ecs_systems()),
-            Box::new(ambient_prefab::systems()),
-            Box::new(ambient_core::hierarchy::systems()),
-            // Happens after the physics step
-            ambient_physics::fetch_simulation_system(),
-            Box::new(ambient_animation::animation_systems()),
-            Box::new(ambient_physics::physx::sync_ecs_physics()),
-            Box::new(ambient_core::transform::TransformSystem::new()),
-            ambient_core::remove_at_time_system(),
-            ambient_core::refcount_system(),
-            Box::new(WorldEventsSystem),
-            Box::new(ambient_core::camera::camera_systems()),
-            Box::new(ambient_physics::server_systems()),
-            Box::new(ambient_package_semantic_native::server_systems()),
-            Box::new(wasm::systems()),
-        ],
-    )
+fn systems(
+    _world: &mut World,
+    persistence: Option<WorldPersistence>,
+    snapshot_interval: Duration,
+) -> SystemGroup {
+    let mut systems: Vec<DynSystem> = vec![
+        ambient_physics::run_simulation_system(),
+        // Can happen *during* the physics step
+        Box::new(ambient_core::async_ecs::async_ecs_systems()),
+        Box::new(ambient_prefab::systems()),
+        Box::new(ambient_core::hierarchy::systems()),
+        Box::new(ambient_ecs::relationships_system()),
+        // Happens after the physics step
+        ambient_physics::fetch_simulation_system(),
+        Box::new(ambient_animation::animation_systems()),
+        Box::new(ambient_physics::physx::sync_ecs_physics()),
+        Box::new(ambient_core::transform::TransformSystem::new()),
+        ambient_core::remove_at_time_system(),
+        ambient_core::refcount_system(),
+        Box::new(WorldEventsSystem),
+        Box::new(ambient_core::camera::camera_systems()),
+        Box::new(ambient_physics::server_systems()),
+        Box::new(ambient_package_semantic_native::server_systems()),
+        Box::new(wasm::systems()),
+    ];
+    if let Some(persistence) = persistence {
+        // Runs last, so that everything that happened this frame is persisted
+        systems.push(persistence::system(persistence, snapshot_interval));
+    }
+    SystemGroup::new("server", systems)
 }
 fn on_forking_systems() -> SystemGroup<ForkingEvent> {
     SystemGroup::new(
@@ -0,0 +1,160 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc},
+    thread::JoinHandle,
+    time::{Duration, SystemTime},
+};
+
+use ambient_ecs::{
+    dont_store, persistent, ArchetypeFilter, DynSystem, FrameEvent, Store, System, World,
+    WorldDiff, WorldPersistence, WorldPersistenceStream, WorldPersistenceWriter, WorldStreamFilter,
+};
+use ambient_network::is_persistent_resources;
+use ambient_sys::time::Instant;
+use anyhow::Context;
+
+pub const DEFAULT_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Spawns the entities persisted by a previous run of the server into `world`, keeping their ids.
+///
+/// Returns true if a persistent resources entity was restored. If the persisted world can't be
+/// read, it is moved aside so that it is not overwritten by the next run, and an error is returned.
+pub fn restore(world: &mut World, path: &Path) -> anyhow::Result<bool> {
+    let persisted = match WorldPersistence::load(path) {
+        Ok(Some(persisted)) => persisted,
+        Ok(None) => return Ok(false),
+        Err(err) => {
+            let backup = move_aside(path)?;
+            return Err(err.context(format!("The unreadable world was moved to {backup:?}")));
+        }
+    };
+    persisted.warnings.log_warnings();
+
+    let mut restored_persistent_resources = false;
+    for (id, entity) in persisted.world.entities() {
+        restored_persistent_resources |= entity.contains(is_persistent_resources());
+        if !world.spawn_with_id(id, entity) {
+            tracing::warn!("Persisted entity {id} already exists in the world; skipping");
+        }
+    }
+    tracing::info!("Restored {} entities from {path:?}", persisted.world.len());
+
+    Ok(restored_persistent_resources)
+}
+
+fn move_aside(path: &Path) -> anyhow::Result<PathBuf> {
+    let secs = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(format!(".corrupt-{secs}"));
+    let backup = PathBuf::from(backup);
+    std::fs::rename(path, &backup)
+        .with_context(|| format!("Failed to move the unreadable world {path:?} to {backup:?}"))?;
+    Ok(backup)
+}
+
+/// Starts persisting the `persistent` entities of `world` to `path`.
+///
+/// A snapshot is written immediately, so that the diff log only contains changes made from now on.
+pub fn start(world: &World, path: &Path) -> anyhow::Result<WorldPersistence> {
+    let filter = WorldStreamFilter::new(
+        ArchetypeFilter::new().incl(persistent()).excl(dont_store()),
+        Arc::new(|comp, _| comp.has_attribute::<Store>()),
+    );
+    let mut persistence = WorldPersistence::new(path, filter)?;
+    persistence.save_snapshot(world)?;
+    Ok(persistence)
+}
+
+pub fn system(persistence: WorldPersistence, snapshot_interval: Duration) -> DynSystem {
+    let (stream, writer) = persistence.into_parts();
+    let (sender, receiver) = mpsc::channel();
+    let thread = std::thread::Builder::new()
+        .name("world_persistence".to_string())
+        .spawn(move || write_persistence(writer, receiver))
+        .expect("Failed to spawn the world persistence thread");
+
+    Box::new(WorldPersistenceSystem {
+        stream,
+        writer: Some((sender, thread)),
+        snapshot_interval,
+        last_snapshot: Instant::now(),
+    })
+}
+
+enum PersistenceWrite {
+    Diff(WorldDiff),
+    Snapshot(World),
+}
+
+/// Writes everything sent by the [WorldPersistenceSystem] until it is dropped, flushing the diff
+/// log once per batch rather than once per frame
+fn write_persistence(
+    mut writer: WorldPersistenceWriter,
+    receiver: mpsc::Receiver<PersistenceWrite>,
+) {
+    while let Ok(write) = receiver.recv() {
+        let result = std::iter::once(write)
+            .chain(receiver.try_iter())
+            .try_for_each(|write| match write {
+                PersistenceWrite::Diff(diff) => writer.write_diff(&diff),
+                PersistenceWrite::Snapshot(snapshot) => writer.write_snapshot(&snapshot),
+            })
+            .and_then(|()| writer.flush());
+
+        if let Err(err) = result {
+            tracing::error!(
+                "Failed to persist the world to {:?}: {err:?}",
+                writer.path()
+            );
+        }
+    }
+}
+
+/// Collects the changes of every frame, and periodically a snapshot, which are written to disk on
+/// a background thread
+struct WorldPersistenceSystem {
+    stream: WorldPersistenceStream,
+    writer: Option<(mpsc::Sender<PersistenceWrite>, JoinHandle<()>)>,
+    snapshot_interval: Duration,
+    last_snapshot: Instant,
+}
+impl System for WorldPersistenceSystem {
+    fn run(&mut self, world: &mut World, _event: &FrameEvent) {
+        let write = if self.last_snapshot.elapsed() >= self.snapshot_interval {
+            self.last_snapshot = Instant::now();
+            PersistenceWrite::Snapshot(self.stream.snapshot(world))
+        } else {
+            let diff = self.stream.next_diff(world);
+            if diff.is_empty() {
+                return;
+            }
+            PersistenceWrite::Diff(diff)
+        };
+
+        if let Some((sender, _)) = &self.writer {
+            if sender.send(write).is_err() {
+                tracing::error!("The world persistence thread has stopped");
+                self.writer = None;
+            }
+        }
+    }
+}
+impl Drop for WorldPersistenceSystem {
+    /// Waits for the pending writes, so that the last frames are not lost on shutdown
+    fn drop(&mut self) {
+        if let Some((sender, thread)) = self.writer.take() {
+            drop(sender);
+            thread.join().ok();
+        }
+    }
+}
+impl std::fmt::Debug for WorldPersistenceSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorldPersistenceSystem")
+            .field("snapshot_interval", &self.snapshot_interval)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,12 +1,12 @@
-use std::{fmt::Display, path::PathBuf, sync::Arc};
+use std::{fmt::Display, path::PathBuf, sync::Arc, time::Duration};
 
 use ambient_ecs::{Entity, EntityId, SystemGroup, World};
 use ambient_native_std::asset_cache::AssetCache;
 use ambient_package_semantic_native::{WasmSpawnRequest, WasmSpawnResponse};
 pub use ambient_wasm::server::{on_forking_systems, on_shutdown_systems};
 use ambient_wasm::shared::{
-    bytecode_from_url, is_module, is_module_on_server, module_enabled, module_name, package_ref,
-    MessageType,
+    bytecode_from_url, is_module, is_module_on_server, module_enabled, module_limits, module_name,
+    package_ref, MessageType, ModuleLimits,
 };
 
 pub fn systems() -> SystemGroup {
@@ -46,8 +46,15 @@ pub fn spawn_package(
         package_id,
         client_modules: client_request,
         server_modules: server_request,
+        limits,
     } = request;
 
+    let limits = ModuleLimits {
+        max_run_time: limits.max_run_time_ms.map(Duration::from_millis),
+        max_memory: limits.max_memory_mb.map(|mb| (mb as usize) << 20),
+        max_spawned_entities_per_frame: limits.max_spawned_entities_per_frame,
+    };
+
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     enum Side {
         Client,
@@ -79,7 +86,8 @@ pub fn spawn_package(
                 .with(self::is_module(), ())
                 .with(self::bytecode_from_url(), url.to_string())
                 .with(self::module_enabled(), enabled)
-                .with(self::package_ref(), package_id);
+                .with(self::package_ref(), package_id)
+                .with(self::module_limits(), limits.clone());
 
             let is_server = target == Side::Server;
             let entity = if is_server {
@@ -103,6 +103,7 @@ pub fn world_instance_systems(full: bool) -> SystemGroup {
             remove_at_time_system(),
             refcount_system(),
             Box::new(ambient_core::hierarchy::systems()),
+            // `relationships_system` is not run here, as the client gets the cleanup from the server
             Box::new(WorldEventsSystem),
             Box::new(ambient_focus::systems()),
             if full {
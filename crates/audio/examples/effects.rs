@@ -0,0 +1,63 @@
+use std::{sync::Arc, time::Duration};
+
+use ambient_audio::{
+    track::Track, AudioStream, BufferedSource, ImpulseResponse, ReverbParams, Source,
+};
+use rand::{thread_rng, Rng};
+
+fn main() {
+    let stream = AudioStream::new().unwrap();
+
+    let mixer = stream.mixer();
+
+    let source = Track::from_vorbis(
+        std::fs::read("../../guest/rust/examples/basics/physics/assets/bonk.ogg")
+            .unwrap()
+            .to_vec(),
+    )
+    .unwrap();
+
+    let sample_rate = source.decode().sample_rate();
+
+    // An impulse response of exponentially decaying noise, which sounds like a large hall. A
+    // recorded impulse response can be loaded with `Track` in the same way as any other audio.
+    let mut rng = thread_rng();
+    let ir_len = sample_rate as usize * 2;
+    let ir = (0..ir_len)
+        .flat_map(|i| {
+            let decay = (-6.0 * i as f32 / ir_len as f32).exp();
+            [
+                rng.gen_range(-1.0..1.0) * decay,
+                rng.gen_range(-1.0..1.0) * decay,
+            ]
+        })
+        .collect::<Vec<f32>>();
+    let ir = Arc::new(ImpulseResponse::new(
+        BufferedSource::new(ir, 2, sample_rate),
+        sample_rate,
+    ));
+
+    let source = source
+        .decode()
+        .feedback_delay(Duration::from_millis(250), 0.5, 0.5)
+        .take(Duration::from_secs(5))
+        .chain(
+            source
+                .decode()
+                .reverb(ReverbParams {
+                    room_size: 0.8,
+                    ..Default::default()
+                })
+                .take(Duration::from_secs(5)),
+        )
+        .chain(
+            source
+                .decode()
+                .convolution_reverb(ir, 0.5)
+                .take(Duration::from_secs(5)),
+        )
+        .repeat();
+
+    let sound = mixer.play(source);
+    sound.wait_blocking();
+}
@@ -71,11 +71,20 @@ impl Future for SoundFut {
                 Poll::Pending
             }
         } else {
-            // Polled for the first time, place a new waker into the mixer
+            // Polled for the first time, place a new waker into the mixer.
+            //
+            // The sources are kept locked so that the sound can not finish between the check and
+            // the waker being registered.
+            let mixer = self.mixer.clone();
+            let sources = mixer.inner.sources.lock();
+            if !sources.contains_key(self.id) {
+                return Poll::Ready(());
+            }
+
             let signal = Arc::new(AsyncSignal::new(cx.waker().clone()));
 
             self.signal = Some(signal.clone());
-            self.mixer.inner.waiters.lock().push((self.id, signal));
+            mixer.inner.waiters.lock().push((self.id, signal));
 
             Poll::Pending
         }
@@ -153,8 +162,12 @@ impl AudioMixer {
         }
     }
 
+    /// Stops a playing sound, and wakes everything waiting for it to finish
     pub fn stop(&self, key: SoundId) {
-        self.inner.sources.lock().remove(key);
+        let mut sources = self.inner.sources.lock();
+        if sources.remove(key).is_some() {
+            self.notify_sound_waiters(key);
+        }
     }
 
     fn notify_sound_waiters(&self, id: SoundId) {
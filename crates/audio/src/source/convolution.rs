@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use num::{complex::Complex32, Zero};
+use rustfft::{Fft, FftPlanner};
+
+use crate::{Frame, SampleConversion, SampleRate, Source};
+
+/// The number of samples convolved at a time. The reverberation is delayed by this amount.
+const BLOCK_LEN: usize = 512;
+
+/// An impulse response, such as a recording of a room, prepared for convolution.
+///
+/// The response is split into partitions of [`BLOCK_LEN`] samples which are transformed to the
+/// frequency domain once, so that it can be shared between many sounds.
+pub struct ImpulseResponse {
+    sample_rate: SampleRate,
+    len: usize,
+    left: Vec<Box<[Complex32]>>,
+    right: Vec<Box<[Complex32]>>,
+}
+
+impl std::fmt::Debug for ImpulseResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImpulseResponse")
+            .field("sample_rate", &self.sample_rate)
+            .field("len", &self.len)
+            .field("partitions", &self.left.len())
+            .finish()
+    }
+}
+
+impl ImpulseResponse {
+    /// Reads the impulse response from `source`, resampled to `sample_rate`.
+    ///
+    /// The response is normalized so that the reverberation is about as loud as the input.
+    /// `source` must be finite.
+    pub fn new<S: Source>(source: S, sample_rate: SampleRate) -> Self {
+        fn read_all(mut source: impl Source) -> Vec<Frame> {
+            std::iter::from_fn(|| source.next_sample()).collect()
+        }
+
+        let samples = if source.sample_rate() == sample_rate {
+            read_all(source)
+        } else {
+            read_all(SampleConversion::new(source, sample_rate))
+        };
+
+        let energy = samples.iter().map(|v| v.length_squared()).sum::<f32>();
+        let gain = if energy > 0.0 {
+            (2.0 / energy).sqrt()
+        } else {
+            0.0
+        };
+
+        let mut planner = FftPlanner::new();
+        let fft = planner.plan_fft_forward(2 * BLOCK_LEN);
+        let mut scratch = vec![Complex32::zero(); fft.get_inplace_scratch_len()];
+
+        let mut transform = |partition: &[Frame], channel: fn(&Frame) -> f32| {
+            let mut buf = vec![Complex32::zero(); 2 * BLOCK_LEN].into_boxed_slice();
+            for (dst, src) in buf.iter_mut().zip(partition) {
+                *dst = Complex32::new(channel(src) * gain, 0.0);
+            }
+            fft.process_with_scratch(&mut buf, &mut scratch);
+            // Normalize for the inverse transform
+            let len = buf.len() as f32;
+            buf.iter_mut().for_each(|v| *v /= len);
+            buf
+        };
+
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for partition in samples.chunks(BLOCK_LEN) {
+            left.push(transform(partition, |v| v.x));
+            right.push(transform(partition, |v| v.y));
+        }
+
+        Self {
+            sample_rate,
+            len: samples.len(),
+            left,
+            right,
+        }
+    }
+
+    pub fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    /// The number of samples in the impulse response
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Convolves a single channel using a uniformly partitioned overlap-save
+#[derive(Clone)]
+struct ConvolutionChannel {
+    /// The transformed input blocks, most recent first
+    history: Vec<Box<[Complex32]>>,
+    head: usize,
+    /// The previous and current input block
+    input: Box<[f32]>,
+    output: Box<[Complex32]>,
+}
+
+impl ConvolutionChannel {
+    fn new(partitions: usize) -> Self {
+        Self {
+            history: (0..partitions.max(1))
+                .map(|_| vec![Complex32::zero(); 2 * BLOCK_LEN].into_boxed_slice())
+                .collect(),
+            head: 0,
+            input: vec![0.0; 2 * BLOCK_LEN].into_boxed_slice(),
+            output: vec![Complex32::zero(); 2 * BLOCK_LEN].into_boxed_slice(),
+        }
+    }
+
+    /// Convolves the current input block, leaving the result in the second half of `output`
+    fn process(
+        &mut self,
+        ir: &[Box<[Complex32]>],
+        fft: &dyn Fft<f32>,
+        ifft: &dyn Fft<f32>,
+        scratch: &mut [Complex32],
+    ) {
+        self.head = (self.head + self.history.len() - 1) % self.history.len();
+        let block = &mut self.history[self.head];
+        for (dst, src) in block.iter_mut().zip(self.input.iter()) {
+            *dst = Complex32::new(*src, 0.0);
+        }
+        fft.process_with_scratch(block, scratch);
+
+        self.output.iter_mut().for_each(|v| *v = Complex32::zero());
+        for (i, h) in ir.iter().enumerate() {
+            let x = &self.history[(self.head + i) % self.history.len()];
+            for ((out, x), h) in self.output.iter_mut().zip(x.iter()).zip(h.iter()) {
+                *out += x * h;
+            }
+        }
+        ifft.process_with_scratch(&mut self.output, scratch);
+
+        // The current block becomes the previous block
+        self.input.copy_within(BLOCK_LEN.., 0);
+    }
+}
+
+/// Reverberates the source by convolving it with an [`ImpulseResponse`].
+///
+/// The reverberation is delayed by 512 samples, and continues after the source has ended until
+/// the end of the impulse response.
+#[derive(Clone)]
+pub struct ConvolutionReverb<S> {
+    source: S,
+    ir: Arc<ImpulseResponse>,
+    mix: f32,
+    fft: Arc<dyn Fft<f32>>,
+    ifft: Arc<dyn Fft<f32>>,
+    scratch: Box<[Complex32]>,
+    left: ConvolutionChannel,
+    right: ConvolutionChannel,
+    /// The position in the current block
+    cursor: usize,
+    /// Set once the source has ended
+    tail: Option<u64>,
+}
+
+impl<S> std::fmt::Debug for ConvolutionReverb<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConvolutionReverb")
+            .field("ir", &self.ir)
+            .field("mix", &self.mix)
+            .field("tail", &self.tail)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> ConvolutionReverb<S>
+where
+    S: Source,
+{
+    /// `mix` is the balance between the original source and the reverberation.
+    ///
+    /// Panics if the impulse response was not prepared for the sample rate of the source.
+    pub fn new(source: S, ir: Arc<ImpulseResponse>, mix: f32) -> Self {
+        assert_eq!(
+            source.sample_rate(),
+            ir.sample_rate,
+            "Impulse response does not match the sample rate of the source"
+        );
+
+        let mut planner = FftPlanner::new();
+        let fft = planner.plan_fft_forward(2 * BLOCK_LEN);
+        let ifft = planner.plan_fft_inverse(2 * BLOCK_LEN);
+        let scratch_len = fft
+            .get_inplace_scratch_len()
+            .max(ifft.get_inplace_scratch_len());
+
+        Self {
+            source,
+            mix: mix.clamp(0.0, 1.0),
+            fft,
+            ifft,
+            scratch: vec![Complex32::zero(); scratch_len].into_boxed_slice(),
+            left: ConvolutionChannel::new(ir.left.len()),
+            right: ConvolutionChannel::new(ir.right.len()),
+            ir,
+            cursor: 0,
+            tail: None,
+        }
+    }
+
+    fn tail_len(&self) -> u64 {
+        (self.ir.len + BLOCK_LEN) as u64
+    }
+}
+
+impl<S> Source for ConvolutionReverb<S>
+where
+    S: Source,
+{
+    fn next_sample(&mut self) -> Option<Frame> {
+        let dry = match self.tail {
+            None => match self.source.next_sample() {
+                Some(sample) => sample,
+                None => {
+                    self.tail = Some(self.tail_len());
+                    Frame::ZERO
+                }
+            },
+            Some(_) => Frame::ZERO,
+        };
+
+        if let Some(tail) = &mut self.tail {
+            *tail = tail.checked_sub(1)?;
+        }
+
+        // The output of the previous block
+        let wet = Frame::new(
+            self.left.output[BLOCK_LEN + self.cursor].re,
+            self.right.output[BLOCK_LEN + self.cursor].re,
+        );
+
+        self.left.input[BLOCK_LEN + self.cursor] = dry.x;
+        self.right.input[BLOCK_LEN + self.cursor] = dry.y;
+        self.cursor += 1;
+
+        if self.cursor == BLOCK_LEN {
+            self.cursor = 0;
+            self.left
+                .process(&self.ir.left, &*self.fft, &*self.ifft, &mut self.scratch);
+            self.right
+                .process(&self.ir.right, &*self.fft, &*self.ifft, &mut self.scratch);
+        }
+
+        Some(dry * (1.0 - self.mix) + wet * self.mix)
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        match self.tail {
+            Some(tail) => Some(tail),
+            None => Some(self.source.sample_count()? + self.tail_len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::BufferedSource;
+
+    #[test]
+    fn convolution_echo() {
+        // An impulse response which echoes the input after 1000 samples
+        let mut ir = vec![0.0; 1001];
+        ir[1000] = 1.0;
+        let ir = Arc::new(ImpulseResponse::new(BufferedSource::new(ir, 1, 8000), 8000));
+
+        let source = BufferedSource::new([1.0, 0.5], 1, 8000);
+        let reverb = ConvolutionReverb::new(source, ir, 1.0);
+
+        let count = reverb.sample_count().unwrap();
+        let samples: Vec<_> = reverb.samples_iter().collect();
+        assert_eq!(samples.len() as u64, count);
+
+        // The impulse response is normalized to an energy of 1 per channel
+        let echo = BLOCK_LEN + 1000;
+        assert_relative_eq!(samples[echo].x, 1.0, epsilon = 1e-4);
+        assert_relative_eq!(samples[echo + 1].x, 0.5, epsilon = 1e-4);
+        assert_relative_eq!(samples[echo + 2].x, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(samples[echo - 1].x, 0.0, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use crate::{to_sample_index, Frame, SampleRate, Source};
+
+use super::decay_len;
+
+/// Repeats the source after `delay`, with each echo attenuated by `feedback`.
+///
+/// The echoes continue after the source has ended until they have decayed.
+#[derive(Debug, Clone)]
+pub struct FeedbackDelay<S> {
+    source: S,
+    buffer: Box<[Frame]>,
+    cursor: usize,
+    feedback: f32,
+    mix: f32,
+    /// Set once the source has ended
+    tail: Option<u64>,
+}
+
+impl<S> FeedbackDelay<S>
+where
+    S: Source,
+{
+    /// `feedback` is clamped to `0.0..=0.99`. `mix` is the balance between the original source
+    /// and the echoes.
+    pub fn new(source: S, delay: Duration, feedback: f32, mix: f32) -> Self {
+        let len = to_sample_index(source.sample_rate(), delay).max(1) as usize;
+        Self {
+            source,
+            buffer: vec![Frame::ZERO; len].into_boxed_slice(),
+            cursor: 0,
+            feedback: feedback.clamp(0.0, 0.99),
+            mix: mix.clamp(0.0, 1.0),
+            tail: None,
+        }
+    }
+
+    fn tail_len(&self) -> u64 {
+        decay_len(self.buffer.len() as u64, self.feedback)
+    }
+}
+
+impl<S> Source for FeedbackDelay<S>
+where
+    S: Source,
+{
+    fn next_sample(&mut self) -> Option<Frame> {
+        let input = match self.tail {
+            None => match self.source.next_sample() {
+                Some(sample) => sample,
+                None => {
+                    self.tail = Some(self.tail_len());
+                    Frame::ZERO
+                }
+            },
+            Some(_) => Frame::ZERO,
+        };
+
+        if let Some(tail) = &mut self.tail {
+            *tail = tail.checked_sub(1)?;
+        }
+
+        let delayed = self.buffer[self.cursor];
+        self.buffer[self.cursor] = input + delayed * self.feedback;
+        self.cursor = (self.cursor + 1) % self.buffer.len();
+
+        Some(input * (1.0 - self.mix) + delayed * self.mix)
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        match self.tail {
+            Some(tail) => Some(tail),
+            None => Some(self.source.sample_count()? + self.tail_len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::BufferedSource;
+
+    #[test]
+    fn feedback_delay() {
+        let source = BufferedSource::new([1.0, 0.0], 1, 4);
+        let delay = FeedbackDelay::new(source, Duration::from_millis(500), 0.5, 0.5);
+
+        let samples = delay.samples_iter().take(7).map(|v| v.x).collect_vec();
+        assert_eq!(samples, [0.5, 0.0, 0.5, 0.0, 0.25, 0.0, 0.125]);
+    }
+
+    #[test]
+    fn feedback_delay_tail() {
+        let source = BufferedSource::new([1.0, 0.0], 1, 4);
+        let delay = FeedbackDelay::new(source, Duration::from_millis(500), 0.5, 1.0);
+
+        let count = delay.sample_count().unwrap();
+        assert_eq!(delay.samples_iter().count() as u64, count);
+    }
+}
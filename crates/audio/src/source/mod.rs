@@ -1,6 +1,8 @@
 mod buffered;
 mod chain;
+mod convolution;
 mod crossfade;
+mod delay;
 pub(crate) mod dynamic_delay;
 pub mod gain;
 pub mod history;
@@ -10,7 +12,9 @@ mod oscilloscope;
 mod pad_to;
 mod pan;
 mod peek;
+mod playback;
 mod repeat;
+mod reverb;
 mod sample_bufferer;
 mod sample_rate;
 mod slice;
@@ -29,14 +33,18 @@ use std::{
 pub use buffered::*;
 pub use chain::*;
 use circular_queue::CircularQueue;
+pub use convolution::*;
 pub use crossfade::*;
+pub use delay::*;
 pub use gain::*;
 pub use mix::*;
 pub use onepole::*;
 pub use pan::*;
 use parking_lot::Mutex;
 pub use peek::*;
+pub use playback::*;
 pub use repeat::*;
+pub use reverb::*;
 pub use sample_rate::*;
 pub use slice::*;
 pub use spatial::*;
@@ -230,6 +238,27 @@ pub trait Source: Send {
         BilinearTransform::new(self, transfer)
     }
 
+    fn feedback_delay(self, delay: Duration, feedback: f32, mix: f32) -> FeedbackDelay<Self>
+    where
+        Self: Sized,
+    {
+        FeedbackDelay::new(self, delay, feedback, mix)
+    }
+
+    fn reverb(self, params: ReverbParams) -> Reverb<Self>
+    where
+        Self: Sized,
+    {
+        Reverb::new(self, params)
+    }
+
+    fn convolution_reverb(self, ir: Arc<ImpulseResponse>, mix: f32) -> ConvolutionReverb<Self>
+    where
+        Self: Sized,
+    {
+        ConvolutionReverb::new(self, ir, mix)
+    }
+
     fn history(self, freq: f32, buf: Arc<Mutex<CircularQueue<Frame>>>) -> History<Self>
     where
         Self: Sized,
@@ -373,3 +402,14 @@ impl<S: Source> SampleIter<S> {
 pub(crate) fn to_sample_index(sample_rate: SampleRate, time: Duration) -> SampleRate {
     (sample_rate * u64::try_from(time.as_nanos()).unwrap()) / 1_000_000_000
 }
+
+/// The number of samples until a loop of `loop_len` samples which is attenuated by `feedback` each
+/// time around has decayed by 60 dB
+pub(crate) fn decay_len(loop_len: u64, feedback: f32) -> u64 {
+    if feedback <= 0.0 {
+        return loop_len;
+    }
+
+    let loops = (0.001f32.ln() / feedback.min(0.999).ln()).ceil() as u64;
+    loop_len * (loops + 1)
+}
@@ -0,0 +1,274 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+
+use crate::{to_sample_index, Frame, SampleRate, Source};
+
+type Restart = Box<dyn Fn() -> Box<dyn Source> + Send + Sync>;
+
+#[derive(Debug, Clone, Copy)]
+struct GainRamp {
+    current: f32,
+    target: f32,
+    step: f32,
+}
+
+impl GainRamp {
+    fn next(&mut self) -> f32 {
+        if self.current != self.target {
+            self.current += self.step;
+            if (self.step >= 0.0 && self.current >= self.target)
+                || (self.step <= 0.0 && self.current <= self.target)
+            {
+                self.current = self.target;
+            }
+        }
+        self.current
+    }
+}
+
+/// Controls a [`Playback`] while it is being played by the mixer.
+pub struct PlaybackControl {
+    restart: Restart,
+    sample_rate: SampleRate,
+    sample_count: Option<u64>,
+    looping: bool,
+    position: AtomicU64,
+    /// A source which has already been advanced to the new position, and the position
+    seeked: Mutex<Option<(Box<dyn Source>, u64)>>,
+    /// The number of seeks which have been started, so that a seek which finishes after a later
+    /// one is discarded
+    seeks: AtomicU64,
+    gain: Mutex<GainRamp>,
+}
+
+impl std::fmt::Debug for PlaybackControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlaybackControl")
+            .field("sample_rate", &self.sample_rate)
+            .field("sample_count", &self.sample_count)
+            .field("looping", &self.looping)
+            .field("position", &self.position)
+            .field("gain", &self.gain)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PlaybackControl {
+    /// The position of the playback from the start of the source
+    pub fn position(&self) -> Duration {
+        Duration::from_secs_f64(
+            self.position.load(Ordering::Relaxed) as f64 / self.sample_rate as f64,
+        )
+    }
+
+    /// The duration of the source, if known
+    pub fn duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f64(
+            self.sample_count? as f64 / self.sample_rate as f64,
+        ))
+    }
+
+    /// The current amplitude, which changes over time while fading
+    pub fn amplitude(&self) -> f32 {
+        self.gain.lock().current
+    }
+
+    /// Moves the playback to `position`.
+    ///
+    /// The source is decoded up to the new position on the calling thread, so this blocks until
+    /// it has been, and should not be called from the audio or main threads. The playback
+    /// continues from its old position until then.
+    pub fn seek(&self, position: Duration) {
+        let seek = self.seeks.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut index = to_sample_index(self.sample_rate, position);
+        if let Some(count) = self.sample_count.filter(|count| *count > 0) {
+            index = if self.looping {
+                index % count
+            } else {
+                index.min(count)
+            };
+        }
+
+        let mut source = (self.restart)();
+        for _ in 0..index {
+            if source.next_sample().is_none() {
+                break;
+            }
+        }
+        let mut seeked = self.seeked.lock();
+        if self.seeks.load(Ordering::Relaxed) == seek {
+            *seeked = Some((source, index));
+        }
+    }
+
+    /// Linearly changes the amplitude to `amplitude` over `duration`
+    pub fn fade(&self, amplitude: f32, duration: Duration) {
+        let samples = to_sample_index(self.sample_rate, duration).max(1);
+        let mut gain = self.gain.lock();
+        gain.target = amplitude;
+        gain.step = (amplitude - gain.current) / samples as f32;
+    }
+}
+
+/// Plays a source which can be seeked, faded and queried for its position through its
+/// [`PlaybackControl`].
+pub struct Playback {
+    source: Box<dyn Source>,
+    control: Arc<PlaybackControl>,
+}
+
+impl std::fmt::Debug for Playback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Playback")
+            .field("control", &self.control)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Playback {
+    /// Plays `source` from the start. The source is cloned whenever playback restarts, which
+    /// happens when seeking or looping.
+    pub fn new<S>(source: S, looping: bool, amplitude: f32) -> Self
+    where
+        S: Source + Clone + 'static,
+    {
+        let control = Arc::new(PlaybackControl {
+            sample_rate: source.sample_rate(),
+            sample_count: source.sample_count(),
+            looping,
+            position: AtomicU64::new(0),
+            seeked: Mutex::new(None),
+            seeks: AtomicU64::new(0),
+            gain: Mutex::new(GainRamp {
+                current: amplitude,
+                target: amplitude,
+                step: 0.0,
+            }),
+            restart: {
+                let source = Mutex::new(source.clone());
+                Box::new(move || Box::new(source.lock().clone()))
+            },
+        });
+        Self {
+            source: Box::new(source),
+            control,
+        }
+    }
+
+    pub fn control(&self) -> Arc<PlaybackControl> {
+        self.control.clone()
+    }
+}
+
+impl Source for Playback {
+    fn next_sample(&mut self) -> Option<Frame> {
+        if let Some((source, position)) = self.control.seeked.lock().take() {
+            self.source = source;
+            self.control.position.store(position, Ordering::Relaxed);
+        }
+
+        let sample = match self.source.next_sample() {
+            Some(sample) => sample,
+            None if self.control.looping => {
+                self.source = (self.control.restart)();
+                self.control.position.store(0, Ordering::Relaxed);
+                self.source.next_sample()?
+            }
+            None => return None,
+        };
+        self.control.position.fetch_add(1, Ordering::Relaxed);
+
+        Some(sample * self.control.gain.lock().next())
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.control.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        if self.control.looping {
+            return None;
+        }
+        let position = match &*self.control.seeked.lock() {
+            Some((_, position)) => *position,
+            None => self.control.position.load(Ordering::Relaxed),
+        };
+        Some(self.control.sample_count?.saturating_sub(position))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::BufferedSource;
+
+    fn source() -> BufferedSource<[f32; 8], f32> {
+        BufferedSource::new([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0], 1, 2)
+    }
+
+    #[test]
+    fn playback_position() {
+        let mut playback = Playback::new(source(), false, 1.0);
+        let control = playback.control();
+        assert_eq!(control.duration(), Some(Duration::from_secs(4)));
+
+        playback.next_sample();
+        playback.next_sample();
+        playback.next_sample();
+        assert_eq!(control.position(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn playback_seek() {
+        let playback = Playback::new(source(), false, 1.0);
+        playback.control().seek(Duration::from_secs(3));
+
+        let samples = playback.samples_iter().collect_vec();
+        assert_eq!(samples, [Frame::splat(6.0), Frame::splat(7.0)]);
+    }
+
+    #[test]
+    fn playback_looping() {
+        let playback = Playback::new(source(), true, 1.0);
+        playback.control().seek(Duration::from_secs(3));
+
+        let samples = playback.samples_iter().take(4).collect_vec();
+        assert_eq!(
+            samples,
+            [
+                Frame::splat(6.0),
+                Frame::splat(7.0),
+                Frame::splat(0.0),
+                Frame::splat(1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn playback_fade() {
+        let playback = Playback::new(BufferedSource::new([1.0; 8], 1, 2), false, 0.0);
+        playback.control().fade(1.0, Duration::from_secs(2));
+
+        let samples = playback.samples_iter().take(6).collect_vec();
+        assert_eq!(
+            samples,
+            [
+                Frame::splat(0.25),
+                Frame::splat(0.5),
+                Frame::splat(0.75),
+                Frame::splat(1.0),
+                Frame::splat(1.0),
+                Frame::splat(1.0)
+            ]
+        );
+    }
+}
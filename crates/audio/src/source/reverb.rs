@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Frame, SampleRate, Source};
+
+use super::decay_len;
+
+/// The tunings of the original Freeverb, in samples at 44.1 kHz
+const COMB_TUNING: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
+const ALLPASS_TUNING: [usize; 4] = [556, 441, 341, 225];
+const STEREO_SPREAD: usize = 23;
+const TUNING_RATE: f32 = 44100.0;
+
+const FIXED_GAIN: f32 = 0.015;
+const SCALE_WET: f32 = 3.0;
+const SCALE_DAMPING: f32 = 0.4;
+const SCALE_ROOM: f32 = 0.28;
+const OFFSET_ROOM: f32 = 0.7;
+const ALLPASS_FEEDBACK: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReverbParams {
+    /// The size of the simulated room, from 0.0 to 1.0. Larger rooms reverberate for longer
+    pub room_size: f32,
+    /// How much the high frequencies are absorbed, from 0.0 to 1.0
+    pub damping: f32,
+    /// The stereo width of the reverberation, from 0.0 to 1.0
+    pub width: f32,
+    /// The balance between the original source and the reverberation
+    pub mix: f32,
+}
+
+impl Default for ReverbParams {
+    fn default() -> Self {
+        Self {
+            room_size: 0.5,
+            damping: 0.5,
+            width: 1.0,
+            mix: 0.3,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Comb {
+    buffer: Box<[f32]>,
+    cursor: usize,
+    store: f32,
+}
+
+impl Comb {
+    fn new(len: usize) -> Self {
+        Self {
+            buffer: vec![0.0; len.max(1)].into_boxed_slice(),
+            cursor: 0,
+            store: 0.0,
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+        let output = self.buffer[self.cursor];
+        self.store = output * (1.0 - damping) + self.store * damping;
+        self.buffer[self.cursor] = input + self.store * feedback;
+        self.cursor = (self.cursor + 1) % self.buffer.len();
+        output
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Allpass {
+    buffer: Box<[f32]>,
+    cursor: usize,
+}
+
+impl Allpass {
+    fn new(len: usize) -> Self {
+        Self {
+            buffer: vec![0.0; len.max(1)].into_boxed_slice(),
+            cursor: 0,
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, input: f32) -> f32 {
+        let delayed = self.buffer[self.cursor];
+        self.buffer[self.cursor] = input + delayed * ALLPASS_FEEDBACK;
+        self.cursor = (self.cursor + 1) % self.buffer.len();
+        delayed - input
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Channel {
+    combs: [Comb; 8],
+    allpasses: [Allpass; 4],
+}
+
+impl Channel {
+    fn new(sample_rate: SampleRate, spread: usize) -> Self {
+        let scale =
+            |len: usize| ((len + spread) as f32 * sample_rate as f32 / TUNING_RATE) as usize;
+        Self {
+            combs: COMB_TUNING.map(|len| Comb::new(scale(len))),
+            allpasses: ALLPASS_TUNING.map(|len| Allpass::new(scale(len))),
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+        let output: f32 = self
+            .combs
+            .iter_mut()
+            .map(|comb| comb.process(input, feedback, damping))
+            .sum();
+
+        self.allpasses
+            .iter_mut()
+            .fold(output, |output, allpass| allpass.process(output))
+    }
+}
+
+/// Algorithmic room reverberation, based on Freeverb.
+///
+/// The reverberation continues after the source has ended until it has decayed.
+#[derive(Debug, Clone)]
+pub struct Reverb<S> {
+    source: S,
+    params: ReverbParams,
+    left: Channel,
+    right: Channel,
+    /// Set once the source has ended
+    tail: Option<u64>,
+}
+
+impl<S> Reverb<S>
+where
+    S: Source,
+{
+    pub fn new(source: S, params: ReverbParams) -> Self {
+        let sample_rate = source.sample_rate();
+        let params = ReverbParams {
+            room_size: params.room_size.clamp(0.0, 1.0),
+            damping: params.damping.clamp(0.0, 1.0),
+            width: params.width.clamp(0.0, 1.0),
+            mix: params.mix.clamp(0.0, 1.0),
+        };
+
+        Self {
+            source,
+            params,
+            left: Channel::new(sample_rate, 0),
+            right: Channel::new(sample_rate, STEREO_SPREAD),
+            tail: None,
+        }
+    }
+
+    fn feedback(&self) -> f32 {
+        self.params.room_size * SCALE_ROOM + OFFSET_ROOM
+    }
+
+    fn tail_len(&self) -> u64 {
+        let longest_comb = self.right.combs.iter().map(|v| v.buffer.len()).max();
+        let allpasses: usize = self.right.allpasses.iter().map(|v| v.buffer.len()).sum();
+        decay_len(longest_comb.unwrap_or_default() as u64, self.feedback()) + allpasses as u64
+    }
+}
+
+impl<S> Source for Reverb<S>
+where
+    S: Source,
+{
+    fn next_sample(&mut self) -> Option<Frame> {
+        let dry = match self.tail {
+            None => match self.source.next_sample() {
+                Some(sample) => sample,
+                None => {
+                    self.tail = Some(self.tail_len());
+                    Frame::ZERO
+                }
+            },
+            Some(_) => Frame::ZERO,
+        };
+
+        if let Some(tail) = &mut self.tail {
+            *tail = tail.checked_sub(1)?;
+        }
+
+        let feedback = self.feedback();
+        let damping = self.params.damping * SCALE_DAMPING;
+        let input = (dry.x + dry.y) * FIXED_GAIN;
+
+        let l = self.left.process(input, feedback, damping);
+        let r = self.right.process(input, feedback, damping);
+
+        let wet = self.params.mix * SCALE_WET;
+        let wet1 = wet * (self.params.width / 2.0 + 0.5);
+        let wet2 = wet * ((1.0 - self.params.width) / 2.0);
+
+        Some(Frame::new(l * wet1 + r * wet2, r * wet1 + l * wet2) + dry * (1.0 - self.params.mix))
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        match self.tail {
+            Some(tail) => Some(tail),
+            None => Some(self.source.sample_count()? + self.tail_len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::BufferedSource;
+
+    #[test]
+    fn reverb_dry() {
+        let source = BufferedSource::new([0.5, -0.5, 0.25], 1, 44100);
+        let params = ReverbParams {
+            mix: 0.0,
+            ..Default::default()
+        };
+        let mut reverb = Reverb::new(source, params);
+
+        assert_eq!(reverb.next_sample(), Some(Frame::splat(0.5)));
+        assert_eq!(reverb.next_sample(), Some(Frame::splat(-0.5)));
+        assert_eq!(reverb.next_sample(), Some(Frame::splat(0.25)));
+    }
+
+    #[test]
+    fn reverb_tail() {
+        let source = BufferedSource::new([1.0; 4], 1, 8000);
+        let reverb = Reverb::new(source, ReverbParams::default());
+
+        let count = reverb.sample_count().unwrap();
+        let samples: Vec<_> = reverb.samples_iter().collect();
+        assert_eq!(samples.len() as u64, count);
+
+        // The reverberation should have decayed by the end of the tail
+        let last = samples[samples.len() - 1000..]
+            .iter()
+            .map(|v| v.abs().max_element())
+            .fold(0.0, f32::max);
+        assert!(last < 1e-3, "{last}");
+    }
+}
@@ -36,6 +36,12 @@ pub fn write(
                 .collect(),
             vec_items: semantic.items.vec_items().to_json(),
             option_items: semantic.items.option_items().to_json(),
+            map_items: semantic
+                .items
+                .map_items()
+                .values()
+                .map(|id| id.to_json())
+                .collect(),
         })?,
     )?;
 
@@ -211,6 +217,7 @@ impl SemanticToJson for sema::Message {
             data: self.data().to_json(),
             description: self.description.to_json(),
             fields: self.fields.to_json(),
+            response: self.response.to_json(),
         }
     }
 }
@@ -231,7 +238,9 @@ impl SemanticToJson for sema::TypeInner {
             sema::TypeInner::Primitive(v) => json::TypeInner::Primitive(v.to_json()),
             sema::TypeInner::Vec(v) => json::TypeInner::Vec(v.to_json()),
             sema::TypeInner::Option(v) => json::TypeInner::Option(v.to_json()),
+            sema::TypeInner::Map(k, v) => json::TypeInner::Map(k.to_json(), v.to_json()),
             sema::TypeInner::Enum(v) => json::TypeInner::Enum(v.to_json()),
+            sema::TypeInner::Struct(v) => json::TypeInner::Struct(v.to_json()),
         }
     }
 }
@@ -259,6 +268,15 @@ impl SemanticToJson for sema::Enum {
         }
     }
 }
+impl SemanticToJson for sema::Struct {
+    type Json = json::Struct;
+    fn to_json(&self) -> Self::Json {
+        json::Struct {
+            description: self.description.to_json(),
+            fields: self.fields.to_json(),
+        }
+    }
+}
 
 impl SemanticToJson for sema::Attribute {
     type Json = json::Attribute;
@@ -327,10 +345,19 @@ impl SemanticToJson for sema::Value {
             sema::Value::Scalar(v) => json::Value::Scalar(v.to_json()),
             sema::Value::Vec(v) => json::Value::Vec(v.to_json()),
             sema::Value::Option(v) => json::Value::Option(v.to_json()),
+            sema::Value::Map(v) => json::Value::Map(
+                v.iter()
+                    .map(|(key, value)| (key.to_json(), value.to_json()))
+                    .collect(),
+            ),
             sema::Value::Enum(ty, id) => json::Value::Enum(json::EnumValue {
                 ty: ty.to_json(),
                 member: id.to_json(),
             }),
+            sema::Value::Struct(ty, fields) => json::Value::Struct(json::StructValue {
+                ty: ty.to_json(),
+                fields: fields.to_json(),
+            }),
         }
     }
 }
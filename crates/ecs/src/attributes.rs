@@ -8,7 +8,10 @@ use std::{
 use as_any::{AsAny, Downcast};
 use serde::{Deserialize, Serialize};
 
-use crate::{ComponentDesc, ComponentEntry, ComponentValue, EnumComponent};
+use crate::{
+    ComponentDesc, ComponentEntry, ComponentValue, EntityId, EnumComponent, PrimitiveComponentType,
+    StructComponent,
+};
 
 /// Represents a single attribute attached to a component
 pub trait ComponentAttribute: 'static + Send + Sync + AsAny {}
@@ -238,6 +241,46 @@ where
     }
 }
 
+/// How important updates to a networked component are, relative to other components, when the
+/// bandwidth to a player is limited. Components without it have a priority of 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkPriority(pub f32);
+impl ComponentAttribute for NetworkPriority {}
+impl<T: ComponentValue> AttributeConstructor<T, f32> for NetworkPriority {
+    fn construct(store: &mut AttributeStore, value: f32) {
+        store.set(Self(value))
+    }
+}
+
+/// What happens to the entities that refer to an entity through a [`Relationship`] when it is
+/// despawned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OnTargetDespawn {
+    /// Despawn the entities that refer to it, and the entities that refer to those in turn
+    DespawnDependents,
+    /// Remove it from the component of the entities that refer to it
+    ClearReference,
+    /// Refuse to despawn it while other entities refer to it
+    Block,
+}
+
+/// The component is a typed edge to the entity or entities it refers to. Reverse lookups of the
+/// edges are kept in [`relationships`](crate::relationships), and the edges are cleaned up when the
+/// entities they refer to are despawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Relationship(pub OnTargetDespawn);
+impl ComponentAttribute for Relationship {}
+impl AttributeConstructor<EntityId, OnTargetDespawn> for Relationship {
+    fn construct(store: &mut AttributeStore, value: OnTargetDespawn) {
+        store.set(Self(value))
+    }
+}
+impl AttributeConstructor<Vec<EntityId>, OnTargetDespawn> for Relationship {
+    fn construct(store: &mut AttributeStore, value: OnTargetDespawn) {
+        store.set(Self(value))
+    }
+}
+
 pub(crate) struct ComponentPath(pub String);
 impl ComponentAttribute for ComponentPath {}
 
@@ -310,3 +353,53 @@ where
         })
     }
 }
+
+/// This component is a struct that can be converted to/from bytes.
+pub struct Struct {
+    pub to_bytes: fn(&dyn Any) -> Vec<u8>,
+    pub from_bytes: fn(ComponentDesc, &[u8]) -> Option<ComponentEntry>,
+    /// The types of the fields of a struct defined outside of the host, in order. Enum fields
+    /// are `U32`.
+    ///
+    /// These structs are stored as bytes, so the fields are needed to check them.
+    pub fields: Option<Vec<PrimitiveComponentType>>,
+}
+impl Struct {
+    /// Converts `bytes` to an entry of `desc`, if they contain exactly the fields of the struct
+    pub fn entry_from_bytes(&self, desc: ComponentDesc, bytes: &[u8]) -> Option<ComponentEntry> {
+        if let Some(fields) = &self.fields {
+            let mut input = bytes;
+            for field in fields {
+                field.read_message_part(&mut input).ok()?;
+            }
+            if !input.is_empty() {
+                return None;
+            }
+        }
+        (self.from_bytes)(desc, bytes)
+    }
+}
+impl ComponentAttribute for Struct {}
+impl<T> AttributeConstructor<T, ()> for Struct
+where
+    T: 'static + StructComponent,
+{
+    fn construct(store: &mut AttributeStore, _: ()) {
+        store.set(Self {
+            to_bytes: |entry| (*entry).downcast_ref::<T>().unwrap().to_bytes(),
+            from_bytes: |desc, value| {
+                Some(ComponentEntry::from_raw_parts(desc, T::from_bytes(value)?))
+            },
+            fields: None,
+        })
+    }
+}
+impl AttributeConstructor<Vec<u8>, &[PrimitiveComponentType]> for Struct {
+    fn construct(store: &mut AttributeStore, fields: &[PrimitiveComponentType]) {
+        store.set(Self {
+            to_bytes: |entry| (*entry).downcast_ref::<Vec<u8>>().unwrap().clone(),
+            from_bytes: |desc, value| Some(ComponentEntry::from_raw_parts(desc, value.to_vec())),
+            fields: Some(fields.to_vec()),
+        })
+    }
+}
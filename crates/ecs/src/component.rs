@@ -47,6 +47,29 @@ impl EnumComponent for u32 {
     }
 }
 
+/// Implemented for component values that are a struct of named fields.
+///
+/// Structs are stored by components defined outside of the host as bytes, in the
+/// [`MessageSerde`](ambient_package_rt::message_serde::MessageSerde) format of their fields.
+pub trait StructComponent: Clone + Send + Sync {
+    fn to_bytes(&self) -> Vec<u8>;
+    fn from_bytes(bytes: &[u8]) -> Option<Self>
+    where
+        Self: Sized;
+}
+impl StructComponent for Vec<u8> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.clone()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Some(bytes.to_vec())
+    }
+}
+
 /// Component key
 pub struct Component<T: 'static> {
     desc: ComponentDesc,
@@ -44,6 +44,8 @@ pub struct ExternalComponentDesc {
     pub name: Option<String>,
     pub description: Option<String>,
     pub attributes: ExternalComponentAttributes,
+    /// The types of the fields of a `Struct` component, in order
+    pub struct_fields: Option<Vec<PrimitiveComponentType>>,
 }
 
 impl From<&PrimitiveComponent> for ExternalComponentDesc {
@@ -54,6 +56,10 @@ impl From<&PrimitiveComponent> for ExternalComponentDesc {
             name: pc.desc.attribute::<Name>().map(|n| n.0.clone()),
             description: pc.desc.attribute::<Description>().map(|n| n.0.clone()),
             attributes: ExternalComponentAttributes::from_existing_component(pc.desc),
+            struct_fields: pc
+                .desc
+                .attribute::<Struct>()
+                .and_then(|struct_| struct_.fields.clone()),
         }
     }
 }
@@ -95,6 +101,9 @@ macro_rules! define_external_component_attribute {
                 if self.enum_ {
                     <Enum as AttributeConstructor<u32, _>>::construct(store, ());
                 }
+                if self.struct_ {
+                    <Struct as AttributeConstructor<Vec<u8>, _>>::construct(store, ());
+                }
             }
         }
         impl<'a> FromIterator<&'a str> for ExternalComponentAttributes {
@@ -121,7 +130,8 @@ define_external_component_attribute! {
         maybe_resource: MaybeResource
     },
     special: {
-        enum_: Enum
+        enum_: Enum,
+        struct_: Struct
     }
 }
 
@@ -151,6 +161,7 @@ impl ComponentRegistry {
                 desc.name.as_deref(),
                 desc.description.as_deref(),
                 desc.attributes,
+                desc.struct_fields.as_deref(),
             );
         }
 
@@ -22,7 +22,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -38,7 +38,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -54,7 +54,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -62,7 +62,7 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("audio" , { # [doc = "**Is audio player**: The entity is an audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is audio player"] , Description ["The entity is an audio player."]] is_audio_player : () , # [doc = "**Is spatial audio player**: The entity is a spatial audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is spatial audio player"] , Description ["The entity is a spatial audio player."]] is_spatial_audio_player : () , # [doc = "**Spatial audio emitter**: The entity is a spatial audio emitter.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio emitter"] , Description ["The entity is a spatial audio emitter."]] spatial_audio_emitter : EntityId , # [doc = "**Spatial audio listener**: The entity is a spatial audio listener.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio listener"] , Description ["The entity is a spatial audio listener."]] spatial_audio_listener : EntityId , # [doc = "**Looping**: Whether or not the audio should loop.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Looping"] , Description ["Whether or not the audio should loop.\n"]] looping : bool , # [doc = "**One pole low pass filter**: With this component, the audio will be filtered with a one pole low pass filter.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["One pole low pass filter"] , Description ["With this component, the audio will be filtered with a one pole low pass filter.\n"]] onepole_lpf : f32 , # [doc = "**Playing sound**: The entity with this comp is a playing sound.\n\nWe can attach other components to it to control the sound parameters.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Playing sound"] , Description ["The entity with this comp is a playing sound.\nWe can attach other components to it to control the sound parameters.\n"]] playing_sound : () , # [doc = "**Amplitude**: The amplitude of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Amplitude"] , Description ["The amplitude of the audio.\n"]] amplitude : f32 , # [doc = "**Panning**: The panning of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Panning"] , Description ["The panning of the audio.\n"]] panning : f32 , # [doc = "**Low_pass filter**: Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Low_pass filter"] , Description ["Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] lpf : Vec2 , # [doc = "**High_pass filter**: High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["High_pass filter"] , Description ["High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] hpf : Vec2 , # [doc = "**Audio URL**: The URL of the assets.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio URL"] , Description ["The URL of the assets.\n"]] audio_url : String , # [doc = "**Trigger at this frame**: The system will watch for this component and PLAY the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Trigger at this frame"] , Description ["The system will watch for this component and PLAY the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] play_now : () , # [doc = "**Stop at this frame**: The system will watch for this component and STOP the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Stop at this frame"] , Description ["The system will watch for this component and STOP the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] stop_now : () , });
+                components ! ("audio" , { # [doc = "**Is audio player**: The entity is an audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is audio player"] , Description ["The entity is an audio player."]] is_audio_player : () , # [doc = "**Is spatial audio player**: The entity is a spatial audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is spatial audio player"] , Description ["The entity is a spatial audio player."]] is_spatial_audio_player : () , # [doc = "**Spatial audio emitter**: The entity is a spatial audio emitter.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio emitter"] , Description ["The entity is a spatial audio emitter."]] spatial_audio_emitter : EntityId , # [doc = "**Spatial audio listener**: The entity is a spatial audio listener.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio listener"] , Description ["The entity is a spatial audio listener."]] spatial_audio_listener : EntityId , # [doc = "**Looping**: Whether or not the audio should loop.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Looping"] , Description ["Whether or not the audio should loop.\n"]] looping : bool , # [doc = "**One pole low pass filter**: With this component, the audio will be filtered with a one pole low pass filter.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["One pole low pass filter"] , Description ["With this component, the audio will be filtered with a one pole low pass filter.\n"]] onepole_lpf : f32 , # [doc = "**Playing sound**: The entity with this comp is a playing sound.\n\nWe can attach other components to it to control the sound parameters.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Playing sound"] , Description ["The entity with this comp is a playing sound.\nWe can attach other components to it to control the sound parameters.\n"]] playing_sound : () , # [doc = "**Amplitude**: The amplitude of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Amplitude"] , Description ["The amplitude of the audio.\n"]] amplitude : f32 , # [doc = "**Panning**: The panning of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Panning"] , Description ["The panning of the audio.\n"]] panning : f32 , # [doc = "**Low_pass filter**: Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Low_pass filter"] , Description ["Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] lpf : Vec2 , # [doc = "**High_pass filter**: High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["High_pass filter"] , Description ["High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] hpf : Vec2 , # [doc = "**Delay**: Feedback delay. The first value is the delay time in seconds, the second is the feedback, and the third is the mix between the original sound and the echoes.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Delay"] , Description ["Feedback delay. The first value is the delay time in seconds, the second is the feedback, and the third is the mix between the original sound and the echoes.\n"]] delay : Vec3 , # [doc = "**Reverb**: Algorithmic room reverb. The first value is the room size, the second is the damping, and the third is the mix between the original sound and the reverb. All values are between 0 and 1.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Reverb"] , Description ["Algorithmic room reverb. The first value is the room size, the second is the damping, and the third is the mix between the original sound and the reverb. All values are between 0 and 1.\n"]] reverb : Vec3 , # [doc = "**Convolution reverb**: The URL of an impulse response to reverberate the audio with, such as a recording of a room.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Convolution reverb"] , Description ["The URL of an impulse response to reverberate the audio with, such as a recording of a room.\n"]] convolution_reverb : String , # [doc = "**Convolution reverb mix**: The mix between the original sound and the convolution reverb. Defaults to 0.5.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Convolution reverb mix"] , Description ["The mix between the original sound and the convolution reverb. Defaults to 0.5.\n"]] convolution_reverb_mix : f32 , # [doc = "**Audio URL**: The URL of the assets.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio URL"] , Description ["The URL of the assets.\n"]] audio_url : String , # [doc = "**Trigger at this frame**: The system will watch for this component and PLAY the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Trigger at this frame"] , Description ["The system will watch for this component and PLAY the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] play_now : () , # [doc = "**Stop at this frame**: The system will watch for this component and STOP the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Stop at this frame"] , Description ["The system will watch for this component and STOP the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] stop_now : () , # [doc = "**Spatial sound**: The URL of a sound which every client plays from this entity, as heard by their active camera.\n\nThe server is the authority for these sounds: the sound plays on each client while this component is attached, and stops when it is removed or the entity is despawned.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Spatial sound"] , Description ["The URL of a sound which every client plays from this entity, as heard by their active camera.\nThe server is the authority for these sounds: the sound plays on each client while this component is attached, and stops when it is removed or the entity is despawned."]] spatial_sound : String , # [doc = "**Spatial sound start time**: The `epoch_time` at which the `spatial_sound` started playing.\n\nClients start the sound from the time that has passed since then, so that clients which connect later hear the same part of it.\n\nIf not attached, the sound plays from the start when it reaches the client.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Spatial sound start time"] , Description ["The `epoch_time` at which the `spatial_sound` started playing.\nClients start the sound from the time that has passed since then, so that clients which connect later hear the same part of it.\nIf not attached, the sound plays from the start when it reaches the client."]] spatial_sound_start_time : Duration , # [doc = "**Spatial sound looping**: If attached, the `spatial_sound` restarts when it reaches the end.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Spatial sound looping"] , Description ["If attached, the `spatial_sound` restarts when it reaches the end."]] spatial_sound_looping : () , # [doc = "**Spatial sound amplitude**: The amplitude of the `spatial_sound`, which can be changed while it plays. Defaults to 1.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Spatial sound amplitude"] , Description ["The amplitude of the `spatial_sound`, which can be changed while it plays. Defaults to 1."]] spatial_sound_amplitude : f32 , });
             }
         }
         pub mod camera {
@@ -70,7 +70,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -86,7 +86,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -94,7 +94,7 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("ecs" , { # [doc = "**Don't automatically despawn on module unload**: Indicates that this entity shouldn't be despawned when the module that spawned it unloads.\n\n*Attributes*: Debuggable, Store"] @ [Debuggable , Store , Name ["Don't automatically despawn on module unload"] , Description ["Indicates that this entity shouldn't be despawned when the module that spawned it unloads."]] dont_despawn_on_unload : () , # [doc = "**Don't store**: Indicates that this entity shouldn't be stored on disk.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Don't store"] , Description ["Indicates that this entity shouldn't be stored on disk."]] dont_store : () , # [doc = "**ID**: The ID of the entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["ID"] , Description ["The ID of the entity."]] id : EntityId , # [doc = "**Remove at game time**: If attached, this entity will be despawned at the specified game time.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Remove at game time"] , Description ["If attached, this entity will be despawned at the specified game time."]] remove_at_game_time : Duration , });
+                components ! ("ecs" , { # [doc = "**Don't automatically despawn on module unload**: Indicates that this entity shouldn't be despawned when the module that spawned it unloads.\n\n*Attributes*: Debuggable, Store"] @ [Debuggable , Store , Name ["Don't automatically despawn on module unload"] , Description ["Indicates that this entity shouldn't be despawned when the module that spawned it unloads."]] dont_despawn_on_unload : () , # [doc = "**Don't store**: Indicates that this entity shouldn't be stored on disk.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Don't store"] , Description ["Indicates that this entity shouldn't be stored on disk."]] dont_store : () , # [doc = "**ID**: The ID of the entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["ID"] , Description ["The ID of the entity."]] id : EntityId , # [doc = "**Persistent**: If attached, this entity will be saved to disk by the server and restored when the server restarts. Only components with the `Store` attribute are saved.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Persistent"] , Description ["If attached, this entity will be saved to disk by the server and restored when the server restarts. Only components with the `Store` attribute are saved."]] persistent : () , # [doc = "**Remove at game time**: If attached, this entity will be despawned at the specified game time.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Remove at game time"] , Description ["If attached, this entity will be despawned at the specified game time."]] remove_at_game_time : Duration , });
             }
         }
         pub mod hierarchy {
@@ -102,7 +102,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -118,7 +118,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -133,7 +133,8 @@ mod raw {
             pub mod messages {
                 use crate::{Entity, EntityId};
                 use ambient_package_rt::message_serde::{
-                    Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                    Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                    RuntimeMessage,
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 #[derive(Clone, Debug)]
@@ -213,7 +214,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -526,7 +527,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -542,7 +543,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -550,7 +551,7 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("network" , { # [doc = "**Is remote entity**: If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server).\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is remote entity"] , Description ["If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server)."]] is_remote_entity : () , # [doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is persistent resources"] , Description ["If attached, this entity contains global resources that are persisted to disk and synchronized to clients."]] is_persistent_resources : () , # [doc = "**Is synchronized resources**: If attached, this entity contains global resources that are synchronized to clients, but not persisted.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is synchronized resources"] , Description ["If attached, this entity contains global resources that are synchronized to clients, but not persisted."]] is_synced_resources : () , # [doc = "**No sync**: If attached, this entity will not be synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["No sync"] , Description ["If attached, this entity will not be synchronized to clients."]] no_sync : () , });
+                components ! ("network" , { # [doc = "**Is remote entity**: If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server).\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is remote entity"] , Description ["If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server)."]] is_remote_entity : () , # [doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Is persistent resources"] , Description ["If attached, this entity contains global resources that are persisted to disk and synchronized to clients."]] is_persistent_resources : () , # [doc = "**Is synchronized resources**: If attached, this entity contains global resources that are synchronized to clients, but not persisted.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is synchronized resources"] , Description ["If attached, this entity contains global resources that are synchronized to clients, but not persisted."]] is_synced_resources : () , # [doc = "**No sync**: If attached, this entity will not be synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["No sync"] , Description ["If attached, this entity will not be synchronized to clients."]] no_sync : () , # [doc = "**Relevancy radius**: If attached to a player entity, only entities within this distance of the player's relevancy origin are synchronized to the player.\n\nEntities without a position, and entities with `always_relevant`, are always synchronized.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Relevancy radius"] , Description ["If attached to a player entity, only entities within this distance of the player's relevancy origin are synchronized to the player.\nEntities without a position, and entities with `always_relevant`, are always synchronized."]] relevancy_radius : f32 , # [doc = "**Relevancy origin**: The entity whose position is used as the centre of the `relevancy_radius` of a player, such as the player's body.\n\nIf not attached, the position of the player entity is used.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Relevancy origin"] , Description ["The entity whose position is used as the centre of the `relevancy_radius` of a player, such as the player's body.\nIf not attached, the position of the player entity is used."]] relevancy_origin : EntityId , # [doc = "**Always relevant**: If attached, this entity is synchronized to players regardless of their `relevancy_radius`.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Always relevant"] , Description ["If attached, this entity is synchronized to players regardless of their `relevancy_radius`."]] always_relevant : () , # [doc = "**Visible to**: If attached, this entity is only synchronized to the listed player entities.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Visible to"] , Description ["If attached, this entity is only synchronized to the listed player entities."]] visible_to : Vec :: < EntityId > , # [doc = "**Relevancy team**: If attached to an entity, the entity is only synchronized to players with the same team.\n\nPlayers are assigned to a team by attaching this component to the player entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Relevancy team"] , Description ["If attached to an entity, the entity is only synchronized to players with the same team.\nPlayers are assigned to a team by attaching this component to the player entity."]] relevancy_team : String , # [doc = "**Relevancy filtering**: If attached as a resource on the server, each player is only sent the entities that are relevant to them, as decided by `relevancy_radius`, `visible_to` and `relevancy_team`.\n\nIf not attached, every entity is sent to every player.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Relevancy filtering"] , Description ["If attached as a resource on the server, each player is only sent the entities that are relevant to them, as decided by `relevancy_radius`, `visible_to` and `relevancy_team`.\nIf not attached, every entity is sent to every player."]] relevancy_filtering : () , # [doc = "**Is predicted**: If attached on the client to an entity synchronized from the server, the client predicts the entity ahead of the server.\n\nWhenever the server sends an update for the entity, all of its synchronized components are reset to their last values from the server, and `prediction_rewound` is attached so that unacknowledged inputs can be replayed.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Is predicted"] , Description ["If attached on the client to an entity synchronized from the server, the client predicts the entity ahead of the server.\nWhenever the server sends an update for the entity, all of its synchronized components are reset to their last values from the server, and `prediction_rewound` is attached so that unacknowledged inputs can be replayed."]] is_predicted : () , # [doc = "**Prediction rewound**: Attached by the client to a predicted entity when it has been reset to its state from the server.\n\nRemove it after replaying the unacknowledged inputs.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Prediction rewound"] , Description ["Attached by the client to a predicted entity when it has been reset to its state from the server.\nRemove it after replaying the unacknowledged inputs."]] prediction_rewound : () , # [doc = "**Acknowledged input sequence**: The sequence number of the last input from the owning player that the server has applied to this entity.\n\nSet this on the server in the same frame that the input is applied, so that the client knows which inputs to replay.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Acknowledged input sequence"] , Description ["The sequence number of the last input from the owning player that the server has applied to this entity.\nSet this on the server in the same frame that the input is applied, so that the client knows which inputs to replay."]] acked_input_sequence : u64 , # [doc = "**Is interpolated**: If attached to an entity on the server, the client renders the entity a short delay behind the server, and interpolates its `translation`, `rotation` and `scale` between the states it has received.\n\nPredicted entities are not interpolated.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is interpolated"] , Description ["If attached to an entity on the server, the client renders the entity a short delay behind the server, and interpolates its `translation`, `rotation` and `scale` between the states it has received.\nPredicted entities are not interpolated."]] is_interpolated : () , # [doc = "**Interpolation delay**: How far behind the server the client renders entities with `is_interpolated`, so that it can interpolate between the states it has received.\n\nLarger delays hide more network jitter. Defaults to 100 milliseconds.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Interpolation delay"] , Description ["How far behind the server the client renders entities with `is_interpolated`, so that it can interpolate between the states it has received.\nLarger delays hide more network jitter. Defaults to 100 milliseconds."]] interpolation_delay : Duration , # [doc = "**Interpolated components**: The paths of components to interpolate on entities with `is_interpolated`, in addition to `translation`, `rotation` and `scale`.\n\nThe components must be of type `F32`, `Vec2`, `Vec3`, `Vec4` or `Quat`.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Interpolated components"] , Description ["The paths of components to interpolate on entities with `is_interpolated`, in addition to `translation`, `rotation` and `scale`.\nThe components must be of type `F32`, `Vec2`, `Vec3`, `Vec4` or `Quat`."]] interpolated_components : Vec :: < String > , # [doc = "**Bandwidth budget**: If attached to a player entity, the number of bytes per second of world diffs that the server sends to the player.\n\nWhen a diff does not fit, the most important component updates are sent first and the rest are delayed. Spawns, despawns and added or removed components are always sent.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Bandwidth budget"] , Description ["If attached to a player entity, the number of bytes per second of world diffs that the server sends to the player.\nWhen a diff does not fit, the most important component updates are sent first and the rest are delayed. Spawns, despawns and added or removed components are always sent."]] bandwidth_budget : u32 , # [doc = "**Component priorities**: The priorities of component updates by component path, used to decide which updates are sent first when a player has a `bandwidth_budget`.\n\nComponents default to a priority of 1. Updates to entities closer to the player's relevancy origin, and updates that have been delayed for longer, are also sent first.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Component priorities"] , Description ["The priorities of component updates by component path, used to decide which updates are sent first when a player has a `bandwidth_budget`.\nComponents default to a priority of 1. Updates to entities closer to the player's relevancy origin, and updates that have been delayed for longer, are also sent first."]] component_priorities : std :: collections :: BTreeMap :: < String , f32 > , # [doc = "**Diff compression**: If attached as a resource on the server, world diffs are compressed with zstd before they are sent to the players.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Diff compression"] , Description ["If attached as a resource on the server, world diffs are compressed with zstd before they are sent to the players."]] diff_compression : () , # [doc = "**Diff quantization**: If attached as a resource on the server, `Vec3`, `Quat` and `Mat4` components are sent to the players with reduced precision.\n\n`Vec3` values are rounded to 1/256 of a unit, rotations are sent to within about 0.0001, and transform matrices are sent as their quantized scale, rotation and translation.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Diff quantization"] , Description ["If attached as a resource on the server, `Vec3`, `Quat` and `Mat4` components are sent to the players with reduced precision.\n`Vec3` values are rounded to 1/256 of a unit, rotations are sent to within about 0.0001, and transform matrices are sent as their quantized scale, rotation and translation."]] diff_quantization : () , # [doc = "**Reconnect grace period**: How long the server keeps the player entity of a player whose connection was lost, so that they can resume their session.\n\nWhile the player is away, their player entity has `disconnected`. Defaults to 30 seconds. If zero, the player entity is despawned as soon as the connection is lost.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Reconnect grace period"] , Description ["How long the server keeps the player entity of a player whose connection was lost, so that they can resume their session.\nWhile the player is away, their player entity has `disconnected`. Defaults to 30 seconds. If zero, the player entity is despawned as soon as the connection is lost."]] reconnect_grace_period : Duration , });
             }
         }
         pub mod package {
@@ -558,7 +559,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -573,7 +574,8 @@ mod raw {
             pub mod messages {
                 use crate::{Entity, EntityId};
                 use ambient_package_rt::message_serde::{
-                    Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                    Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                    RuntimeMessage,
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 #[derive(Clone, Debug)]
@@ -716,7 +718,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -724,7 +726,311 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("physics" , { # [doc = "**Angular velocity**: Angular velocity (radians/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's angular velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Angular velocity"] , Description ["Angular velocity (radians/second) of this entity in the physics scene.\nUpdating this component will update the entity's angular velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead."]] angular_velocity : Vec3 , # [doc = "**Cube collider**: If attached, this entity will have a cube physics collider.\n\n`x, y, z` is the size of the cube.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cube collider"] , Description ["If attached, this entity will have a cube physics collider.\n`x, y, z` is the size of the cube."]] cube_collider : Vec3 , # [doc = "**Character controller height**: The height of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller height"] , Description ["The height of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider."]] character_controller_height : f32 , # [doc = "**Character controller radius**: The radius of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller radius"] , Description ["The radius of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider."]] character_controller_radius : f32 , # [doc = "**Collider from URL**: This entity will load its physics collider from the URL.\n\nThe value is the URL to load from.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider from URL"] , Description ["This entity will load its physics collider from the URL.\nThe value is the URL to load from."]] collider_from_url : String , # [doc = "**Collider loaded**: This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`).\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider loaded"] , Description ["This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`)."]] collider_loaded : () , # [doc = "**Collider loads**: Contains all colliders that were loaded in this physics tick.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Collider loads"] , Description ["Contains all colliders that were loaded in this physics tick."]] collider_loads : Vec :: < EntityId > , # [doc = "**Contact offset**: Contact offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Contact offset"] , Description ["Contact offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene."]] contact_offset : f32 , # [doc = "**Density**: The density of this entity.\n\nThis is used to update the `mass` when the entity is rescaled.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Density"] , Description ["The density of this entity.\nThis is used to update the `mass` when the entity is rescaled."]] density : f32 , # [doc = "**Dynamic**: If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Dynamic"] , Description ["If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static."]] dynamic : bool , # [doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Kinematic"] , Description ["If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally."]] kinematic : () , # [doc = "**Linear velocity**: Linear velocity (meters/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's linear velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Linear velocity"] , Description ["Linear velocity (meters/second) of this entity in the physics scene.\nUpdating this component will update the entity's linear velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead."]] linear_velocity : Vec3 , # [doc = "**Make physics static**: All physics objects will be made static when loaded.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Make physics static"] , Description ["All physics objects will be made static when loaded."]] make_physics_static : bool , # [doc = "**Mass**: The mass of this entity, measured in kilograms.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Mass"] , Description ["The mass of this entity, measured in kilograms."]] mass : f32 , # [doc = "**Physics controlled**: If attached, this entity will be controlled by physics.\n\nNote that this requires the entity to have a collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Physics controlled"] , Description ["If attached, this entity will be controlled by physics.\nNote that this requires the entity to have a collider."]] physics_controlled : () , # [doc = "**Plane collider**: If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Plane collider"] , Description ["If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead."]] plane_collider : () , # [doc = "**Rest offset**: Rest offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rest offset"] , Description ["Rest offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene."]] rest_offset : f32 , # [doc = "**Sphere collider**: If attached, this entity will have a sphere physics collider.\n\nThe value corresponds to the radius of the sphere.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sphere collider"] , Description ["If attached, this entity will have a sphere physics collider.\nThe value corresponds to the radius of the sphere."]] sphere_collider : f32 , # [doc = "**Unit mass**: The mass of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit mass"] , Description ["The mass of a character/unit."]] unit_mass : f32 , # [doc = "**Unit velocity**: The velocity of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit velocity"] , Description ["The velocity of a character/unit."]] unit_velocity : Vec3 , # [doc = "**Unit yaw**: The yaw of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit yaw"] , Description ["The yaw of a character/unit."]] unit_yaw : f32 , # [doc = "**Visualize collider**: If attached, the collider will be rendered.\n\n\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n\n\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Visualize collider"] , Description ["If attached, the collider will be rendered.\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n"]] visualize_collider : () , });
+                components ! ("physics" , { # [doc = "**Angular velocity**: Angular velocity (radians/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's angular velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Angular velocity"] , Description ["Angular velocity (radians/second) of this entity in the physics scene.\nUpdating this component will update the entity's angular velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead."]] angular_velocity : Vec3 , # [doc = "**Capsule collider**: If attached, this entity will have a capsule physics collider, standing upright along the Z axis.\n\n`x` is the radius of the capsule, and `y` is its total height, including the hemispheres at both ends.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Capsule collider"] , Description ["If attached, this entity will have a capsule physics collider, standing upright along the Z axis.\n`x` is the radius of the capsule, and `y` is its total height, including the hemispheres at both ends."]] capsule_collider : Vec2 , # [doc = "**Contact events**: If attached, the `ContactBegin`, `ContactPersist` and `ContactEnd` messages will be sent for contacts involving this entity's collider.\n\nThese are opt-in, as `ContactPersist` is sent every physics step for as long as two colliders touch.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Contact events"] , Description ["If attached, the `ContactBegin`, `ContactPersist` and `ContactEnd` messages will be sent for contacts involving this entity's collider.\nThese are opt-in, as `ContactPersist` is sent every physics step for as long as two colliders touch."]] contact_events : () , # [doc = "**Convex collider**: If attached, this entity will have a convex physics collider, built from the convex hull of the given points.\n\nThe points don't need to be in any order.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Convex collider"] , Description ["If attached, this entity will have a convex physics collider, built from the convex hull of the given points.\nThe points don't need to be in any order."]] convex_collider : Vec :: < Vec3 > , # [doc = "**Cube collider**: If attached, this entity will have a cube physics collider.\n\n`x, y, z` is the size of the cube.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cube collider"] , Description ["If attached, this entity will have a cube physics collider.\n`x, y, z` is the size of the cube."]] cube_collider : Vec3 , # [doc = "**Cylinder collider**: If attached, this entity will have a cylinder physics collider, standing upright along the Z axis.\n\n`x` is the radius of the cylinder, and `y` is its height.\n\nThe cylinder is approximated with a convex mesh.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cylinder collider"] , Description ["If attached, this entity will have a cylinder physics collider, standing upright along the Z axis.\n`x` is the radius of the cylinder, and `y` is its height.\nThe cylinder is approximated with a convex mesh."]] cylinder_collider : Vec2 , # [doc = "**Character controller height**: The height of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller height"] , Description ["The height of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider."]] character_controller_height : f32 , # [doc = "**Character controller radius**: The radius of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller radius"] , Description ["The radius of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider."]] character_controller_radius : f32 , # [doc = "**Collider from URL**: This entity will load its physics collider from the URL.\n\nThe value is the URL to load from.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider from URL"] , Description ["This entity will load its physics collider from the URL.\nThe value is the URL to load from."]] collider_from_url : String , # [doc = "**Collider loaded**: This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`).\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider loaded"] , Description ["This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`)."]] collider_loaded : () , # [doc = "**Collider loads**: Contains all colliders that were loaded in this physics tick.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Collider loads"] , Description ["Contains all colliders that were loaded in this physics tick."]] collider_loads : Vec :: < EntityId > , # [doc = "**Contact offset**: Contact offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Contact offset"] , Description ["Contact offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene."]] contact_offset : f32 , # [doc = "**Density**: The density of this entity.\n\nThis is used to update the `mass` when the entity is rescaled.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Density"] , Description ["The density of this entity.\nThis is used to update the `mass` when the entity is rescaled."]] density : f32 , # [doc = "**Dynamic**: If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Dynamic"] , Description ["If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static."]] dynamic : bool , # [doc = "**Heightfield collider**: If attached, this entity will have a heightfield physics collider, such as for terrain.\n\nThe value is a grid of heights, row by row; the number of columns is set by `heightfield_collider_columns`.\n\nRows run along the Y axis and columns along the X axis, starting at the entity's origin.\n\nHeightfields can only be used for static colliders.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Heightfield collider"] , Description ["If attached, this entity will have a heightfield physics collider, such as for terrain.\nThe value is a grid of heights, row by row; the number of columns is set by `heightfield_collider_columns`.\nRows run along the Y axis and columns along the X axis, starting at the entity's origin.\nHeightfields can only be used for static colliders."]] heightfield_collider : Vec :: < f32 > , # [doc = "**Heightfield collider cell size**: The distance between two neighboring samples of the `heightfield_collider`.\n\nDefaults to 1.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Heightfield collider cell size"] , Description ["The distance between two neighboring samples of the `heightfield_collider`.\nDefaults to 1."]] heightfield_collider_cell_size : f32 , # [doc = "**Heightfield collider columns**: The number of columns in the `heightfield_collider`.\n\nThere must be at least two rows and two columns.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Heightfield collider columns"] , Description ["The number of columns in the `heightfield_collider`.\nThere must be at least two rows and two columns."]] heightfield_collider_columns : u32 , # [doc = "**Joint type**: If attached, this entity is a physics joint of the given type, connecting `joint_actor_0` to `joint_actor_1`.\n\nThe joint is created once both actors have colliders, and released when this entity is despawned or either actor is.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint type"] , Description ["If attached, this entity is a physics joint of the given type, connecting `joint_actor_0` to `joint_actor_1`.\nThe joint is created once both actors have colliders, and released when this entity is despawned or either actor is."]] joint_type : crate :: generated :: raw :: ambient_core :: physics :: types :: JointType , # [doc = "**Joint actor 0**: The first entity connected by this joint. It must have a collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint actor 0"] , Description ["The first entity connected by this joint. It must have a collider."]] joint_actor_0 : EntityId , # [doc = "**Joint actor 1**: The second entity connected by this joint. It must have a collider.\n\nIf not attached, the first entity is attached to a fixed point in the world instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint actor 1"] , Description ["The second entity connected by this joint. It must have a collider.\nIf not attached, the first entity is attached to a fixed point in the world instead."]] joint_actor_1 : EntityId , # [doc = "**Joint local frame 0**: The frame of the joint, relative to `joint_actor_0`.\n\nThe X axis of this frame is the axis of revolute and prismatic joints. Defaults to identity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint local frame 0"] , Description ["The frame of the joint, relative to `joint_actor_0`.\nThe X axis of this frame is the axis of revolute and prismatic joints. Defaults to identity."]] joint_local_frame_0 : Mat4 , # [doc = "**Joint local frame 1**: The frame of the joint, relative to `joint_actor_1` (or the world, if there is no second actor).\n\nDefaults to identity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint local frame 1"] , Description ["The frame of the joint, relative to `joint_actor_1` (or the world, if there is no second actor).\nDefaults to identity."]] joint_local_frame_1 : Mat4 , # [doc = "**Joint limit**: The lower (`x`) and upper (`y`) limit of the joint's motion.\n\nFor revolute joints, this is the rotation around the joint's X axis in radians; for prismatic joints, the translation along the X axis in meters; and for distance joints, the minimum and maximum distance between the actors in meters.\n\nThe lower limit must not be greater than the upper limit, and distances must not be negative, or the limit is ignored.\n\nIf not attached, the motion is not limited.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint limit"] , Description ["The lower (`x`) and upper (`y`) limit of the joint's motion.\nFor revolute joints, this is the rotation around the joint's X axis in radians; for prismatic joints, the translation along the X axis in meters; and for distance joints, the minimum and maximum distance between the actors in meters.\nThe lower limit must not be greater than the upper limit, and distances must not be negative, or the limit is ignored.\nIf not attached, the motion is not limited."]] joint_limit : Vec2 , # [doc = "**Joint cone limit**: Limits the motion of a spherical joint to an elliptical cone around its X axis.\n\n`x` is the maximum angle (radians) around the Y axis, and `y` is the maximum angle around the Z axis.\n\nBoth angles must be between 0 and π, or the limit is ignored.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint cone limit"] , Description ["Limits the motion of a spherical joint to an elliptical cone around its X axis.\n`x` is the maximum angle (radians) around the Y axis, and `y` is the maximum angle around the Z axis.\nBoth angles must be between 0 and π, or the limit is ignored."]] joint_cone_limit : Vec2 , # [doc = "**Joint drive velocity**: The joint will be driven like a motor towards this velocity.\n\nFor revolute and spherical joints, this is the angular velocity around the joint's X axis in radians/second; for prismatic joints, the velocity along the X axis in meters/second.\n\nRemove this component to let the joint move freely again.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive velocity"] , Description ["The joint will be driven like a motor towards this velocity.\nFor revolute and spherical joints, this is the angular velocity around the joint's X axis in radians/second; for prismatic joints, the velocity along the X axis in meters/second.\nRemove this component to let the joint move freely again."]] joint_drive_velocity : f32 , # [doc = "**Joint drive force limit**: The maximum torque (revolute and spherical joints) or force (prismatic joints) the joint's drive can apply. If not attached, it is unlimited.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive force limit"] , Description ["The maximum torque (revolute and spherical joints) or force (prismatic joints) the joint's drive can apply. If not attached, it is unlimited."]] joint_drive_force_limit : f32 , # [doc = "**Joint spring stiffness**: If attached to a distance joint, the distance limit is enforced by a spring with this stiffness instead of being rigid.\n\n`joint_spring_damping` controls the damping of the spring.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint spring stiffness"] , Description ["If attached to a distance joint, the distance limit is enforced by a spring with this stiffness instead of being rigid.\n`joint_spring_damping` controls the damping of the spring."]] joint_spring_stiffness : f32 , # [doc = "**Joint spring damping**: The damping of the spring of a distance joint. See `joint_spring_stiffness`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint spring damping"] , Description ["The damping of the spring of a distance joint. See `joint_spring_stiffness`."]] joint_spring_damping : f32 , # [doc = "**Joint break force**: The linear force (in newtons) at which this joint breaks.\n\nIf not attached, the joint can not be broken by linear forces.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint break force"] , Description ["The linear force (in newtons) at which this joint breaks.\nIf not attached, the joint can not be broken by linear forces."]] joint_break_force : f32 , # [doc = "**Joint break torque**: The torque (in newton meters) at which this joint breaks.\n\nIf not attached, the joint can not be broken by torque.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint break torque"] , Description ["The torque (in newton meters) at which this joint breaks.\nIf not attached, the joint can not be broken by torque."]] joint_break_torque : f32 , # [doc = "**Joint broken**: This component is automatically attached to a joint once it has broken.\n\nA broken joint no longer constrains its actors; despawn it and spawn a new joint to reconnect them.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint broken"] , Description ["This component is automatically attached to a joint once it has broken.\nA broken joint no longer constrains its actors; despawn it and spawn a new joint to reconnect them."]] joint_broken : () , # [doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Kinematic"] , Description ["If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally."]] kinematic : () , # [doc = "**Linear velocity**: Linear velocity (meters/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's linear velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Linear velocity"] , Description ["Linear velocity (meters/second) of this entity in the physics scene.\nUpdating this component will update the entity's linear velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead."]] linear_velocity : Vec3 , # [doc = "**Make physics static**: All physics objects will be made static when loaded.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Make physics static"] , Description ["All physics objects will be made static when loaded."]] make_physics_static : bool , # [doc = "**Mass**: The mass of this entity, measured in kilograms.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Mass"] , Description ["The mass of this entity, measured in kilograms."]] mass : f32 , # [doc = "**Physics controlled**: If attached, this entity will be controlled by physics.\n\nNote that this requires the entity to have a collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Physics controlled"] , Description ["If attached, this entity will be controlled by physics.\nNote that this requires the entity to have a collider."]] physics_controlled : () , # [doc = "**Plane collider**: If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Plane collider"] , Description ["If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead."]] plane_collider : () , # [doc = "**Rest offset**: Rest offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rest offset"] , Description ["Rest offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene."]] rest_offset : f32 , # [doc = "**Sphere collider**: If attached, this entity will have a sphere physics collider.\n\nThe value corresponds to the radius of the sphere.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sphere collider"] , Description ["If attached, this entity will have a sphere physics collider.\nThe value corresponds to the radius of the sphere."]] sphere_collider : f32 , # [doc = "**Unit mass**: The mass of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit mass"] , Description ["The mass of a character/unit."]] unit_mass : f32 , # [doc = "**Unit velocity**: The velocity of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit velocity"] , Description ["The velocity of a character/unit."]] unit_velocity : Vec3 , # [doc = "**Unit yaw**: The yaw of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit yaw"] , Description ["The yaw of a character/unit."]] unit_yaw : f32 , # [doc = "**Visualize collider**: If attached, the collider will be rendered.\n\n\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n\n\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Visualize collider"] , Description ["If attached, the collider will be rendered.\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n"]] visualize_collider : () , });
+            }
+            #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
+            #[doc = r" and with other modules."]
+            pub mod messages {
+                use crate::{Entity, EntityId};
+                use ambient_package_rt::message_serde::{
+                    Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                    RuntimeMessage,
+                };
+                use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactBegin**: Sent when two colliders start touching, if either of them has `contact_events`.\nThe points and normals are in world space, and `impulse` is the total impulse applied between the colliders by this contact."]
+                pub struct ContactBegin {
+                    pub ids: Vec<EntityId>,
+                    pub points: Vec<Vec3>,
+                    pub normals: Vec<Vec3>,
+                    pub impulse: Vec3,
+                }
+                impl ContactBegin {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        ids: impl Into<Vec<EntityId>>,
+                        points: impl Into<Vec<Vec3>>,
+                        normals: impl Into<Vec<Vec3>>,
+                        impulse: impl Into<Vec3>,
+                    ) -> Self {
+                        Self {
+                            ids: ids.into(),
+                            points: points.into(),
+                            normals: normals.into(),
+                            impulse: impulse.into(),
+                        }
+                    }
+                }
+                impl Message for ContactBegin {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactBegin"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.ids.serialize_message_part(&mut output)?;
+                        self.points.serialize_message_part(&mut output)?;
+                        self.normals.serialize_message_part(&mut output)?;
+                        self.impulse.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            ids: Vec::<EntityId>::deserialize_message_part(&mut input)?,
+                            points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            impulse: Vec3::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactBegin {}
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactPersist**: Sent every physics step for as long as two colliders keep touching, if either of them has `contact_events`.\nThe fields are the same as those of `ContactBegin`."]
+                pub struct ContactPersist {
+                    pub ids: Vec<EntityId>,
+                    pub points: Vec<Vec3>,
+                    pub normals: Vec<Vec3>,
+                    pub impulse: Vec3,
+                }
+                impl ContactPersist {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        ids: impl Into<Vec<EntityId>>,
+                        points: impl Into<Vec<Vec3>>,
+                        normals: impl Into<Vec<Vec3>>,
+                        impulse: impl Into<Vec3>,
+                    ) -> Self {
+                        Self {
+                            ids: ids.into(),
+                            points: points.into(),
+                            normals: normals.into(),
+                            impulse: impulse.into(),
+                        }
+                    }
+                }
+                impl Message for ContactPersist {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactPersist"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.ids.serialize_message_part(&mut output)?;
+                        self.points.serialize_message_part(&mut output)?;
+                        self.normals.serialize_message_part(&mut output)?;
+                        self.impulse.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            ids: Vec::<EntityId>::deserialize_message_part(&mut input)?,
+                            points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            impulse: Vec3::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactPersist {}
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactEnd**: Sent when two colliders stop touching, if either of them has `contact_events`."]
+                pub struct ContactEnd {
+                    pub ids: Vec<EntityId>,
+                }
+                impl ContactEnd {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(ids: impl Into<Vec<EntityId>>) -> Self {
+                        Self { ids: ids.into() }
+                    }
+                }
+                impl Message for ContactEnd {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactEnd"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.ids.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            ids: Vec::<EntityId>::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactEnd {}
+                #[derive(Clone, Debug)]
+                #[doc = "**TriggerEnter**: Sent when the collider of a dynamic entity or character starts overlapping a trigger area collider."]
+                pub struct TriggerEnter {
+                    pub trigger: EntityId,
+                    pub id: EntityId,
+                }
+                impl TriggerEnter {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(trigger: impl Into<EntityId>, id: impl Into<EntityId>) -> Self {
+                        Self {
+                            trigger: trigger.into(),
+                            id: id.into(),
+                        }
+                    }
+                }
+                impl Message for TriggerEnter {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::TriggerEnter"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.trigger.serialize_message_part(&mut output)?;
+                        self.id.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            trigger: EntityId::deserialize_message_part(&mut input)?,
+                            id: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for TriggerEnter {}
+                #[derive(Clone, Debug)]
+                #[doc = "**TriggerExit**: Sent when the collider of a dynamic entity or character stops overlapping a trigger area collider, including when either of them is despawned."]
+                pub struct TriggerExit {
+                    pub trigger: EntityId,
+                    pub id: EntityId,
+                }
+                impl TriggerExit {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(trigger: impl Into<EntityId>, id: impl Into<EntityId>) -> Self {
+                        Self {
+                            trigger: trigger.into(),
+                            id: id.into(),
+                        }
+                    }
+                }
+                impl Message for TriggerExit {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::TriggerExit"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.trigger.serialize_message_part(&mut output)?;
+                        self.id.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            trigger: EntityId::deserialize_message_part(&mut input)?,
+                            id: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for TriggerExit {}
+                #[derive(Clone, Debug)]
+                #[doc = "**JointBroken**: Sent when a joint breaks because the force or torque on it exceeded its `joint_break_force` or `joint_break_torque`."]
+                pub struct JointBroken {
+                    pub joint: EntityId,
+                    pub actors: Vec<EntityId>,
+                }
+                impl JointBroken {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        joint: impl Into<EntityId>,
+                        actors: impl Into<Vec<EntityId>>,
+                    ) -> Self {
+                        Self {
+                            joint: joint.into(),
+                            actors: actors.into(),
+                        }
+                    }
+                }
+                impl Message for JointBroken {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::JointBroken"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.joint.serialize_message_part(&mut output)?;
+                        self.actors.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            joint: EntityId::deserialize_message_part(&mut input)?,
+                            actors: Vec::<EntityId>::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for JointBroken {}
+            }
+            #[doc = r" Auto-generated type definitions."]
+            pub mod types {
+                use ambient_package_rt::message_serde::*;
+                use serde;
+                #[derive(
+                    Copy,
+                    Clone,
+                    Debug,
+                    PartialEq,
+                    Eq,
+                    serde :: Serialize,
+                    serde :: Deserialize,
+                    Default,
+                )]
+                #[serde(crate = "self::serde")]
+                #[doc = "**JointType**: The type of a physics joint."]
+                pub enum JointType {
+                    #[default]
+                    #[doc = "Locks the actors together, removing all relative motion."]
+                    Fixed,
+                    #[doc = "Allows the actors to rotate relative to each other around the joint's X axis, like a hinge."]
+                    Revolute,
+                    #[doc = "Allows the actors to rotate freely relative to each other around the joint's origin, like a ball-and-socket."]
+                    Spherical,
+                    #[doc = "Allows the actors to slide relative to each other along the joint's X axis."]
+                    Prismatic,
+                    #[doc = "Keeps the distance between the joint's origins within the `joint_limit`."]
+                    Distance,
+                }
+                impl crate::EnumComponent for JointType {
+                    fn to_u32(&self) -> u32 {
+                        match self {
+                            Self::Fixed => JointType::Fixed as u32,
+                            Self::Revolute => JointType::Revolute as u32,
+                            Self::Spherical => JointType::Spherical as u32,
+                            Self::Prismatic => JointType::Prismatic as u32,
+                            Self::Distance => JointType::Distance as u32,
+                        }
+                    }
+                    fn from_u32(value: u32) -> Option<Self> {
+                        if value == JointType::Fixed as u32 {
+                            return Some(Self::Fixed);
+                        }
+                        if value == JointType::Revolute as u32 {
+                            return Some(Self::Revolute);
+                        }
+                        if value == JointType::Spherical as u32 {
+                            return Some(Self::Spherical);
+                        }
+                        if value == JointType::Prismatic as u32 {
+                            return Some(Self::Prismatic);
+                        }
+                        if value == JointType::Distance as u32 {
+                            return Some(Self::Distance);
+                        }
+                        None
+                    }
+                }
+                impl MessageSerde for JointType {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        crate::EnumComponent::to_u32(self).serialize_message_part(output)
+                    }
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        crate::EnumComponent::from_u32(u32::deserialize_message_part(input)?)
+                            .ok_or(MessageSerdeError::InvalidValue)
+                    }
+                }
             }
         }
         pub mod player {
@@ -732,7 +1038,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -740,7 +1046,75 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("player" , { # [doc = "**Local user ID**: The user ID of the local player.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Local user ID"] , Description ["The user ID of the local player."]] local_user_id : String , # [doc = "**Is player**: This entity is a player.\n\nNote that this is a logical construct; a player's body may be separate from the player itself.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Is player"] , Description ["This entity is a player.\nNote that this is a logical construct; a player's body may be separate from the player itself."]] is_player : () , # [doc = "**User ID**: An identifier attached to all things owned by a user, and supplied by the user.\n\nThis can be attached to more than just the player; by convention, it is also attached to related entities, including their camera and body.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["User ID"] , Description ["An identifier attached to all things owned by a user, and supplied by the user.\nThis can be attached to more than just the player; by convention, it is also attached to related entities, including their camera and body."]] user_id : String , });
+                components ! ("player" , { # [doc = "**Local user ID**: The user ID of the local player.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Local user ID"] , Description ["The user ID of the local player."]] local_user_id : String , # [doc = "**Is player**: This entity is a player.\n\nNote that this is a logical construct; a player's body may be separate from the player itself.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Is player"] , Description ["This entity is a player.\nNote that this is a logical construct; a player's body may be separate from the player itself."]] is_player : () , # [doc = "**User ID**: An identifier attached to all things owned by a user, and supplied by the user.\n\nThis can be attached to more than just the player; by convention, it is also attached to related entities, including their camera and body.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["User ID"] , Description ["An identifier attached to all things owned by a user, and supplied by the user.\nThis can be attached to more than just the player; by convention, it is also attached to related entities, including their camera and body."]] user_id : String , # [doc = "**Disconnected**: This player has lost their connection, and their player entity is kept until they reconnect or the `reconnect_grace_period` runs out.\n\nPlayers who disconnect on purpose are despawned immediately.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Disconnected"] , Description ["This player has lost their connection, and their player entity is kept until they reconnect or the `reconnect_grace_period` runs out.\nPlayers who disconnect on purpose are despawned immediately."]] disconnected : () , });
+            }
+            #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
+            #[doc = r" and with other modules."]
+            pub mod messages {
+                use crate::{Entity, EntityId};
+                use ambient_package_rt::message_serde::{
+                    Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                    RuntimeMessage,
+                };
+                use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+                #[derive(Clone, Debug)]
+                #[doc = "**Disconnect**: Sent to server modules when a player loses their connection, and their player entity is marked as `disconnected`."]
+                pub struct Disconnect {
+                    pub player: EntityId,
+                }
+                impl Disconnect {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(player: impl Into<EntityId>) -> Self {
+                        Self {
+                            player: player.into(),
+                        }
+                    }
+                }
+                impl Message for Disconnect {
+                    fn id() -> &'static str {
+                        "ambient_core::player::Disconnect"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.player.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            player: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for Disconnect {}
+                #[derive(Clone, Debug)]
+                #[doc = "**Reconnect**: Sent to server modules when a player whose connection was lost resumes their session."]
+                pub struct Reconnect {
+                    pub player: EntityId,
+                }
+                impl Reconnect {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(player: impl Into<EntityId>) -> Self {
+                        Self {
+                            player: player.into(),
+                        }
+                    }
+                }
+                impl Message for Reconnect {
+                    fn id() -> &'static str {
+                        "ambient_core::player::Reconnect"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.player.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            player: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for Reconnect {}
             }
         }
         pub mod prefab {
@@ -748,7 +1122,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -764,7 +1138,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -780,7 +1154,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -796,7 +1170,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -812,7 +1186,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -828,7 +1202,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -935,7 +1309,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -951,7 +1325,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -966,7 +1340,8 @@ mod raw {
             pub mod messages {
                 use crate::{Entity, EntityId};
                 use ambient_package_rt::message_serde::{
-                    Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                    Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                    RuntimeMessage,
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 #[derive(Clone, Debug)]
@@ -1009,7 +1384,7 @@ mod raw {
             pub mod components {
                 use crate::{
                     components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    Networked, Resource, Store, Struct,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -1017,7 +1392,7 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("wasm" , { # [doc = "**Is module**: A module.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Is module"] , Description ["A module."]] is_module : () , # [doc = "**Is module on server**: Whether or not this module is on the server.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Is module on server"] , Description ["Whether or not this module is on the server."]] is_module_on_server : () , # [doc = "**Bytecode from URL**: Asset URL for the bytecode of a WASM component.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Bytecode from URL"] , Description ["Asset URL for the bytecode of a WASM component."]] bytecode_from_url : String , # [doc = "**Module enabled**: Whether or not this module is enabled.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Module enabled"] , Description ["Whether or not this module is enabled."]] module_enabled : bool , # [doc = "**Module name**: The name of this module.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Module name"] , Description ["The name of this module."]] module_name : String , # [doc = "**Package reference**: The package that this module belongs to.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Package reference"] , Description ["The package that this module belongs to."]] package_ref : EntityId , });
+                components ! ("wasm" , { # [doc = "**Is module**: A module.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Is module"] , Description ["A module."]] is_module : () , # [doc = "**Is module on server**: Whether or not this module is on the server.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Is module on server"] , Description ["Whether or not this module is on the server."]] is_module_on_server : () , # [doc = "**Bytecode from URL**: Asset URL for the bytecode of a WASM component.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Bytecode from URL"] , Description ["Asset URL for the bytecode of a WASM component."]] bytecode_from_url : String , # [doc = "**Module enabled**: Whether or not this module is enabled.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Module enabled"] , Description ["Whether or not this module is enabled."]] module_enabled : bool , # [doc = "**Module name**: The name of this module.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Module name"] , Description ["The name of this module."]] module_name : String , # [doc = "**Package reference**: The package that this module belongs to.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Package reference"] , Description ["The package that this module belongs to."]] package_ref : EntityId , # [doc = "**Module execution time**: The time spent running this module per frame, including the host calls it made, averaged over the last `module_profiling_interval`.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module execution time"] , Description ["The time spent running this module per frame, including the host calls it made, averaged over the last `module_profiling_interval`."]] module_execution_time : Duration , # [doc = "**Module message times**: The time spent running this module per frame, by the name of the message it was handling, averaged over the last `module_profiling_interval`. Frame and lifecycle events are messages too.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module message times"] , Description ["The time spent running this module per frame, by the name of the message it was handling, averaged over the last `module_profiling_interval`. Frame and lifecycle events are messages too."]] module_message_times : std :: collections :: BTreeMap :: < String , Duration > , # [doc = "**Module host call counts**: The number of calls this module made to the host per frame, by the WIT interface that was called, such as `entity` or `server-physics`, averaged over the last `module_profiling_interval`.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module host call counts"] , Description ["The number of calls this module made to the host per frame, by the WIT interface that was called, such as `entity` or `server-physics`, averaged over the last `module_profiling_interval`."]] module_host_call_counts : std :: collections :: BTreeMap :: < String , u32 > , # [doc = "**Module host call times**: The time spent in calls this module made to the host per frame, by the WIT interface that was called, averaged over the last `module_profiling_interval`. Only measured on native.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module host call times"] , Description ["The time spent in calls this module made to the host per frame, by the WIT interface that was called, averaged over the last `module_profiling_interval`. Only measured on native."]] module_host_call_times : std :: collections :: BTreeMap :: < String , Duration > , # [doc = "**Module profiling interval**: If attached as a resource, the profile of each module is attached to it as `module_execution_time`, `module_message_times`, `module_host_call_counts` and `module_host_call_times` at this interval.\n\nThe profile components are not updated unless this is attached on the same side as the module.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Module profiling interval"] , Description ["If attached as a resource, the profile of each module is attached to it as `module_execution_time`, `module_message_times`, `module_host_call_counts` and `module_host_call_times` at this interval.\nThe profile components are not updated unless this is attached on the same side as the module."]] module_profiling_interval : Duration , });
             }
         }
         #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
@@ -1025,7 +1400,7 @@ mod raw {
         pub mod messages {
             use crate::{Entity, EntityId};
             use ambient_package_rt::message_serde::{
-                Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                Message, MessageSerde, MessageSerdeError, ModuleMessage, Request, RuntimeMessage,
             };
             use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
             #[derive(Clone, Debug)]
@@ -1499,6 +1874,64 @@ mod raw {
             }
             impl RuntimeMessage for HttpResponse {}
             #[derive(Clone, Debug)]
+            #[doc = "**SoundFinished**: Sent to a module when a sound it played has finished playing, or was stopped."]
+            pub struct SoundFinished {
+                pub sound: EntityId,
+            }
+            impl SoundFinished {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(sound: impl Into<EntityId>) -> Self {
+                    Self {
+                        sound: sound.into(),
+                    }
+                }
+            }
+            impl Message for SoundFinished {
+                fn id() -> &'static str {
+                    "ambient_core::SoundFinished"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.sound.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        sound: EntityId::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for SoundFinished {}
+            #[derive(Clone, Debug)]
+            #[doc = "**TimerExpired**: Sent to a module when a timer it scheduled expires."]
+            pub struct TimerExpired {
+                pub timer: u64,
+            }
+            impl TimerExpired {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(timer: impl Into<u64>) -> Self {
+                    Self {
+                        timer: timer.into(),
+                    }
+                }
+            }
+            impl Message for TimerExpired {
+                fn id() -> &'static str {
+                    "ambient_core::TimerExpired"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.timer.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        timer: u64::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for TimerExpired {}
+            #[derive(Clone, Debug)]
             #[doc = "**WasmRebuild**: Sent when a request for WASM rebuilding is completed."]
             pub struct WasmRebuild {
                 pub error: Option<String>,
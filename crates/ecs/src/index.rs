@@ -1,20 +1,129 @@
 use std::{
     cmp::Ordering,
-    collections::{btree_set::Range, BTreeSet, HashMap},
+    collections::{btree_set::Range, hash_map::DefaultHasher, BTreeSet, HashMap},
     fmt::Debug,
+    hash::{Hash, Hasher},
     ops::RangeBounds,
+    time::Duration,
 };
 
+use ambient_shared_types::{
+    primitive_component_definitions, ProceduralMaterialHandle, ProceduralMeshHandle,
+    ProceduralSamplerHandle, ProceduralTextureHandle,
+};
+use glam::{IVec2, IVec3, IVec4, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
 use itertools::Itertools;
 
 use crate::{
     ArchetypeFilter, Component, ComponentDesc, ComponentEntry, ComponentValue, EntityId, FnSystem,
-    Query, SystemGroup, World,
+    PrimitiveComponent, PrimitiveComponentType, Query, QueryState, SystemGroup, World,
 };
 
+/// Component values which can be ordered and hashed by an index, including floating point values
+/// which are not `Ord` or `Hash`
+pub trait IndexValue: ComponentValue {
+    fn index_cmp(&self, other: &Self) -> Ordering;
+    fn index_hash(&self, state: &mut dyn Hasher);
+}
+
+macro_rules! impl_index_value_for_ord {
+    ($($type:ty),*) => {
+        $(
+            impl IndexValue for $type {
+                fn index_cmp(&self, other: &Self) -> Ordering {
+                    self.cmp(other)
+                }
+                fn index_hash(&self, mut state: &mut dyn Hasher) {
+                    self.hash(&mut state)
+                }
+            }
+        )*
+    };
+}
+impl_index_value_for_ord!(
+    (),
+    bool,
+    EntityId,
+    String,
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    Duration,
+    ProceduralMeshHandle,
+    ProceduralTextureHandle,
+    ProceduralSamplerHandle,
+    ProceduralMaterialHandle
+);
+
+macro_rules! impl_index_value_for_arrays {
+    ($($type:ty),*) => {
+        $(
+            impl IndexValue for $type {
+                fn index_cmp(&self, other: &Self) -> Ordering {
+                    self.to_array().cmp(&other.to_array())
+                }
+                fn index_hash(&self, mut state: &mut dyn Hasher) {
+                    self.to_array().hash(&mut state)
+                }
+            }
+        )*
+    };
+}
+impl_index_value_for_arrays!(UVec2, UVec3, UVec4, IVec2, IVec3, IVec4);
+
+macro_rules! impl_index_value_for_floats {
+    ($($type:ty),*) => {
+        $(
+            impl IndexValue for $type {
+                fn index_cmp(&self, other: &Self) -> Ordering {
+                    self.total_cmp(other)
+                }
+                fn index_hash(&self, mut state: &mut dyn Hasher) {
+                    self.to_bits().hash(&mut state)
+                }
+            }
+        )*
+    };
+}
+impl_index_value_for_floats!(f32, f64);
+
+macro_rules! impl_index_value_for_float_arrays {
+    ($($type:ty => $to_array:ident),*) => {
+        $(
+            impl IndexValue for $type {
+                fn index_cmp(&self, other: &Self) -> Ordering {
+                    self.$to_array()
+                        .iter()
+                        .zip(other.$to_array().iter())
+                        .map(|(a, b)| a.total_cmp(b))
+                        .find(|ordering| ordering.is_ne())
+                        .unwrap_or(Ordering::Equal)
+                }
+                fn index_hash(&self, mut state: &mut dyn Hasher) {
+                    self.$to_array().map(f32::to_bits).hash(&mut state)
+                }
+            }
+        )*
+    };
+}
+impl_index_value_for_float_arrays!(
+    Vec2 => to_array,
+    Vec3 => to_array,
+    Vec4 => to_array,
+    Quat => to_array,
+    Mat4 => to_cols_array
+);
+
 #[derive(Clone)]
 pub struct IndexColumns {
     comparators: Vec<fn(&ComponentEntry, &ComponentEntry) -> Ordering>,
+    /// Only set for the columns added with [`IndexColumns::add_value_column`]
+    hashers: Vec<Option<fn(&ComponentEntry, &mut dyn Hasher)>>,
     components: Vec<ComponentDesc>,
 }
 
@@ -31,6 +140,7 @@ impl IndexColumns {
     pub fn new() -> Self {
         Self {
             comparators: Default::default(),
+            hashers: Default::default(),
             components: Default::default(),
         }
     }
@@ -38,10 +148,75 @@ impl IndexColumns {
     pub fn add_column<T: ComponentValue + Ord>(mut self, component: Component<T>) -> Self {
         self.comparators
             .push(|a, b| a.downcast_ref::<T>().cmp(b.downcast_ref::<T>()));
+        self.hashers.push(None);
+        self.components.push(component.desc());
+        self
+    }
+
+    /// Adds a column which can also be used by a [`HashIndex`]
+    pub fn add_value_column<T: IndexValue>(mut self, component: Component<T>) -> Self {
+        self.comparators
+            .push(|a, b| a.downcast_ref::<T>().index_cmp(b.downcast_ref::<T>()));
+        self.hashers.push(Some(|entry, state| {
+            entry.downcast_ref::<T>().index_hash(state)
+        }));
         self.components.push(component.desc());
         self
     }
 
+    pub fn components(&self) -> &[ComponentDesc] {
+        &self.components
+    }
+
+    /// Returns true if all of the columns can be hashed
+    pub fn is_hashable(&self) -> bool {
+        self.hashers.iter().all(Option::is_some)
+    }
+
+    /// Creates the fields of a key from the values of the columns, in order
+    pub fn exact_fields(&self, values: Vec<ComponentEntry>) -> Vec<IndexField> {
+        values
+            .into_iter()
+            .zip(self.comparators.iter())
+            .map(|(value, &comparator)| IndexField::Exact(IndexFieldValue { comparator, value }))
+            .collect()
+    }
+
+    /// Returns true if `values` are values of the columns, in order
+    fn matches_values(&self, values: &[ComponentEntry]) -> bool {
+        values.len() == self.components.len()
+            && values
+                .iter()
+                .zip(self.components.iter())
+                .all(|(value, &component)| value.desc() == component)
+    }
+
+    fn hash_values(&self, values: &[ComponentEntry]) -> u64 {
+        let mut state = DefaultHasher::new();
+        for (value, hasher) in values.iter().zip(self.hashers.iter()) {
+            (hasher.expect("Column can't be hashed"))(value, &mut state);
+        }
+        state.finish()
+    }
+
+    fn values_eq(&self, a: &[ComponentEntry], b: &[ComponentEntry]) -> bool {
+        a.iter()
+            .zip(b.iter())
+            .zip(self.comparators.iter())
+            .all(|((a, b), comparator)| comparator(a, b) == Ordering::Equal)
+    }
+
+    fn try_values_from_entity(
+        &self,
+        world: &World,
+        entity: EntityId,
+    ) -> Option<Vec<ComponentEntry>> {
+        self.components
+            .iter()
+            .map(|&component| world.get_entry(entity, component).ok())
+            .collect()
+    }
+
     pub fn key_from_entity(&self, world: &World, entity: EntityId) -> IndexKey {
         self.try_key_from_entity(world, entity).unwrap()
     }
@@ -104,6 +279,10 @@ impl Index {
         }
     }
 
+    pub fn columns(&self) -> &IndexColumns {
+        &self.columns
+    }
+
     /// Query the set between the specified ordered range
     pub fn range<R>(&self, range: R) -> Range<'_, IndexKey>
     where
@@ -223,63 +402,337 @@ impl Ord for IndexFieldValue {
     }
 }
 
+/// An index which can be kept up to date with an [`IndexUpdater`] or [`entity_index_system`]
+pub trait EntityIndex: ComponentValue {
+    type Key;
+
+    /// The components the keys of the index are made from
+    fn components(&self) -> Vec<ComponentDesc>;
+    fn try_key_from_entity(&self, world: &World, id: EntityId) -> Option<Self::Key>;
+    fn insert_key(&mut self, id: EntityId, key: Self::Key);
+    fn remove_entity(&mut self, id: EntityId) -> bool;
+}
+
+impl EntityIndex for Index {
+    type Key = IndexKey;
+
+    fn components(&self) -> Vec<ComponentDesc> {
+        self.columns.components.clone()
+    }
+    fn try_key_from_entity(&self, world: &World, id: EntityId) -> Option<Self::Key> {
+        self.columns.try_key_from_entity(world, id)
+    }
+    fn insert_key(&mut self, _id: EntityId, key: Self::Key) {
+        self.insert(key);
+    }
+    fn remove_entity(&mut self, id: EntityId) -> bool {
+        self.remove(id)
+    }
+}
+
+/// An ECS entity index for looking up entities by the exact values of their components
+///
+/// All of the columns must be added with [`IndexColumns::add_value_column`]
+#[derive(Clone, Debug)]
+pub struct HashIndex {
+    columns: IndexColumns,
+    buckets: HashMap<u64, Vec<(EntityId, Vec<ComponentEntry>)>>,
+    ids_to_hashes: HashMap<EntityId, u64>,
+}
+
+impl HashIndex {
+    pub fn new(columns: IndexColumns) -> Self {
+        assert!(
+            columns.is_hashable(),
+            "All columns of a hash index must be added with add_value_column"
+        );
+        Self {
+            columns,
+            buckets: Default::default(),
+            ids_to_hashes: Default::default(),
+        }
+    }
+    pub fn columns(&self) -> &IndexColumns {
+        &self.columns
+    }
+    pub fn insert_entity(&mut self, world: &World, id: EntityId) {
+        let values = self.columns.try_values_from_entity(world, id).unwrap();
+        self.insert(id, values);
+    }
+    /// Inserts `id` with the values of its columns, replacing its previous values
+    pub fn insert(&mut self, id: EntityId, values: Vec<ComponentEntry>) {
+        assert!(self.columns.matches_values(&values));
+        self.remove(id);
+        let hash = self.columns.hash_values(&values);
+        self.buckets.entry(hash).or_default().push((id, values));
+        self.ids_to_hashes.insert(id, hash);
+    }
+    pub fn remove(&mut self, id: EntityId) -> bool {
+        let Some(hash) = self.ids_to_hashes.remove(&id) else {
+            return false;
+        };
+        if let Some(bucket) = self.buckets.get_mut(&hash) {
+            bucket.retain(|(entry_id, _)| *entry_id != id);
+            if bucket.is_empty() {
+                self.buckets.remove(&hash);
+            }
+        }
+        true
+    }
+    /// The entities whose columns are equal to `values`, which are the values of the columns in
+    /// order
+    pub fn get<'a>(&'a self, values: &'a [ComponentEntry]) -> impl Iterator<Item = EntityId> + 'a {
+        let bucket = if self.columns.matches_values(values) {
+            self.buckets.get(&self.columns.hash_values(values))
+        } else {
+            None
+        };
+        bucket
+            .into_iter()
+            .flatten()
+            .filter(|(_, entry_values)| self.columns.values_eq(values, entry_values))
+            .map(|(id, _)| *id)
+    }
+}
+
+impl EntityIndex for HashIndex {
+    type Key = Vec<ComponentEntry>;
+
+    fn components(&self) -> Vec<ComponentDesc> {
+        self.columns.components.clone()
+    }
+    fn try_key_from_entity(&self, world: &World, id: EntityId) -> Option<Self::Key> {
+        self.columns.try_values_from_entity(world, id)
+    }
+    fn insert_key(&mut self, id: EntityId, key: Self::Key) {
+        self.insert(id, key);
+    }
+    fn remove_entity(&mut self, id: EntityId) -> bool {
+        self.remove(id)
+    }
+}
+
+/// An ECS entity index which sorts entities into the cells of a uniform grid by a position, such
+/// as their `translation`, for finding the entities in an area
+#[derive(Clone, Debug)]
+pub struct GridIndex {
+    component: Component<Vec3>,
+    cell_size: f32,
+    cells: HashMap<IVec3, Vec<EntityId>>,
+    positions: HashMap<EntityId, Vec3>,
+}
+
+impl GridIndex {
+    pub fn new(component: Component<Vec3>, cell_size: f32) -> Self {
+        assert!(
+            cell_size > 0.,
+            "The cell size of a grid index must be positive"
+        );
+        Self {
+            component,
+            cell_size,
+            cells: Default::default(),
+            positions: Default::default(),
+        }
+    }
+    fn cell(&self, position: Vec3) -> IVec3 {
+        (position / self.cell_size).floor().as_ivec3()
+    }
+    pub fn insert_entity(&mut self, world: &World, id: EntityId) {
+        self.insert(id, world.get(id, self.component).unwrap());
+    }
+    /// Inserts `id` at `position`, replacing its previous position
+    pub fn insert(&mut self, id: EntityId, position: Vec3) {
+        self.remove(id);
+        self.cells.entry(self.cell(position)).or_default().push(id);
+        self.positions.insert(id, position);
+    }
+    pub fn remove(&mut self, id: EntityId) -> bool {
+        let Some(position) = self.positions.remove(&id) else {
+            return false;
+        };
+        let cell = self.cell(position);
+        if let Some(entities) = self.cells.get_mut(&cell) {
+            entities.retain(|&entity| entity != id);
+            if entities.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+        true
+    }
+    /// The entities inside the box from `min` to `max`
+    pub fn within_box(&self, min: Vec3, max: Vec3) -> Vec<EntityId> {
+        self.find(min, max, |position| {
+            position.cmpge(min).all() && position.cmple(max).all()
+        })
+    }
+    /// The entities at most `radius` away from `center`
+    pub fn within_radius(&self, center: Vec3, radius: f32) -> Vec<EntityId> {
+        let offset = Vec3::splat(radius);
+        self.find(center - offset, center + offset, |position| {
+            position.distance_squared(center) <= radius * radius
+        })
+    }
+    fn find(&self, min: Vec3, max: Vec3, contains: impl Fn(Vec3) -> bool) -> Vec<EntityId> {
+        let (min_cell, max_cell) = (self.cell(min), self.cell(max));
+        let size = (max_cell - min_cell + IVec3::ONE)
+            .max(IVec3::ZERO)
+            .as_dvec3();
+        let n_cells = size.x * size.y * size.z;
+        // Visiting more cells than there are entities is slower than checking all of the entities
+        if n_cells > self.positions.len() as f64 {
+            return self
+                .positions
+                .iter()
+                .filter(|(_, &position)| contains(position))
+                .map(|(&id, _)| id)
+                .collect();
+        }
+
+        let mut entities = Vec::new();
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                for z in min_cell.z..=max_cell.z {
+                    let Some(cell) = self.cells.get(&IVec3::new(x, y, z)) else {
+                        continue;
+                    };
+                    entities.extend(
+                        cell.iter()
+                            .copied()
+                            .filter(|id| contains(self.positions[id])),
+                    );
+                }
+            }
+        }
+        entities
+    }
+}
+
+impl EntityIndex for GridIndex {
+    type Key = Vec3;
+
+    fn components(&self) -> Vec<ComponentDesc> {
+        vec![self.component.desc()]
+    }
+    fn try_key_from_entity(&self, world: &World, id: EntityId) -> Option<Self::Key> {
+        world.get(id, self.component).ok()
+    }
+    fn insert_key(&mut self, id: EntityId, key: Self::Key) {
+        self.insert(id, key);
+    }
+    fn remove_entity(&mut self, id: EntityId) -> bool {
+        self.remove(id)
+    }
+}
+
+/// A change to make to an [`EntityIndex`]
+#[derive(Debug, Clone)]
+pub enum IndexChange<K> {
+    Insert(EntityId, K),
+    Remove(EntityId),
+}
+
+impl<K> IndexChange<K> {
+    pub fn apply<I: EntityIndex<Key = K>>(self, index: &mut I) {
+        match self {
+            Self::Insert(id, key) => {
+                index.remove_entity(id);
+                index.insert_key(id, key);
+            }
+            Self::Remove(id) => {
+                index.remove_entity(id);
+            }
+        }
+    }
+}
+
+/// Keeps an [`EntityIndex`] up to date with the entities which match a filter and have the
+/// components of the index, by following their spawns, despawns and changes
+#[derive(Debug, Clone)]
+pub struct IndexUpdater {
+    spawned: (Query, QueryState),
+    despawned: (Query, QueryState),
+    changed: (Query, QueryState),
+}
+
+impl IndexUpdater {
+    pub fn new(mut filter: ArchetypeFilter, components: &[ComponentDesc]) -> Self {
+        for &c in components {
+            filter = filter.incl_ref(c);
+        }
+        Self {
+            spawned: (Query::new(filter.clone()).spawned(), QueryState::new()),
+            despawned: (Query::new(filter.clone()).despawned(), QueryState::new()),
+            changed: (
+                Query::any_changed(components.to_vec()).filter(&filter),
+                QueryState::new(),
+            ),
+        }
+    }
+
+    /// The changes to make to `index` since the last update
+    pub fn changes<I: EntityIndex>(
+        &mut self,
+        world: &World,
+        index: &I,
+    ) -> Vec<IndexChange<I::Key>> {
+        let (q, qs) = &mut self.spawned;
+        let mut changes = q
+            .iter(world, Some(qs))
+            .filter_map(|x| {
+                Some(IndexChange::Insert(
+                    x.id(),
+                    index.try_key_from_entity(world, x.id())?,
+                ))
+            })
+            .collect_vec();
+        let (q, qs) = &mut self.despawned;
+        changes.extend(q.iter(world, Some(qs)).map(|x| IndexChange::Remove(x.id())));
+        let (q, qs) = &mut self.changed;
+        changes.extend(q.iter(world, Some(qs)).filter_map(|x| {
+            Some(IndexChange::Insert(
+                x.id(),
+                index.try_key_from_entity(world, x.id())?,
+            ))
+        }));
+        changes
+    }
+
+    pub fn update<I: EntityIndex>(&mut self, world: &World, index: &mut I) {
+        for change in self.changes(world, index) {
+            change.apply(index);
+        }
+    }
+}
+
 /// Creates and maintains an ECS Index as a resource on the world
 pub fn index_system(
-    mut filter: ArchetypeFilter,
+    filter: ArchetypeFilter,
     columns: IndexColumns,
     index_resource: Component<Index>,
 ) -> SystemGroup {
-    for &c in &columns.components {
-        filter = filter.incl_ref(c);
-    }
-    let components = columns.components.clone();
+    entity_index_system(filter, Index::new(columns), index_resource)
+}
+
+/// Creates and maintains any [`EntityIndex`] as a resource on the world, starting from `index`
+pub fn entity_index_system<I: EntityIndex>(
+    filter: ArchetypeFilter,
+    index: I,
+    index_resource: Component<I>,
+) -> SystemGroup {
+    let mut updater = IndexUpdater::new(filter, &index.components());
     SystemGroup::new(
         "index_system",
-        vec![
-            Box::new(FnSystem::new(move |world, _| {
-                if !world.has_component(world.resource_entity(), index_resource) {
-                    world.add_resource(index_resource, Index::new(columns.clone()));
-                }
-            })),
-            Query::new(filter.clone())
-                .spawned()
-                .to_system(move |q, world, qs, _| {
-                    let keys = {
-                        let index = world.resource(index_resource);
-                        q.iter(world, Some(qs))
-                            .map(|x| index.columns.key_from_entity(world, x.id()))
-                            .collect_vec()
-                    };
-                    let index = world.resource_mut(index_resource);
-                    for key in keys {
-                        index.insert(key);
-                    }
-                }),
-            Query::new(filter.clone())
-                .despawned()
-                .to_system(move |q, world, qs, _| {
-                    let ids = q.iter(world, Some(qs)).map(|x| x.id()).collect_vec();
-                    let index = world.resource_mut(index_resource);
-                    for id in ids {
-                        index.remove(id);
-                    }
-                }),
-            Query::any_changed(components)
-                .filter(&filter)
-                .to_system(move |q, world, qs, _| {
-                    let keys = {
-                        let index = world.resource(index_resource);
-                        q.iter(world, Some(qs))
-                            .map(|x| index.columns.key_from_entity(world, x.id()))
-                            .collect_vec()
-                    };
-                    let index = world.resource_mut(index_resource);
-                    for key in keys {
-                        index.remove(key.id().unwrap());
-                        index.insert(key);
-                    }
-                }),
-        ],
+        vec![Box::new(FnSystem::new(move |world, _| {
+            if !world.has_component(world.resource_entity(), index_resource) {
+                world.add_resource(index_resource, index.clone());
+            }
+            let changes = updater.changes(world, world.resource(index_resource));
+            let index = world.resource_mut(index_resource);
+            for change in changes {
+                change.apply(index);
+            }
+        }))],
     )
 }
 
@@ -302,3 +755,21 @@ impl IndexExt for World {
         }
     }
 }
+
+macro_rules! make_primitive_index_columns {
+    ($(($value:ident, $type:ty)),*) => {
+        impl IndexColumns {
+            /// Adds a value column for a component with a primitive type. Returns `None` for
+            /// components with container types.
+            pub fn add_primitive_column(self, component: &PrimitiveComponent) -> Option<Self> {
+                match component.ty {
+                    $(PrimitiveComponentType::$value => {
+                        Some(self.add_value_column(Component::<$type>::new(component.desc)))
+                    })*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+primitive_component_definitions!(make_primitive_index_columns);
@@ -39,14 +39,21 @@ mod helpers;
 mod index;
 mod location;
 mod message_serde;
+#[cfg(not(target_os = "unknown"))]
+mod persistence;
 mod primitive_component;
 mod query;
+mod relation;
+mod relationship;
+mod schedule;
 mod serialization;
 mod stream;
 pub use ambient_package_rt::message_serde::*;
 pub use archetype::*;
 pub use attributes::*;
-pub use component::{Component, ComponentDesc, ComponentValue, ComponentValueBase, EnumComponent};
+pub use component::{
+    Component, ComponentDesc, ComponentValue, ComponentValueBase, EnumComponent, StructComponent,
+};
 pub use component_entry::*;
 pub use component_registry::*;
 pub use component_ser::*;
@@ -56,8 +63,13 @@ pub use helpers::*;
 pub use index::*;
 pub use location::*;
 pub use message_serde::*;
+#[cfg(not(target_os = "unknown"))]
+pub use persistence::*;
 pub use primitive_component::*;
 pub use query::*;
+pub use relation::*;
+pub use relationship::*;
+pub use schedule::*;
 pub use serialization::*;
 pub use stream::*;
 
@@ -81,7 +93,8 @@ mod internal_components {
     use super::Message;
 
     use crate::{
-        components, Description, Resource, WorldEventReader, WorldEventSource, WorldEvents,
+        components, Description, Relationships, Resource, WorldEventReader, WorldEventSource,
+        WorldEvents,
     };
 
     pub trait WorldEventsExt {
@@ -115,10 +128,15 @@ mod internal_components {
             Description["A global general event queue for this ecs World. Can be used to dispatch or listen to any kinds of events."]
         ]
         world_events: WorldEvents,
+        @[
+            Resource,
+            Description["Reverse lookups for the relationship components of this ecs World, which are kept up to date by the relationships system."]
+        ]
+        relationships: Relationships,
     });
 }
 pub use generated::ecs::components::*;
-pub use internal_components::{read_messages, world_events, WorldEventsExt};
+pub use internal_components::{read_messages, relationships, world_events, WorldEventsExt};
 
 pub fn init_components() {
     generated::init();
@@ -862,6 +880,8 @@ pub enum ECSError {
         component_path: String,
         entity_id: EntityId,
     },
+    #[error("Component `{component_path}` is not in the access declared by the system")]
+    AccessNotDeclared { component_path: String },
 }
 
 struct MapEntity {
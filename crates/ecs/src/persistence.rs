@@ -0,0 +1,301 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::{
+    with_component_registry, DeserEntityDataWithWarnings, DeserWorldWithWarnings,
+    ECSDeserializationWarnings, Entity, EntityId, World, WorldContext, WorldDiff, WorldStream,
+    WorldStreamFilter,
+};
+
+const SNAPSHOT_FILENAME: &str = "snapshot.json";
+const DIFF_LOG_FILENAME: &str = "diffs.jsonl";
+
+/// Persists the entities matched by a [`WorldStreamFilter`] to a directory on disk.
+///
+/// The directory contains a snapshot of the world, written with the regular serde
+/// serialization of [`World`], and an append-only log of the [`WorldDiff`](crate::WorldDiff)s
+/// that happened since the snapshot was taken, one JSON object per line.
+///
+/// Use [`WorldPersistence::load`] to get the persisted world back, and
+/// [`WorldPersistence::into_parts`] to write to disk on another thread.
+pub struct WorldPersistence {
+    stream: WorldPersistenceStream,
+    writer: WorldPersistenceWriter,
+}
+impl WorldPersistence {
+    /// Starts persisting to `path`, appending to the diff log if it already exists.
+    ///
+    /// The persisted world must have shape change tracking enabled (see [`World::init_shape_change_tracking`]).
+    pub fn new(path: impl Into<PathBuf>, filter: WorldStreamFilter) -> anyhow::Result<Self> {
+        Ok(Self {
+            stream: WorldPersistenceStream {
+                stream: WorldStream::new(filter.clone()),
+                filter,
+            },
+            writer: WorldPersistenceWriter::new(path.into())?,
+        })
+    }
+
+    /// Splits this into the part which reads the changes from the world, and the part which
+    /// writes them to disk
+    pub fn into_parts(self) -> (WorldPersistenceStream, WorldPersistenceWriter) {
+        (self.stream, self.writer)
+    }
+
+    pub fn path(&self) -> &Path {
+        self.writer.path()
+    }
+
+    /// The number of diffs appended to the log since the last snapshot
+    pub fn diffs_since_snapshot(&self) -> usize {
+        self.writer.diffs_since_snapshot()
+    }
+
+    /// Appends the changes since the last call (or the last snapshot) to the diff log
+    pub fn record_diff(&mut self, world: &World) -> anyhow::Result<()> {
+        let diff = self.stream.next_diff(world);
+        if diff.is_empty() {
+            return Ok(());
+        }
+        self.writer.write_diff(&diff)?;
+        self.writer.flush()
+    }
+
+    /// Writes a new snapshot of the world and truncates the diff log
+    pub fn save_snapshot(&mut self, world: &World) -> anyhow::Result<()> {
+        let snapshot = self.stream.snapshot(world);
+        self.writer.write_snapshot(&snapshot)
+    }
+
+    /// Loads the world persisted to `path` by replaying the diff log on top of the last snapshot.
+    ///
+    /// Returns `None` if nothing has been persisted to `path` yet. Components that no longer exist
+    /// or fail to deserialize, for example because the schema changed between runs, are skipped
+    /// and reported in the returned warnings.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Option<DeserWorldWithWarnings>> {
+        let path = path.as_ref();
+        let snapshot_path = path.join(SNAPSHOT_FILENAME);
+        let log_path = path.join(DIFF_LOG_FILENAME);
+        if !snapshot_path.exists() && !log_path.exists() {
+            return Ok(None);
+        }
+
+        let mut res = if snapshot_path.exists() {
+            let content = fs::read(&snapshot_path)
+                .with_context(|| format!("Failed to read world snapshot {snapshot_path:?}"))?;
+            serde_json::from_slice(&content)
+                .with_context(|| format!("Failed to deserialize world snapshot {snapshot_path:?}"))?
+        } else {
+            DeserWorldWithWarnings {
+                world: World::new_with_config("deserialized", WorldContext::Prefab, false),
+                warnings: Default::default(),
+            }
+        };
+
+        if log_path.exists() {
+            let log = File::open(&log_path)
+                .with_context(|| format!("Failed to open world diff log {log_path:?}"))?;
+            for (index, line) in BufReader::new(log).lines().enumerate() {
+                let line = line?;
+                if line.is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<PersistedDiff>(&line) {
+                    Ok(diff) => diff.apply(&mut res.world, &mut res.warnings),
+                    // The last line may be incomplete if the server was killed while writing it
+                    Err(err) => res.warnings.push((
+                        EntityId::null(),
+                        format!("{DIFF_LOG_FILENAME}:{}", index + 1),
+                        err.to_string(),
+                    )),
+                }
+            }
+        }
+
+        Ok(Some(res))
+    }
+}
+
+/// Reads what has to be persisted from the world. See [`WorldPersistence::into_parts`].
+pub struct WorldPersistenceStream {
+    filter: WorldStreamFilter,
+    stream: WorldStream,
+}
+impl WorldPersistenceStream {
+    /// The changes since the last call, or the last snapshot
+    pub fn next_diff(&mut self, world: &World) -> WorldDiff {
+        self.stream.next_diff(world)
+    }
+
+    /// Copies the persisted entities of the world, to be written with
+    /// [`WorldPersistenceWriter::write_snapshot`]
+    pub fn snapshot(&mut self, world: &World) -> World {
+        // Everything that happened up until now is part of the snapshot
+        self.stream.next_diff(world);
+
+        let mut snapshot = World::new_with_config("snapshot", world.context(), false);
+        for id in self.filter.all_entities(world) {
+            snapshot.spawn_with_id(id, self.filter.read_entity_components(world, id).into());
+        }
+        snapshot
+    }
+}
+
+/// Writes snapshots and diffs to disk. See [`WorldPersistence::into_parts`].
+pub struct WorldPersistenceWriter {
+    path: PathBuf,
+    log: BufWriter<File>,
+    diffs_since_snapshot: usize,
+}
+impl WorldPersistenceWriter {
+    fn new(path: PathBuf) -> anyhow::Result<Self> {
+        fs::create_dir_all(&path)
+            .with_context(|| format!("Failed to create world persistence directory {path:?}"))?;
+        let log = open_diff_log(&path, false)?;
+        Ok(Self {
+            path,
+            log,
+            diffs_since_snapshot: 0,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The number of diffs appended to the log since the last snapshot
+    pub fn diffs_since_snapshot(&self) -> usize {
+        self.diffs_since_snapshot
+    }
+
+    /// Appends `diff` to the diff log. It is buffered until [`WorldPersistenceWriter::flush`] is called.
+    pub fn write_diff(&mut self, diff: &WorldDiff) -> anyhow::Result<()> {
+        serde_json::to_writer(&mut self.log, diff)?;
+        self.log.write_all(b"\n")?;
+        self.diffs_since_snapshot += 1;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> anyhow::Result<()> {
+        self.log.flush()?;
+        Ok(())
+    }
+
+    /// Replaces the snapshot with `snapshot` and truncates the diff log
+    pub fn write_snapshot(&mut self, snapshot: &World) -> anyhow::Result<()> {
+        let content = serde_json::to_vec(snapshot)?;
+
+        // Write to a temporary file first, so that a crash can't leave a half-written snapshot behind
+        let snapshot_path = self.path.join(SNAPSHOT_FILENAME);
+        let tmp_path = snapshot_path.with_extension("json.tmp");
+        fs::write(&tmp_path, content)
+            .with_context(|| format!("Failed to write world snapshot to {tmp_path:?}"))?;
+        fs::rename(&tmp_path, &snapshot_path)
+            .with_context(|| format!("Failed to replace world snapshot {snapshot_path:?}"))?;
+
+        self.log = open_diff_log(&self.path, true)?;
+        self.diffs_since_snapshot = 0;
+        Ok(())
+    }
+}
+
+fn open_diff_log(path: &Path, truncate: bool) -> anyhow::Result<BufWriter<File>> {
+    let log_path = path.join(DIFF_LOG_FILENAME);
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(!truncate)
+        .truncate(truncate)
+        .open(&log_path)
+        .with_context(|| format!("Failed to open world diff log {log_path:?}"))?;
+    Ok(BufWriter::new(file))
+}
+
+/// The serialized form of a [`WorldDiff`](crate::WorldDiff), read back in a way that reports bad
+/// components as warnings instead of failing the entire diff
+#[derive(Deserialize)]
+struct PersistedDiff {
+    changes: Vec<PersistedChange>,
+}
+impl PersistedDiff {
+    fn apply(self, world: &mut World, warnings: &mut ECSDeserializationWarnings) {
+        for change in self.changes {
+            change.apply(world, warnings);
+        }
+    }
+}
+
+#[derive(Deserialize)]
+enum PersistedChange {
+    Spawn(EntityId, DeserEntityDataWithWarnings),
+    Despawn(EntityId),
+    AddComponents(EntityId, DeserEntityDataWithWarnings),
+    RemoveComponents(EntityId, Vec<String>),
+    SetComponents(EntityId, DeserEntityDataWithWarnings),
+}
+impl PersistedChange {
+    fn apply(self, world: &mut World, warnings: &mut ECSDeserializationWarnings) {
+        let (id, change, result) = match self {
+            Self::Spawn(id, data) => {
+                let entity = take_entity(id, data, warnings);
+                let result = if world.spawn_with_id(id, entity) {
+                    Ok(())
+                } else {
+                    Err(format!("Entity {id} already exists"))
+                };
+                (id, "spawn", result)
+            }
+            // Despawns are not filtered by the world stream, so most of these refer to
+            // entities that were never persisted
+            Self::Despawn(id) => {
+                world.despawn(id);
+                (id, "despawn", Ok(()))
+            }
+            Self::AddComponents(id, data) => {
+                let entity = take_entity(id, data, warnings);
+                let result = world.add_components(id, entity);
+                (id, "add_components", result.map_err(|err| err.to_string()))
+            }
+            Self::RemoveComponents(id, paths) => {
+                let mut components = Vec::new();
+                for path in paths {
+                    match with_component_registry(|r| r.get_by_path(&path)) {
+                        Some(desc) => components.push(desc),
+                        None => warnings.push((id, path.clone(), format!("No such component: {path}"))),
+                    }
+                }
+                let result = world.remove_components(id, components);
+                (id, "remove_components", result.map_err(|err| err.to_string()))
+            }
+            Self::SetComponents(id, data) => {
+                let entity = take_entity(id, data, warnings);
+                let result = world.set_components(id, entity);
+                (id, "set_components", result.map_err(|err| err.to_string()))
+            }
+        };
+
+        if let Err(err) = result {
+            warnings.push((id, change.to_string(), err));
+        }
+    }
+}
+
+fn take_entity(
+    id: EntityId,
+    data: DeserEntityDataWithWarnings,
+    warnings: &mut ECSDeserializationWarnings,
+) -> Entity {
+    warnings.extend(
+        data.warnings
+            .warnings
+            .into_iter()
+            .map(|(_, key, err)| (id, key, err)),
+    );
+    data.entity
+}
@@ -1,4 +1,9 @@
-use std::{any::TypeId, collections::HashMap, time::Duration};
+use std::{
+    any::TypeId,
+    collections::{BTreeMap, HashMap},
+    io::Read,
+    time::Duration,
+};
 
 use glam::{IVec2, IVec3, IVec4, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
 use once_cell::sync::Lazy;
@@ -6,7 +11,8 @@ use paste::paste;
 
 use crate::{
     AttributeConstructor, AttributeStore, ComponentDesc, ComponentRegistry, ComponentVTable,
-    Description, EntityId, ExternalComponentAttributes, Name,
+    Description, EntityId, ExternalComponentAttributes, MessageSerde, MessageSerdeError, Name,
+    Struct,
 };
 
 use ambient_shared_types::primitive_component_definitions;
@@ -44,13 +50,16 @@ macro_rules! make_primitive_component {
             #[derive(serde::Serialize, serde::Deserialize)]
             pub enum PrimitiveComponentContainerType {
                 Vec,
-                Option
+                Option,
+                /// A map with keys of the given type, which is either `String` or `U32`
+                Map(PrimitiveComponentType),
             }
             impl PrimitiveComponentContainerType {
                 pub fn as_str(&self) -> &'static str {
                     match self {
                         Self::Vec => "Vec",
                         Self::Option => "Option",
+                        Self::Map(_) => "Map",
                     }
                 }
             }
@@ -61,6 +70,8 @@ macro_rules! make_primitive_component {
                 $($value), *,
                 $([< Vec $value >]), *,
                 $([< Option$value >]), *,
+                $([< MapString $value >]), *,
+                $([< MapU32 $value >]), *,
             }
 
             impl TryFrom<&str> for PrimitiveComponentType {
@@ -69,7 +80,7 @@ macro_rules! make_primitive_component {
                 fn try_from(value: &str) -> Result<Self, Self::Error> {
                     match value {
                         $(stringify!($value) => Ok(Self::$value),)*
-                        "Vec" | "Option" => Err("The specified type is a container type, not primitive"),
+                        "Vec" | "Option" | "Map" => Err("The specified type is a container type, not primitive"),
                         _ => Err("Unsupported type")
                     }
                 }
@@ -104,15 +115,35 @@ macro_rules! make_primitive_component {
                     }
                 }
 
+                /// Only `String` and `U32` can be used as `key`.
+                pub fn to_map_type(&self, key: Self) -> Option<Self> {
+                    match (key, self) {
+                        $((Self::String, Self::$value) => Some(Self::[<MapString $value>]),)*
+                        $((Self::U32, Self::$value) => Some(Self::[<MapU32 $value>]),)*
+                        _ => None
+                    }
+                }
+
                 pub fn decompose_container_type(&self) -> Option<(PrimitiveComponentContainerType, Self)> {
                     match self {
                         $(Self::[<Vec $value>] => Some((PrimitiveComponentContainerType::Vec, Self::$value)),)*
                         $(Self::[<Option $value>] => Some((PrimitiveComponentContainerType::Option, Self::$value)),)*
+                        $(Self::[<MapString $value>] => Some((PrimitiveComponentContainerType::Map(Self::String), Self::$value)),)*
+                        $(Self::[<MapU32 $value>] => Some((PrimitiveComponentContainerType::Map(Self::U32), Self::$value)),)*
                         _ => None
                     }
                 }
 
-                pub(crate) fn register(&self, reg: &mut ComponentRegistry, path: &str, name: Option<&str>, description: Option<&str>, attributes: ExternalComponentAttributes) {
+                /// Reads a value of this type in the [MessageSerde] format from `input`, to check that it is
+                /// valid. Not supported for the container types.
+                pub fn read_message_part(&self, input: &mut dyn Read) -> Result<(), MessageSerdeError> {
+                    match self {
+                        $(Self::$value => <$type as MessageSerde>::deserialize_message_part(input).map(|_| ()),)*
+                        _ => Err(MessageSerdeError::UnsupportedType),
+                    }
+                }
+
+                pub(crate) fn register(&self, reg: &mut ComponentRegistry, path: &str, name: Option<&str>, description: Option<&str>, attributes: ExternalComponentAttributes, struct_fields: Option<&[PrimitiveComponentType]>) {
                     let mut store = AttributeStore::new();
                     let vtable = match self {
                         $(
@@ -125,8 +156,17 @@ macro_rules! make_primitive_component {
                             PrimitiveComponentType::[< Option $value >] => {
                                 build_attribute_registration!(Option<$type>, store, name, description, attributes)
                             },
+                            PrimitiveComponentType::[< MapString $value >] => {
+                                build_attribute_registration!(BTreeMap<String, $type>, store, name, description, attributes)
+                            },
+                            PrimitiveComponentType::[< MapU32 $value >] => {
+                                build_attribute_registration!(BTreeMap<u32, $type>, store, name, description, attributes)
+                            },
                         )*
                     };
+                    if let Some(fields) = struct_fields {
+                        <Struct as AttributeConstructor<Vec<u8>, _>>::construct(&mut store, fields);
+                    }
 
                     reg.register_external(path.into(), vtable, store);
                 }
@@ -142,6 +182,8 @@ macro_rules! make_primitive_component {
                     $((TypeId::of::<$type>(), PrimitiveComponentType::$value),)*
                     $((TypeId::of::<Vec<$type>>(), PrimitiveComponentType::[<Vec $value>]),)*
                     $((TypeId::of::<Option<$type>>(), PrimitiveComponentType::[<Option $value>]),)*
+                    $((TypeId::of::<BTreeMap<String, $type>>(), PrimitiveComponentType::[<MapString $value>]),)*
+                    $((TypeId::of::<BTreeMap<u32, $type>>(), PrimitiveComponentType::[<MapU32 $value>]),)*
                 ])
             });
         }
@@ -255,18 +255,18 @@ impl<T: ComponentValue> ComponentsTupleAppend<T> for () {
 }
 
 #[derive(Debug, Clone)]
-struct ArchetypesQueryState {
-    archetypes: Vec<usize>,
+pub(super) struct ArchetypesQueryState {
+    pub(super) archetypes: Vec<usize>,
     archetype_index: usize,
 }
 impl ArchetypesQueryState {
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         Self {
             archetypes: Vec::new(),
             archetype_index: 0,
         }
     }
-    fn update_archetypes(&mut self, world: &World, filter: &ArchetypeFilter) {
+    pub(super) fn update_archetypes(&mut self, world: &World, filter: &ArchetypeFilter) {
         for i in self.archetype_index..world.archetypes.len() {
             if filter.matches(&world.archetypes[i].active_components) {
                 self.archetypes.push(i);
@@ -279,6 +279,9 @@ impl ArchetypesQueryState {
 #[derive(Debug, Clone)]
 pub(super) struct ChangeReaders(SparseVec<SparseVec<FramedEventsReader<EntityId>>>);
 impl ChangeReaders {
+    pub(super) fn new() -> Self {
+        Self(SparseVec::new())
+    }
     pub(super) fn get(&mut self, arch: usize, comp: usize) -> &mut FramedEventsReader<EntityId> {
         let a = self.0.get_mut_or_insert_with(arch, SparseVec::new);
         a.get_mut_or_insert_with(comp, FramedEventsReader::new)
@@ -305,12 +308,13 @@ pub struct QueryState {
     pub(super) change_readers: ChangeReaders,
     movein_readers: MoveinReaders,
     moveout_readers: MoveoutReaders,
-    ticker: u64,
+    marks: QueryMarks,
     entered: HashSet<EntityId>,
     world_version: u64,
     entities: Vec<EntityAccessor>,
 
     archetypes: ArchetypesQueryState,
+    relations: Vec<RelationQueryState>,
 }
 impl QueryState {
     pub fn new() -> Self {
@@ -319,15 +323,36 @@ impl QueryState {
             change_readers: ChangeReaders(SparseVec::new()),
             movein_readers: MoveinReaders(SparseVec::new()),
             moveout_readers: MoveoutReaders(SparseVec::new()),
-            ticker: 0,
+            marks: QueryMarks::default(),
             entered: Default::default(),
             world_version: 0,
             entities: Vec::new(),
             archetypes: ArchetypesQueryState::new(),
+            relations: Vec::new(),
         }
     }
     pub(super) fn prepare_for_query(&mut self, world: &World) {
-        self.ticker = world.query_ticker.0.fetch_add(1, Ordering::SeqCst) + 1;
+        self.marks.ticker = world.query_ticker.0.fetch_add(1, Ordering::SeqCst) + 1;
+        self.marks.marked.clear();
+    }
+}
+
+/// Keeps track of the entities returned by an iteration of a query
+#[derive(Debug, Clone, Default)]
+struct QueryMarks {
+    ticker: u64,
+    /// The entities returned by this iteration, when the query runs in a parallel system
+    marked: HashSet<EntityId>,
+}
+impl QueryMarks {
+    /// Returns true if `id` has not been returned by this iteration of the query yet. Queries in
+    /// parallel systems keep track of this themselves, as the markers on the archetypes are shared.
+    fn mark(&mut self, arch: &Archetype, index: usize, id: EntityId) -> bool {
+        if is_running_shared() {
+            self.marked.insert(id)
+        } else {
+            arch.query_mark(index, self.ticker)
+        }
     }
 }
 
@@ -354,6 +379,8 @@ impl QueryEvent {
 pub struct Query {
     pub filter: ArchetypeFilter,
     pub event: QueryEvent,
+    /// The entities must also match all of these relations
+    pub relations: Vec<RelationFilter>,
 }
 
 impl Query {
@@ -361,6 +388,7 @@ impl Query {
         Self {
             filter,
             event: QueryEvent::Frame,
+            relations: Vec::new(),
         }
     }
 
@@ -392,6 +420,7 @@ impl Query {
             } else {
                 QueryEvent::Frame
             },
+            relations: Vec::new(),
         }
     }
 
@@ -458,6 +487,44 @@ impl Query {
             .union_with(&filter.not_components);
         self
     }
+    pub fn related_ref(mut self, relation: RelationFilter) -> Self {
+        self.filter = self.filter.incl_ref(relation.component);
+        if !relation.changed.is_empty() && self.event.is_frame() {
+            self.event = QueryEvent::Changed { components: vec![] };
+        }
+        self.relations.push(relation);
+        self
+    }
+    /// The entities must refer to an entity matching `relation` through its component. If the
+    /// relation tracks changes, this turns the query into a change query.
+    pub fn related(self, relation: RelationFilter) -> Self {
+        self.related_ref(relation)
+    }
+    fn matches_relations(&self, world: &World, id: EntityId) -> bool {
+        self.relations
+            .iter()
+            .all(|relation| relation.matches_entity(world, id))
+    }
+    /// The entities in the archetypes of the query which match its relations
+    fn related_entities(&self, world: &World, state: &QueryState) -> Vec<EntityId> {
+        state
+            .archetypes
+            .archetypes
+            .iter()
+            .flat_map(|i| world.archetypes[*i].entity_indices_to_ids.iter().copied())
+            .filter(|&id| self.matches_relations(world, id))
+            .collect()
+    }
+    fn push_marked(world: &World, state: &mut QueryState, id: EntityId) {
+        if let Some(loc) = world.locs.get(&id) {
+            if state
+                .marks
+                .mark(&world.archetypes[loc.archetype], loc.index, id)
+            {
+                state.entities.push(EntityAccessor::World { id });
+            }
+        }
+    }
     fn get_changed(&self, world: &World, state: &mut QueryState, components: &Vec<ComponentDesc>) {
         if !state.inited && !world.ignore_query_inits {
             for arch in state
@@ -490,7 +557,8 @@ impl Query {
                         if let Some(loc) = world.locs.get(&entity_id) {
                             if loc.archetype == arch.id
                                 && arch_comp.get_content_version(loc.index) > state.world_version
-                                && arch.query_mark(loc.index, state.ticker)
+                                && self.matches_relations(world, entity_id)
+                                && state.marks.mark(arch, loc.index, entity_id)
                             {
                                 state.entities.push(EntityAccessor::World { id: entity_id });
                             }
@@ -500,7 +568,94 @@ impl Query {
             }
         }
     }
+    /// Finds the entities whose related entities had their tracked components changed
+    fn get_related_changed(&self, world: &World, state: &mut QueryState) {
+        let init = !state.inited && !world.ignore_query_inits;
+        let world_version = state.world_version;
+        state
+            .relations
+            .resize_with(self.relations.len(), RelationQueryState::new);
+
+        let mut sources = Vec::new();
+        for (relation, relation_state) in self.relations.iter().zip(&mut state.relations) {
+            if relation.changed.is_empty() {
+                continue;
+            }
+            let changed = relation_state.changed_targets(world, relation, world_version, init);
+            if changed.is_empty() {
+                continue;
+            }
+            sources.extend(
+                state
+                    .archetypes
+                    .archetypes
+                    .iter()
+                    .flat_map(|i| world.archetypes[*i].entity_indices_to_ids.iter().copied())
+                    .filter(|&id| {
+                        relation
+                            .targets(world, id)
+                            .iter()
+                            .any(|target| changed.contains(target))
+                    }),
+            );
+        }
+        for id in sources {
+            if self.matches_relations(world, id) {
+                Self::push_marked(world, state, id);
+            }
+        }
+    }
+    /// Relations can start or stop matching without the entity changing archetype, so the entities
+    /// of relational queries are compared to the ones of the previous evaluation instead
+    fn get_related_spawned(&self, world: &World, state: &mut QueryState) {
+        if !self.init_state_event_readers(world, state) {
+            state.entities.clear();
+        }
+        let related = self.related_entities(world, state);
+        for &id in &related {
+            if state.entered.insert(id) {
+                Self::push_marked(world, state, id);
+            }
+        }
+        let related: HashSet<_> = related.into_iter().collect();
+        state.entered.retain(|id| related.contains(id));
+    }
+    fn get_related_despawned(&self, world: &World, state: &mut QueryState) {
+        let init = self.init_state_event_readers(world, state);
+        let related: HashSet<_> = self.related_entities(world, state).into_iter().collect();
+        if init {
+            state.entered = related;
+            return;
+        }
+
+        state.entities.clear();
+        for arch in state
+            .archetypes
+            .archetypes
+            .iter()
+            .map(|i| &world.archetypes[*i])
+        {
+            let read = state.moveout_readers.get(arch.id);
+            for (event_id, (id, _)) in read.iter(&arch.moveout_events) {
+                if !related.contains(id) && state.entered.remove(id) {
+                    state.entities.push(EntityAccessor::Despawned {
+                        id: *id,
+                        archetype: arch.id,
+                        event_id,
+                    });
+                }
+            }
+        }
+        // These entities are still in the query's archetypes, but their relations no longer match
+        for &id in state.entered.difference(&related) {
+            state.entities.push(EntityAccessor::World { id });
+        }
+        state.entered = related;
+    }
     fn get_spawned(&self, world: &World, state: &mut QueryState) {
+        if !self.relations.is_empty() {
+            return self.get_related_spawned(world, state);
+        }
         if self.init_state_event_readers(world, state) {
             state.entities.extend(self.filter.iter_entities(world));
             for ea in state.entities.iter() {
@@ -521,7 +676,9 @@ impl Query {
                 if let Some(loc) = world.locs.get(id) {
                     if loc.archetype == arch.id && state.entered.insert(*id) {
                         let process =
-                            world.archetypes[loc.archetype].query_mark(loc.index, state.ticker);
+                            state
+                                .marks
+                                .mark(&world.archetypes[loc.archetype], loc.index, *id);
                         if process {
                             state.entities.push(EntityAccessor::World { id: *id });
                         }
@@ -537,6 +694,9 @@ impl Query {
         }
     }
     fn get_despawned(&self, world: &World, state: &mut QueryState) {
+        if !self.relations.is_empty() {
+            return self.get_related_despawned(world, state);
+        }
         if self.init_state_event_readers(world, state) {
             return;
         }
@@ -593,16 +753,26 @@ impl Query {
             state.archetypes.update_archetypes(world, &self.filter);
         }
         if let QueryEvent::Frame = &self.event {
-            if let Some(state) = state {
-                return Box::new(state.archetypes.archetypes.iter().flat_map(|i| {
+            let entities: Box<dyn Iterator<Item = EntityAccessor> + 'a> = if let Some(state) = state
+            {
+                Box::new(state.archetypes.archetypes.iter().flat_map(|i| {
                     world.archetypes[*i]
                         .entity_indices_to_ids
                         .iter()
                         .map(move |&id| EntityAccessor::World { id })
-                }));
+                }))
             } else {
-                return Box::new(self.filter.iter_entities(world));
+                Box::new(self.filter.iter_entities(world))
+            };
+            if self.relations.is_empty() {
+                return entities;
             }
+            let relations = self.relations.clone();
+            return Box::new(entities.filter(move |ea| {
+                relations
+                    .iter()
+                    .all(|relation| relation.matches_entity(world, ea.id()))
+            }));
         }
 
         let state = state.expect("Spawn/despawn/change queries must have a query state");
@@ -613,6 +783,7 @@ impl Query {
             QueryEvent::Changed { components } => {
                 self.get_spawned(world, state);
                 self.get_changed(world, state, components);
+                self.get_related_changed(world, state);
             }
             QueryEvent::Spawned => self.get_spawned(world, state),
             QueryEvent::Despawned => self.get_despawned(world, state),
@@ -663,6 +834,10 @@ impl Query {
     }
     fn add_component(&mut self, query: &Self, component: ComponentDesc) {
         self.filter = query.filter.clone().incl(component);
+        self.relations = query.relations.clone();
+        if self.event.is_frame() && self.relations.iter().any(|r| !r.changed.is_empty()) {
+            self.event = QueryEvent::Changed { components: vec![] };
+        }
         if query.event.is_spawned() {
             self.event = QueryEvent::Spawned;
         } else if query.event.is_despawned() {
@@ -799,6 +974,11 @@ impl<'a, R: ComponentQuery<'a> + Clone + 'static> TypedReadQuery<R> {
         self.query = self.query.optional_changed(component.into());
         self
     }
+    /// The entities must refer to an entity matching `relation`. See [`Query::related`].
+    pub fn related(mut self, relation: RelationFilter) -> Self {
+        self.query = self.query.related(relation);
+        self
+    }
     pub fn spawned(mut self) -> Self {
         self.query.event = QueryEvent::Spawned;
         self
@@ -878,6 +1058,40 @@ impl<'a, R: ComponentQuery<'a> + Clone + 'static> TypedReadQuery<R> {
         })))
     }
 
+    /// The components the query reads
+    pub fn access(&self) -> SystemAccess {
+        let mut reads = ComponentSet::new();
+        self.read_components.write_component_ids(&mut reads);
+        SystemAccess::from_query(&self.query, reads, ComponentSet::new())
+    }
+    /// Like [`Self::iter`], for systems created with [`Self::to_parallel_system`].
+    ///
+    /// Panics if the query reads components outside of the access of the world.
+    pub fn iter_shared(
+        &self,
+        world: &'a SharedWorld,
+        state: Option<&'a mut QueryState>,
+    ) -> impl Iterator<Item = (EntityId, <R as ComponentQuery<'a>>::Data)> + 'a {
+        self.iter(world.world_for(&self.access()), state)
+    }
+    /// Creates a system which can run in parallel with the systems next to it, as long as they do
+    /// not write the components of the query. The system can only access the world through
+    /// [`SharedWorld`], using [`Self::iter_shared`].
+    pub fn to_parallel_system<
+        F: FnMut(&Self, &mut SharedWorld, Option<&mut QueryState>, &E) + Send + Sync + 'static,
+        E: 'static,
+    >(
+        self,
+        name: &'static str,
+        mut update: F,
+    ) -> DynSystem<E> {
+        let mut state = QueryState::new();
+        Box::new(ParallelSystem::new(self.access(), move |world, event| {
+            profiling::scope!(name);
+            update(&self, world, Some(&mut state), event);
+        }))
+    }
+
     pub fn with_commands<F, E>(self, update: F) -> DynSystem<E>
     where
         F: Fn(&Self, &mut World, Option<&mut QueryState>, &E, &mut Commands)
@@ -976,6 +1190,11 @@ impl<'a, RW: ComponentQuery<'a> + Clone + 'static, R: ComponentQuery<'a> + Clone
         self.query = self.query.optional_changed(component.desc());
         self
     }
+    /// The entities must refer to an entity matching `relation`. See [`Query::related`].
+    pub fn related(mut self, relation: RelationFilter) -> Self {
+        self.query = self.query.related(relation);
+        self
+    }
     pub fn spawned(mut self) -> Self {
         self.query.event = QueryEvent::Spawned;
         self
@@ -995,10 +1214,46 @@ impl<'a, RW: ComponentQuery<'a> + Clone + 'static, R: ComponentQuery<'a> + Clone
             <RW as ComponentQuery<'a>>::DataMut,
             <R as ComponentQuery<'a>>::Data,
         ),
+    > + 'a {
+        // Safety: the world is borrowed exclusively
+        unsafe { self.iter_unchecked(world, state) }
+    }
+    /// Like [`Self::iter`], for systems created with [`Self::to_parallel_system`]. The view is
+    /// borrowed exclusively while iterating, so the components can't be accessed in other ways.
+    ///
+    /// Panics if the query accesses components outside of the access of the world.
+    pub fn iter_shared(
+        &self,
+        world: &'a mut SharedWorld,
+        state: Option<&'a mut QueryState>,
+    ) -> impl Iterator<
+        Item = (
+            EntityId,
+            <RW as ComponentQuery<'a>>::DataMut,
+            <R as ComponentQuery<'a>>::Data,
+        ),
+    > + 'a {
+        let world = world.world_for(&self.access());
+        // Safety: the view only hands out references to the components of its access through
+        // borrows of itself, and the systems that run at the same time don't access them
+        unsafe { self.iter_unchecked(world, state) }
+    }
+    /// # Safety
+    ///
+    /// Nothing else may access the components of the query while the iterator is alive.
+    unsafe fn iter_unchecked(
+        &self,
+        world: &'a World,
+        state: Option<&'a mut QueryState>,
+    ) -> impl Iterator<
+        Item = (
+            EntityId,
+            <RW as ComponentQuery<'a>>::DataMut,
+            <R as ComponentQuery<'a>>::Data,
+        ),
     > + 'a {
         let rw = self.read_write_components.clone();
         let r = self.read_components.clone();
-        let world = &*world;
         self.query.iter(world, state).map(move |acc| {
             (
                 acc.id(),
@@ -1007,6 +1262,31 @@ impl<'a, RW: ComponentQuery<'a> + Clone + 'static, R: ComponentQuery<'a> + Clone
             )
         })
     }
+    /// The components the query reads and writes
+    pub fn access(&self) -> SystemAccess {
+        let mut reads = ComponentSet::new();
+        let mut writes = ComponentSet::new();
+        self.read_components.write_component_ids(&mut reads);
+        self.read_write_components.write_component_ids(&mut writes);
+        SystemAccess::from_query(&self.query, reads, writes)
+    }
+    /// Creates a system which can run in parallel with the systems next to it, as long as they do
+    /// not access the components of the query. The system can only access the world through
+    /// [`SharedWorld`], using [`Self::iter_shared`].
+    pub fn to_parallel_system<
+        F: FnMut(&Self, &mut SharedWorld, Option<&mut QueryState>, &E) + Send + Sync + 'static,
+        E: 'static,
+    >(
+        self,
+        name: &'static str,
+        mut update: F,
+    ) -> DynSystem<E> {
+        let mut state = QueryState::new();
+        Box::new(ParallelSystem::new(self.access(), move |world, event| {
+            profiling::scope!(name);
+            update(&self, world, Some(&mut state), event);
+        }))
+    }
     pub fn to_system<
         F: Fn(&Self, &mut World, Option<&mut QueryState>, &E) + Send + Sync + 'static,
         E: 'static,
@@ -1052,6 +1332,25 @@ pub struct FrameEvent;
 
 pub trait System<E = FrameEvent>: Send + std::fmt::Debug {
     fn run(&mut self, world: &mut World, event: &E);
+    /// The system, if it only needs shared access to the world. Such systems can be run in
+    /// parallel by a [`SystemGroup`]; see [`SystemAccess`].
+    fn as_shared(&mut self) -> Option<&mut dyn SharedSystem<E>> {
+        None
+    }
+}
+
+/// A system which can run with shared access to the world, at the same time as other systems whose
+/// access does not conflict with its own
+pub trait SharedSystem<E = FrameEvent>: Send {
+    /// The components the system accesses
+    fn access(&self) -> &SystemAccess;
+    /// Runs the system. It may only access the components in [`Self::access`].
+    ///
+    /// # Safety
+    ///
+    /// Nothing else may access the components in [`Self::access`] while the system runs, unless
+    /// both only read them.
+    unsafe fn run_shared(&mut self, world: &World, event: &E);
 }
 
 pub struct FnSystem<E = FrameEvent>(Box<dyn FnMut(&mut World, &E) + Sync + Send>);
@@ -1091,12 +1390,20 @@ impl<E> SystemGroup<E> {
     }
 }
 
-impl<E> System<E> for SystemGroup<E> {
+impl<E: Sync> System<E> for SystemGroup<E> {
     fn run(&mut self, world: &mut World, event: &E) {
         profiling::scope!("SystemGroup::run", &self.0);
         let _span = tracing::debug_span!("SystemGroup::run", "{}", &self.0).entered();
-        for system in self.1.iter_mut() {
-            system.run(world, event);
+        let mut systems = &mut self.1[..];
+        while !systems.is_empty() {
+            let len = parallel_batch_len(systems);
+            let (batch, rest) = systems.split_at_mut(len);
+            if let [system] = batch {
+                system.run(world, event);
+            } else {
+                run_parallel(batch, world, event);
+            }
+            systems = rest;
         }
     }
 }
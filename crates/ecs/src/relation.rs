@@ -0,0 +1,145 @@
+use super::*;
+
+/// A filter on the entities that an entity refers to through one of its components, which is
+/// used to join queries across relations such as `parent` and `children`.
+///
+/// The relation component must be an `EntityId` or a `Vec<EntityId>`. An entity matches if at least
+/// one of the entities it refers to matches the filter.
+#[derive(Debug, Clone)]
+pub struct RelationFilter {
+    pub component: ComponentDesc,
+    pub filter: ArchetypeFilter,
+    /// Changes to these components of the related entities trigger change queries
+    pub changed: Vec<ComponentDesc>,
+}
+
+impl RelationFilter {
+    /// Follows `component`, which must be an `EntityId` or `Vec<EntityId>` component
+    pub fn new(component: impl Into<ComponentDesc>) -> Self {
+        let component = component.into();
+        if !Self::is_relation(component) {
+            panic!(
+                "Relations must be EntityId or Vec<EntityId> components, but {} is a {}",
+                component.path(),
+                component.type_name()
+            );
+        }
+        Self {
+            component,
+            filter: ArchetypeFilter::new(),
+            changed: Vec::new(),
+        }
+    }
+
+    /// Returns true if `component` can be followed by a relation
+    pub fn is_relation(component: ComponentDesc) -> bool {
+        component.is::<EntityId>() || component.is::<Vec<EntityId>>()
+    }
+
+    pub fn incl(mut self, component: impl Into<ComponentDesc>) -> Self {
+        self.filter = self.filter.incl(component);
+        self
+    }
+
+    pub fn excl(mut self, component: impl Into<ComponentDesc>) -> Self {
+        self.filter = self.filter.excl(component);
+        self
+    }
+
+    /// Changes to this component of a related entity trigger the query for the entities that
+    /// refer to it. The component is not required.
+    pub fn when_changed(mut self, component: impl Into<ComponentDesc>) -> Self {
+        self.changed.push(component.into());
+        self
+    }
+
+    /// The entities that `id` refers to
+    pub fn targets<'a>(&self, world: &'a World, id: EntityId) -> &'a [EntityId] {
+        relation_targets(world, self.component, id)
+    }
+
+    /// Returns true if `id` refers to an entity which matches the filter
+    pub fn matches_entity(&self, world: &World, id: EntityId) -> bool {
+        self.targets(world, id)
+            .iter()
+            .any(|&target| self.filter.matches_entity(world, target))
+    }
+}
+
+/// The entities that `id` refers to through `component`, which must be an `EntityId` or a
+/// `Vec<EntityId>` component
+pub(crate) fn relation_targets(
+    world: &World,
+    component: ComponentDesc,
+    id: EntityId,
+) -> &[EntityId] {
+    if component.is::<EntityId>() {
+        world
+            .get_ref(id, Component::<EntityId>::new(component))
+            .map(std::slice::from_ref)
+            .unwrap_or_default()
+    } else {
+        world
+            .get_ref(id, Component::<Vec<EntityId>>::new(component))
+            .map(|targets| targets.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// The state of a query for one of its relations
+#[derive(Debug, Clone)]
+pub(super) struct RelationQueryState {
+    pub(super) archetypes: ArchetypesQueryState,
+    pub(super) change_readers: ChangeReaders,
+}
+
+impl RelationQueryState {
+    pub(super) fn new() -> Self {
+        Self {
+            archetypes: ArchetypesQueryState::new(),
+            change_readers: ChangeReaders::new(),
+        }
+    }
+
+    /// Returns the related entities whose `relation.changed` components changed since
+    /// `world_version`
+    pub(super) fn changed_targets(
+        &mut self,
+        world: &World,
+        relation: &RelationFilter,
+        world_version: u64,
+        init: bool,
+    ) -> HashSet<EntityId> {
+        self.archetypes.update_archetypes(world, &relation.filter);
+
+        let mut changed = HashSet::new();
+        for arch in self
+            .archetypes
+            .archetypes
+            .iter()
+            .map(|i| &world.archetypes[*i])
+        {
+            for comp in &relation.changed {
+                let Some(arch_comp) = arch.components.get(comp.index() as _) else {
+                    continue;
+                };
+                let read = self.change_readers.get(arch.id, comp.index() as _);
+                let events = &*arch_comp.changes.borrow();
+                if init {
+                    read.move_to_end(events);
+                    continue;
+                }
+                for (_, &id) in read.iter(events) {
+                    if let Some(loc) = world.locs.get(&id) {
+                        if loc.archetype == arch.id
+                            && arch_comp.get_content_version(loc.index) > world_version
+                        {
+                            changed.insert(id);
+                        }
+                    }
+                }
+            }
+        }
+        changed
+    }
+}
@@ -0,0 +1,307 @@
+use super::*;
+
+/// The edges of a single [`Relationship`] component, in both directions
+#[derive(Debug, Clone)]
+pub struct RelationshipIndex {
+    component: ComponentDesc,
+    targets: HashMap<EntityId, Vec<EntityId>>,
+    dependents: HashMap<EntityId, Vec<EntityId>>,
+}
+
+impl RelationshipIndex {
+    pub fn new(component: impl Into<ComponentDesc>) -> Self {
+        Self {
+            component: component.into(),
+            targets: HashMap::new(),
+            dependents: HashMap::new(),
+        }
+    }
+
+    pub fn component(&self) -> ComponentDesc {
+        self.component
+    }
+
+    /// The entities that `id` refers to
+    pub fn targets(&self, id: EntityId) -> &[EntityId] {
+        self.targets
+            .get(&id)
+            .map(|x| x.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The entities that refer to `target`
+    pub fn dependents(&self, target: EntityId) -> &[EntityId] {
+        self.dependents
+            .get(&target)
+            .map(|x| x.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl EntityIndex for RelationshipIndex {
+    type Key = Vec<EntityId>;
+
+    fn components(&self) -> Vec<ComponentDesc> {
+        vec![self.component]
+    }
+    fn try_key_from_entity(&self, world: &World, id: EntityId) -> Option<Self::Key> {
+        Some(relation_targets(world, self.component, id).to_vec())
+    }
+    fn insert_key(&mut self, id: EntityId, targets: Self::Key) {
+        for &target in &targets {
+            let dependents = self.dependents.entry(target).or_default();
+            if !dependents.contains(&id) {
+                dependents.push(id);
+            }
+        }
+        self.targets.insert(id, targets);
+    }
+    fn remove_entity(&mut self, id: EntityId) -> bool {
+        let Some(targets) = self.targets.remove(&id) else {
+            return false;
+        };
+        for target in targets {
+            if let Some(dependents) = self.dependents.get_mut(&target) {
+                dependents.retain(|&x| x != id);
+                if dependents.is_empty() {
+                    self.dependents.remove(&target);
+                }
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RelationshipEdges {
+    on_target_despawn: OnTargetDespawn,
+    index: RelationshipIndex,
+    updater: IndexUpdater,
+}
+
+/// Reverse lookups for all of the [`Relationship`] components, which are kept on the world as the
+/// [`relationships`] resource and brought up to date by [`relationships_system`].
+///
+/// The lookups reflect the world as of the last update; use [`sync_relationships`] to bring them up
+/// to date in the middle of a frame.
+#[derive(Debug, Clone)]
+pub struct Relationships {
+    edges: Vec<RelationshipEdges>,
+    registered_components: usize,
+    despawned: (Query, QueryState),
+}
+
+impl Default for Relationships {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Relationships {
+    pub fn new() -> Self {
+        Self {
+            edges: Vec::new(),
+            registered_components: 0,
+            despawned: (Query::all().despawned(), QueryState::new()),
+        }
+    }
+
+    /// The index of a [`Relationship`] component
+    pub fn get(&self, component: impl Into<ComponentDesc>) -> Option<&RelationshipIndex> {
+        let component = component.into();
+        self.edges
+            .iter()
+            .find(|edges| edges.index.component == component)
+            .map(|edges| &edges.index)
+    }
+
+    /// The entities that refer to `target` through `component`
+    pub fn dependents(&self, component: impl Into<ComponentDesc>, target: EntityId) -> &[EntityId] {
+        self.get(component)
+            .map(|index| index.dependents(target))
+            .unwrap_or_default()
+    }
+
+    /// Starts following the relationship components registered since the last update, and brings
+    /// the edges up to date
+    fn update(&mut self, world: &World) {
+        let components = with_component_registry(|cr| {
+            cr.all()
+                .skip(self.registered_components)
+                .collect::<Vec<_>>()
+        });
+        self.registered_components += components.len();
+        for component in components {
+            if let Some(relationship) = component.attribute::<Relationship>() {
+                self.edges.push(RelationshipEdges {
+                    on_target_despawn: relationship.0,
+                    index: RelationshipIndex::new(component),
+                    updater: IndexUpdater::new(ArchetypeFilter::new(), &[component]),
+                });
+            }
+        }
+
+        for edges in &mut self.edges {
+            edges.updater.update(world, &mut edges.index);
+        }
+    }
+
+    /// The entities despawned since the last time this was called
+    fn take_despawned(&mut self, world: &World) -> Vec<EntityId> {
+        let (q, qs) = &mut self.despawned;
+        q.iter(world, Some(qs))
+            .map(|ea| ea.id())
+            .filter(|&id| !world.exists(id))
+            .unique()
+            .collect()
+    }
+
+    /// `roots` and the entities that depend on them through [`OnTargetDespawn::DespawnDependents`]
+    fn despawn_set(&self, roots: Vec<EntityId>) -> (Vec<EntityId>, HashSet<EntityId>) {
+        let mut ids = roots;
+        let mut seen = ids.iter().copied().collect::<HashSet<_>>();
+        let mut i = 0;
+        while i < ids.len() {
+            let id = ids[i];
+            i += 1;
+            for edges in &self.edges {
+                if edges.on_target_despawn != OnTargetDespawn::DespawnDependents {
+                    continue;
+                }
+                for &dependent in edges.index.dependents(id) {
+                    if seen.insert(dependent) {
+                        ids.push(dependent);
+                    }
+                }
+            }
+        }
+        (ids, seen)
+    }
+
+    fn check_blocked(
+        &self,
+        ids: &[EntityId],
+        despawned: &HashSet<EntityId>,
+    ) -> Result<(), DespawnBlocked> {
+        for &id in ids {
+            for edges in &self.edges {
+                if edges.on_target_despawn != OnTargetDespawn::Block {
+                    continue;
+                }
+                let dependents = edges.index.dependents(id);
+                if let Some(&dependent) = dependents.iter().find(|x| !despawned.contains(x)) {
+                    return Err(DespawnBlocked {
+                        entity_id: id,
+                        dependent,
+                        component_path: edges.index.component.path(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the references to `ids` from the entities which are not despawned with them, and
+    /// despawns them. All of the changes go through the world, so that they are streamed to clients.
+    fn despawn(&self, world: &mut World, ids: &[EntityId], despawned: &HashSet<EntityId>) {
+        for &id in ids {
+            for edges in &self.edges {
+                let component = edges.index.component;
+                for &dependent in edges.index.dependents(id) {
+                    if despawned.contains(&dependent) {
+                        continue;
+                    }
+                    if edges.on_target_despawn == OnTargetDespawn::Block {
+                        tracing::warn!(
+                            "{id} was despawned while {dependent} refers to it through {}",
+                            component.path()
+                        );
+                    }
+                    if component.is::<EntityId>() {
+                        world.remove_component(dependent, component).ok();
+                    } else {
+                        let component = Component::<Vec<EntityId>>::new(component);
+                        if let Ok(targets) = world.get_ref(dependent, component) {
+                            let targets = targets.iter().copied().filter(|&x| x != id).collect();
+                            world.set(dependent, component, targets).ok();
+                        }
+                    }
+                }
+            }
+        }
+        for &id in ids {
+            world.despawn(id);
+        }
+    }
+}
+
+/// Returned by [`despawn_with_relationships`] when an entity can't be despawned because another
+/// entity refers to it through a relationship with [`OnTargetDespawn::Block`]
+#[derive(Debug, Clone, Error, PartialEq)]
+#[error("Can't despawn {entity_id}, because {dependent} refers to it through {component_path}")]
+pub struct DespawnBlocked {
+    pub entity_id: EntityId,
+    pub dependent: EntityId,
+    pub component_path: String,
+}
+
+fn with_relationships<R>(
+    world: &mut World,
+    func: impl FnOnce(&mut World, &mut Relationships) -> R,
+) -> R {
+    if !world.has_component(world.resource_entity(), relationships()) {
+        world.add_resource(relationships(), Relationships::new());
+    }
+    let mut state = std::mem::take(world.resource_mut(relationships()));
+    state.update(world);
+    let despawned = state.take_despawned(world);
+    if !despawned.is_empty() {
+        let (ids, despawned) = state.despawn_set(despawned);
+        state.despawn(world, &ids, &despawned);
+        state.update(world);
+    }
+    let result = func(world, &mut state);
+    state.update(world);
+    *world.resource_mut(relationships()) = state;
+    result
+}
+
+/// Brings the [`relationships`] up to date, and cleans up after the entities which were despawned
+/// with [`World::despawn`] since the last update. References to them through relationships with
+/// [`OnTargetDespawn::Block`] are cleared, as it is too late to block the despawn.
+pub fn sync_relationships(world: &mut World) {
+    with_relationships(world, |_, _| {});
+}
+
+/// Despawns `id` along with the entities that depend on it, according to the [`OnTargetDespawn`] of
+/// the relationships that refer to it, and returns the ids of the despawned entities.
+///
+/// Nothing is despawned if any of the entities is the target of a relationship with
+/// [`OnTargetDespawn::Block`] from an entity which is not despawned.
+pub fn despawn_with_relationships(
+    world: &mut World,
+    id: EntityId,
+) -> Result<Vec<EntityId>, DespawnBlocked> {
+    if !world.exists(id) {
+        return Ok(Vec::new());
+    }
+    with_relationships(world, |world, relationships| {
+        let (ids, despawned) = relationships.despawn_set(vec![id]);
+        relationships.check_blocked(&ids, &despawned)?;
+        relationships.despawn(world, &ids, &despawned);
+        Ok(ids)
+    })
+}
+
+/// Keeps the [`relationships`] up to date, and cleans up after despawned entities.
+///
+/// This should only run on the server. The cleanup changes the world, which would make replicated
+/// worlds diverge from the server.
+pub fn relationships_system() -> SystemGroup {
+    SystemGroup::new(
+        "relationships",
+        vec![Box::new(FnSystem::new(|world, _| {
+            sync_relationships(world);
+        }))],
+    )
+}
@@ -0,0 +1,247 @@
+use std::cell::Cell;
+
+use super::*;
+
+thread_local! {
+    static RUNNING_SHARED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns true if the current thread is running a system with shared access to the world, in
+/// which case queries can't use the markers shared by all queries on the world
+pub(super) fn is_running_shared() -> bool {
+    RUNNING_SHARED.with(|running| running.get())
+}
+
+/// The components a system reads and writes.
+///
+/// A [`SystemGroup`] runs consecutive systems with an access in parallel, as long as none of them
+/// writes a component that another one reads or writes. Systems without an access run on their own,
+/// in order, so adding an access never changes the order in which systems see each other's changes.
+#[derive(Debug, Clone)]
+pub struct SystemAccess {
+    pub reads: ComponentSet,
+    pub writes: ComponentSet,
+}
+
+impl Default for SystemAccess {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemAccess {
+    pub fn new() -> Self {
+        Self {
+            reads: ComponentSet::new(),
+            writes: ComponentSet::new(),
+        }
+    }
+
+    pub fn read(mut self, component: impl Into<ComponentDesc>) -> Self {
+        self.reads.insert(component.into());
+        self
+    }
+
+    pub fn write(mut self, component: impl Into<ComponentDesc>) -> Self {
+        self.writes.insert(component.into());
+        self
+    }
+
+    /// Returns true if the systems with these accesses can't run at the same time
+    pub fn conflicts_with(&self, other: &SystemAccess) -> bool {
+        !self.writes.is_disjoint(&other.writes)
+            || !self.writes.is_disjoint(&other.reads)
+            || !self.reads.is_disjoint(&other.writes)
+    }
+
+    pub fn union_with(&mut self, other: &SystemAccess) {
+        self.reads.union_with(&other.reads);
+        self.writes.union_with(&other.writes);
+    }
+
+    /// Returns true if everything that `other` reads or writes is allowed by this access
+    pub fn allows(&self, other: &SystemAccess) -> bool {
+        let mut readable = self.reads.clone();
+        readable.union_with(&self.writes);
+        readable.is_superset(&other.reads) && self.writes.is_superset(&other.writes)
+    }
+
+    /// The access of a system which iterates `query` and writes `writes`. The components that the
+    /// query reads to find changes or follow relations are also read.
+    pub fn from_query(query: &Query, reads: ComponentSet, writes: ComponentSet) -> Self {
+        let mut access = Self { reads, writes };
+        if let QueryEvent::Changed { components } = &query.event {
+            for &component in components {
+                access.reads.insert(component);
+            }
+        }
+        for relation in &query.relations {
+            access.reads.insert(relation.component);
+            for &component in &relation.changed {
+                access.reads.insert(component);
+            }
+        }
+        access.reads.0.difference_with(&access.writes.0);
+        access
+    }
+}
+
+/// Shared access to the world, limited to the components in a [`SystemAccess`]. This is what
+/// [`ParallelSystem`]s see of the world, as other systems may access it at the same time.
+///
+/// Components outside of the access can't be accessed, and components can only be mutated through
+/// an exclusive borrow of the view, so that the references it hands out never alias.
+pub struct SharedWorld<'a> {
+    world: &'a World,
+    access: &'a SystemAccess,
+}
+
+impl<'a> SharedWorld<'a> {
+    pub(super) fn new(world: &'a World, access: &'a SystemAccess) -> Self {
+        Self { world, access }
+    }
+
+    pub fn access(&self) -> &SystemAccess {
+        self.access
+    }
+
+    pub fn exists(&self, entity_id: EntityId) -> bool {
+        self.world.exists(entity_id)
+    }
+
+    pub fn has_component(&self, entity_id: EntityId, component: impl Into<ComponentDesc>) -> bool {
+        self.world.has_component(entity_id, component)
+    }
+
+    pub fn get<T: Copy + ComponentValue>(
+        &self,
+        entity_id: EntityId,
+        component: Component<T>,
+    ) -> Result<T, ECSError> {
+        self.get_ref(entity_id, component).copied()
+    }
+
+    pub fn get_ref<T: ComponentValue>(
+        &self,
+        entity_id: EntityId,
+        component: Component<T>,
+    ) -> Result<&T, ECSError> {
+        let desc = component.desc();
+        if !self.access.reads.contains(desc) && !self.access.writes.contains(desc) {
+            return Err(ECSError::AccessNotDeclared {
+                component_path: desc.path(),
+            });
+        }
+        self.world.get_ref(entity_id, component)
+    }
+
+    pub fn get_mut<T: ComponentValue>(
+        &mut self,
+        entity_id: EntityId,
+        component: Component<T>,
+    ) -> Result<&mut T, ECSError> {
+        let desc = component.desc();
+        if !self.access.writes.contains(desc) {
+            return Err(ECSError::AccessNotDeclared {
+                component_path: desc.path(),
+            });
+        }
+        self.world.get_mut_unsafe(entity_id, component)
+    }
+
+    /// The world, for queries whose access has been checked against the view
+    pub(super) fn world_for(&self, access: &SystemAccess) -> &'a World {
+        assert!(
+            self.access.allows(access),
+            "A query accesses components outside of the access declared by its system"
+        );
+        self.world
+    }
+}
+
+/// A system which only accesses the world through the components in its [`SystemAccess`], and can
+/// therefore run in parallel with other systems. Structural changes, such as spawning entities or
+/// adding components, need exclusive access to the world and can't be made by parallel systems.
+pub struct ParallelSystem<E = FrameEvent> {
+    access: SystemAccess,
+    func: Box<dyn FnMut(&mut SharedWorld, &E) + Sync + Send>,
+}
+
+impl<E> ParallelSystem<E> {
+    pub fn new<F>(access: SystemAccess, func: F) -> Self
+    where
+        F: FnMut(&mut SharedWorld, &E) + Send + Sync + 'static,
+    {
+        Self {
+            access,
+            func: Box::new(func),
+        }
+    }
+}
+
+impl<E> System<E> for ParallelSystem<E> {
+    fn run(&mut self, world: &mut World, event: &E) {
+        // Safety: the world is borrowed exclusively
+        unsafe { self.run_shared(world, event) };
+    }
+    fn as_shared(&mut self) -> Option<&mut dyn SharedSystem<E>> {
+        Some(self)
+    }
+}
+
+impl<E> SharedSystem<E> for ParallelSystem<E> {
+    fn access(&self) -> &SystemAccess {
+        &self.access
+    }
+    unsafe fn run_shared(&mut self, world: &World, event: &E) {
+        (self.func)(&mut SharedWorld::new(world, &self.access), event);
+    }
+}
+
+impl<E> std::fmt::Debug for ParallelSystem<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParallelSystem")
+            .field("access", &self.access)
+            .finish()
+    }
+}
+
+/// The number of systems at the start of `systems` which can run in parallel. At least one.
+pub(super) fn parallel_batch_len<E>(systems: &mut [DynSystem<E>]) -> usize {
+    let mut batch_access = SystemAccess::new();
+    for (i, system) in systems.iter_mut().enumerate() {
+        match system.as_shared().map(|system| system.access()) {
+            Some(access) if !batch_access.conflicts_with(access) => batch_access.union_with(access),
+            _ => return i.max(1),
+        }
+    }
+    systems.len()
+}
+
+/// Runs systems which do not conflict with each other with shared access to the world, spread over
+/// the threads of the global thread pool
+pub(super) fn run_parallel<E: Sync>(systems: &mut [DynSystem<E>], world: &World, event: &E) {
+    let systems = systems.iter_mut().filter_map(|system| system.as_shared());
+
+    #[cfg(not(target_os = "unknown"))]
+    {
+        use rayon::prelude::*;
+        systems
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .for_each(|system| run_shared(system, world, event));
+    }
+    // There are no threads on the web
+    #[cfg(target_os = "unknown")]
+    systems.for_each(|system| run_shared(system, world, event));
+}
+
+fn run_shared<E>(system: &mut dyn SharedSystem<E>, world: &World, event: &E) {
+    // Restores the previous value, as the thread pool may run another system on this thread while
+    // this one waits for its own parallel work
+    let was_running_shared = RUNNING_SHARED.with(|running| running.replace(true));
+    // Safety: the systems in a batch don't conflict with each other, and the world is borrowed
+    // exclusively by the system group for the duration of the batch
+    unsafe { system.run_shared(world, event) };
+    RUNNING_SHARED.with(|running| running.set(was_running_shared));
+}
@@ -246,6 +246,10 @@ impl WorldStreamFilter {
             .iter(world, None)
             .map(|x| x.id())
     }
+    /// Whether the entity exists and is streamed
+    pub fn contains(&self, world: &World, id: EntityId) -> bool {
+        self.arch_filter.matches_entity(world, id)
+    }
     pub fn get_entity_components(&self, world: &World, id: EntityId) -> Vec<ComponentDesc> {
         world
             .get_components(id)
@@ -254,7 +258,47 @@ impl WorldStreamFilter {
             .filter(|&comp| (self.component_filter)(comp, WorldStreamCompEvent::Init))
             .collect_vec()
     }
-    fn read_entity_components(&self, world: &World, id: EntityId) -> Vec<ComponentEntry> {
+    /// Creates the diff for a client which is only sent the `relevant` entities, given that it has
+    /// already been sent the `known` entities. `diff` is the next diff of the world stream.
+    ///
+    /// Entities which have become relevant are spawned with all their components, and entities
+    /// which are no longer relevant are despawned. `known` is updated to `relevant`.
+    pub fn relevant_diff(
+        &self,
+        world: &World,
+        diff: &FrozenWorldDiff,
+        known: &mut HashSet<EntityId>,
+        relevant: HashSet<EntityId>,
+    ) -> WorldDiff {
+        let mut changes = known
+            .difference(&relevant)
+            .map(|&id| WorldChange::Despawn(id))
+            .collect_vec();
+        changes.extend(
+            diff.into_iter()
+                .filter(|change| match change {
+                    // Covered by the entities which entered or left the relevant set
+                    WorldChange::Spawn(_, _) | WorldChange::Despawn(_) => false,
+                    change => {
+                        let id = change.entity_id();
+                        known.contains(&id) && relevant.contains(&id)
+                    }
+                })
+                .cloned(),
+        );
+        changes.extend(
+            relevant
+                .difference(known)
+                .map(|&id| WorldChange::Spawn(id, self.read_entity_components(world, id).into())),
+        );
+        *known = relevant;
+        WorldDiff { changes }
+    }
+    pub(crate) fn read_entity_components(
+        &self,
+        world: &World,
+        id: EntityId,
+    ) -> Vec<ComponentEntry> {
         self.get_entity_components(world, id)
             .into_iter()
             .map(|comp| world.get_entry(id, comp).unwrap())
@@ -288,6 +332,16 @@ impl WorldChange {
         matches!(self, Self::RemoveComponents(_, _))
     }
 
+    pub fn entity_id(&self) -> EntityId {
+        match self {
+            Self::Spawn(id, _)
+            | Self::Despawn(id)
+            | Self::AddComponents(id, _)
+            | Self::RemoveComponents(id, _)
+            | Self::SetComponents(id, _) => *id,
+        }
+    }
+
     fn apply(self, world: &mut World, spawned_extra_data: &Entity, panic_on_error: bool) {
         match self {
             Self::Spawn(id, data) => {
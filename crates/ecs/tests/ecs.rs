@@ -1,6 +1,7 @@
 use ambient_ecs::{
-    components, query, query_mut, ECSError, Entity, EntityId, Query, QueryState, Resource, World,
-    WorldContext,
+    components, query, query_mut, with_component_registry, ComponentRegistry, ECSError, Entity,
+    EntityId, ExternalComponentDesc, PrimitiveComponentType, Query, QueryState, Resource, Struct,
+    World, WorldContext,
 };
 use itertools::Itertools;
 
@@ -295,3 +296,48 @@ fn can_add_a_resource() {
     init();
     World::new_unknown("can_add_a_resource").add_resource(a_resource(), ());
 }
+
+#[test]
+fn external_struct_components_are_stored_as_bytes() {
+    init();
+    ComponentRegistry::get_mut().add_external(vec![ExternalComponentDesc {
+        path: "ambient_core::test::external_struct".to_string(),
+        ty: PrimitiveComponentType::VecU8,
+        name: None,
+        description: None,
+        attributes: ["Networked", "Struct"].into_iter().collect(),
+        struct_fields: None,
+    }]);
+
+    let desc =
+        with_component_registry(|r| r.get_by_path("ambient_core::test::external_struct")).unwrap();
+    let struct_ = desc.attribute::<Struct>().unwrap();
+    let entry = (struct_.from_bytes)(desc, &[1, 2, 3]).unwrap();
+    assert_eq!(entry.downcast_ref::<Vec<u8>>(), &vec![1, 2, 3]);
+    assert_eq!((struct_.to_bytes)(entry.as_any()), vec![1, 2, 3]);
+}
+
+#[test]
+fn external_struct_bytes_are_checked_against_their_fields() {
+    init();
+    ComponentRegistry::get_mut().add_external(vec![ExternalComponentDesc {
+        path: "ambient_core::test::external_struct_with_fields".to_string(),
+        ty: PrimitiveComponentType::VecU8,
+        name: None,
+        description: None,
+        attributes: ["Networked", "Struct"].into_iter().collect(),
+        struct_fields: Some(vec![
+            PrimitiveComponentType::U32,
+            PrimitiveComponentType::Empty,
+        ]),
+    }]);
+
+    let desc = with_component_registry(|r| {
+        r.get_by_path("ambient_core::test::external_struct_with_fields")
+    })
+    .unwrap();
+    let struct_ = desc.attribute::<Struct>().unwrap();
+    assert!(struct_.entry_from_bytes(desc, &[0, 0, 0, 1]).is_some());
+    assert!(struct_.entry_from_bytes(desc, &[0, 0, 1]).is_none());
+    assert!(struct_.entry_from_bytes(desc, &[0, 0, 0, 1, 2]).is_none());
+}
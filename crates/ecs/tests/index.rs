@@ -1,14 +1,19 @@
 use std::ops::Bound;
 
 use ambient_ecs::{
-    components, index_system, ArchetypeFilter, Entity, FrameEvent, Index, IndexColumns, IndexField,
-    IndexKey, System, World,
+    components, entity_index_system, index_system, ArchetypeFilter, Entity, FrameEvent, GridIndex,
+    HashIndex, Index, IndexColumns, IndexField, IndexKey, System, World,
 };
+use glam::{vec3, Vec3};
 use itertools::Itertools;
 
 components!("test", {
     a: i32,
+    b: f32,
+    position: Vec3,
     test_index: Index,
+    test_hash_index: HashIndex,
+    test_grid_index: GridIndex,
 });
 
 fn init() {
@@ -210,3 +215,122 @@ fn multiple_for_exact_value() {
         vec![y, z].into_iter().sorted().collect_vec()
     );
 }
+
+#[test]
+fn hash_index() {
+    init();
+    let mut world = World::new_unknown("hash_index");
+    let mut index = HashIndex::new(
+        IndexColumns::new()
+            .add_value_column(a())
+            .add_value_column(b()),
+    );
+
+    let x = world.spawn(Entity::new().with(a(), 5).with(b(), 0.5));
+    let y = world.spawn(Entity::new().with(a(), 5).with(b(), 0.5));
+    let z = world.spawn(Entity::new().with(a(), 5).with(b(), 1.5));
+    for id in [x, y, z] {
+        index.insert_entity(&world, id);
+    }
+
+    let values = Entity::new().with(a(), 5).with(b(), 0.5);
+    let values = vec![
+        values.get_entry(a()).unwrap().clone(),
+        values.get_entry(b()).unwrap().clone(),
+    ];
+    assert_eq!(
+        index.get(&values).sorted().collect_vec(),
+        vec![x, y].into_iter().sorted().collect_vec()
+    );
+
+    index.remove(x);
+    assert_eq!(index.get(&values).collect_vec(), vec![y]);
+
+    // Values of other components never match
+    assert!(index.get(&values[..1]).next().is_none());
+}
+
+#[test]
+fn hash_index_as_resource() {
+    init();
+    let mut world = World::new_unknown("hash_index_as_resource");
+    let mut systems = entity_index_system(
+        ArchetypeFilter::new(),
+        HashIndex::new(IndexColumns::new().add_value_column(a())),
+        test_hash_index(),
+    );
+    let key = |value: i32| {
+        vec![Entity::new()
+            .with(a(), value)
+            .get_entry(a())
+            .unwrap()
+            .clone()]
+    };
+
+    let x = world.spawn(Entity::new().with(a(), 5));
+    systems.run(&mut world, &FrameEvent);
+    assert_eq!(
+        world.resource(test_hash_index()).get(&key(5)).collect_vec(),
+        vec![x]
+    );
+
+    world.set(x, a(), 3).unwrap();
+    systems.run(&mut world, &FrameEvent);
+    let index = world.resource(test_hash_index());
+    assert!(index.get(&key(5)).next().is_none());
+    assert_eq!(index.get(&key(3)).collect_vec(), vec![x]);
+
+    world.despawn(x);
+    systems.run(&mut world, &FrameEvent);
+    assert!(world
+        .resource(test_hash_index())
+        .get(&key(3))
+        .next()
+        .is_none());
+}
+
+#[test]
+fn grid_index() {
+    init();
+    let mut world = World::new_unknown("grid_index");
+    let mut systems = entity_index_system(
+        ArchetypeFilter::new(),
+        GridIndex::new(position(), 2.),
+        test_grid_index(),
+    );
+
+    let x = world.spawn(Entity::new().with(position(), vec3(0.5, 0.5, 0.)));
+    let y = world.spawn(Entity::new().with(position(), vec3(-1.5, 0., 0.)));
+    let z = world.spawn(Entity::new().with(position(), vec3(10., 0., 0.)));
+    systems.run(&mut world, &FrameEvent);
+
+    let index = world.resource(test_grid_index());
+    assert_eq!(
+        index
+            .within_radius(Vec3::ZERO, 2.)
+            .into_iter()
+            .sorted()
+            .collect_vec(),
+        vec![x, y].into_iter().sorted().collect_vec()
+    );
+    assert_eq!(
+        index
+            .within_box(Vec3::ZERO, Vec3::splat(100.))
+            .into_iter()
+            .sorted()
+            .collect_vec(),
+        vec![x, z].into_iter().sorted().collect_vec()
+    );
+
+    world.set(z, position(), vec3(1., 0., 0.)).unwrap();
+    systems.run(&mut world, &FrameEvent);
+    assert_eq!(
+        world
+            .resource(test_grid_index())
+            .within_radius(Vec3::ZERO, 2.)
+            .into_iter()
+            .sorted()
+            .collect_vec(),
+        vec![x, y, z].into_iter().sorted().collect_vec()
+    );
+}
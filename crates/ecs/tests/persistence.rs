@@ -0,0 +1,177 @@
+use std::path::PathBuf;
+
+use ambient_ecs::{
+    components, dont_store, ArchetypeFilter, Entity, Serializable, Store, World, WorldContext,
+    WorldPersistence, WorldStreamFilter,
+};
+use itertools::Itertools;
+
+components!("test", {
+    @[Store]
+    a: f32,
+    @[Store]
+    b: String,
+    @[Serializable]
+    not_stored: f32,
+});
+
+fn init() {
+    ambient_ecs::init_components();
+    init_components();
+}
+
+fn persistence_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir()
+        .join("ambient_ecs_persistence_tests")
+        .join(format!("{name}_{}", std::process::id()));
+    std::fs::remove_dir_all(&path).ok();
+    path
+}
+
+fn filter() -> WorldStreamFilter {
+    WorldStreamFilter::new(
+        ArchetypeFilter::new().excl(dont_store()),
+        std::sync::Arc::new(|comp, _| comp.has_attribute::<Store>()),
+    )
+}
+
+fn new_world() -> World {
+    let mut world = World::new_with_config("persistence", WorldContext::Server, false);
+    world.init_shape_change_tracking();
+    world
+}
+
+fn dump_content_string(world: &World) -> String {
+    world
+        .entities()
+        .into_iter()
+        .sorted_by_key(|(id, _)| *id)
+        .map(|(id, entity)| format!("{id}: {:?}", entity.serializable()))
+        .join("\n")
+}
+
+#[test]
+fn snapshot_and_diffs_roundtrip() {
+    init();
+    let path = persistence_dir("snapshot_and_diffs_roundtrip");
+
+    let mut world = new_world();
+    let x = Entity::new()
+        .with(a(), 1.)
+        .with(b(), "x".to_string())
+        .spawn(&mut world);
+    let y = Entity::new().with(a(), 2.).spawn(&mut world);
+    Entity::new()
+        .with(a(), 3.)
+        .with(dont_store(), ())
+        .spawn(&mut world);
+
+    let mut persistence = WorldPersistence::new(&path, filter()).unwrap();
+    persistence.save_snapshot(&world).unwrap();
+    world.next_frame();
+
+    world.set(x, a(), 5.).unwrap();
+    world.remove_component(x, b()).unwrap();
+    world.add_component(y, b(), "y".to_string()).unwrap();
+    persistence.record_diff(&world).unwrap();
+    world.next_frame();
+
+    let z = Entity::new().with(a(), 7.).spawn(&mut world);
+    world.despawn(y);
+    persistence.record_diff(&world).unwrap();
+    assert_eq!(persistence.diffs_since_snapshot(), 2);
+
+    let loaded = WorldPersistence::load(&path).unwrap().unwrap();
+    assert!(loaded.warnings.is_empty());
+    assert_eq!(loaded.world.len(), 2);
+    assert_eq!(loaded.world.get(x, a()).unwrap(), 5.);
+    assert!(!loaded.world.has_component(x, b()));
+    assert!(!loaded.world.exists(y));
+    assert_eq!(loaded.world.get(z, a()).unwrap(), 7.);
+
+    // A new snapshot compacts the log without changing the loaded world
+    persistence.save_snapshot(&world).unwrap();
+    assert_eq!(persistence.diffs_since_snapshot(), 0);
+    let compacted = WorldPersistence::load(&path).unwrap().unwrap();
+    assert_eq!(
+        dump_content_string(&compacted.world),
+        dump_content_string(&loaded.world)
+    );
+}
+
+#[test]
+fn only_stored_components_are_persisted() {
+    init();
+    let path = persistence_dir("only_stored_components_are_persisted");
+
+    let mut world = new_world();
+    let x = Entity::new()
+        .with(a(), 1.)
+        .with(not_stored(), 2.)
+        .spawn(&mut world);
+
+    let mut persistence = WorldPersistence::new(&path, filter()).unwrap();
+    persistence.save_snapshot(&world).unwrap();
+
+    let loaded = WorldPersistence::load(&path).unwrap().unwrap();
+    assert!(loaded.world.has_component(x, a()));
+    assert!(!loaded.world.has_component(x, not_stored()));
+}
+
+#[test]
+fn load_reports_schema_changes_as_warnings() {
+    init();
+    let path = persistence_dir("load_reports_schema_changes_as_warnings");
+    assert!(WorldPersistence::load(&path).unwrap().is_none());
+
+    let mut world = new_world();
+    let x = Entity::new().with(a(), 1.).spawn(&mut world);
+    let mut persistence = WorldPersistence::new(&path, filter()).unwrap();
+    persistence.save_snapshot(&world).unwrap();
+    drop(persistence);
+
+    // Simulate a component that was removed from the schema, as well as a truncated last line
+    let mut log = std::fs::read_to_string(path.join("diffs.jsonl")).unwrap();
+    log.push_str(&format!(
+        r#"{{"changes":[{{"SetComponents":["{x}",{{"ambient_core::test::a":4.0,"ambient_core::test::removed":1.0}}]}}]}}"#
+    ));
+    log.push_str("\n{\"changes\":[");
+    std::fs::write(path.join("diffs.jsonl"), log).unwrap();
+
+    let loaded = WorldPersistence::load(&path).unwrap().unwrap();
+    assert_eq!(loaded.world.get(x, a()).unwrap(), 4.);
+    assert_eq!(loaded.warnings.len(), 2);
+    assert_eq!(loaded.warnings[0].0, x);
+    assert_eq!(loaded.warnings[0].1, "ambient_core::test::removed");
+}
+
+#[test]
+fn parts_write_on_another_thread() {
+    init();
+    let path = persistence_dir("parts_write_on_another_thread");
+
+    let mut world = new_world();
+    let x = Entity::new().with(a(), 1.).spawn(&mut world);
+    let (mut stream, mut writer) = WorldPersistence::new(&path, filter()).unwrap().into_parts();
+    let snapshot = stream.snapshot(&world);
+    world.next_frame();
+
+    world.set(x, a(), 2.).unwrap();
+    let diff = stream.next_diff(&world);
+
+    std::thread::spawn(move || {
+        writer.write_snapshot(&snapshot).unwrap();
+        writer.write_diff(&diff).unwrap();
+        // Diffs are buffered until they are flushed
+        assert_eq!(
+            std::fs::read_to_string(writer.path().join("diffs.jsonl")).unwrap(),
+            ""
+        );
+        writer.flush().unwrap();
+    })
+    .join()
+    .unwrap();
+
+    let loaded = WorldPersistence::load(&path).unwrap().unwrap();
+    assert_eq!(loaded.world.get(x, a()).unwrap(), 2.);
+}
@@ -0,0 +1,108 @@
+use ambient_ecs::{
+    components, query, ArchetypeFilter, Entity, EntityId, Query, QueryState, RelationFilter, World,
+};
+use itertools::Itertools;
+
+components!("test", {
+    parent: EntityId,
+    children: Vec<EntityId>,
+    a: f32,
+    b: f32,
+});
+
+fn init() {
+    init_components();
+}
+
+fn ids(q: &Query, world: &World, state: &mut QueryState) -> Vec<EntityId> {
+    q.iter(world, Some(state)).map(|ea| ea.id()).collect_vec()
+}
+
+#[test]
+fn parent_has_component() {
+    init();
+    let mut world = World::new_unknown("parent_has_component");
+    let with_a = world.spawn(Entity::new().with(a(), 1.));
+    let without_a = world.spawn(Entity::new().with(b(), 1.));
+    let child = world.spawn(Entity::new().with(parent(), with_a));
+    world.spawn(Entity::new().with(parent(), without_a));
+
+    let q = query(parent()).related(RelationFilter::new(parent()).incl(a()));
+    assert_eq!(q.collect_ids(&world, None), vec![child]);
+}
+
+#[test]
+fn with_child_matching_filter() {
+    init();
+    let mut world = World::new_unknown("with_child_matching_filter");
+    let child_a = world.spawn(Entity::new().with(a(), 1.));
+    let child_b = world.spawn(Entity::new().with(b(), 1.));
+    let x = world.spawn(Entity::new().with(children(), vec![child_b, child_a]));
+    world.spawn(Entity::new().with(children(), vec![child_b]));
+
+    let q = Query::new(ArchetypeFilter::new())
+        .related(RelationFilter::new(children()).incl(a()).excl(b()));
+    assert_eq!(ids(&q, &world, &mut QueryState::new()), vec![x]);
+}
+
+#[test]
+fn spawned_and_despawned_follow_the_relation() {
+    init();
+    let mut world = World::new_unknown("spawned_and_despawned_follow_the_relation");
+    let p = world.spawn(Entity::new().with(b(), 1.));
+    let child = world.spawn(Entity::new().with(parent(), p));
+
+    let relation = RelationFilter::new(parent()).incl(a());
+    let spawned = Query::all().related(relation.clone()).spawned();
+    let despawned = Query::all().related(relation).despawned();
+    let (mut spawned_state, mut despawned_state) = (QueryState::new(), QueryState::new());
+    assert!(ids(&spawned, &world, &mut spawned_state).is_empty());
+    assert!(ids(&despawned, &world, &mut despawned_state).is_empty());
+
+    // The child starts matching when its parent gets the component
+    world.add_component(p, a(), 1.).unwrap();
+    assert_eq!(ids(&spawned, &world, &mut spawned_state), vec![child]);
+    assert!(ids(&spawned, &world, &mut spawned_state).is_empty());
+    assert!(ids(&despawned, &world, &mut despawned_state).is_empty());
+
+    // And stops matching when its parent loses it
+    world.remove_component(p, a()).unwrap();
+    assert!(ids(&spawned, &world, &mut spawned_state).is_empty());
+    assert_eq!(ids(&despawned, &world, &mut despawned_state), vec![child]);
+
+    // Despawning a matching child is also reported
+    world.add_component(p, a(), 1.).unwrap();
+    assert_eq!(ids(&spawned, &world, &mut spawned_state), vec![child]);
+    assert!(ids(&despawned, &world, &mut despawned_state).is_empty());
+    world.despawn(child);
+    assert_eq!(ids(&despawned, &world, &mut despawned_state), vec![child]);
+}
+
+#[test]
+fn changes_propagate_across_the_relation() {
+    init();
+    let mut world = World::new_unknown("changes_propagate_across_the_relation");
+    let p = world.spawn(Entity::new().with(a(), 1.));
+    let child = world.spawn(Entity::new().with(parent(), p).with(b(), 1.));
+    let other = world.spawn(Entity::new().with(b(), 1.));
+
+    let q = Query::all()
+        .when_changed(b())
+        .related(RelationFilter::new(parent()).when_changed(a()));
+    let mut state = QueryState::new();
+    assert_eq!(ids(&q, &world, &mut state), vec![child]);
+    assert!(ids(&q, &world, &mut state).is_empty());
+
+    world.set(p, a(), 2.).unwrap();
+    assert_eq!(ids(&q, &world, &mut state), vec![child]);
+    assert!(ids(&q, &world, &mut state).is_empty());
+
+    // Both the entity and its parent changing only reports the entity once
+    world.set(p, a(), 3.).unwrap();
+    world.set(child, b(), 2.).unwrap();
+    assert_eq!(ids(&q, &world, &mut state), vec![child]);
+
+    // Entities which are not related are not reported
+    world.set(other, b(), 2.).unwrap();
+    assert!(ids(&q, &world, &mut state).is_empty());
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use ambient_ecs::{
+    components, despawn_with_relationships, relationships, sync_relationships, ArchetypeFilter,
+    DespawnBlocked, Entity, EntityId, OnTargetDespawn, Relationship, Serializable, World,
+    WorldStream, WorldStreamFilter,
+};
+
+components!("test", {
+    @[Serializable, Relationship[OnTargetDespawn::DespawnDependents]]
+    owner: EntityId,
+    @[Serializable, Relationship[OnTargetDespawn::ClearReference]]
+    target: EntityId,
+    @[Serializable, Relationship[OnTargetDespawn::ClearReference]]
+    inventory: Vec<EntityId>,
+    @[Relationship[OnTargetDespawn::Block]]
+    reserved_by: EntityId,
+    @[Serializable]
+    a: f32,
+});
+
+fn init() {
+    ambient_ecs::init_components();
+    init_components();
+}
+
+#[test]
+fn reverse_lookups() {
+    init();
+    let mut world = World::new_unknown("reverse_lookups");
+    let player = world.spawn(Entity::new().with(a(), 1.));
+    let sword = world.spawn(Entity::new().with(owner(), player));
+    let shield = world.spawn(Entity::new().with(owner(), player));
+    let chest = world.spawn(Entity::new().with(inventory(), vec![sword, shield]));
+
+    sync_relationships(&mut world);
+    let lookups = world.resource(relationships());
+    assert_eq!(lookups.dependents(owner(), player), &[sword, shield]);
+    assert_eq!(lookups.dependents(inventory(), sword), &[chest]);
+    assert_eq!(
+        lookups.get(inventory()).unwrap().targets(chest),
+        &[sword, shield]
+    );
+    assert!(lookups.get(a()).is_none());
+
+    world.set(sword, owner(), chest).unwrap();
+    world.set(chest, inventory(), vec![shield]).unwrap();
+    sync_relationships(&mut world);
+    let lookups = world.resource(relationships());
+    assert_eq!(lookups.dependents(owner(), player), &[shield]);
+    assert_eq!(lookups.dependents(owner(), chest), &[sword]);
+    assert!(lookups.dependents(inventory(), sword).is_empty());
+}
+
+#[test]
+fn cascades() {
+    init();
+    let mut world = World::new_unknown("cascades");
+    let player = world.spawn(Entity::new().with(a(), 1.));
+    let sword = world.spawn(Entity::new().with(owner(), player));
+    let gem = world.spawn(Entity::new().with(owner(), sword));
+    let enemy = world.spawn(Entity::new().with(target(), player));
+    let chest = world.spawn(Entity::new().with(inventory(), vec![gem, player]));
+
+    assert_eq!(
+        despawn_with_relationships(&mut world, player),
+        Ok(vec![player, sword, gem])
+    );
+    assert!(!world.exists(sword));
+    assert!(!world.exists(gem));
+    assert!(!world.has_component(enemy, target()));
+    assert_eq!(world.get_ref(chest, inventory()), Ok(&vec![]));
+}
+
+#[test]
+fn block() {
+    init();
+    let mut world = World::new_unknown("block");
+    let seat = world.spawn(Entity::new().with(a(), 1.));
+    let player = world.spawn(Entity::new().with(reserved_by(), seat));
+
+    assert_eq!(
+        despawn_with_relationships(&mut world, seat),
+        Err(DespawnBlocked {
+            entity_id: seat,
+            dependent: player,
+            component_path: reserved_by().path(),
+        })
+    );
+    assert!(world.exists(seat));
+
+    // Dependents which are despawned along with it do not block it
+    world.add_component(player, owner(), seat).unwrap();
+    assert_eq!(
+        despawn_with_relationships(&mut world, seat),
+        Ok(vec![seat, player])
+    );
+}
+
+#[test]
+fn plain_despawns_are_cleaned_up() {
+    init();
+    let mut world = World::new_unknown("plain_despawns_are_cleaned_up");
+    let player = world.spawn(Entity::new().with(a(), 1.));
+    let sword = world.spawn(Entity::new().with(owner(), player));
+    let enemy = world.spawn(Entity::new().with(target(), player));
+    sync_relationships(&mut world);
+
+    world.despawn(player);
+    sync_relationships(&mut world);
+    assert!(!world.exists(sword));
+    assert!(!world.has_component(enemy, target()));
+    assert!(world
+        .resource(relationships())
+        .dependents(owner(), player)
+        .is_empty());
+}
+
+#[test]
+fn cascades_are_replicated() {
+    init();
+    let mut source = World::new_with_config(
+        "cascades_are_replicated_src",
+        ambient_ecs::WorldContext::Unknown,
+        true,
+    );
+    source.init_shape_change_tracking();
+    let mut dest = World::new_unknown("cascades_are_replicated_dst");
+    let mut stream = WorldStream::new(WorldStreamFilter::new(
+        ArchetypeFilter::new().excl(relationships()),
+        Arc::new(|_, _| true),
+    ));
+
+    let player = source.spawn(Entity::new().with(a(), 1.));
+    let sword = source.spawn(Entity::new().with(owner(), player));
+    let enemy = source.spawn(Entity::new().with(target(), player));
+    let chest = source.spawn(Entity::new().with(inventory(), vec![sword]));
+    stream.next_diff(&source).apply(&mut dest, Entity::new());
+
+    despawn_with_relationships(&mut source, player).unwrap();
+    stream.next_diff(&source).apply(&mut dest, Entity::new());
+    assert!(!dest.exists(player));
+    assert!(!dest.exists(sword));
+    assert!(!dest.has_component(enemy, target()));
+    assert_eq!(dest.get_ref(chest, inventory()), Ok(&vec![]));
+
+    // The reverse lookups are rebuilt from the replicated components
+    sync_relationships(&mut dest);
+    assert!(dest
+        .resource(relationships())
+        .dependents(inventory(), sword)
+        .is_empty());
+}
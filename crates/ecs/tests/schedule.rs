@@ -0,0 +1,166 @@
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use ambient_ecs::{
+    components, query, query_mut, ECSError, Entity, FnSystem, FrameEvent, ParallelSystem, System,
+    SystemAccess, SystemGroup, World,
+};
+
+components!("test", {
+    a: f32,
+    b: f32,
+    c: f32,
+});
+
+fn init() {
+    init_components();
+}
+
+#[test]
+fn disjoint_systems_run_in_parallel() {
+    init();
+    let mut world = World::new_unknown("disjoint_systems_run_in_parallel");
+    let x = world.spawn(Entity::new().with(a(), 1.).with(b(), 1.).with(c(), 2.));
+
+    let mut systems = SystemGroup::new(
+        "test",
+        vec![
+            query_mut(a(), c()).to_parallel_system("a", |q, world, qs, _| {
+                for (_, a, c) in q.iter_shared(world, qs) {
+                    *a += *c;
+                }
+            }),
+            query_mut(b(), c()).to_parallel_system("b", |q, world, qs, _| {
+                for (_, b, c) in q.iter_shared(world, qs) {
+                    *b *= *c;
+                }
+            }),
+        ],
+    );
+    systems.run(&mut world, &FrameEvent);
+
+    assert_eq!(world.get(x, a()), Ok(3.));
+    assert_eq!(world.get(x, b()), Ok(2.));
+}
+
+#[test]
+fn conflicting_systems_run_in_order() {
+    init();
+    let mut world = World::new_unknown("conflicting_systems_run_in_order");
+    let x = world.spawn(Entity::new().with(a(), 1.));
+
+    let mut systems = SystemGroup::new(
+        "test",
+        vec![
+            query_mut(a(), ()).to_parallel_system("double", |q, world, qs, _| {
+                for (_, a, _) in q.iter_shared(world, qs) {
+                    *a *= 2.;
+                }
+            }),
+            query_mut(a(), ()).to_parallel_system("increment", |q, world, qs, _| {
+                for (_, a, _) in q.iter_shared(world, qs) {
+                    *a += 1.;
+                }
+            }),
+            // Systems without an access run on their own, after the ones before them
+            Box::new(FnSystem::new(move |world, _| {
+                world.set(x, a(), world.get(x, a()).unwrap() * 10.).unwrap();
+            })),
+        ],
+    );
+    systems.run(&mut world, &FrameEvent);
+
+    assert_eq!(world.get(x, a()), Ok(30.));
+}
+
+#[test]
+fn change_queries_in_parallel_systems() {
+    init();
+    let mut world = World::new_unknown("change_queries_in_parallel_systems");
+    let x = world.spawn(Entity::new().with(a(), 1.).with(b(), 1.));
+    let y = world.spawn(Entity::new().with(a(), 1.).with(b(), 1.));
+
+    let seen = Arc::new(AtomicU32::new(0));
+    let mut systems = SystemGroup::new(
+        "test",
+        (0..4)
+            .map(|_| {
+                let seen = seen.clone();
+                query((a().changed(), b().changed())).to_parallel_system(
+                    "changes",
+                    move |q, world, qs, _| {
+                        let count = q.iter_shared(world, qs).count() as u32;
+                        seen.fetch_add(count, Ordering::SeqCst);
+                    },
+                )
+            })
+            .collect(),
+    );
+
+    systems.run(&mut world, &FrameEvent);
+    assert_eq!(seen.swap(0, Ordering::SeqCst), 8);
+
+    // Entities with both components changed are only returned once by each query
+    world.set(x, a(), 2.).unwrap();
+    world.set(x, b(), 2.).unwrap();
+    world.set(y, b(), 2.).unwrap();
+    systems.run(&mut world, &FrameEvent);
+    assert_eq!(seen.load(Ordering::SeqCst), 8);
+}
+
+#[test]
+fn declared_access() {
+    init();
+    let mut world = World::new_unknown("declared_access");
+    let x = world.spawn(Entity::new().with(a(), 1.).with(b(), 1.));
+
+    let access = SystemAccess::new().read(a()).write(b());
+    assert!(access.conflicts_with(&SystemAccess::new().write(a())));
+    assert!(access.conflicts_with(&SystemAccess::new().read(b())));
+    assert!(!access.conflicts_with(&SystemAccess::new().read(a()).write(c())));
+
+    let mut systems = SystemGroup::new(
+        "test",
+        vec![Box::new(ParallelSystem::new(access, move |world, _| {
+            let value = world.get(x, a()).unwrap() + 1.;
+            *world.get_mut(x, b()).unwrap() = value;
+
+            // Components outside of the access can't be accessed, and read components can't be
+            // written
+            assert!(matches!(
+                world.get(x, c()),
+                Err(ECSError::AccessNotDeclared { .. })
+            ));
+            assert!(matches!(
+                world.get_mut(x, a()),
+                Err(ECSError::AccessNotDeclared { .. })
+            ));
+        }))],
+    );
+    systems.run(&mut world, &FrameEvent);
+    assert_eq!(world.get(x, b()), Ok(2.));
+}
+
+#[test]
+#[should_panic(expected = "outside of the access")]
+fn queries_outside_of_declared_access_panic() {
+    init();
+    let mut world = World::new_unknown("queries_outside_of_declared_access_panic");
+    world.spawn(Entity::new().with(a(), 1.));
+
+    let query = query_mut(a(), ());
+    let mut systems = SystemGroup::new(
+        "test",
+        vec![Box::new(ParallelSystem::new(
+            SystemAccess::new().read(a()),
+            move |world, _| {
+                for (_, a, _) in query.iter_shared(world, None) {
+                    *a += 1.;
+                }
+            },
+        ))],
+    );
+    systems.run(&mut world, &FrameEvent);
+}
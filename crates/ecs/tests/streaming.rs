@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use ambient_ecs::{
     components, ArchetypeFilter, Entity, EntityId, FrozenWorldDiff, Serializable, World, WorldDiff,
@@ -88,6 +88,63 @@ fn streaming() {
     assert_eq!(dump_content_string(&source), dump_content_string(&dest));
 }
 
+#[test]
+fn relevant_streaming() {
+    init();
+    let mut source = World::new_with_config(
+        "relevant_streaming_src",
+        ambient_ecs::WorldContext::Unknown,
+        true,
+    );
+    source.init_shape_change_tracking();
+    source
+        .add_component(source.resource_entity(), no_sync(), ())
+        .ok();
+    let mut dest = World::new_unknown("relevant_streaming_dst");
+    let filter = WorldStreamFilter::new(
+        ArchetypeFilter::new().excl(no_sync()),
+        Arc::new(|_, _| true),
+    );
+    let mut stream = WorldStream::new(filter.clone());
+    let mut known = HashSet::new();
+
+    // Only the relevant entities are replicated
+    let mut step = |source: &World, relevant: &[EntityId]| {
+        let diff: FrozenWorldDiff = stream.next_diff(source).into();
+        let relevant = relevant.iter().copied().collect();
+        let diff = filter.relevant_diff(source, &diff, &mut known, relevant);
+        diff.apply(&mut dest, Entity::new());
+
+        let mut expected = source.clone();
+        for id in filter.all_entities(source).collect_vec() {
+            if !known.contains(&id) {
+                expected.despawn(id);
+            }
+        }
+        assert_eq!(dump_content_string(&expected), dump_content_string(&dest));
+    };
+
+    let x = Entity::new().with(a(), 1.).spawn(&mut source);
+    let y = Entity::new().with(a(), 2.).spawn(&mut source);
+    step(&source, &[x]);
+
+    // Changes to irrelevant entities are not sent
+    source.set(x, a(), 3.).unwrap();
+    source.set(y, a(), 4.).unwrap();
+    source.add_component(y, b(), 5.).unwrap();
+    step(&source, &[x]);
+
+    // Entering entities are spawned with their current state, and leaving entities are despawned
+    step(&source, &[y]);
+
+    source.add_component(y, c(), 6.).unwrap();
+    source.set(x, a(), 7.).unwrap();
+    step(&source, &[x, y]);
+
+    source.despawn(y).unwrap();
+    step(&source, &[x]);
+}
+
 fn dump_content_string(world: &World) -> String {
     let mut entities = world.entities();
     entities.sort_unstable_by_key(|(id, _)| *id);
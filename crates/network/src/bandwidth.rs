@@ -0,0 +1,359 @@
+//! Bandwidth budgets for the world diffs sent to each player.
+//!
+//! When a player has a `bandwidth_budget`, the spawns, despawns and added or removed components of
+//! each diff are always sent, and the component updates are sent in order of priority until the
+//! budget runs out. The rest are delayed until the next diff, and their priority grows with every
+//! diff they are delayed for.
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use ambient_ecs::{
+    generated::network::components::{
+        bandwidth_budget, component_priorities, diff_compression, diff_quantization,
+        relevancy_origin,
+    },
+    with_component_registry, ComponentDesc, Entity, EntityId, FrozenWorldDiff, NetworkPriority,
+    World, WorldChange, WorldDiff,
+};
+use ambient_sys::time::Instant;
+use itertools::Itertools;
+
+use crate::{
+    diff_serialization::{DiffEncoding, DiffSerializer},
+    relevancy::position,
+};
+
+/// How often delayed component updates are retried if no new diffs arrive
+pub const RETRY_INTERVAL: Duration = Duration::from_millis(20);
+/// Entities this far from the player's relevancy origin have half the priority of entities at it
+const PRIORITY_HALF_DISTANCE: f32 = 10.0;
+/// The approximate size of the tag and id of a change
+const CHANGE_SIZE: u64 = 18;
+
+/// How the diffs for a player are sent
+#[derive(Debug, Clone, Default)]
+pub struct DiffSettings {
+    /// The number of bytes per second that can be sent to the player, if limited
+    pub bandwidth_budget: Option<u32>,
+    /// The priorities set by the `component_priorities` resource, by component index
+    pub component_priorities: Arc<HashMap<u32, f32>>,
+    pub encoding: DiffEncoding,
+}
+
+impl DiffSettings {
+    /// Reads the settings which apply to all players from the resources of `world`
+    pub fn from_world(world: &World) -> Self {
+        let component_priorities = world
+            .resource_opt(component_priorities())
+            .map(|priorities| {
+                with_component_registry(|registry| {
+                    priorities
+                        .iter()
+                        .filter_map(|(path, &priority)| match registry.get_by_path(path) {
+                            Some(desc) => Some((desc.index(), priority)),
+                            None => {
+                                tracing::warn!("Unknown prioritized component {path}");
+                                None
+                            }
+                        })
+                        .collect()
+                })
+            })
+            .unwrap_or_default();
+
+        Self {
+            bandwidth_budget: None,
+            component_priorities: Arc::new(component_priorities),
+            encoding: DiffEncoding {
+                compress: world.resource_opt(diff_compression()).is_some(),
+                quantize: world.resource_opt(diff_quantization()).is_some(),
+            },
+        }
+    }
+
+    /// The settings for `player`
+    pub fn for_player(&self, world: &World, player: EntityId) -> Self {
+        Self {
+            bandwidth_budget: world.get(player, bandwidth_budget()).ok(),
+            ..self.clone()
+        }
+    }
+
+    /// The priority of updates to `desc`, from `component_priorities` or its [`NetworkPriority`]
+    pub fn component_priority(&self, desc: ComponentDesc) -> f32 {
+        self.component_priorities
+            .get(&desc.index())
+            .copied()
+            .or_else(|| desc.attribute::<NetworkPriority>().map(|v| v.0))
+            .unwrap_or(1.0)
+    }
+}
+
+/// A world diff on its way to a player, along with how to send it
+#[derive(Debug, Clone)]
+pub struct PlayerDiff {
+    pub diff: FrozenWorldDiff,
+    pub settings: DiffSettings,
+    /// The priorities of the entities updated by the diff, by their distance to the player. Only
+    /// set if the player has a bandwidth budget.
+    pub entity_priorities: HashMap<EntityId, f32>,
+}
+
+impl PlayerDiff {
+    /// Prepares `diff` to be sent to `player`. `settings` are the settings of the world.
+    pub fn new(
+        world: &World,
+        settings: &DiffSettings,
+        player: EntityId,
+        diff: FrozenWorldDiff,
+    ) -> Self {
+        let settings = settings.for_player(world, player);
+        let entity_priorities = match settings.bandwidth_budget {
+            Some(_) => entity_priorities(world, player, &diff),
+            None => HashMap::new(),
+        };
+        Self {
+            diff,
+            settings,
+            entity_priorities,
+        }
+    }
+}
+
+impl From<FrozenWorldDiff> for PlayerDiff {
+    fn from(diff: FrozenWorldDiff) -> Self {
+        Self {
+            diff,
+            settings: DiffSettings::default(),
+            entity_priorities: HashMap::new(),
+        }
+    }
+}
+
+impl From<WorldDiff> for PlayerDiff {
+    fn from(diff: WorldDiff) -> Self {
+        FrozenWorldDiff::from(diff).into()
+    }
+}
+
+fn entity_priorities(
+    world: &World,
+    player: EntityId,
+    diff: &FrozenWorldDiff,
+) -> HashMap<EntityId, f32> {
+    let origin = world.get(player, relevancy_origin()).unwrap_or(player);
+    let Some(origin) = position(world, origin) else {
+        return HashMap::new();
+    };
+
+    diff.into_iter()
+        .filter_map(|change| match change {
+            WorldChange::SetComponents(id, _) => {
+                let distance = position(world, *id)?.distance(origin);
+                Some((
+                    *id,
+                    PRIORITY_HALF_DISTANCE / (PRIORITY_HALF_DISTANCE + distance),
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Limits the component updates sent to a player to their bandwidth budget
+#[derive(Debug)]
+pub struct BandwidthLimiter {
+    /// The number of bytes which can be sent now. Negative if a diff was larger than the budget.
+    available: f32,
+    last_refill: Instant,
+    /// The number of diffs each delayed component update has been delayed for
+    delays: HashMap<(EntityId, u32), u32>,
+    entity_priorities: HashMap<EntityId, f32>,
+}
+
+impl Default for BandwidthLimiter {
+    fn default() -> Self {
+        Self {
+            available: 0.0,
+            last_refill: Instant::now(),
+            delays: HashMap::new(),
+            entity_priorities: HashMap::new(),
+        }
+    }
+}
+
+impl BandwidthLimiter {
+    /// Records the entity priorities of a diff which is waiting to be sent
+    pub fn add_priorities(&mut self, priorities: &HashMap<EntityId, f32>) {
+        self.entity_priorities.extend(priorities);
+    }
+
+    /// Returns true if there is budget left to send a diff
+    pub fn can_send(&mut self, settings: &DiffSettings) -> bool {
+        match settings.bandwidth_budget {
+            Some(budget) => {
+                self.refill(budget);
+                self.available > 0.0
+            }
+            None => true,
+        }
+    }
+
+    /// Records that a diff of `bytes` was sent
+    pub fn sent(&mut self, settings: &DiffSettings, bytes: usize) {
+        if settings.bandwidth_budget.is_some() {
+            self.available -= bytes as f32;
+        }
+    }
+
+    fn refill(&mut self, budget: u32) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f32();
+        self.last_refill = now;
+        // Unused budget is kept for up to a second, so that bursts of changes can be sent at once
+        self.available = (self.available + elapsed * budget as f32).min(budget as f32);
+    }
+
+    /// Removes the component updates which do not fit in the budget from `diff`, and returns them
+    /// so that they can be sent with the next diff
+    pub fn limit(
+        &mut self,
+        diff: &mut WorldDiff,
+        settings: &DiffSettings,
+    ) -> Vec<(EntityId, Entity)> {
+        let Some(budget) = settings.bandwidth_budget else {
+            self.delays.clear();
+            self.entity_priorities.clear();
+            return Vec::new();
+        };
+
+        // Everything but the component updates is always sent
+        let mut available = self.available;
+        let mut updates = Vec::new();
+        for change in &diff.changes {
+            match change {
+                WorldChange::SetComponents(id, entity) => {
+                    let change_size = CHANGE_SIZE / entity.len().max(1) as u64;
+                    for entry in entity.iter() {
+                        let desc = entry.desc();
+                        let delay = self.delays.get(&(*id, desc.index())).copied().unwrap_or(0);
+                        let priority = settings.component_priority(desc)
+                            * self.entity_priorities.get(id).copied().unwrap_or(1.0)
+                            * (1 + delay) as f32;
+                        let size =
+                            DiffSerializer::entry_size(entry, settings.encoding) + change_size;
+                        updates.push((priority, size, *id, desc));
+                    }
+                }
+                WorldChange::Spawn(_, entity) | WorldChange::AddComponents(_, entity) => {
+                    available -= (CHANGE_SIZE
+                        + entity
+                            .iter()
+                            .map(|entry| DiffSerializer::entry_size(entry, settings.encoding))
+                            .sum::<u64>()) as f32;
+                }
+                WorldChange::Despawn(_) | WorldChange::RemoveComponents(_, _) => {
+                    available -= CHANGE_SIZE as f32;
+                }
+            }
+        }
+
+        // The updates are sent strictly in order of priority, so that large updates are not
+        // starved by smaller ones. If the budget is full, the first update is sent regardless of
+        // its size, so that updates larger than the budget are eventually sent.
+        let mut delayed = HashMap::<EntityId, Vec<ComponentDesc>>::new();
+        let mut fits = true;
+        for (i, (_, size, id, desc)) in updates
+            .into_iter()
+            .sorted_by(|a, b| b.0.total_cmp(&a.0))
+            .enumerate()
+        {
+            let size = size as f32;
+            fits &= size <= available || (i == 0 && self.available >= budget as f32);
+            if fits {
+                available -= size;
+                self.delays.remove(&(id, desc.index()));
+            } else {
+                *self.delays.entry((id, desc.index())).or_default() += 1;
+                delayed.entry(id).or_default().push(desc);
+            }
+        }
+
+        self.entity_priorities
+            .retain(|id, _| delayed.contains_key(id));
+
+        let mut deferred = Vec::new();
+        diff.changes.retain_mut(|change| {
+            let WorldChange::SetComponents(id, entity) = change else {
+                return true;
+            };
+            let Some(components) = delayed.remove(id) else {
+                return true;
+            };
+            let mut delayed_entity = Entity::new();
+            for desc in components {
+                if let Some(entry) = entity.remove_raw(desc) {
+                    delayed_entity.set_entry(entry);
+                }
+            }
+            deferred.push((*id, delayed_entity));
+            !entity.is_empty()
+        });
+        deferred
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::{components, Serializable};
+
+    use super::*;
+
+    components!("test", {
+        @[Serializable]
+        health: u32,
+        @[Serializable, NetworkPriority[10.0]]
+        ammo: u32,
+    });
+
+    #[test]
+    fn updates_are_sent_in_order_of_priority() {
+        // Arrange
+        init_components();
+        let settings = DiffSettings {
+            bandwidth_budget: Some(1000),
+            ..Default::default()
+        };
+        let (player, enemy) = (EntityId::new(), EntityId::new());
+        let entity = Entity::new().with(health(), 100).with(ammo(), 20);
+        let mut diff = WorldDiff {
+            changes: vec![
+                WorldChange::SetComponents(player, entity.clone()),
+                WorldChange::Despawn(enemy),
+            ],
+        };
+        // Only the despawn and the ammo fit
+        let ammo_size =
+            DiffSerializer::entry_size(entity.get_entry(ammo()).unwrap(), settings.encoding);
+        let mut limiter = BandwidthLimiter {
+            available: (2 * CHANGE_SIZE + ammo_size) as f32,
+            ..Default::default()
+        };
+
+        // Act
+        let delayed = limiter.limit(&mut diff, &settings);
+
+        // Assert
+        assert_eq!(diff.changes.len(), 2);
+        let WorldChange::SetComponents(id, sent) = &diff.changes[0] else {
+            panic!("Expected a set, got {:?}", diff.changes);
+        };
+        assert_eq!(*id, player);
+        assert_eq!(sent.components(), vec![ammo().desc()]);
+        assert!(matches!(diff.changes[1], WorldChange::Despawn(id) if id == enemy));
+
+        assert_eq!(delayed.len(), 1);
+        assert_eq!(delayed[0].0, player);
+        assert_eq!(delayed[0].1.get(health()), Some(100));
+        assert_eq!(limiter.delays[&(player, health().desc().index())], 1);
+    }
+}
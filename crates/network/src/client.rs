@@ -39,9 +39,9 @@ components!("network::client", {
 });
 
 #[cfg(not(target_os = "unknown"))]
-pub type PlatformSendStream = quinn::SendStream;
+pub type PlatformSendStream = crate::DynSend;
 #[cfg(not(target_os = "unknown"))]
-pub type PlatformRecvStream = quinn::RecvStream;
+pub type PlatformRecvStream = crate::DynRecv;
 
 #[cfg(target_os = "unknown")]
 pub type PlatformSendStream = crate::webtransport::SendStream;
@@ -240,6 +240,14 @@ pub struct NetworkStats {
     pub bytes_received: u64,
     pub packets_sent: u64,
     pub packets_lost: u64,
+    /// How far behind the server remote entities are rendered
+    pub interpolation_delay_ms: u64,
+    /// The variation in the time between world diffs
+    pub snapshot_jitter_ms: u64,
+    /// The number of times a remote entity was extrapolated because a world diff was late
+    pub extrapolated_frames: u64,
+    /// The bytes of world diffs received for each component since the previous stats, largest first
+    pub component_bytes: Vec<(String, u64)>,
 }
 
 impl Display for NetworkStats {
@@ -247,11 +255,12 @@ impl Display for NetworkStats {
         let loss = self.packets_lost as f32 / self.packets_sent as f32;
         write!(
             f,
-            "{:?} ms rtt, {}/s out, {}/s in, {:.03} loss",
+            "{:?} ms rtt, {}/s out, {}/s in, {:.03} loss, {} ms jitter",
             self.latency_ms,
             to_byte_unit(self.bytes_sent),
             to_byte_unit(self.bytes_received),
             if loss.is_nan() { 0.0 } else { loss },
+            self.snapshot_jitter_ms,
         )
     }
 }
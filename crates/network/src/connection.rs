@@ -1,35 +1,56 @@
+//! Transport agnostic connections.
+//!
+//! The protocol runs on top of any [`Connection`], which provides QUIC-like unidirectional and
+//! bidirectional streams and unreliable datagrams. Besides QUIC, connections can be made over a
+//! WebSocket for networks which block UDP, or in-process for tests.
+use std::time::Duration;
+
 use async_trait::async_trait;
 use bytes::Bytes;
-use quinn::{RecvStream, SendStream};
 
-use crate::NetworkError;
+use crate::{DynRecv, DynSend, NetworkError};
+
+/// The traffic of a connection since it was established
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TransportStats {
+    pub rtt: Duration,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub packets_sent: u64,
+    pub packets_lost: u64,
+}
 
 #[async_trait]
-pub trait Connection: Clone + Send + Sync {
-    async fn open_uni(&self) -> Result<SendStream, NetworkError>;
-    async fn open_bi(&self) -> Result<(SendStream, RecvStream), NetworkError>;
-    async fn accept_uni(&self) -> Result<RecvStream, NetworkError>;
-    async fn accept_bi(&self) -> Result<(SendStream, RecvStream), NetworkError>;
+pub trait Connection: 'static + Clone + Send + Sync {
+    async fn open_uni(&self) -> Result<DynSend, NetworkError>;
+    async fn open_bi(&self) -> Result<(DynSend, DynRecv), NetworkError>;
+    async fn accept_uni(&self) -> Result<DynRecv, NetworkError>;
+    async fn accept_bi(&self) -> Result<(DynSend, DynRecv), NetworkError>;
     async fn read_datagram(&self) -> Result<Bytes, NetworkError>;
     fn send_datagram(&self, data: Bytes) -> Result<(), NetworkError>;
+    /// Returns true if the peer is on the same machine
+    fn is_local(&self) -> bool;
+    fn stats(&self) -> TransportStats;
 }
 
 #[async_trait]
 impl Connection for quinn::Connection {
-    async fn open_uni(&self) -> Result<SendStream, NetworkError> {
-        Ok(self.open_uni().await?)
+    async fn open_uni(&self) -> Result<DynSend, NetworkError> {
+        Ok(Box::pin(self.open_uni().await?))
     }
 
-    async fn open_bi(&self) -> Result<(SendStream, RecvStream), NetworkError> {
-        Ok(self.open_bi().await?)
+    async fn open_bi(&self) -> Result<(DynSend, DynRecv), NetworkError> {
+        let (send, recv) = self.open_bi().await?;
+        Ok((Box::pin(send), Box::pin(recv)))
     }
 
-    async fn accept_uni(&self) -> Result<RecvStream, NetworkError> {
-        Ok(self.accept_uni().await?)
+    async fn accept_uni(&self) -> Result<DynRecv, NetworkError> {
+        Ok(Box::pin(self.accept_uni().await?))
     }
 
-    async fn accept_bi(&self) -> Result<(SendStream, RecvStream), NetworkError> {
-        Ok(self.accept_bi().await?)
+    async fn accept_bi(&self) -> Result<(DynSend, DynRecv), NetworkError> {
+        let (send, recv) = self.accept_bi().await?;
+        Ok((Box::pin(send), Box::pin(recv)))
     }
 
     async fn read_datagram(&self) -> Result<Bytes, NetworkError> {
@@ -39,4 +60,19 @@ impl Connection for quinn::Connection {
     fn send_datagram(&self, data: Bytes) -> Result<(), NetworkError> {
         Ok(self.send_datagram(data)?)
     }
+
+    fn is_local(&self) -> bool {
+        self.remote_address().ip().is_loopback()
+    }
+
+    fn stats(&self) -> TransportStats {
+        let stats = self.stats();
+        TransportStats {
+            rtt: self.rtt(),
+            bytes_sent: stats.udp_tx.bytes,
+            bytes_received: stats.udp_rx.bytes,
+            packets_sent: stats.path.sent_packets,
+            packets_lost: stats.path.lost_packets,
+        }
+    }
 }
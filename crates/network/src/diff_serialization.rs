@@ -1,5 +1,9 @@
 //! Utilities for `WorldDiff` serialization like `WorldDiffDeduplicator` or `DiffSerializer`.
-use std::collections::HashMap;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    io::Read,
+};
 
 use ambient_ecs::{
     with_component_registry, ComponentDesc, ComponentEntry, ComponentRegistry, Entity, EntityId,
@@ -12,6 +16,8 @@ use serde::{
     Deserialize, Deserializer,
 };
 
+use crate::quantization::{next_quantized_entry, QuantizedValue};
+
 /// Bincode options used for diff serialization
 pub fn bincode_options() -> impl Options {
     bincode::DefaultOptions::new()
@@ -19,6 +25,45 @@ pub fn bincode_options() -> impl Options {
         .allow_trailing_bytes()
 }
 
+#[cfg(not(target_os = "unknown"))]
+fn compress(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    zstd::encode_all(data, zstd::DEFAULT_COMPRESSION_LEVEL)
+}
+
+#[cfg(target_os = "unknown")]
+fn compress(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    Ok(ruzstd::encoding::compress_to_vec(
+        data,
+        ruzstd::encoding::CompressionLevel::Fastest,
+    ))
+}
+
+#[cfg(not(target_os = "unknown"))]
+fn decoder(payload: &[u8]) -> std::io::Result<impl Read + '_> {
+    zstd::stream::read::Decoder::new(payload)
+}
+
+#[cfg(target_os = "unknown")]
+fn decoder(payload: &[u8]) -> std::io::Result<impl Read + '_> {
+    ruzstd::decoding::StreamingDecoder::new(payload)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+/// Decompresses a diff compressed with `DiffEncoding::compress`, rejecting diffs larger than
+/// `limit` bytes without decompressing the rest of them
+fn decompress(payload: &[u8], limit: usize) -> Result<Vec<u8>, bincode::Error> {
+    let mut decompressed = Vec::new();
+    decoder(payload)?
+        .take(limit as u64 + 1)
+        .read_to_end(&mut decompressed)?;
+    if decompressed.len() > limit {
+        return Err(<bincode::Error as serde::de::Error>::custom(format!(
+            "Compressed diff is larger than {limit} bytes"
+        )));
+    }
+    Ok(decompressed)
+}
+
 /// `WorldDiffDeduplicator` filters out duplicated `WorldChange::SetComponents` changes.
 ///
 /// It keeps track of serialized values of all `WorldChange::SetComponents` passed to it in the previous call to
@@ -80,6 +125,12 @@ impl WorldDiffDeduplicator {
         });
         self.last_diff = new_diff;
     }
+
+    /// Forgets the last value of a component, so that the next value is not discarded even if it is the same. Used for
+    /// changes which were deduplicated but not sent.
+    pub fn forget(&mut self, id: EntityId, desc: ComponentDesc) {
+        self.last_diff.remove(&(id, desc.index()));
+    }
 }
 
 /// Explicit tag used for WorldChange enum on the wire
@@ -205,6 +256,42 @@ impl From<&ComponentDesc> for UnknownComponent {
     }
 }
 
+/// How a diff is encoded, in addition to the optimisations that `DiffSerializer` always makes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiffEncoding {
+    /// Compress the diff with zstd
+    pub compress: bool,
+    /// Serialize `Vec3`, `Quat` and `Mat4` values with reduced precision
+    pub quantize: bool,
+}
+impl DiffEncoding {
+    const COMPRESSED: u8 = 1 << 0;
+    const QUANTIZED: u8 = 1 << 1;
+
+    fn to_flags(self) -> u8 {
+        let mut flags = 0;
+        if self.compress {
+            flags |= Self::COMPRESSED;
+        }
+        if self.quantize {
+            flags |= Self::QUANTIZED;
+        }
+        flags
+    }
+
+    fn from_flags(flags: u8) -> Result<Self, bincode::Error> {
+        if flags & !(Self::COMPRESSED | Self::QUANTIZED) != 0 {
+            return Err(serde::de::Error::custom(format!(
+                "Unknown diff flags: {flags:#x}"
+            )));
+        }
+        Ok(Self {
+            compress: flags & Self::COMPRESSED != 0,
+            quantize: flags & Self::QUANTIZED != 0,
+        })
+    }
+}
+
 /// `DiffSerializer` is optimized for serializing `WorldDiff` for transfer over network.
 ///
 /// Compared to the regular text serialization of components it makes a few optimisations like:
@@ -228,7 +315,10 @@ impl From<&ComponentDesc> for UnknownComponent {
 ///
 /// See `bincode_options()` function for exact bincode options used in serialization.
 ///
-/// On the top level each diff consist of 2 parts:
+/// Each diff starts with a byte of flags, which records the `DiffEncoding` of the diff. If the diff is compressed, the
+/// rest of it is compressed with zstd.
+///
+/// After the flags, each diff consist of 2 parts:
 /// 1. Component path index - mapping of internal component index (`u32`) to `UnknownComponent` (either a `String` path
 ///     or an `ExternalComponent`) serialized directly with bincode.
 /// 2. Collection of `WorldChange` elements with custom serialization.
@@ -246,6 +336,9 @@ impl From<&ComponentDesc> for UnknownComponent {
 /// `ComponentEntry` and `ComponentDesc` are serialized differently compared to the textual representation. Instead of
 /// serializing the full component path, `DiffSerializer` serializes only the internal component index (`u32`).
 ///
+/// If the diff is quantized, the values of `Vec3`, `Quat` and `Mat4` components are serialized with reduced precision,
+/// see the `quantization` module for details.
+///
 /// ## Example
 ///
 /// ```
@@ -265,14 +358,14 @@ impl From<&ComponentDesc> for UnknownComponent {
 /// let diff = WorldDiff { changes: vec![WorldChange::SetComponents(id, entity)] };
 /// let serialized = serializer.serialize(&diff).unwrap();
 ///
-/// assert_eq!(serialized.as_ref(), b"\x01\0\0\x18ambient_core::test::text\x01\x04\xfd\xef\xbe\xad\xde\xef\xbe\xad\xde\x01\0\x03foo");
+/// assert_eq!(serialized.as_ref(), b"\0\x01\0\0\x18ambient_core::test::text\x01\x04\xfd\xef\xbe\xad\xde\xef\xbe\xad\xde\x01\0\x03foo");
 ///
 /// // note that when the same component is seen again, it won't be included in the index
 /// let new_entity = Entity::new().with(text(), "bar".to_string());
 /// let new_diff = WorldDiff { changes: vec![WorldChange::SetComponents(id, new_entity)] };
 /// let new_serialized = serializer.serialize(&new_diff).unwrap();
 ///
-/// assert_eq!(new_serialized.as_ref(), b"\0\x01\x04\xfd\xef\xbe\xad\xde\xef\xbe\xad\xde\x01\0\x03bar");
+/// assert_eq!(new_serialized.as_ref(), b"\0\0\x01\x04\xfd\xef\xbe\xad\xde\xef\xbe\xad\xde\x01\0\x03bar");
 ///
 /// // that's why it's crucial for deserializer to see all payloads
 /// let mut deserializer = DiffSerializer::default();
@@ -281,6 +374,8 @@ impl From<&ComponentDesc> for UnknownComponent {
 #[derive(Clone, Default)]
 pub struct DiffSerializer {
     known_component_paths: HashMap<u32, String>,
+    /// The number of bytes of each component deserialized since they were last taken
+    component_bytes: HashMap<u32, u64>,
 }
 
 impl std::fmt::Debug for DiffSerializer {
@@ -293,9 +388,11 @@ impl DiffSerializer {
         &mut self,
         unknown_components: HashMap<u32, UnknownComponent>,
         diff: &NetworkedWorldDiff,
+        encoding: DiffEncoding,
     ) -> Result<Bytes, bincode::Error> {
+        let mut buffer = vec![encoding.to_flags()];
         // serialize unknown components so that deserialize can map idx to component path
-        let mut buffer = bincode_options().serialize(&unknown_components)?;
+        bincode_options().serialize_into(&mut buffer, &unknown_components)?;
         // keep them
         self.known_component_paths.extend(
             unknown_components
@@ -303,7 +400,12 @@ impl DiffSerializer {
                 .map(|(k, v)| (k, v.into_path())),
         );
         // serialize the actual change
-        buffer.extend_from_slice(&bincode_options().serialize(&diff)?);
+        bincode_options().serialize_into(&mut buffer, &diff)?;
+        if encoding.compress {
+            let compressed = compress(&buffer[1..])?;
+            buffer.truncate(1);
+            buffer.extend_from_slice(&compressed);
+        }
         Ok(buffer.into())
     }
 
@@ -313,7 +415,11 @@ impl DiffSerializer {
         // create dummy diff
         let diff = Default::default();
         // serialize everything
-        self.serialize_parts(unknown_components, &NetworkedWorldDiff(&diff))
+        self.serialize_parts(
+            unknown_components,
+            &NetworkedWorldDiff(&diff, false),
+            DiffEncoding::default(),
+        )
     }
 
     fn collect_all_unknown_external_components(&self) -> HashMap<u32, UnknownComponent> {
@@ -327,10 +433,42 @@ impl DiffSerializer {
     }
 
     pub fn serialize(&mut self, diff: &WorldDiff) -> Result<Bytes, bincode::Error> {
+        self.serialize_with(diff, DiffEncoding::default())
+    }
+
+    pub fn serialize_with(
+        &mut self,
+        diff: &WorldDiff,
+        encoding: DiffEncoding,
+    ) -> Result<Bytes, bincode::Error> {
         // get all component that we haven't seen before
         let unknown_components = self.collect_unknown_components(diff.changes.iter());
         // serialize everything
-        self.serialize_parts(unknown_components, &NetworkedWorldDiff(diff))
+        self.serialize_parts(
+            unknown_components,
+            &NetworkedWorldDiff(diff, encoding.quantize),
+            encoding,
+        )
+    }
+
+    /// The number of bytes that `entry` takes up in a diff, excluding the change it belongs to
+    pub fn entry_size(entry: &ComponentEntry, encoding: DiffEncoding) -> u64 {
+        bincode_options()
+            .serialized_size(&NetworkedComponentEntry(entry, encoding.quantize))
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of bytes of each component that have been deserialized since this was
+    /// last called, by component path. The sizes are of the uncompressed diff, so they do not
+    /// include the savings of `DiffEncoding::compress`.
+    pub fn take_component_bytes(&mut self) -> HashMap<String, u64> {
+        self.component_bytes
+            .drain()
+            .filter_map(|(index, bytes)| {
+                let path = self.known_component_paths.get(&index)?;
+                Some((path.clone(), bytes))
+            })
+            .collect()
     }
 
     fn collect_unknown_components<'a, I>(&self, changes: I) -> HashMap<u32, UnknownComponent>
@@ -365,8 +503,24 @@ impl DiffSerializer {
     }
 
     pub fn deserialize(&mut self, message: Bytes) -> Result<WorldDiff, bincode::Error> {
-        let mut deserializer =
-            bincode::Deserializer::with_reader(message.as_ref(), bincode_options());
+        let (&flags, payload) = message
+            .split_first()
+            .ok_or_else(|| <bincode::Error as serde::de::Error>::custom("Empty diff"))?;
+        let encoding = DiffEncoding::from_flags(flags)?;
+        let decompressed;
+        let payload = if encoding.compress {
+            decompressed = decompress(payload, crate::MAX_FRAME_SIZE)?;
+            decompressed.as_slice()
+        } else {
+            payload
+        };
+
+        let counter = ByteCounter::default();
+        let reader = CountingReader {
+            inner: payload,
+            position: &counter.position,
+        };
+        let mut deserializer = bincode::Deserializer::with_reader(reader, bincode_options());
         // deserialize component paths we should know about
         let unknown_components = HashMap::<u32, UnknownComponent>::deserialize(&mut deserializer)?;
         let external_components: Vec<_> = unknown_components
@@ -382,20 +536,52 @@ impl DiffSerializer {
                 .map(|(k, v)| (k, v.into_path())),
         );
         // deserialize the actual changes
-        deserializer.deserialize_seq(NetworkedChangesVisitor::from(&*self))
+        let diff = deserializer.deserialize_seq(NetworkedChangesVisitor {
+            known_component_paths: &self.known_component_paths,
+            quantized: encoding.quantize,
+            counter: &counter,
+        })?;
+        for (index, bytes) in counter.component_bytes.into_inner() {
+            *self.component_bytes.entry(index).or_default() += bytes;
+        }
+        Ok(diff)
+    }
+}
+
+/// Measures how many bytes of a diff each component takes up as it is deserialized
+#[derive(Debug, Default)]
+struct ByteCounter {
+    position: Cell<u64>,
+    component_bytes: RefCell<HashMap<u32, u64>>,
+}
+impl ByteCounter {
+    fn add(&self, desc: ComponentDesc, start: u64) {
+        let bytes = self.position.get() - start;
+        *self
+            .component_bytes
+            .borrow_mut()
+            .entry(desc.index())
+            .or_default() += bytes;
+    }
+}
+
+struct CountingReader<'a> {
+    inner: &'a [u8],
+    position: &'a Cell<u64>,
+}
+impl<'a> Read for CountingReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.position.set(self.position.get() + len as u64);
+        Ok(len)
     }
 }
 
 #[derive(Clone, Copy, Debug)]
 struct NetworkedChangesVisitor<'a> {
     known_component_paths: &'a HashMap<u32, String>,
-}
-impl<'a> From<&'a DiffSerializer> for NetworkedChangesVisitor<'a> {
-    fn from(value: &'a DiffSerializer) -> Self {
-        Self {
-            known_component_paths: &value.known_component_paths,
-        }
-    }
+    quantized: bool,
+    counter: &'a ByteCounter,
 }
 impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangesVisitor<'a> {
     type Value = WorldDiff;
@@ -423,6 +609,8 @@ impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangesVisitor<'a> {
 #[derive(Clone, Copy, Debug)]
 struct NetworkedChangeVisitor<'a> {
     known_component_paths: &'a HashMap<u32, String>,
+    quantized: bool,
+    counter: &'a ByteCounter,
 }
 impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangeVisitor<'a> {
     type Value = WorldChange;
@@ -526,6 +714,8 @@ impl<'de> serde::de::DeserializeSeed<'de> for NetworkedEntityIdVisitor {
 #[derive(Clone, Copy, Debug)]
 struct NetworkedEntityVisitor<'a> {
     known_component_paths: &'a HashMap<u32, String>,
+    quantized: bool,
+    counter: &'a ByteCounter,
 }
 impl<'a, 'de> serde::de::Visitor<'de> for NetworkedEntityVisitor<'a> {
     type Value = Entity;
@@ -653,6 +843,8 @@ impl<'a, 'de> serde::de::DeserializeSeed<'de> for NetworkedComponentDescVisitor<
 #[derive(Clone, Copy, Debug)]
 struct NetworkedComponentEntryVisitor<'a> {
     known_component_paths: &'a HashMap<u32, String>,
+    quantized: bool,
+    counter: &'a ByteCounter,
 }
 impl<'a, 'de> serde::de::Visitor<'de> for NetworkedComponentEntryVisitor<'a> {
     type Value = ComponentEntry;
@@ -665,6 +857,7 @@ impl<'a, 'de> serde::de::Visitor<'de> for NetworkedComponentEntryVisitor<'a> {
     where
         A: serde::de::SeqAccess<'de>,
     {
+        let start = self.counter.position.get();
         let desc = seq
             .next_element_seed(NetworkedComponentDescVisitor::from(self))?
             .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
@@ -674,9 +867,16 @@ impl<'a, 'de> serde::de::Visitor<'de> for NetworkedComponentEntryVisitor<'a> {
                 desc
             )));
         };
-        let entry = seq
-            .next_element_seed(ser.deserializer(desc))?
-            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+        let entry = match self
+            .quantized
+            .then(|| next_quantized_entry(&mut seq, desc))
+            .flatten()
+        {
+            Some(entry) => entry?,
+            None => seq.next_element_seed(ser.deserializer(desc))?,
+        }
+        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+        self.counter.add(desc, start);
         Ok(entry)
     }
 }
@@ -691,8 +891,9 @@ impl<'a, 'de> serde::de::DeserializeSeed<'de> for NetworkedComponentEntryVisitor
     }
 }
 
+/// The diff, and whether it is quantized
 #[derive(Clone, Copy, Debug)]
-struct NetworkedWorldDiff<'a>(&'a WorldDiff);
+struct NetworkedWorldDiff<'a>(&'a WorldDiff, bool);
 impl<'a> serde::Serialize for NetworkedWorldDiff<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -700,7 +901,7 @@ impl<'a> serde::Serialize for NetworkedWorldDiff<'a> {
     {
         let mut seq = serializer.serialize_seq(Some(self.0.changes.len()))?;
         for change in self.0.changes.iter() {
-            seq.serialize_element(&NetworkedWorldChange::from(change))?;
+            seq.serialize_element(&NetworkedWorldChange::new(change, self.1))?;
         }
         seq.end()
     }
@@ -715,6 +916,26 @@ enum NetworkedWorldChange<'a> {
     SetComponents(u128, NetworkedEntity<'a>),
 }
 impl<'a> NetworkedWorldChange<'a> {
+    fn new(value: &'a WorldChange, quantized: bool) -> Self {
+        match value {
+            WorldChange::Spawn(id, entity) => Self::Spawn(id.0, NetworkedEntity(entity, quantized)),
+            WorldChange::Despawn(id) => Self::Despawn(id.0),
+            WorldChange::AddComponents(id, entity) => {
+                Self::AddComponents(id.0, NetworkedEntity(entity, quantized))
+            }
+            WorldChange::RemoveComponents(id, components) => Self::RemoveComponents(
+                id.0,
+                components
+                    .iter()
+                    .map(|desc| NetworkedComponentDesc(desc.index()))
+                    .collect(),
+            ),
+            WorldChange::SetComponents(id, entity) => {
+                Self::SetComponents(id.0, NetworkedEntity(entity, quantized))
+            }
+        }
+    }
+
     fn id(&self) -> u128 {
         match self {
             NetworkedWorldChange::Spawn(id, _)
@@ -734,27 +955,6 @@ impl<'a> NetworkedWorldChange<'a> {
         }
     }
 }
-impl<'a> From<&'a WorldChange> for NetworkedWorldChange<'a> {
-    fn from(value: &'a WorldChange) -> Self {
-        match value {
-            WorldChange::Spawn(id, entity) => Self::Spawn(id.0, NetworkedEntity(entity)),
-            WorldChange::Despawn(id) => Self::Despawn(id.0),
-            WorldChange::AddComponents(id, entity) => {
-                Self::AddComponents(id.0, NetworkedEntity(entity))
-            }
-            WorldChange::RemoveComponents(id, components) => Self::RemoveComponents(
-                id.0,
-                components
-                    .iter()
-                    .map(|desc| NetworkedComponentDesc(desc.index()))
-                    .collect(),
-            ),
-            WorldChange::SetComponents(id, entity) => {
-                Self::SetComponents(id.0, NetworkedEntity(entity))
-            }
-        }
-    }
-}
 impl<'a> serde::Serialize for NetworkedWorldChange<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -781,7 +981,7 @@ impl<'a> serde::Serialize for NetworkedWorldChange<'a> {
 }
 
 #[derive(Clone, Copy, Debug)]
-struct NetworkedEntity<'a>(&'a Entity);
+struct NetworkedEntity<'a>(&'a Entity, bool);
 impl<'a> serde::Serialize for NetworkedEntity<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -789,14 +989,14 @@ impl<'a> serde::Serialize for NetworkedEntity<'a> {
     {
         let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
         for entry in self.0.iter() {
-            seq.serialize_element(&NetworkedComponentEntry(entry))?;
+            seq.serialize_element(&NetworkedComponentEntry(entry, self.1))?;
         }
         seq.end()
     }
 }
 
 #[derive(Clone, Copy, Debug)]
-struct NetworkedComponentEntry<'a>(&'a ComponentEntry);
+struct NetworkedComponentEntry<'a>(&'a ComponentEntry, bool);
 impl<'a> serde::Serialize for NetworkedComponentEntry<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -810,7 +1010,10 @@ impl<'a> serde::Serialize for NetworkedComponentEntry<'a> {
                 self.0
             )));
         };
-        seq.serialize_element(ser.serialize(self.0))?;
+        match self.1.then(|| QuantizedValue::from_entry(self.0)).flatten() {
+            Some(value) => seq.serialize_element(&value)?,
+            None => seq.serialize_element(ser.serialize(self.0))?,
+        }
         seq.end()
     }
 }
@@ -829,13 +1032,26 @@ macro_rules! impl_from_kcp {
         }
     };
 }
-impl_from_kcp!(NetworkedChangesVisitor<'a>, NetworkedChangeVisitor<'a>);
-impl_from_kcp!(NetworkedChangeVisitor<'a>, NetworkedEntityVisitor<'a>);
+macro_rules! impl_from_decoder {
+    ($source:ty, $target:ty) => {
+        impl<'a> From<$source> for $target {
+            fn from(value: $source) -> Self {
+                Self {
+                    known_component_paths: value.known_component_paths,
+                    quantized: value.quantized,
+                    counter: value.counter,
+                }
+            }
+        }
+    };
+}
+impl_from_decoder!(NetworkedChangesVisitor<'a>, NetworkedChangeVisitor<'a>);
+impl_from_decoder!(NetworkedChangeVisitor<'a>, NetworkedEntityVisitor<'a>);
 impl_from_kcp!(
     NetworkedChangeVisitor<'a>,
     NetworkedComponentDescsVisitor<'a>
 );
-impl_from_kcp!(
+impl_from_decoder!(
     NetworkedEntityVisitor<'a>,
     NetworkedComponentEntryVisitor<'a>
 );
@@ -854,7 +1070,10 @@ impl_from_kcp!(
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use ambient_ecs::components;
+    use glam::{vec3, EulerRot, Mat4, Quat, Vec3};
 
     use super::*;
 
@@ -865,6 +1084,14 @@ mod tests {
         float: f32,
         @[Serializable]
         counter: usize,
+        @[Serializable]
+        scores: BTreeMap<String, u32>,
+        @[Serializable]
+        position: Vec3,
+        @[Serializable]
+        orientation: Quat,
+        @[Serializable]
+        transform: Mat4,
     });
 
     fn assert_same_diffs<'a, 'b, A, B>(a: A, b: B)
@@ -978,6 +1205,25 @@ mod tests {
         });
     }
 
+    #[test]
+    fn map_components_serialize_correctly() {
+        init_components();
+        let id = EntityId::new();
+        let entity = Entity::new().with(
+            scores(),
+            BTreeMap::from([("alice".to_string(), 3), ("bob".to_string(), 5)]),
+        );
+        assert_passes_through_serialization(WorldDiff {
+            changes: vec![WorldChange::Spawn(id, entity.clone())],
+        });
+        assert_passes_through_serialization(WorldDiff {
+            changes: vec![WorldChange::SetComponents(
+                id,
+                Entity::new().with(scores(), BTreeMap::new()),
+            )],
+        });
+    }
+
     #[test]
     fn component_paths_are_passed_once() {
         // Arrange
@@ -1021,6 +1267,176 @@ mod tests {
         assert_same_diffs(&diff, &second_diff);
     }
 
+    #[test]
+    fn quantized_values_are_close() {
+        // Arrange
+        init_components();
+        let id = EntityId::new();
+        let rotation = Quat::from_euler(EulerRot::XYZ, 0.3, -1.2, 2.5);
+        let entity = Entity::new()
+            .with(text(), "foo".to_string())
+            .with(position(), vec3(1.5, -20.123, 3000.0))
+            .with(orientation(), rotation)
+            .with(
+                transform(),
+                Mat4::from_scale_rotation_translation(Vec3::splat(2.0), rotation, Vec3::X),
+            );
+        let diff = WorldDiff {
+            changes: vec![WorldChange::Spawn(id, entity.clone())],
+        };
+        let encoding = DiffEncoding {
+            compress: false,
+            quantize: true,
+        };
+
+        // Act
+        let full = DiffSerializer::default().serialize(&diff).unwrap();
+        let quantized = DiffSerializer::default()
+            .serialize_with(&diff, encoding)
+            .unwrap();
+        let received = DiffSerializer::default()
+            .deserialize(quantized.clone())
+            .unwrap();
+
+        // Assert
+        assert!(quantized.len() < full.len());
+        let WorldChange::Spawn(received_id, received) = &received.changes[0] else {
+            panic!("Expected a spawn, got {:?}", received.changes);
+        };
+        assert_eq!(*received_id, id);
+        assert_eq!(received.get_ref(text()), entity.get_ref(text()));
+        assert!(received
+            .get(position())
+            .unwrap()
+            .abs_diff_eq(entity.get(position()).unwrap(), 1.0 / 256.0));
+        assert!(received.get(orientation()).unwrap().angle_between(rotation) < 1e-3);
+        assert!(received
+            .get(transform())
+            .unwrap()
+            .abs_diff_eq(entity.get(transform()).unwrap(), 1e-3));
+    }
+
+    #[test]
+    fn out_of_range_values_are_not_quantized() {
+        init_components();
+        let values = [Vec3::splat(1e9), Vec3::NAN, Vec3::INFINITY];
+        for value in values {
+            let diff = WorldDiff {
+                changes: vec![WorldChange::SetComponents(
+                    EntityId::new(),
+                    Entity::new().with(position(), value),
+                )],
+            };
+            let encoding = DiffEncoding {
+                compress: false,
+                quantize: true,
+            };
+            let bytes = DiffSerializer::default()
+                .serialize_with(&diff, encoding)
+                .unwrap();
+            let received = DiffSerializer::default().deserialize(bytes).unwrap();
+            let WorldChange::SetComponents(_, received) = &received.changes[0] else {
+                panic!("Expected a set, got {:?}", received.changes);
+            };
+            assert_eq!(
+                received
+                    .get(position())
+                    .unwrap()
+                    .to_array()
+                    .map(f32::to_bits),
+                value.to_array().map(f32::to_bits)
+            );
+        }
+    }
+
+    #[test]
+    fn compressed_diffs_deserialize_correctly() {
+        // Arrange
+        init_components();
+        let changes = (0..100)
+            .map(|i| {
+                let entity = Entity::new()
+                    .with(text(), "the same text for every entity".to_string())
+                    .with(counter(), i);
+                WorldChange::Spawn(EntityId::new(), entity)
+            })
+            .collect();
+        let diff = WorldDiff { changes };
+        let encoding = DiffEncoding {
+            compress: true,
+            quantize: false,
+        };
+
+        // Act
+        let full = DiffSerializer::default().serialize(&diff).unwrap();
+        let compressed = DiffSerializer::default()
+            .serialize_with(&diff, encoding)
+            .unwrap();
+        let received = DiffSerializer::default()
+            .deserialize(compressed.clone())
+            .unwrap();
+
+        // Assert
+        assert!(compressed.len() < full.len());
+        assert_same_diffs(&diff, &received);
+    }
+
+    #[test]
+    fn compressed_diffs_larger_than_the_limit_are_rejected() {
+        // Arrange
+        let compressed = compress(&[0; 1024]).unwrap();
+
+        // Act
+        let within = decompress(&compressed, 1024);
+        let over = decompress(&compressed, 1023);
+
+        // Assert
+        assert_eq!(within.unwrap().len(), 1024);
+        assert!(over.is_err());
+    }
+
+    #[test]
+    fn corrupt_compressed_diffs_are_rejected() {
+        // Arrange
+        let mut message = vec![DiffEncoding::COMPRESSED];
+        message.extend_from_slice(&[0xff; 16]);
+
+        // Act
+        let result = DiffSerializer::default().deserialize(message.into());
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserializer_counts_component_bytes() {
+        // Arrange
+        init_components();
+        let id = EntityId::new();
+        let entity = Entity::new()
+            .with(text(), "a longer piece of text".to_string())
+            .with(float(), 1.0);
+        let diff = WorldDiff {
+            changes: vec![WorldChange::SetComponents(id, entity.clone())],
+        };
+        let mut serializer = DiffSerializer::default();
+        let mut deserializer = DiffSerializer::default();
+
+        // Act
+        for _ in 0..2 {
+            let bytes = serializer.serialize(&diff).unwrap();
+            deserializer.deserialize(bytes).unwrap();
+        }
+        let component_bytes = deserializer.take_component_bytes();
+
+        // Assert
+        for entry in entity.iter() {
+            let size = DiffSerializer::entry_size(entry, DiffEncoding::default());
+            assert_eq!(component_bytes[&entry.path()], 2 * size);
+        }
+        assert!(deserializer.take_component_bytes().is_empty());
+    }
+
     #[test]
     fn world_change_tag_to_and_from_u8_matches() {
         for tag in [
@@ -0,0 +1,494 @@
+//! Snapshot interpolation of entities synchronized from the server.
+//!
+//! The states of entities with `is_interpolated` are buffered as they arrive, and the entities are
+//! rendered a short delay behind the server so that they can be interpolated between the buffered states,
+//! rather than jumping to each state as it arrives. If the next state is late, the entity is
+//! extrapolated for a short time.
+use std::{collections::VecDeque, time::Duration};
+
+use ambient_core::transform::{rotation, scale, translation};
+use ambient_ecs::{
+    components,
+    generated::network::components::{
+        interpolated_components, interpolation_delay, is_interpolated, is_predicted,
+    },
+    query, with_component_registry, ComponentDesc, ComponentEntry, Entity, EntityId, FnSystem,
+    Resource, SystemGroup, World, WorldChange, WorldDiff,
+};
+use ambient_sys::time::Instant;
+use glam::{Quat, Vec2, Vec3, Vec4};
+use itertools::Itertools;
+
+use crate::client::NetworkStats;
+
+/// Used if the `interpolation_delay` resource is not set
+pub const DEFAULT_INTERPOLATION_DELAY: Duration = Duration::from_millis(100);
+/// How long an entity is extrapolated for when the next state is late
+const MAX_EXTRAPOLATION: Duration = Duration::from_millis(250);
+/// The weight of each new snapshot in the running averages
+const SMOOTHING: f32 = 0.1;
+
+components!("network::interpolation", {
+    /// The buffered states of an interpolated entity
+    interpolation_buffer: InterpolationBuffer,
+    @[Resource]
+    interpolation_state: InterpolationState,
+});
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Sample {
+    F32(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Vec4(Vec4),
+    Quat(Quat),
+}
+
+impl Sample {
+    fn from_entry(entry: &ComponentEntry) -> Option<Self> {
+        if let Some(&v) = entry.try_downcast_ref::<f32>() {
+            Some(Self::F32(v))
+        } else if let Some(&v) = entry.try_downcast_ref::<Vec2>() {
+            Some(Self::Vec2(v))
+        } else if let Some(&v) = entry.try_downcast_ref::<Vec3>() {
+            Some(Self::Vec3(v))
+        } else if let Some(&v) = entry.try_downcast_ref::<Vec4>() {
+            Some(Self::Vec4(v))
+        } else {
+            entry.try_downcast_ref::<Quat>().map(|&v| Self::Quat(v))
+        }
+    }
+
+    fn into_entry(self, desc: ComponentDesc) -> ComponentEntry {
+        match self {
+            Self::F32(v) => ComponentEntry::from_raw_parts(desc, v),
+            Self::Vec2(v) => ComponentEntry::from_raw_parts(desc, v),
+            Self::Vec3(v) => ComponentEntry::from_raw_parts(desc, v),
+            Self::Vec4(v) => ComponentEntry::from_raw_parts(desc, v),
+            Self::Quat(v) => ComponentEntry::from_raw_parts(desc, v),
+        }
+    }
+
+    /// Interpolates towards `other`, or extrapolates past it if `t` is greater than 1
+    fn lerp(self, other: Self, t: f32) -> Self {
+        match (self, other) {
+            (Self::F32(a), Self::F32(b)) => Self::F32(a + (b - a) * t),
+            (Self::Vec2(a), Self::Vec2(b)) => Self::Vec2(a.lerp(b, t)),
+            (Self::Vec3(a), Self::Vec3(b)) => Self::Vec3(a.lerp(b, t)),
+            (Self::Vec4(a), Self::Vec4(b)) => Self::Vec4(a.lerp(b, t)),
+            (Self::Quat(a), Self::Quat(b)) => Self::Quat(a.slerp(b, t).normalize()),
+            _ => other,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Track {
+    component: ComponentDesc,
+    /// The states of the component by the time they arrived, oldest first
+    samples: VecDeque<(Instant, Sample)>,
+}
+
+/// The buffered states of an interpolated entity
+#[derive(Debug, Clone, Default)]
+pub struct InterpolationBuffer {
+    tracks: Vec<Track>,
+}
+
+impl InterpolationBuffer {
+    fn push(
+        &mut self,
+        component: ComponentDesc,
+        current: Option<Sample>,
+        time: Instant,
+        sample: Sample,
+        previous_snapshot: Option<Instant>,
+    ) {
+        let track = match self.tracks.iter().position(|v| v.component == component) {
+            Some(index) => &mut self.tracks[index],
+            None => {
+                self.tracks.push(Track {
+                    component,
+                    samples: VecDeque::new(),
+                });
+                self.tracks.last_mut().unwrap()
+            }
+        };
+
+        // The component did not change in the snapshots since its last state, so start the
+        // interpolation from the previous snapshot rather than from the last state
+        let last = match track.samples.back() {
+            Some(&(last_time, last)) => Some((last_time, last)),
+            None => previous_snapshot.zip(current),
+        };
+        if let (Some((last_time, last)), Some(previous_snapshot)) = (last, previous_snapshot) {
+            if last_time < previous_snapshot || track.samples.is_empty() {
+                track.samples.push_back((previous_snapshot, last));
+            }
+        }
+
+        match track.samples.back_mut() {
+            Some((last_time, last)) if *last_time == time => *last = sample,
+            _ => track.samples.push_back((time, sample)),
+        }
+    }
+
+    /// Returns the state of the entity at `render_time`, and whether it had to be extrapolated.
+    /// Tracks which have settled on their last state are removed.
+    fn sample(&mut self, render_time: Instant, last_snapshot: Instant) -> (Entity, bool) {
+        let mut data = Entity::new();
+        let mut extrapolated = false;
+
+        self.tracks.retain_mut(|track| {
+            let samples = &mut track.samples;
+            while samples.len() > 2 && samples[1].0 <= render_time {
+                samples.pop_front();
+            }
+
+            let (value, settled) = match (samples.front(), samples.get(1)) {
+                (Some(&(t0, a)), Some(&(t1, b))) if render_time > t0 => {
+                    let span = t1.duration_since(t0).as_secs_f32();
+                    let t = render_time.duration_since(t0).as_secs_f32() / span.max(f32::EPSILON);
+                    if t <= 1.0 {
+                        (a.lerp(b, t), false)
+                    } else if t1 < last_snapshot {
+                        // Later snapshots did not change the component, so it has stopped
+                        (b, true)
+                    } else {
+                        // The next snapshot is late
+                        extrapolated = true;
+                        let max = 1.0 + MAX_EXTRAPOLATION.as_secs_f32() / span.max(f32::EPSILON);
+                        (a.lerp(b, t.min(max)), false)
+                    }
+                }
+                (Some(&(_, a)), Some(_)) => (a, false),
+                (Some(&(_, a)), None) => (a, true),
+                (None, _) => return false,
+            };
+
+            data.set_entry(value.into_entry(track.component));
+            !settled
+        });
+
+        (data, extrapolated)
+    }
+
+    /// Returns the last buffered state of each component
+    fn latest(&self) -> Entity {
+        let mut data = Entity::new();
+        for track in &self.tracks {
+            if let Some(&(_, value)) = track.samples.back() {
+                data.set_entry(value.into_entry(track.component));
+            }
+        }
+        data
+    }
+}
+
+/// The timing of the snapshots received from the server
+#[derive(Debug, Clone, Default)]
+pub struct InterpolationState {
+    last_snapshot: Option<Instant>,
+    mean_interval: f32,
+    /// The mean deviation of the time between snapshots from the average, in seconds
+    jitter: f32,
+    /// The number of entity frames which were extrapolated since the stats were last taken
+    extrapolated_frames: u64,
+}
+
+/// The interpolated states of the entities in a diff from the server
+pub struct Snapshot {
+    time: Instant,
+    samples: Vec<(EntityId, ComponentDesc, Option<Sample>, Sample)>,
+}
+
+impl Snapshot {
+    /// Reads the interpolated components from `diff`. Must be called before the diff is applied
+    /// to `world`.
+    pub fn from_diff(world: &World, diff: &WorldDiff) -> Self {
+        let components = interpolated(world);
+        let mut samples = Vec::new();
+        for change in &diff.changes {
+            let (id, data) = match change {
+                WorldChange::Spawn(id, data)
+                | WorldChange::AddComponents(id, data)
+                | WorldChange::SetComponents(id, data) => (*id, data),
+                WorldChange::Despawn(_) | WorldChange::RemoveComponents(_, _) => continue,
+            };
+            // Entities opt in on the server, so it may only be in the diff that spawns them
+            if world.has_component(id, is_predicted())
+                || !(world.has_component(id, is_interpolated()) || data.contains(is_interpolated()))
+            {
+                continue;
+            }
+
+            for &component in &components {
+                let Some(sample) = data.get_entry(component).and_then(Sample::from_entry) else {
+                    continue;
+                };
+                let current = world
+                    .get_entry(id, component)
+                    .ok()
+                    .and_then(|v| Sample::from_entry(&v));
+                samples.push((id, component, current, sample));
+            }
+        }
+
+        Self {
+            time: Instant::now(),
+            samples,
+        }
+    }
+
+    /// Buffers the states in the snapshot. Must be called after the diff is applied to `world`.
+    pub fn record(self, world: &mut World) {
+        if world.resource_opt(interpolation_state()).is_none() {
+            world.add_resource(interpolation_state(), InterpolationState::default());
+        }
+        let state = world.resource_mut(interpolation_state());
+        let previous_snapshot = state.last_snapshot.replace(self.time);
+        if let Some(previous_snapshot) = previous_snapshot {
+            let interval = self.time.duration_since(previous_snapshot).as_secs_f32();
+            if state.mean_interval == 0.0 {
+                state.mean_interval = interval;
+            }
+            state.mean_interval += (interval - state.mean_interval) * SMOOTHING;
+            state.jitter += ((interval - state.mean_interval).abs() - state.jitter) * SMOOTHING;
+        }
+
+        for (id, component, current, sample) in self.samples {
+            if !world.exists(id) {
+                continue;
+            }
+            if !world.has_component(id, interpolation_buffer()) {
+                world
+                    .add_component(id, interpolation_buffer(), Default::default())
+                    .unwrap();
+            }
+            world.get_mut(id, interpolation_buffer()).unwrap().push(
+                component,
+                current,
+                self.time,
+                sample,
+                previous_snapshot,
+            );
+        }
+    }
+}
+
+/// Fills in the interpolation stats of `stats`, resetting the counters
+pub(crate) fn take_stats(world: &mut World, stats: &mut NetworkStats) {
+    stats.interpolation_delay_ms = interpolation_delay_of(world).as_millis() as u64;
+    if let Some(state) = world.resource_mut_opt(interpolation_state()) {
+        stats.snapshot_jitter_ms = (state.jitter * 1000.0) as u64;
+        stats.extrapolated_frames = std::mem::take(&mut state.extrapolated_frames);
+    }
+}
+
+/// Moves interpolated entities to their interpolated states
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "network/interpolation",
+        vec![Box::new(FnSystem::new(|world, _| {
+            // Entities which are no longer interpolated are moved to their last state from the
+            // server
+            let stopped = query(interpolation_buffer())
+                .excl(is_interpolated())
+                .iter(world, None)
+                .map(|(id, buffer)| (id, buffer.latest()))
+                .collect_vec();
+            for (id, data) in stopped {
+                for entry in data {
+                    world.set_entry(id, entry).ok();
+                }
+                world.remove_component(id, interpolation_buffer()).ok();
+            }
+
+            let Some(last_snapshot) = world
+                .resource_opt(interpolation_state())
+                .and_then(|v| v.last_snapshot)
+            else {
+                return;
+            };
+            let render_time = Instant::now() - interpolation_delay_of(world);
+
+            let ids = query(interpolation_buffer())
+                .excl(is_predicted())
+                .iter(world, None)
+                .map(|(id, _)| id)
+                .collect_vec();
+            let mut extrapolated_frames = 0;
+            for id in ids {
+                let buffer = world.get_mut(id, interpolation_buffer()).unwrap();
+                let (data, extrapolated) = buffer.sample(render_time, last_snapshot);
+                let settled = buffer.tracks.is_empty();
+                extrapolated_frames += extrapolated as u64;
+
+                // The entity may have lost some of the components since they were buffered
+                for entry in data {
+                    world.set_entry(id, entry).ok();
+                }
+                if settled {
+                    world.remove_component(id, interpolation_buffer()).ok();
+                }
+            }
+            world
+                .resource_mut(interpolation_state())
+                .extrapolated_frames += extrapolated_frames;
+        }))],
+    )
+}
+
+fn interpolation_delay_of(world: &World) -> Duration {
+    world
+        .resource_opt(interpolation_delay())
+        .copied()
+        .unwrap_or(DEFAULT_INTERPOLATION_DELAY)
+}
+
+/// The components that are interpolated
+fn interpolated(world: &World) -> Vec<ComponentDesc> {
+    let mut components = vec![translation().desc(), rotation().desc(), scale().desc()];
+    if let Some(paths) = world.resource_opt(interpolated_components()) {
+        with_component_registry(|registry| {
+            for path in paths {
+                match registry.get_by_path(path) {
+                    Some(component) => components.push(component),
+                    None => tracing::warn!("Unknown interpolated component {path}"),
+                }
+            }
+        });
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    components!("test", {
+        value: f32,
+    });
+
+    const SNAPSHOT: Duration = Duration::from_millis(100);
+
+    fn init() {
+        ambient_ecs::init_components();
+        init_components();
+    }
+
+    /// A buffer which received `10.0` one snapshot after the entity was at `0.0`
+    fn buffer(start: Instant) -> InterpolationBuffer {
+        let mut buffer = InterpolationBuffer::default();
+        buffer.push(
+            value().desc(),
+            Some(Sample::F32(0.0)),
+            start + SNAPSHOT,
+            Sample::F32(10.0),
+            Some(start),
+        );
+        buffer
+    }
+
+    fn sample_at(
+        buffer: &mut InterpolationBuffer,
+        render_time: Instant,
+        last_snapshot: Instant,
+    ) -> (Option<f32>, bool) {
+        let (data, extrapolated) = buffer.sample(render_time, last_snapshot);
+        (data.get(value()), extrapolated)
+    }
+
+    #[test]
+    fn states_are_interpolated_between_snapshots() {
+        init();
+        let start = Instant::now();
+        let mut buffer = buffer(start);
+
+        let last_snapshot = start + SNAPSHOT;
+        assert_eq!(
+            sample_at(&mut buffer, start, last_snapshot),
+            (Some(0.0), false)
+        );
+        let (v, extrapolated) = sample_at(&mut buffer, start + SNAPSHOT / 2, last_snapshot);
+        assert!((v.unwrap() - 5.0).abs() < 1e-3);
+        assert!(!extrapolated);
+        assert_eq!(buffer.tracks.len(), 1);
+    }
+
+    #[test]
+    fn late_snapshots_are_extrapolated_for_a_limited_time() {
+        init();
+        let start = Instant::now();
+        let mut buffer = buffer(start);
+
+        let last_snapshot = start + SNAPSHOT;
+        let (v, extrapolated) = sample_at(&mut buffer, start + SNAPSHOT * 3 / 2, last_snapshot);
+        assert!((v.unwrap() - 15.0).abs() < 1e-3);
+        assert!(extrapolated);
+
+        let max = 10.0 * (1.0 + MAX_EXTRAPOLATION.as_secs_f32() / SNAPSHOT.as_secs_f32());
+        let (v, _) = sample_at(&mut buffer, start + SNAPSHOT * 10, last_snapshot);
+        assert!((v.unwrap() - max).abs() < 1e-3);
+    }
+
+    #[test]
+    fn tracks_settle_when_later_snapshots_do_not_change_them() {
+        init();
+        let start = Instant::now();
+        let mut buffer = buffer(start);
+
+        // Another snapshot arrived without the component
+        let last_snapshot = start + SNAPSHOT * 2;
+        assert_eq!(
+            sample_at(&mut buffer, start + SNAPSHOT * 3 / 2, last_snapshot),
+            (Some(10.0), false)
+        );
+        assert!(buffer.tracks.is_empty());
+    }
+
+    #[test]
+    fn pushes_continue_from_the_previous_snapshot() {
+        init();
+        let start = Instant::now();
+        let mut buffer = buffer(start);
+
+        // The component was unchanged for a snapshot, so the next state is interpolated from
+        // when it was last known to be at `10.0`, rather than from its last change
+        buffer.push(
+            value().desc(),
+            Some(Sample::F32(10.0)),
+            start + SNAPSHOT * 3,
+            Sample::F32(20.0),
+            Some(start + SNAPSHOT * 2),
+        );
+        // A second state at the same time replaces the first
+        buffer.push(
+            value().desc(),
+            Some(Sample::F32(10.0)),
+            start + SNAPSHOT * 3,
+            Sample::F32(30.0),
+            Some(start + SNAPSHOT * 2),
+        );
+
+        let samples = buffer.tracks[0].samples.iter().map(|v| v.1).collect_vec();
+        assert_eq!(
+            samples,
+            [
+                Sample::F32(0.0),
+                Sample::F32(10.0),
+                Sample::F32(10.0),
+                Sample::F32(30.0)
+            ]
+        );
+        assert_eq!(buffer.latest().get(value()), Some(30.0));
+
+        let last_snapshot = start + SNAPSHOT * 3;
+        assert_eq!(
+            sample_at(&mut buffer, start + SNAPSHOT * 2, last_snapshot),
+            (Some(10.0), false)
+        );
+        let (v, _) = sample_at(&mut buffer, start + SNAPSHOT * 5 / 2, last_snapshot);
+        assert!((v.unwrap() - 20.0).abs() < 1e-3);
+    }
+}
@@ -16,13 +16,20 @@ pub use ambient_ecs::generated::network::components::{
 
 pub type AsyncMutex<T> = tokio::sync::Mutex<T>;
 
+pub mod bandwidth;
 pub mod bytes_ext;
 pub mod client;
 pub mod client_game_state;
 pub mod codec;
+#[cfg(not(target_os = "unknown"))]
+pub mod connection;
 pub mod diff_serialization;
 pub mod hooks;
+pub mod interpolation;
+pub mod prediction;
 pub mod proto;
+pub(crate) mod quantization;
+pub mod relevancy;
 pub mod rpc;
 pub mod serialization;
 pub mod server;
@@ -54,6 +61,8 @@ pub fn init_all_components() {
     client::init_components();
     server::init_components();
     client_game_state::init_components();
+    prediction::init_components();
+    interpolation::init_components();
 }
 
 pub trait ServerWorldExt {
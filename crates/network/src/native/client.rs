@@ -1,7 +1,14 @@
 use crate::{
-    client::{CleanupFunc, ClientState, Control, GameClientRenderTarget, LoadedFunc, NetworkStats},
+    client::{
+        CleanupFunc, ClientState, Control, GameClientRenderTarget, LoadedFunc, NetworkStats,
+        NetworkTransport,
+    },
     client_game_state::{game_screen_render_target, ClientGameState},
-    native::load_root_certs,
+    connection::Connection,
+    native::{
+        client_connection::ConnectionKind, load_root_certs, loopback::LoopbackConnector,
+        websocket::WebSocketConnection,
+    },
     proto::{
         client::{ClientProtoState, SharedClientGameState},
         ClientRequest,
@@ -26,10 +33,11 @@ use ambient_renderer::RenderTarget;
 use ambient_rpc::RpcRegistry;
 use ambient_ui_native::{Centered, Dock, FlowColumn, FlowRow, StylesExt, Text, Throbber};
 use anyhow::Context;
-use futures::{SinkExt, StreamExt};
+use bytes::Bytes;
+use futures::{future::BoxFuture, SinkExt, StreamExt};
 use glam::uvec2;
 use parking_lot::Mutex;
-use quinn::{ClientConfig, Connection, Endpoint, TransportConfig};
+use quinn::{ClientConfig, Endpoint, TransportConfig};
 use rand::Rng;
 use rustls::Certificate;
 use std::{
@@ -37,7 +45,17 @@ use std::{
     sync::Arc,
     time::Duration,
 };
-use tokio::net::ToSocketAddrs;
+use tokio::net::{TcpStream, ToSocketAddrs};
+use tokio_rustls::TlsConnector;
+use tokio_tungstenite::tungstenite::protocol::Role;
+use uuid::Uuid;
+
+/// How long to wait for a QUIC connection before falling back to a WebSocket
+const QUIC_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long the client tries to reconnect after losing its connection
+const RESUME_TIMEOUT: Duration = Duration::from_secs(30);
+/// How long to wait between attempts to reconnect
+const RESUME_RETRY_INTERVAL: Duration = Duration::from_secs(1);
 
 #[derive(Debug, Clone)]
 pub struct ResolvedAddr {
@@ -72,6 +90,9 @@ impl ResolvedAddr {
 pub struct ClientView {
     pub server_addr: ResolvedAddr,
     pub cert: Option<Vec<u8>>,
+    /// Connects to a server in the same process instead of `server_addr`. See
+    /// [`GameServer::loopback_connector`](crate::native::server::GameServer::loopback_connector).
+    pub loopback: Option<LoopbackConnector>,
     pub user_id: String,
     pub fail_on_version_mismatch: bool,
     pub systems_and_resources: Cb<dyn Fn() -> (SystemGroup, Entity) + Sync + Send>,
@@ -91,6 +112,7 @@ impl ElementComponent for ClientView {
             on_loaded,
             inner,
             cert,
+            loopback,
         } = *self;
 
         let gpu = hooks.world.resource(gpu()).clone();
@@ -153,77 +175,92 @@ impl ElementComponent for ClientView {
         use_task(hooks, move |ui_world| {
             let local_resources = world_instance_resources(AppResources::from_world(ui_world))
                 .with(game_screen_render_target(), render_target.0.clone());
-            let task = async move {
-                let conn = open_connection(server_addr.clone(), cert.map(Certificate))
-                    .await
-                    .with_context(|| {
-                        format!("Failed to connect to endpoint \"{server_addr:?}\"")
-                    })?;
-
-                handle_connection(
-                    conn.clone(),
-                    &assets,
+            let cert = cert.map(Certificate);
+            let mut create_game_state = move |args: OnConnectionState| {
+                let OnConnectionState {
+                    assets,
                     user_id,
-                    fail_on_version_mismatch,
-                    move |args| {
-                        let OnConnectionState {
-                            assets,
-                            user_id,
-                            main_package_name,
-                        } = args;
-
-                        set_window_title(main_package_name.to_string());
-
-                        let (systems, resources) = systems_and_resources();
-                        let resources = local_resources
-                            .clone()
-                            .with(ambient_core::player::local_user_id(), user_id.into())
-                            .with_merge(resources);
-
-                        let game_state = ClientGameState::new(
-                            &gpu,
-                            assets.clone(),
-                            user_id.into(),
-                            systems,
-                            resources,
-                        );
-
-                        // Create a handle for the game client
-                        let client_state = ClientState::new(
-                            Arc::new(conn.clone()),
-                            Arc::new(create_rpc_registry()),
-                            Arc::new(Mutex::new(game_state)),
-                            user_id.into(),
-                        );
-
-                        let game_state = &client_state.game_state;
-                        let cleanup = {
-                            // Lock before setting
-                            let game_state = &mut game_state.lock();
-
-                            // Updates the game client context in the Ui tree
-                            // Update the resources on the client side world to reflect the new connection
-                            // state
-
-                            game_state.world.add_resource(
-                                crate::client::client_state(),
-                                Some(client_state.clone()),
-                            );
-
-                            (on_loaded)(&client_state, game_state)?
-                        };
-
-                        // Set the client last so that the game state is initialized first
-                        set_client_state(Some(client_state.clone()));
-
-                        Ok((game_state.clone(), cleanup))
-                    },
-                    // game_state,
-                    control_rx,
-                )
-                .await?;
-
-                Ok(()) as anyhow::Result<()>
+                    main_package_name,
+                    transport,
+                } = args;
+
+                set_window_title(main_package_name.to_string());
+
+                let (systems, resources) = systems_and_resources();
+                let resources = local_resources
+                    .clone()
+                    .with(ambient_core::player::local_user_id(), user_id.into())
+                    .with_merge(resources);
+
+                let game_state =
+                    ClientGameState::new(&gpu, assets.clone(), user_id.into(), systems, resources);
+
+                // Create a handle for the game client
+                let client_state = ClientState::new(
+                    transport,
+                    Arc::new(create_rpc_registry()),
+                    Arc::new(Mutex::new(game_state)),
+                    user_id.into(),
+                );
+
+                let game_state = &client_state.game_state;
+                let cleanup = {
+                    // Lock before setting
+                    let game_state = &mut game_state.lock();
+
+                    // Updates the game client context in the Ui tree
+                    // Update the resources on the client side world to reflect the new connection
+                    // state
+
+                    game_state
+                        .world
+                        .add_resource(crate::client::client_state(), Some(client_state.clone()));
+
+                    (on_loaded)(&client_state, game_state)?
+                };
+
+                // Set the client last so that the game state is initialized first
+                set_client_state(Some(client_state.clone()));
+
+                Ok((game_state.clone(), cleanup))
+            };
+
+            let task = async move {
+                let mut conn =
+                    open_connection(server_addr.clone(), cert.clone(), loopback.as_ref())
+                        .await
+                        .with_context(|| {
+                            format!("Failed to connect to endpoint \"{server_addr:?}\"")
+                        })?;
+
+                // The game state is kept when the connection is lost, so that the session can be
+                // resumed
+                let mut session = None;
+                loop {
+                    let result = handle_connection(
+                        conn,
+                        &assets,
+                        &user_id,
+                        fail_on_version_mismatch,
+                        &mut session,
+                        &mut create_game_state,
+                        &control_rx,
+                    )
+                    .await;
+
+                    let resumable = session
+                        .as_ref()
+                        .is_some_and(|session| session.resume_token.is_some());
+                    match result {
+                        Err(err) if resumable && err.is::<NetworkError>() => {
+                            tracing::warn!("Connection lost, resuming the session: {err:?}");
+                            conn = reconnect(&server_addr, cert.clone(), loopback.as_ref())
+                                .await
+                                .context("Failed to resume the session")?;
+                        }
+                        result => return result,
+                    }
+                }
             };
 
             async move {
@@ -273,31 +310,82 @@ struct OnConnectionState<'a> {
     assets: &'a AssetCache,
     user_id: &'a str,
     main_package_name: &'a str,
+    transport: Arc<dyn NetworkTransport>,
 }
 
-async fn handle_connection(
-    conn: quinn::Connection,
+/// The state of the client which outlives a connection, so that the session can be resumed if the
+/// connection is lost
+struct Session {
+    /// Sent to the server to resume the session. `None` if the session can not be resumed.
+    resume_token: Option<Uuid>,
+    transport: Arc<SessionTransport>,
+    game_state: SharedClientGameState,
+    cleanup: Option<CleanupFunc>,
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        tracing::debug!("Running connection cleanup");
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup()
+        }
+    }
+}
+
+/// The transport of a session, which moves to the new connection when the session is resumed
+struct SessionTransport(Mutex<ConnectionKind>);
+
+impl SessionTransport {
+    fn connection(&self) -> ConnectionKind {
+        self.0.lock().clone()
+    }
+}
+
+impl NetworkTransport for SessionTransport {
+    fn request_bi(&self, id: u32, data: Bytes) -> BoxFuture<Result<Bytes, NetworkError>> {
+        let conn = self.connection();
+        Box::pin(async move { NetworkTransport::request_bi(&conn, id, data).await })
+    }
+
+    fn request_uni(&self, id: u32, data: Bytes) -> BoxFuture<Result<(), NetworkError>> {
+        let conn = self.connection();
+        Box::pin(async move { NetworkTransport::request_uni(&conn, id, data).await })
+    }
+
+    fn send_datagram(&self, id: u32, data: Bytes) -> BoxFuture<Result<(), NetworkError>> {
+        let conn = self.connection();
+        Box::pin(async move { NetworkTransport::send_datagram(&conn, id, data).await })
+    }
+}
+
+async fn handle_connection<F>(
+    conn: ConnectionKind,
     assets: &AssetCache,
-    user_id: String,
+    user_id: &str,
     fail_on_version_mismatch: bool,
-    mut on_loaded: impl FnMut(OnConnectionState) -> anyhow::Result<(SharedClientGameState, CleanupFunc)>
+    session: &mut Option<Session>,
+    on_loaded: &mut F,
+    control_rx: &flume::Receiver<Control>,
+) -> anyhow::Result<()>
+where
+    F: FnMut(OnConnectionState) -> anyhow::Result<(SharedClientGameState, CleanupFunc)>
         + Send
         + Sync,
-    control_rx: flume::Receiver<Control>,
-) -> anyhow::Result<()> {
+{
     let mut request_send = FramedSendStream::new(conn.open_uni().await?);
 
     // Accept the diff and stat stream
     // Nothing is read from them until the connection has been accepted
 
-    // Send a connection request
+    // Send a connection request, resuming the session of the lost connection if there is one
     tracing::info!("Attempting to connect using {user_id:?}");
 
+    let resume_token = session.as_ref().and_then(|session| session.resume_token);
     request_send
-        .send(ClientRequest::Connect(user_id.clone()))
+        .send(ClientRequest::Connect(user_id.to_string(), resume_token))
         .await?;
 
-    let mut client = ClientProtoState::Pending(user_id.clone());
+    let mut client = ClientProtoState::Pending(user_id.to_string());
 
     let mut push_recv = FramedRecvStream::new(conn.accept_uni().await?);
 
@@ -309,37 +397,52 @@ async fn handle_connection(
 
     assert!(ContentBaseUrlKey.exists(assets));
 
-    let main_package_name = if let ClientProtoState::Connected(connected) = &client {
-        connected.main_package_name.clone()
-    } else {
+    let ClientProtoState::Connected(connected) = &mut client else {
         tracing::warn!("Connection failed or was denied");
         return Ok(());
     };
+    let main_package_name = connected.main_package_name.clone();
+    let resume_token = connected.resume_token;
 
     tracing::info!("Connection successfully established");
 
-    // Create the game client
-
     let mut diff_stream = RawFramedRecvStream::new(conn.accept_uni().await?);
 
-    let (shared_client_state, cleanup) = on_loaded(OnConnectionState {
-        assets,
-        user_id: &user_id,
-        main_package_name: &main_package_name,
-    })?;
-
-    let on_disconnect = move || {
-        tracing::debug!("Running connection cleanup");
-        cleanup()
+    let shared_client_state = match session {
+        Some(session) => {
+            tracing::info!("Resuming the session");
+            // The world of the game client is replaced with the first diff
+            connected.resync();
+            *session.transport.0.lock() = conn.clone();
+            session.resume_token = resume_token;
+            session.game_state.clone()
+        }
+        None => {
+            // Create the game client
+            let transport = Arc::new(SessionTransport(Mutex::new(conn.clone())));
+            let (game_state, cleanup) = on_loaded(OnConnectionState {
+                assets,
+                user_id,
+                main_package_name: &main_package_name,
+                transport: transport.clone(),
+            })?;
+            session
+                .insert(Session {
+                    resume_token,
+                    transport,
+                    game_state,
+                    cleanup: Some(cleanup),
+                })
+                .game_state
+                .clone()
+        }
     };
 
-    scopeguard::defer!(on_disconnect());
-
     let stats_interval = 5;
     let mut stats_timer = tokio::time::interval(Duration::from_secs_f32(stats_interval as f32));
     let mut prev_stats = conn.stats();
 
-    let mut control_rx = control_rx.into_stream();
+    let mut control_rx = control_rx.stream();
 
     while let ClientProtoState::Connected(connected) = &mut client {
         tokio::select! {
@@ -350,11 +453,12 @@ async fn handle_connection(
                 let stats = conn.stats();
 
                 client.process_client_stats(&shared_client_state, NetworkStats {
-                    latency_ms: conn.rtt().as_millis() as u64,
-                    bytes_sent: (stats.udp_tx.bytes - prev_stats.udp_tx.bytes) / stats_interval,
-                    bytes_received: (stats.udp_rx.bytes - prev_stats.udp_rx.bytes) / stats_interval,
-                    packets_sent: (stats.path.sent_packets - prev_stats.path.sent_packets),
-                    packets_lost: (stats.path.lost_packets - prev_stats.path.lost_packets),
+                    latency_ms: stats.rtt.as_millis() as u64,
+                    bytes_sent: (stats.bytes_sent - prev_stats.bytes_sent) / stats_interval,
+                    bytes_received: (stats.bytes_received - prev_stats.bytes_received) / stats_interval,
+                    packets_sent: (stats.packets_sent - prev_stats.packets_sent),
+                    packets_lost: (stats.packets_lost - prev_stats.packets_lost),
+                    ..Default::default()
                 });
 
                 prev_stats = stats;
@@ -364,6 +468,10 @@ async fn handle_connection(
                 match control {
                     Control::Disconnect => {
                         tracing::debug!("Disconnecting manually");
+                        // The session ends with this connection
+                        if let Some(session) = session {
+                            session.resume_token = None;
+                        }
                         // Tell the server that we want to gracefully disconnect
                         request_send.send(ClientRequest::Disconnect).await?;
                     }
@@ -388,27 +496,107 @@ async fn handle_connection(
     Ok(())
 }
 
+/// Reconnects to the server after the connection was lost, retrying until the session can no longer
+/// be resumed
+async fn reconnect(
+    server_addr: &ResolvedAddr,
+    cert: Option<Certificate>,
+    loopback: Option<&LoopbackConnector>,
+) -> anyhow::Result<ConnectionKind> {
+    let deadline = tokio::time::Instant::now() + RESUME_TIMEOUT;
+    loop {
+        match open_connection(server_addr.clone(), cert.clone(), loopback).await {
+            Ok(conn) => return Ok(conn),
+            Err(err) if tokio::time::Instant::now() < deadline => {
+                tracing::debug!("Failed to reconnect, retrying: {err:?}");
+                tokio::time::sleep(RESUME_RETRY_INTERVAL).await;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
 /// Connnect to the server endpoint.
-#[tracing::instrument(level = "debug", skip(cert))]
-async fn open_connection(
+///
+/// Falls back to a WebSocket if the server can not be reached over QUIC, which happens on networks
+/// that block UDP. A server in the same process is connected to through `loopback` instead.
+#[tracing::instrument(level = "debug", skip(cert, loopback))]
+pub async fn open_connection(
     server_addr: ResolvedAddr,
     cert: Option<Certificate>,
-) -> anyhow::Result<Connection> {
+    loopback: Option<&LoopbackConnector>,
+) -> anyhow::Result<ConnectionKind> {
+    if let Some(loopback) = loopback {
+        tracing::debug!("Connecting to world instance in the same process");
+        return Ok(loopback.connect()?.into());
+    }
+
     tracing::debug!("Connecting to world instance: {server_addr:?}");
 
+    let conn = match open_quic_connection(&server_addr, cert.clone()).await {
+        Ok(conn) => conn,
+        Err(err) => {
+            tracing::warn!("Failed to connect over QUIC, falling back to a WebSocket: {err:?}");
+            let conn = open_websocket_connection(&server_addr, cert)
+                .await
+                .context("Failed to connect over a WebSocket")?;
+
+            tracing::debug!("Got websocket connection");
+            return Ok(conn.into());
+        }
+    };
+
+    tracing::debug!("Got connection");
+    Ok(conn.into())
+}
+
+/// Connects to the server over QUIC, failing if the server does not respond in time
+async fn open_quic_connection(
+    server_addr: &ResolvedAddr,
+    cert: Option<Certificate>,
+) -> anyhow::Result<quinn::Connection> {
     let endpoint =
         create_client_endpoint_random_port(cert).context("Failed to create client endpoint")?;
 
     tracing::debug!("Got endpoint");
-    let conn = endpoint
-        .connect(server_addr.addr, &server_addr.host_name)?
-        .await?;
+    let connecting = endpoint.connect(server_addr.addr, &server_addr.host_name)?;
 
-    tracing::debug!("Got connection");
+    let conn = tokio::time::timeout(QUIC_CONNECT_TIMEOUT, connecting)
+        .await
+        .context("Timed out connecting")??;
     Ok(conn)
 }
 
-pub fn create_client_endpoint_random_port(cert: Option<Certificate>) -> anyhow::Result<Endpoint> {
+/// Connects to the WebSocket of the server, which listens on the same port over TCP
+async fn open_websocket_connection(
+    server_addr: &ResolvedAddr,
+    cert: Option<Certificate>,
+) -> anyhow::Result<WebSocketConnection> {
+    let tls_config = client_tls_config(cert)?;
+
+    let stream = TcpStream::connect(server_addr.addr).await?;
+    stream.set_nodelay(true)?;
+
+    let server_name = rustls::ServerName::try_from(server_addr.host_name.as_str())?;
+    let stream = TlsConnector::from(Arc::new(tls_config))
+        .connect(server_name, stream)
+        .await?;
+
+    let url = format!(
+        "wss://{}:{}",
+        server_addr.host_name,
+        server_addr.addr.port()
+    );
+    let (socket, _) = tokio_tungstenite::client_async(url, stream).await?;
+
+    Ok(WebSocketConnection::new(
+        socket,
+        Role::Client,
+        Some(server_addr.addr),
+    ))
+}
+
+fn client_tls_config(cert: Option<Certificate>) -> anyhow::Result<rustls::ClientConfig> {
     let mut roots = load_root_certs();
 
     if let Some(cert) = cert {
@@ -417,6 +605,18 @@ pub fn create_client_endpoint_random_port(cert: Option<Certificate>) -> anyhow::
             .context("Failed to add custom certificate")?;
     }
 
+    Ok(rustls::ClientConfig::builder()
+        .with_safe_default_cipher_suites()
+        .with_safe_default_kx_groups()
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_root_certificates(roots)
+        .with_no_client_auth())
+}
+
+pub fn create_client_endpoint_random_port(cert: Option<Certificate>) -> anyhow::Result<Endpoint> {
+    let tls_config = client_tls_config(cert)?;
+
     for _ in 0..10 {
         let client_port = {
             let mut rng = rand::thread_rng();
@@ -426,13 +626,7 @@ pub fn create_client_endpoint_random_port(cert: Option<Certificate>) -> anyhow::
         let client_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), client_port);
 
         if let Ok(mut endpoint) = Endpoint::client(client_addr) {
-            let mut tls_config = rustls::ClientConfig::builder()
-                .with_safe_default_cipher_suites()
-                .with_safe_default_kx_groups()
-                .with_protocol_versions(&[&rustls::version::TLS13])
-                .unwrap()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
+            let mut tls_config = tls_config.clone();
 
             // tls_config.enable_early_data = true;
             tls_config.alpn_protocols = vec!["ambient-02".into()];
@@ -1,18 +1,25 @@
 use ambient_proxy::client::ProxiedConnection;
+use async_trait::async_trait;
 use bytes::Bytes;
-use quinn::{Connection, RecvStream, SendStream};
 
-use crate::NetworkError;
+use crate::{
+    connection::{Connection, TransportStats},
+    native::{loopback::LoopbackConnection, websocket::WebSocketConnection},
+    DynRecv, DynSend, NetworkError,
+};
 
-/// Incoming quinn connection from the client that can be either direct or proxied
+/// Connection between the client and the server that can be either direct, proxied, over a
+/// WebSocket or in-process
 #[derive(Debug, Clone)]
 pub enum ConnectionKind {
-    Direct(Connection),
+    Direct(quinn::Connection),
     Proxied(ProxiedConnection),
+    WebSocket(WebSocketConnection),
+    Loopback(LoopbackConnection),
 }
 
-impl From<Connection> for ConnectionKind {
-    fn from(value: Connection) -> Self {
+impl From<quinn::Connection> for ConnectionKind {
+    fn from(value: quinn::Connection) -> Self {
         Self::Direct(value)
     }
 }
@@ -23,59 +30,95 @@ impl From<ProxiedConnection> for ConnectionKind {
     }
 }
 
-impl ConnectionKind {
-    pub fn is_local(&self) -> bool {
-        match self {
-            ConnectionKind::Direct(conn) => conn.remote_address().ip().is_loopback(),
-            ConnectionKind::Proxied(_) => false,
-        }
+impl From<WebSocketConnection> for ConnectionKind {
+    fn from(value: WebSocketConnection) -> Self {
+        Self::WebSocket(value)
+    }
+}
+
+impl From<LoopbackConnection> for ConnectionKind {
+    fn from(value: LoopbackConnection) -> Self {
+        Self::Loopback(value)
     }
+}
 
-    #[inline]
-    pub async fn open_uni(&self) -> Result<SendStream, NetworkError> {
+#[async_trait]
+impl Connection for ConnectionKind {
+    async fn open_uni(&self) -> Result<DynSend, NetworkError> {
         match self {
-            ConnectionKind::Direct(conn) => Ok(conn.open_uni().await?),
-            ConnectionKind::Proxied(conn) => Ok(conn.open_uni().await?),
+            ConnectionKind::Direct(conn) => Connection::open_uni(conn).await,
+            ConnectionKind::Proxied(conn) => Ok(Box::pin(conn.open_uni().await?)),
+            ConnectionKind::WebSocket(conn) => Connection::open_uni(conn).await,
+            ConnectionKind::Loopback(conn) => Connection::open_uni(conn).await,
         }
     }
 
-    #[inline]
-    pub async fn open_bi(&self) -> Result<(SendStream, RecvStream), NetworkError> {
+    async fn open_bi(&self) -> Result<(DynSend, DynRecv), NetworkError> {
         match self {
-            ConnectionKind::Direct(conn) => Ok(conn.open_bi().await?),
-            ConnectionKind::Proxied(conn) => Ok(conn.open_bi().await?),
+            ConnectionKind::Direct(conn) => Connection::open_bi(conn).await,
+            ConnectionKind::Proxied(conn) => {
+                let (send, recv) = conn.open_bi().await?;
+                Ok((Box::pin(send), Box::pin(recv)))
+            }
+            ConnectionKind::WebSocket(conn) => Connection::open_bi(conn).await,
+            ConnectionKind::Loopback(conn) => Connection::open_bi(conn).await,
         }
     }
 
-    #[inline]
-    pub async fn accept_uni(&self) -> Result<RecvStream, NetworkError> {
+    async fn accept_uni(&self) -> Result<DynRecv, NetworkError> {
         match self {
-            ConnectionKind::Direct(conn) => Ok(conn.accept_uni().await?),
-            ConnectionKind::Proxied(conn) => Ok(conn.accept_uni().await),
+            ConnectionKind::Direct(conn) => Connection::accept_uni(conn).await,
+            ConnectionKind::Proxied(conn) => Ok(Box::pin(conn.accept_uni().await)),
+            ConnectionKind::WebSocket(conn) => Connection::accept_uni(conn).await,
+            ConnectionKind::Loopback(conn) => Connection::accept_uni(conn).await,
         }
     }
 
-    #[inline]
-    pub async fn accept_bi(&self) -> Result<(SendStream, RecvStream), NetworkError> {
+    async fn accept_bi(&self) -> Result<(DynSend, DynRecv), NetworkError> {
         match self {
-            ConnectionKind::Direct(conn) => Ok(conn.accept_bi().await?),
-            ConnectionKind::Proxied(conn) => Ok(conn.accept_bi().await),
+            ConnectionKind::Direct(conn) => Connection::accept_bi(conn).await,
+            ConnectionKind::Proxied(conn) => {
+                let (send, recv) = conn.accept_bi().await;
+                Ok((Box::pin(send), Box::pin(recv)))
+            }
+            ConnectionKind::WebSocket(conn) => Connection::accept_bi(conn).await,
+            ConnectionKind::Loopback(conn) => Connection::accept_bi(conn).await,
         }
     }
 
-    #[inline]
-    pub async fn read_datagram(&self) -> Result<Bytes, NetworkError> {
+    async fn read_datagram(&self) -> Result<Bytes, NetworkError> {
         match self {
             ConnectionKind::Direct(conn) => Ok(conn.read_datagram().await?),
             ConnectionKind::Proxied(conn) => Ok(conn.read_datagram().await),
+            ConnectionKind::WebSocket(conn) => Connection::read_datagram(conn).await,
+            ConnectionKind::Loopback(conn) => Connection::read_datagram(conn).await,
         }
     }
 
-    #[inline]
-    pub fn send_datagram(&self, data: Bytes) -> Result<(), NetworkError> {
+    fn send_datagram(&self, data: Bytes) -> Result<(), NetworkError> {
         match self {
             ConnectionKind::Direct(conn) => Ok(conn.send_datagram(data)?),
             ConnectionKind::Proxied(conn) => Ok(conn.send_datagram(data)?),
+            ConnectionKind::WebSocket(conn) => Connection::send_datagram(conn, data),
+            ConnectionKind::Loopback(conn) => Connection::send_datagram(conn, data),
+        }
+    }
+
+    fn is_local(&self) -> bool {
+        match self {
+            ConnectionKind::Direct(conn) => Connection::is_local(conn),
+            ConnectionKind::Proxied(_) => false,
+            ConnectionKind::WebSocket(conn) => Connection::is_local(conn),
+            ConnectionKind::Loopback(conn) => Connection::is_local(conn),
+        }
+    }
+
+    fn stats(&self) -> TransportStats {
+        match self {
+            ConnectionKind::Direct(conn) => Connection::stats(conn),
+            ConnectionKind::Proxied(_) => TransportStats::default(),
+            ConnectionKind::WebSocket(conn) => Connection::stats(conn),
+            ConnectionKind::Loopback(conn) => Connection::stats(conn),
         }
     }
 }
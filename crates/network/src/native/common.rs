@@ -4,7 +4,7 @@ use h3::quic::BidiStream;
 use h3_webtransport::server::WebTransportSession;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
-use crate::{client::NetworkTransport, NetworkError, MAX_FRAME_SIZE};
+use crate::{client::NetworkTransport, connection::Connection, NetworkError, MAX_FRAME_SIZE};
 
 impl NetworkTransport for WebTransportSession<h3_quinn::Connection, Bytes> {
     fn request_bi(&self, id: u32, data: Bytes) -> BoxFuture<Result<Bytes, NetworkError>> {
@@ -53,57 +53,27 @@ impl NetworkTransport for WebTransportSession<h3_quinn::Connection, Bytes> {
     }
 }
 
-impl NetworkTransport for quinn::Connection {
+impl<C: Connection> NetworkTransport for C {
     fn request_bi(&self, id: u32, data: Bytes) -> BoxFuture<Result<Bytes, NetworkError>> {
         Box::pin(async move {
-            let (mut send, mut recv) = self.open_bi().await?;
+            let (mut send, recv) = self.open_bi().await?;
 
             send.write_u32(id).await?;
             send.write_all(&data).await?;
 
             drop(send);
 
-            let buf = recv.read_to_end(MAX_FRAME_SIZE).await?.into();
-
-            Ok(buf)
-        })
-    }
-
-    fn request_uni(&self, id: u32, data: Bytes) -> BoxFuture<Result<(), NetworkError>> {
-        Box::pin(async move {
-            let mut send = self.open_uni().await?;
-
-            send.write_u32(id).await?;
-            send.write_all(&data).await?;
-
-            Ok(())
-        })
-    }
-
-    fn send_datagram(&self, id: u32, data: Bytes) -> BoxFuture<Result<(), NetworkError>> {
-        let mut bytes = bytes::BytesMut::with_capacity(4 + data.len());
-        bytes.put_u32(id);
-        bytes.put(data);
-
-        let res = self.send_datagram(bytes.freeze()).map_err(Into::into);
-
-        Box::pin(futures::future::ready(res))
-    }
-}
-
-impl NetworkTransport for crate::native::client_connection::ConnectionKind {
-    fn request_bi(&self, id: u32, data: Bytes) -> BoxFuture<Result<Bytes, NetworkError>> {
-        Box::pin(async move {
-            let (mut send, mut recv) = self.open_bi().await?;
-
-            send.write_u32(id).await?;
-            send.write_all(&data).await?;
-
-            drop(send);
+            let mut buf = Vec::new();
 
-            let buf = recv.read_to_end(MAX_FRAME_SIZE).await?.into();
+            let read = recv
+                .take(MAX_FRAME_SIZE as u64 + 1)
+                .read_to_end(&mut buf)
+                .await?;
+            if read > MAX_FRAME_SIZE {
+                return Err(NetworkError::FrameTooLarge);
+            }
 
-            Ok(buf)
+            Ok(buf.into())
         })
     }
 
@@ -123,7 +93,7 @@ impl NetworkTransport for crate::native::client_connection::ConnectionKind {
         bytes.put_u32(id);
         bytes.put(data);
 
-        let res = self.send_datagram(bytes.freeze()).map_err(Into::into);
+        let res = Connection::send_datagram(self, bytes.freeze());
 
         Box::pin(futures::future::ready(res))
     }
@@ -1,10 +1,13 @@
 //! Contains native implementations of the network interface.
 //!
-//! This included quinn server+client and webtransport server using `h3`
+//! This included quinn server+client, webtransport server using `h3`, and the WebSocket and
+//! in-process loopback transports
 pub mod client;
 pub mod client_connection;
 pub mod common;
+pub mod loopback;
 pub mod server;
+pub mod websocket;
 mod webtransport;
 
 #[cfg(feature = "tls-native-roots")]
@@ -25,12 +25,22 @@ use futures::{SinkExt, StreamExt};
 use parking_lot::{Mutex, RwLock};
 use quinn::{ClientConfig, Connecting, Endpoint, ServerConfig, TransportConfig};
 use rustls::{Certificate, PrivateKey};
-use tokio::time::{interval, MissedTickBehavior};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    time::{interval, MissedTickBehavior},
+};
+use tokio_rustls::TlsAcceptor;
+use tokio_tungstenite::tungstenite::protocol::Role;
 use uuid::Uuid;
 
 use crate::{
+    connection::Connection,
     native::{
-        client_connection::ConnectionKind, load_root_certs, webtransport::handle_h3_connection,
+        client_connection::ConnectionKind,
+        load_root_certs,
+        loopback::{loopback_channel, LoopbackConnector, LoopbackListener},
+        websocket::WebSocketConnection,
+        webtransport::handle_h3_connection,
     },
     proto::{
         server::{handle_diffs, ConnectionData, ServerProtoState},
@@ -51,8 +61,16 @@ pub struct Crypto {
 }
 
 /// Quinn and Webtransport game server
+///
+/// Clients which can not reach the server over UDP can connect over a WebSocket on the same port,
+/// and clients in the same process can connect through [`GameServer::loopback_connector`].
 pub struct GameServer {
     endpoint: Endpoint,
+    /// Missing if the TCP port could not be bound, in which case only QUIC is served
+    websocket: Option<TcpListener>,
+    websocket_tls: TlsAcceptor,
+    loopback_connector: LoopbackConnector,
+    loopback_listener: LoopbackListener,
     /// Shuts down the server if there are no players
     pub inactivity_shutdown: Option<Duration>,
     proxy_settings: Option<ProxySettings>,
@@ -66,10 +84,25 @@ impl GameServer {
         crypto: &Crypto,
     ) -> anyhow::Result<Self> {
         let endpoint = create_server(server_addr, crypto)?;
+        let websocket = match TcpListener::bind(endpoint.local_addr()?).await {
+            Ok(listener) => Some(listener),
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to bind websocket listener, only accepting QUIC connections: {err}"
+                );
+                None
+            }
+        };
+        let websocket_tls = TlsAcceptor::from(Arc::new(server_tls_config(crypto)?));
+        let (loopback_connector, loopback_listener) = loopback_channel();
 
         tracing::debug!("GameServer listening on port {}", server_addr.port());
         Ok(Self {
             endpoint,
+            websocket,
+            websocket_tls,
+            loopback_connector,
+            loopback_listener,
             inactivity_shutdown,
             proxy_settings,
         })
@@ -114,6 +147,9 @@ impl GameServer {
     ) -> SharedServerState {
         let Self {
             endpoint,
+            websocket,
+            websocket_tls,
+            loopback_listener,
             proxy_settings,
             ..
         } = self;
@@ -129,6 +165,7 @@ impl GameServer {
                     systems: create_server_systems(&mut world),
                     world,
                     world_stream: WorldStream::new(world_stream_filter.clone()),
+                    relevancy: None,
                 },
             )]
             .into_iter()
@@ -172,6 +209,14 @@ impl GameServer {
                     let fut = resolve_connection(conn, state.clone(), world_stream_filter.clone(), ServerBaseUrlKey.get(&assets));
                     tokio::spawn(async move {  log_result!(fut.await) });
                 }
+                Ok((stream, addr)) = accept_websocket(websocket.as_ref()) => {
+                    let fut = resolve_websocket_connection(stream, addr, websocket_tls.clone(), state.clone(), world_stream_filter.clone(), ServerBaseUrlKey.get(&assets));
+                    tokio::spawn(async move {  log_result!(fut.await) });
+                }
+                Ok(conn) = loopback_listener.accept() => {
+                    let fut = handle_connection(conn.into(), state.clone(), world_stream_filter.clone(), ServerBaseUrlKey.get(&assets));
+                    tokio::spawn(async move {  log_result!(fut.await) });
+                }
                 _ = sim_interval.tick() => {
                     fps_counter.frame_start();
                     let mut state = state.lock();
@@ -223,6 +268,22 @@ impl GameServer {
             .local_addr()
             .expect("Failed go get socket address for endpoint")
     }
+
+    /// Returns a connector for clients in the same process, which connect without going through
+    /// the network
+    pub fn loopback_connector(&self) -> LoopbackConnector {
+        self.loopback_connector.clone()
+    }
+}
+
+/// Accepts a websocket connection, or waits forever if there is no listener
+async fn accept_websocket(
+    listener: Option<&TcpListener>,
+) -> std::io::Result<(TcpStream, SocketAddr)> {
+    match listener {
+        Some(listener) => listener.accept().await,
+        None => std::future::pending().await,
+    }
 }
 
 async fn resolve_connection(
@@ -262,7 +323,7 @@ async fn resolve_connection(
 
     tracing::debug!("Accepted connection");
     if protocol == b"ambient-02" {
-        handle_quinn_connection(
+        handle_connection(
             conn.into(),
             state.clone(),
             world_stream_filter.clone(),
@@ -283,9 +344,35 @@ async fn resolve_connection(
     }
 }
 
+async fn resolve_websocket_connection(
+    stream: TcpStream,
+    addr: SocketAddr,
+    tls: TlsAcceptor,
+    state: SharedServerState,
+    world_stream_filter: WorldStreamFilter,
+    content_base_url: AbsAssetUrl,
+) -> anyhow::Result<()> {
+    tracing::debug!("Received websocket connection");
+
+    stream.set_nodelay(true)?;
+    let stream = tls.accept(stream).await.context("TLS handshake failed")?;
+    let socket = tokio_tungstenite::accept_async(stream)
+        .await
+        .context("Failed to accept websocket")?;
+
+    tracing::debug!("Accepted websocket connection");
+    handle_connection(
+        WebSocketConnection::new(socket, Role::Server, Some(addr)).into(),
+        state,
+        world_stream_filter,
+        content_base_url,
+    )
+    .await
+}
+
 /// Setup the protocol and enter the update loop for a new connected client
 #[tracing::instrument(level = "info", skip_all, fields(content_base_url))]
-async fn handle_quinn_connection(
+async fn handle_connection(
     conn: ConnectionKind,
     state: SharedServerState,
     world_stream_filter: WorldStreamFilter,
@@ -299,7 +386,8 @@ async fn handle_quinn_connection(
 
     let (diffs_tx, diffs_rx) = flume::unbounded();
 
-    let server_info = ServerInfo::new(&mut state.lock(), content_base_url);
+    let resume_token = Uuid::new_v4();
+    let server_info = ServerInfo::new(&mut state.lock(), content_base_url, resume_token);
 
     let mut server = ServerProtoState::default();
 
@@ -317,6 +405,7 @@ async fn handle_quinn_connection(
         state,
         diff_tx: diffs_tx,
         connection_id: Uuid::new_v4(),
+        resume_token,
         world_stream_filter,
     };
 
@@ -334,7 +423,7 @@ async fn handle_quinn_connection(
     let mut server = scopeguard::guard(server, |mut server| {
         if !server.is_disconnected() {
             tracing::info!("Connection closed abruptly from {server:?}");
-            server.process_disconnect(&data);
+            server.process_connection_lost(&data);
         }
     });
 
@@ -411,7 +500,7 @@ async fn start_proxy_connection(
         Arc::new(
             move |_player_id, conn: ambient_proxy::client::ProxiedConnection| {
                 tracing::debug!("Accepted connection via proxy");
-                let task = handle_quinn_connection(
+                let task = handle_connection(
                     conn.into(),
                     state.clone(),
                     world_stream_filter.clone(),
@@ -461,8 +550,8 @@ async fn start_proxy_connection(
     }
 }
 
-fn create_server(server_addr: SocketAddr, crypto: &Crypto) -> anyhow::Result<Endpoint> {
-    let mut tls_config = rustls::ServerConfig::builder()
+fn server_tls_config(crypto: &Crypto) -> anyhow::Result<rustls::ServerConfig> {
+    Ok(rustls::ServerConfig::builder()
         .with_safe_default_cipher_suites()
         .with_safe_default_kx_groups()
         .with_protocol_versions(&[&rustls::version::TLS13])
@@ -471,7 +560,11 @@ fn create_server(server_addr: SocketAddr, crypto: &Crypto) -> anyhow::Result<End
         .with_single_cert(
             crypto.cert_chain.iter().cloned().map(Certificate).collect(),
             PrivateKey(crypto.key.clone()),
-        )?;
+        )?)
+}
+
+fn create_server(server_addr: SocketAddr, crypto: &Crypto) -> anyhow::Result<Endpoint> {
+    let mut tls_config = server_tls_config(crypto)?;
 
     tls_config.max_early_data_size = u32::MAX;
     let alpn: Vec<Vec<u8>> = vec![
@@ -0,0 +1,313 @@
+//! A fallback transport for networks which block UDP.
+//!
+//! The streams and datagrams of a connection are multiplexed over a single WebSocket. As the
+//! WebSocket runs over TCP, datagrams are delivered reliably and in order, and a lost packet stalls
+//! all the streams until it has been retransmitted.
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{SinkExt, StreamExt};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio_tungstenite::{
+    tungstenite::{protocol::Role, Message},
+    WebSocketStream,
+};
+
+use crate::{
+    connection::{Connection, TransportStats},
+    DynRecv, DynSend, NetworkError,
+};
+
+/// The capacity of each stream. Writes wait for the stream to be sent once it is full.
+const STREAM_BUFFER_SIZE: usize = 64 * 1024;
+/// The largest chunk of a stream sent in a single message
+const MAX_CHUNK_SIZE: usize = 16 * 1024;
+/// The number of messages which can be queued for sending before writes wait
+const OUTGOING_CAPACITY: usize = 64;
+/// The number of received chunks which can be queued for each stream before reading from the
+/// websocket waits
+const STREAM_CHUNK_CAPACITY: usize = STREAM_BUFFER_SIZE / MAX_CHUNK_SIZE;
+/// The number of streams which can wait to be accepted
+const INCOMING_STREAM_CAPACITY: usize = 16;
+/// The number of received datagrams which can wait to be read. Further datagrams are dropped.
+const DATAGRAM_CAPACITY: usize = 256;
+
+#[derive(Debug, Serialize, Deserialize)]
+enum Frame {
+    OpenUni(u64),
+    OpenBi(u64),
+    Data(u64, Bytes),
+    Finish(u64),
+    Datagram(Bytes),
+}
+
+type StreamMap = Arc<Mutex<HashMap<u64, flume::Sender<Bytes>>>>;
+
+/// A connection multiplexed over a WebSocket
+#[derive(Clone)]
+pub struct WebSocketConnection {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    outgoing: flume::Sender<Message>,
+    /// Closes the websocket once dropped, after the queued messages have been sent
+    _close: flume::Sender<()>,
+    /// Streams opened by the client have even ids, and streams opened by the server have odd ids
+    next_stream_id: AtomicU64,
+    /// The streams which are being received
+    streams: StreamMap,
+    incoming_uni: flume::Receiver<DynRecv>,
+    incoming_bi: flume::Receiver<(DynSend, DynRecv)>,
+    datagrams: flume::Receiver<Bytes>,
+    remote_addr: Option<SocketAddr>,
+    bytes_sent: Arc<AtomicU64>,
+    bytes_received: Arc<AtomicU64>,
+}
+
+impl WebSocketConnection {
+    /// Runs a connection over an established WebSocket
+    pub fn new<S>(socket: WebSocketStream<S>, role: Role, remote_addr: Option<SocketAddr>) -> Self
+    where
+        S: 'static + AsyncRead + AsyncWrite + Unpin + Send,
+    {
+        let (mut sink, mut stream) = socket.split();
+        let (outgoing, outgoing_rx) = flume::bounded(OUTGOING_CAPACITY);
+        let (close, close_rx) = flume::bounded::<()>(1);
+        let streams = StreamMap::default();
+        let (uni_tx, incoming_uni) = flume::bounded(INCOMING_STREAM_CAPACITY);
+        let (bi_tx, incoming_bi) = flume::bounded(INCOMING_STREAM_CAPACITY);
+        let (datagram_tx, datagrams) = flume::bounded(DATAGRAM_CAPACITY);
+        let bytes_sent = Arc::new(AtomicU64::new(0));
+        let bytes_received = Arc::new(AtomicU64::new(0));
+
+        tokio::spawn({
+            let bytes_sent = bytes_sent.clone();
+            async move {
+                loop {
+                    let msg = tokio::select! {
+                        biased;
+                        Ok(msg) = outgoing_rx.recv_async() => msg,
+                        _ = close_rx.recv_async() => Message::Close(None),
+                    };
+                    let close = matches!(msg, Message::Close(_));
+                    if let Message::Binary(data) = &msg {
+                        bytes_sent.fetch_add(data.len() as u64, Ordering::Relaxed);
+                    }
+                    if let Err(err) = sink.send(msg).await {
+                        tracing::debug!("Failed to send websocket message: {err}");
+                        break;
+                    }
+                    if close {
+                        break;
+                    }
+                }
+            }
+        });
+
+        tokio::spawn({
+            let outgoing = outgoing.clone();
+            let streams = streams.clone();
+            let bytes_received = bytes_received.clone();
+            async move {
+                while let Some(Ok(msg)) = stream.next().await {
+                    // Pings are answered by the websocket itself
+                    let Message::Binary(data) = msg else {
+                        continue;
+                    };
+                    bytes_received.fetch_add(data.len() as u64, Ordering::Relaxed);
+
+                    let frame = match bincode::deserialize(&data) {
+                        Ok(v) => v,
+                        Err(err) => {
+                            tracing::warn!("Received malformed websocket frame: {err}");
+                            break;
+                        }
+                    };
+                    match frame {
+                        Frame::OpenUni(id) => {
+                            uni_tx.send_async(recv_stream(&streams, id)).await.ok();
+                        }
+                        Frame::OpenBi(id) => {
+                            let recv = recv_stream(&streams, id);
+                            bi_tx
+                                .send_async((send_stream(&outgoing, id), recv))
+                                .await
+                                .ok();
+                        }
+                        Frame::Data(id, data) => {
+                            // A full stream holds back the whole connection, like TCP itself
+                            let tx = streams.lock().get(&id).cloned();
+                            if let Some(tx) = tx {
+                                tx.send_async(data).await.ok();
+                            }
+                        }
+                        Frame::Finish(id) => {
+                            streams.lock().remove(&id);
+                        }
+                        Frame::Datagram(data) => {
+                            // Datagrams are unreliable, so they are dropped rather than waited for
+                            datagram_tx.try_send(data).ok();
+                        }
+                    }
+                }
+
+                // End all the streams which are being received
+                streams.lock().clear();
+            }
+        });
+
+        Self {
+            inner: Arc::new(Inner {
+                outgoing,
+                _close: close,
+                next_stream_id: AtomicU64::new(match role {
+                    Role::Client => 0,
+                    Role::Server => 1,
+                }),
+                streams,
+                incoming_uni,
+                incoming_bi,
+                datagrams,
+                remote_addr,
+                bytes_sent,
+                bytes_received,
+            }),
+        }
+    }
+
+    fn next_stream_id(&self) -> u64 {
+        self.inner.next_stream_id.fetch_add(2, Ordering::Relaxed)
+    }
+}
+
+impl Debug for WebSocketConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketConnection")
+            .field("remote_addr", &self.inner.remote_addr)
+            .finish()
+    }
+}
+
+/// Sends a frame, waiting for space in the outgoing queue
+async fn send_frame(outgoing: &flume::Sender<Message>, frame: &Frame) -> Result<(), NetworkError> {
+    let data = bincode::serialize(frame)?;
+    outgoing
+        .send_async(Message::Binary(data))
+        .await
+        .map_err(|_| NetworkError::ConnectionClosed)
+}
+
+/// Creates the sending half of a stream, which sends everything written to it in chunks
+fn send_stream(outgoing: &flume::Sender<Message>, id: u64) -> DynSend {
+    let (send, mut pump) = tokio::io::duplex(STREAM_BUFFER_SIZE);
+    let outgoing = outgoing.clone();
+
+    tokio::spawn(async move {
+        let mut buf = vec![0; MAX_CHUNK_SIZE];
+        loop {
+            let frame = match pump.read(&mut buf).await {
+                Ok(0) | Err(_) => Frame::Finish(id),
+                Ok(len) => Frame::Data(id, Bytes::copy_from_slice(&buf[..len])),
+            };
+            let finished = matches!(frame, Frame::Finish(_));
+            if send_frame(&outgoing, &frame).await.is_err() || finished {
+                break;
+            }
+        }
+    });
+
+    Box::pin(send)
+}
+
+/// Creates the receiving half of a stream, which ends once the stream is finished
+fn recv_stream(streams: &StreamMap, id: u64) -> DynRecv {
+    let (mut pump, recv) = tokio::io::duplex(STREAM_BUFFER_SIZE);
+    let (tx, rx) = flume::bounded::<Bytes>(STREAM_CHUNK_CAPACITY);
+    streams.lock().insert(id, tx);
+
+    tokio::spawn(async move {
+        while let Ok(data) = rx.recv_async().await {
+            if pump.write_all(&data).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    Box::pin(recv)
+}
+
+#[async_trait]
+impl Connection for WebSocketConnection {
+    async fn open_uni(&self) -> Result<DynSend, NetworkError> {
+        let id = self.next_stream_id();
+        send_frame(&self.inner.outgoing, &Frame::OpenUni(id)).await?;
+        Ok(send_stream(&self.inner.outgoing, id))
+    }
+
+    async fn open_bi(&self) -> Result<(DynSend, DynRecv), NetworkError> {
+        let id = self.next_stream_id();
+        // Listen for the response before the peer knows about the stream
+        let recv = recv_stream(&self.inner.streams, id);
+        send_frame(&self.inner.outgoing, &Frame::OpenBi(id)).await?;
+        Ok((send_stream(&self.inner.outgoing, id), recv))
+    }
+
+    async fn accept_uni(&self) -> Result<DynRecv, NetworkError> {
+        self.inner
+            .incoming_uni
+            .recv_async()
+            .await
+            .map_err(|_| NetworkError::ConnectionClosed)
+    }
+
+    async fn accept_bi(&self) -> Result<(DynSend, DynRecv), NetworkError> {
+        self.inner
+            .incoming_bi
+            .recv_async()
+            .await
+            .map_err(|_| NetworkError::ConnectionClosed)
+    }
+
+    async fn read_datagram(&self) -> Result<Bytes, NetworkError> {
+        self.inner
+            .datagrams
+            .recv_async()
+            .await
+            .map_err(|_| NetworkError::ConnectionClosed)
+    }
+
+    fn send_datagram(&self, data: Bytes) -> Result<(), NetworkError> {
+        let data = bincode::serialize(&Frame::Datagram(data))?;
+        match self.inner.outgoing.try_send(Message::Binary(data)) {
+            // Like an unreliable datagram, it is dropped when the connection is congested
+            Ok(()) | Err(flume::TrySendError::Full(_)) => Ok(()),
+            Err(flume::TrySendError::Disconnected(_)) => Err(NetworkError::ConnectionClosed),
+        }
+    }
+
+    fn is_local(&self) -> bool {
+        self.inner
+            .remote_addr
+            .is_some_and(|addr| addr.ip().is_loopback())
+    }
+
+    fn stats(&self) -> TransportStats {
+        TransportStats {
+            bytes_sent: self.inner.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.inner.bytes_received.load(Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+}
@@ -88,7 +88,8 @@ async fn handle_webtransport_session(
     let sid = conn.session_id();
     let (diffs_tx, diffs_rx) = flume::unbounded();
 
-    let server_info = ServerInfo::new(&mut state.lock(), content_base_url);
+    let resume_token = Uuid::new_v4();
+    let server_info = ServerInfo::new(&mut state.lock(), content_base_url, resume_token);
 
     let mut server = proto::server::ServerProtoState::default();
 
@@ -112,6 +113,7 @@ async fn handle_webtransport_session(
         state,
         diff_tx: diffs_tx,
         connection_id: Uuid::new_v4(),
+        resume_token,
         world_stream_filter,
     };
 
@@ -129,7 +131,7 @@ async fn handle_webtransport_session(
     let mut server = scopeguard::guard(server, |mut server| {
         if !server.is_disconnected() {
             tracing::info!("Connection closed abruptly from {server:?}");
-            server.process_disconnect(&data);
+            server.process_connection_lost(&data);
         }
     });
 
@@ -0,0 +1,170 @@
+//! Client-side prediction of entities synchronized from the server.
+//!
+//! The client keeps the last state of each predicted entity received from the server. Whenever
+//! the server sends an update for a predicted entity, the entity is rewound to that state and
+//! marked with `prediction_rewound`, so that the inputs which the server has not acknowledged
+//! yet can be replayed on top of it.
+use std::collections::HashMap;
+
+use ambient_ecs::{
+    components,
+    generated::network::components::{is_predicted, prediction_rewound},
+    Entity, EntityId, Networked, World, WorldChange, WorldDiff,
+};
+
+components!("network::prediction", {
+    /// The last state of a predicted entity received from the server
+    authoritative_state: Entity,
+});
+
+/// Applies a diff from the server, rewinding the predicted entities it updates
+pub fn apply_server_diff(world: &mut World, diff: WorldDiff, spawned_extra_data: Entity) {
+    let mut rewound: HashMap<EntityId, Entity> = HashMap::new();
+    for change in &diff.changes {
+        let id = change.entity_id();
+        if !world.has_component(id, is_predicted()) {
+            continue;
+        }
+
+        let state = rewound.entry(id).or_insert_with(|| {
+            world
+                .get_ref(id, authoritative_state())
+                .cloned()
+                .unwrap_or_else(|_| networked_state(world, id))
+        });
+        match change {
+            WorldChange::AddComponents(_, data) | WorldChange::SetComponents(_, data) => {
+                state.merge(data.clone());
+            }
+            WorldChange::RemoveComponents(_, components) => {
+                for &component in components {
+                    state.remove_raw(component);
+                }
+            }
+            WorldChange::Spawn(_, _) | WorldChange::Despawn(_) => {}
+        }
+    }
+
+    diff.apply(world, spawned_extra_data);
+
+    for (id, state) in rewound {
+        if !world.exists(id) {
+            continue;
+        }
+        let data = state
+            .clone()
+            .with(authoritative_state(), state)
+            .with(prediction_rewound(), ());
+        world.add_components(id, data).unwrap();
+    }
+}
+
+/// The synchronized components of an entity which has not been predicted yet
+fn networked_state(world: &World, id: EntityId) -> Entity {
+    world
+        .get_components(id)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|component| component.has_attribute::<Networked>())
+        .filter_map(|component| world.get_entry(id, component).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::components;
+
+    use super::*;
+
+    components!("test", {
+        @[Networked]
+        health: u32,
+        @[Networked]
+        ammo: u32,
+    });
+
+    fn init() {
+        ambient_ecs::init_components();
+        super::init_components();
+        init_components();
+    }
+
+    fn spawn_predicted(world: &mut World) -> EntityId {
+        Entity::new()
+            .with(health(), 100)
+            .with(ammo(), 10)
+            .with(is_predicted(), ())
+            .spawn(world)
+    }
+
+    #[test]
+    fn predicted_entities_are_rewound_to_the_server_state() {
+        init();
+        let mut world = World::new("prediction_test", ambient_ecs::WorldContext::Client);
+        let id = spawn_predicted(&mut world);
+
+        // The server state arrives while the client has predicted ahead of it
+        world.set(id, health(), 50).unwrap();
+        let diff = WorldDiff::new().set(id, health(), 80);
+        apply_server_diff(&mut world, diff, Entity::new());
+
+        assert_eq!(world.get(id, health()), Ok(80));
+        assert_eq!(world.get(id, ammo()), Ok(10));
+        assert!(world.has_component(id, prediction_rewound()));
+
+        // A later update only changes one component, and the other one is rewound to its last state
+        // from the server
+        world.remove_component(id, prediction_rewound()).unwrap();
+        world.set(id, health(), 40).unwrap();
+        let diff = WorldDiff::new().set(id, ammo(), 9);
+        apply_server_diff(&mut world, diff, Entity::new());
+
+        assert_eq!(world.get(id, health()), Ok(80));
+        assert_eq!(world.get(id, ammo()), Ok(9));
+        assert!(world.has_component(id, prediction_rewound()));
+        let state = world.get_ref(id, authoritative_state()).unwrap();
+        assert_eq!(state.get(health()), Some(80));
+        assert_eq!(state.get(ammo()), Some(9));
+    }
+
+    #[test]
+    fn removed_components_are_removed_from_the_server_state() {
+        init();
+        let mut world = World::new("prediction_test", ambient_ecs::WorldContext::Client);
+        let id = spawn_predicted(&mut world);
+
+        let diff = WorldDiff::new().remove_component(id, ammo().desc());
+        apply_server_diff(&mut world, diff, Entity::new());
+
+        assert!(!world.has_component(id, ammo()));
+        let state = world.get_ref(id, authoritative_state()).unwrap();
+        assert_eq!(state.get(ammo()), None);
+        assert_eq!(state.get(health()), Some(100));
+    }
+
+    #[test]
+    fn entities_which_are_not_predicted_are_not_rewound() {
+        init();
+        let mut world = World::new("prediction_test", ambient_ecs::WorldContext::Client);
+        let id = Entity::new().with(health(), 100).spawn(&mut world);
+
+        let diff = WorldDiff::new().set(id, health(), 80);
+        apply_server_diff(&mut world, diff, Entity::new());
+
+        assert_eq!(world.get(id, health()), Ok(80));
+        assert!(!world.has_component(id, prediction_rewound()));
+        assert!(!world.has_component(id, authoritative_state()));
+    }
+
+    #[test]
+    fn despawned_predicted_entities_stay_despawned() {
+        init();
+        let mut world = World::new("prediction_test", ambient_ecs::WorldContext::Client);
+        let id = spawn_predicted(&mut world);
+
+        let diff = WorldDiff::new().set(id, health(), 80).despawn(vec![id]);
+        apply_server_diff(&mut world, diff, Entity::new());
+
+        assert!(!world.exists(id));
+    }
+}
@@ -1,12 +1,16 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
-use ambient_ecs::{generated::network::components::is_remote_entity, Entity};
+use ambient_ecs::{
+    generated::network::components::is_remote_entity, query, Entity, EntityId, World, WorldChange,
+    WorldDiff,
+};
 use ambient_native_std::{
     asset_cache::{AssetCache, SyncAssetKeyExt},
     asset_url::ContentBaseUrlKey,
 };
 use anyhow::Context;
 use bytes::Bytes;
+use itertools::Itertools;
 use parking_lot::Mutex;
 use tokio::io::AsyncReadExt;
 use tracing::{debug_span, Instrument};
@@ -19,7 +23,7 @@ use crate::{
     },
     client_game_state::ClientGameState,
     diff_serialization::DiffSerializer,
-    log_task_result,
+    interpolation, log_task_result, prediction,
     proto::*,
 };
 
@@ -30,6 +34,10 @@ use crate::{
 pub(crate) struct ConnectedClient {
     diff_serializer: DiffSerializer,
     pub main_package_name: String,
+    /// Sent back to the server to resume the session if the connection is lost
+    pub resume_token: Option<uuid::Uuid>,
+    /// Whether the next non-empty diff replaces the world kept from a previous connection
+    resync: bool,
 }
 
 #[derive(Debug)]
@@ -81,6 +89,8 @@ impl ClientProtoState {
                 *self = Self::Connected(ConnectedClient {
                     diff_serializer: Default::default(),
                     main_package_name: server_info.main_package_name,
+                    resume_token: server_info.resume_token,
+                    resync: false,
                 });
 
                 Ok(())
@@ -100,11 +110,22 @@ impl ClientProtoState {
     pub fn process_client_stats(
         &mut self,
         state: &SharedClientGameState,
-        stats: crate::client::NetworkStats,
+        mut stats: crate::client::NetworkStats,
     ) {
         use crate::client::client_network_stats;
 
+        if let Self::Connected(connected) = self {
+            let mut component_bytes: Vec<_> = connected
+                .diff_serializer
+                .take_component_bytes()
+                .into_iter()
+                .collect();
+            component_bytes.sort_by(|a, b| b.1.cmp(&a.1));
+            stats.component_bytes = component_bytes;
+        }
+
         let mut gs = state.lock();
+        interpolation::take_stats(&mut gs.world, &mut stats);
         tracing::debug!(?stats, "Client network stats");
         gs.world.add_resource(client_network_stats(), stats);
     }
@@ -128,15 +149,33 @@ impl ClientProtoState {
 }
 
 impl ConnectedClient {
+    /// Replaces the world kept from a lost connection with the first diff of this connection
+    #[cfg(not(target_os = "unknown"))]
+    pub fn resync(&mut self) {
+        self.resync = true;
+    }
+
     pub fn process_diff(
         &mut self,
         state: &SharedClientGameState,
         diff: Bytes,
     ) -> anyhow::Result<()> {
-        let diff = self.diff_serializer.deserialize(diff)?;
+        let mut diff = self.diff_serializer.deserialize(diff)?;
         let mut gs = state.lock();
+        // The first diff of a connection spawns every entity the player should know about. The
+        // diffs before it only register external components, and are empty.
+        if self.resync && !diff.is_empty() {
+            self.resync = false;
+            diff = resync_diff(&gs.world, diff);
+        }
         tracing::trace!(diff=?diff.len(), "Applying diff");
-        diff.apply(&mut gs.world, Entity::new().with(is_remote_entity(), ()));
+        let snapshot = interpolation::Snapshot::from_diff(&gs.world, &diff);
+        prediction::apply_server_diff(
+            &mut gs.world,
+            diff,
+            Entity::new().with(is_remote_entity(), ()),
+        );
+        snapshot.record(&mut gs.world);
         Ok(())
     }
 
@@ -248,3 +287,86 @@ impl ConnectedClient {
         rt.spawn(task);
     }
 }
+
+/// Turns the first diff of a resumed session into a diff from the world the client kept. The remote
+/// entities which the diff does not spawn are despawned, and the ones which still exist are updated
+/// in place.
+fn resync_diff(world: &World, diff: WorldDiff) -> WorldDiff {
+    let spawned: HashSet<EntityId> = diff
+        .changes
+        .iter()
+        .filter_map(|change| match change {
+            WorldChange::Spawn(id, _) => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    let mut changes = query(())
+        .incl(is_remote_entity())
+        .iter(world, None)
+        .map(|(id, _)| id)
+        .filter(|id| !spawned.contains(id))
+        .map(WorldChange::Despawn)
+        .collect_vec();
+    for change in diff.changes {
+        match change {
+            WorldChange::Spawn(id, data) if world.exists(id) => {
+                let (set, added): (Vec<_>, Vec<_>) = data
+                    .into_iter()
+                    .partition(|entry| world.has_component(id, entry.desc()));
+                if !added.is_empty() {
+                    changes.push(WorldChange::AddComponents(id, added.into_iter().collect()));
+                }
+                if !set.is_empty() {
+                    changes.push(WorldChange::SetComponents(id, set.into_iter().collect()));
+                }
+            }
+            change => changes.push(change),
+        }
+    }
+    WorldDiff { changes }
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::{components, Networked, WorldContext};
+
+    use super::*;
+
+    components!("test", {
+        @[Networked]
+        health: u32,
+        @[Networked]
+        ammo: u32,
+    });
+
+    #[test]
+    fn resync_updates_the_kept_world() {
+        // Arrange
+        ambient_ecs::init_components();
+        init_components();
+        let mut world = World::new("resync_test", WorldContext::Client);
+        let remote = Entity::new().with(is_remote_entity(), ());
+        let kept = remote.clone().with(health(), 50).spawn(&mut world);
+        let despawned = remote.spawn(&mut world);
+        let local = Entity::new().with(health(), 10).spawn(&mut world);
+        let spawned = EntityId::new();
+        let diff = WorldDiff {
+            changes: vec![
+                WorldChange::Spawn(kept, Entity::new().with(health(), 100).with(ammo(), 20)),
+                WorldChange::Spawn(spawned, Entity::new().with(health(), 100)),
+            ],
+        };
+
+        // Act
+        let diff = resync_diff(&world, diff);
+        diff.apply(&mut world, Entity::new().with(is_remote_entity(), ()));
+
+        // Assert
+        assert_eq!(world.get(kept, health()).unwrap(), 100);
+        assert_eq!(world.get(kept, ammo()).unwrap(), 20);
+        assert_eq!(world.get(spawned, health()).unwrap(), 100);
+        assert!(!world.exists(despawned));
+        assert!(world.exists(local));
+    }
+}
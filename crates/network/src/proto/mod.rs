@@ -1,5 +1,6 @@
 use ambient_core::main_package_name;
 use ambient_native_std::{ambient_version, asset_url::AbsAssetUrl};
+use uuid::Uuid;
 
 pub mod client;
 pub mod server;
@@ -7,8 +8,9 @@ pub mod server;
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 /// Request sent by the client to the server
 pub enum ClientRequest {
-    /// Connect to the server with the specified user id
-    Connect(String),
+    /// Connect to the server with the specified user id, and the resume token of the previous
+    /// connection if it was lost
+    Connect(String, Option<Uuid>),
     /// Client wants to disconnect
     Disconnect,
 }
@@ -35,10 +37,18 @@ pub struct ServerInfo {
     /// Defaults to the version of the crate.
     /// TODO: use semver
     pub version: String,
+
+    /// Sent back by the client when it reconnects after losing this connection, to resume its
+    /// session. `None` if the server does not keep the sessions of lost connections.
+    pub resume_token: Option<Uuid>,
 }
 
 impl ServerInfo {
-    pub fn new(state: &mut crate::server::ServerState, content_base_url: AbsAssetUrl) -> Self {
+    pub fn new(
+        state: &mut crate::server::ServerState,
+        content_base_url: AbsAssetUrl,
+        resume_token: Uuid,
+    ) -> Self {
         let instance = state
             .instances
             .get(crate::server::MAIN_INSTANCE_ID)
@@ -49,6 +59,7 @@ impl ServerInfo {
             main_package_name: world.resource(main_package_name()).clone(),
             content_base_url,
             version: ambient_version().to_string(),
+            resume_token: (!instance.reconnect_grace_period().is_zero()).then_some(resume_token),
         }
     }
 }
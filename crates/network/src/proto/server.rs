@@ -2,17 +2,23 @@ use std::{collections::HashMap, sync::Arc};
 
 use ambient_core::player::get_by_user_id;
 use ambient_ecs::{
-    ComponentRegistry, Entity, EntityId, FrozenWorldDiff, WorldChange, WorldDiff, WorldStreamFilter,
+    generated::player::components::disconnected, ComponentRegistry, Entity, EntityId, WorldChange,
+    WorldDiff, WorldStreamFilter,
 };
 use ambient_native_std::{fps_counter::FpsSample, log_result};
+use ambient_sys::time::sleep_label;
 use anyhow::Context;
 use bytes::Bytes;
-use futures::{future::OptionFuture, Stream, StreamExt};
+use futures::{
+    future::{FusedFuture, OptionFuture},
+    Stream, StreamExt,
+};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
 use tracing::{debug_span, Instrument};
 use uuid::Uuid;
 
 use crate::{
+    bandwidth::{self, BandwidthLimiter, DiffSettings, PlayerDiff},
     bytes_ext::BufExt,
     client::NetworkTransport,
     diff_serialization::{DiffSerializer, WorldDiffDeduplicator},
@@ -21,7 +27,7 @@ use crate::{
     server::{
         bi_stream_handlers, create_player_entity_data, datagram_handlers, uni_stream_handlers,
     },
-    server::{SharedServerState, MAIN_INSTANCE_ID},
+    server::{ServerState, SharedServerState, MAIN_INSTANCE_ID},
     stream,
 };
 
@@ -62,10 +68,13 @@ impl std::fmt::Debug for ConnectedClient {
 /// Holds information relevant for all states of a given connection to a client
 pub struct ConnectionData {
     pub(crate) state: SharedServerState,
-    pub(crate) diff_tx: flume::Sender<FrozenWorldDiff>,
+    pub(crate) diff_tx: flume::Sender<PlayerDiff>,
     /// Unique identifier for this session
     /// Used to declare ownership of the player entity when multiple simultaneous connections are made or reconnected
     pub(crate) connection_id: Uuid,
+    /// Sent to the client in the `ServerInfo`, so that it can resume its session if this
+    /// connection is lost
+    pub(crate) resume_token: Uuid,
     pub(crate) conn: Arc<dyn NetworkTransport>,
     pub(crate) world_stream_filter: WorldStreamFilter,
 }
@@ -85,6 +94,7 @@ pub struct Player {
     pub instance: String,
     control_tx: flume::Sender<ServerPush>,
     connection_id: Uuid,
+    resume_token: Uuid,
 }
 
 impl Player {
@@ -95,6 +105,7 @@ impl Player {
             instance: instance.into(),
             control_tx,
             connection_id: Uuid::new_v4(),
+            resume_token: Uuid::new_v4(),
         }
     }
 
@@ -116,13 +127,13 @@ impl ServerProtoState {
                 tracing::debug!("Client is disconnected, ignoring control frame");
                 Ok(())
             }
-            (ClientRequest::Connect(user_id), Self::PendingConnection) => {
+            (ClientRequest::Connect(user_id, resume_token), Self::PendingConnection) => {
                 // Connect the user
                 tracing::debug!("User connected");
-                self.process_connect(data, user_id);
+                self.process_connect(data, user_id, resume_token);
                 Ok(())
             }
-            (ClientRequest::Connect(_), Self::Connected(_)) => {
+            (ClientRequest::Connect(..), Self::Connected(_)) => {
                 tracing::warn!("Client already connected");
                 Ok(())
             }
@@ -134,28 +145,63 @@ impl ServerProtoState {
     }
 
     #[tracing::instrument(level = "debug")]
-    fn process_connect(&mut self, data: &ConnectionData, user_id: String) {
+    fn process_connect(
+        &mut self,
+        data: &ConnectionData,
+        user_id: String,
+        resume_token: Option<Uuid>,
+    ) {
         let mut state = data.state.lock();
+        let state = &mut *state;
+
+        // The player takes over the player entity of their previous connection if it is still
+        // open, or if they resume the session of a lost connection with its token
+        let previous_instance = state.players.remove(&user_id).and_then(|player| {
+            player.abort();
+
+            let instance = state.instances.get_mut(&player.instance).unwrap();
+            let id = get_by_user_id(&instance.world, &user_id)?;
+            if instance.world.has_component(id, disconnected())
+                && resume_token != Some(player.resume_token)
+            {
+                tracing::debug!(user_id, ?id, "Session was not resumed, despawning player");
+                instance.despawn_player(&user_id);
+                return None;
+            }
+
+            Some(player.instance)
+        });
 
         let (control_tx, control_rx) = flume::unbounded();
 
-        let old_player = state.players.insert(
+        let instance_id = previous_instance.unwrap_or_else(|| MAIN_INSTANCE_ID.to_string());
+        state.players.insert(
             user_id.clone(),
             Player {
-                instance: MAIN_INSTANCE_ID.to_string(),
+                instance: instance_id.clone(),
                 control_tx,
                 connection_id: data.connection_id,
+                resume_token: data.resume_token,
             },
         );
 
-        let instance = state.instances.get_mut(MAIN_INSTANCE_ID).unwrap();
+        let instance = state.instances.get_mut(&instance_id).unwrap();
 
         // Bring world stream up to the current time
         tracing::debug!("[{}] Broadcasting diffs", user_id);
         instance.broadcast_diffs();
         tracing::debug!("[{}] Creating init diff", user_id);
 
-        let diff = data.world_stream_filter.initial_diff(&instance.world);
+        let id = get_by_user_id(&instance.world, &user_id);
+
+        // The first diff of a connection spawns every entity the player should know about, so that
+        // a client which resumes its session can replace the world it kept with it. With relevancy,
+        // the relevant entities of a new player are spawned by the next broadcast instead.
+        let diff = match id {
+            Some(id) => instance.resume_diff(id),
+            None if instance.relevancy.is_some() => WorldDiff::new(),
+            None => data.world_stream_filter.initial_diff(&instance.world),
+        };
 
         log_result!(data.diff_tx.send(diff.into()));
         tracing::debug!("[{}] Init diff sent", user_id);
@@ -167,12 +213,11 @@ impl ServerProtoState {
             data.connection_id,
         );
 
-        if let Some(old_player) = old_player {
-            old_player.control_tx.send(ServerPush::Disconnect).ok();
-
-            let id = get_by_user_id(&instance.world, &user_id).unwrap();
-
+        if let Some(id) = id {
             instance.world.add_components(id, entity_data).unwrap();
+            if instance.world.has_component(id, disconnected()) {
+                instance.world.remove_component(id, disconnected()).unwrap();
+            }
 
             tracing::debug!(user_id, ?id, "Player reconnected");
         } else {
@@ -186,11 +231,24 @@ impl ServerProtoState {
         });
     }
 
+    /// Processes a graceful disconnect, which despawns the player entity
     #[tracing::instrument(level = "debug")]
     pub fn process_disconnect(&mut self, data: &ConnectionData) {
+        self.disconnect(data, false);
+    }
+
+    /// Processes a connection which was closed abruptly. The player entity is kept for the
+    /// reconnection grace period, so that the player can resume their session.
+    #[tracing::instrument(level = "debug")]
+    pub fn process_connection_lost(&mut self, data: &ConnectionData) {
+        self.disconnect(data, true);
+    }
+
+    fn disconnect(&mut self, data: &ConnectionData, lost: bool) {
         if let Self::Connected(ConnectedClient { user_id, .. }) = self {
-            tracing::debug!(%user_id, "User disconnected");
+            tracing::debug!(%user_id, lost, "User disconnected");
             let mut state = data.state.lock();
+            let state = &mut *state;
 
             let Some(player) = state.players.get(&**user_id) else {
                 tracing::warn!("Attempt to disconnect a client that was not connected");
@@ -202,13 +260,32 @@ impl ServerProtoState {
                 return;
             }
 
-            let player = state.players.remove(&**user_id).unwrap();
-            tracing::debug!("Despawning the player from world: {:?}", player.instance);
-            state
-                .instances
-                .get_mut(&player.instance)
-                .unwrap()
-                .despawn_player(user_id);
+            let instance = state.instances.get_mut(&player.instance).unwrap();
+            let grace_period = instance.reconnect_grace_period();
+            if lost && !grace_period.is_zero() {
+                tracing::debug!(?grace_period, "Keeping the player for the grace period");
+                instance.disconnect_player(user_id);
+
+                let state = data.state.clone();
+                let user_id = user_id.clone();
+                let connection_id = data.connection_id;
+                ambient_sys::task::spawn(async move {
+                    sleep_label(grace_period, "reconnect grace period").await;
+
+                    let mut state = state.lock();
+                    // The player resumed their session if the connection id has changed
+                    let expired = state
+                        .players
+                        .get(&*user_id)
+                        .is_some_and(|player| player.connection_id == connection_id);
+                    if expired {
+                        tracing::debug!(%user_id, "Grace period ended");
+                        remove_player(&mut state, &user_id);
+                    }
+                });
+            } else {
+                remove_player(state, user_id);
+            }
         } else {
             tracing::warn!("Tried to disconnect a client that was not connected");
         }
@@ -241,6 +318,17 @@ impl ServerProtoState {
     }
 }
 
+/// Removes the player from the server and despawns their player entity
+fn remove_player(state: &mut ServerState, user_id: &str) {
+    let player = state.players.remove(user_id).unwrap();
+    tracing::debug!("Despawning the player from world: {:?}", player.instance);
+    state
+        .instances
+        .get_mut(&player.instance)
+        .unwrap()
+        .despawn_player(user_id);
+}
+
 impl ConnectedClient {
     /// Processes an incoming datagram
     #[tracing::instrument(level = "debug", skip(data))]
@@ -357,10 +445,10 @@ pub async fn handle_stats<S>(
     log_network_result!(stats.map(Ok).forward(stream).await);
 }
 
-/// Sends the world diffs over the network
+/// Sends the world diffs over the network, within the bandwidth budget of the player
 pub async fn handle_diffs<S>(
     stream: stream::FramedSendStream<WorldDiff, S>,
-    diffs_rx: flume::Receiver<FrozenWorldDiff>,
+    diffs_rx: flume::Receiver<PlayerDiff>,
 ) where
     S: Unpin + AsyncWrite,
 {
@@ -381,9 +469,13 @@ pub async fn handle_diffs<S>(
 
     let mut needs_external_components = false;
     let mut intermediate_diff = IntermediateWorldDiff::default();
+    let mut settings = DiffSettings::default();
+    let mut limiter = BandwidthLimiter::default();
 
     let mut stream = Some(stream);
     let mut sending_future = OptionFuture::default();
+    // wakes the loop up to send delayed changes
+    let mut retry = OptionFuture::default();
 
     loop {
         tokio::select! {
@@ -405,12 +497,18 @@ pub async fn handle_diffs<S>(
                 needs_external_components = true;
             }
             Ok(diff) = diffs_rx.recv_async() => {
-                intermediate_diff.merge_in(&diff);
+                intermediate_diff.merge_in(&diff.diff);
+                limiter.add_priorities(&diff.entity_priorities);
+                settings = diff.settings;
+            }
+            Some(()) = &mut retry => {
+                retry = None.into();
             }
         }
 
         // check if we have anything to send
-        if needs_external_components || !intermediate_diff.is_empty() {
+        let send_diff = !intermediate_diff.is_empty() && limiter.can_send(&settings);
+        if needs_external_components || send_diff {
             let Some(mut stream) = stream.take() else {
                 // stream is not available - we must be still in progress of sending the previous message
                 continue;
@@ -427,11 +525,22 @@ pub async fn handle_diffs<S>(
                 let mut diff = intermediate_diff.take();
                 let merged_changes_count = diff.changes.len();
                 deduplicator.deduplicate(&mut diff);
-                let msg = serializer.serialize(&diff).unwrap();
+                // the changes which don't fit in the budget are sent later
+                let delayed = limiter.limit(&mut diff, &settings);
+                let delayed_changes_count = delayed.len();
+                for (id, entity) in &delayed {
+                    for entry in entity.iter() {
+                        deduplicator.forget(*id, entry.desc());
+                    }
+                }
+                intermediate_diff.delay(delayed);
+                let msg = serializer.serialize_with(&diff, settings.encoding).unwrap();
+                limiter.sent(&settings, msg.len());
                 tracing::trace!(
                     input_diffs_count,
                     input_changes_count,
                     merged_changes_count,
+                    delayed_changes_count,
                     final_changes_count = diff.changes.len(),
                     bytes = msg.len(),
                 );
@@ -460,6 +569,14 @@ pub async fn handle_diffs<S>(
             }))
             .into();
         }
+
+        if !intermediate_diff.is_empty() && retry.is_terminated() {
+            retry = Some(Box::pin(sleep_label(
+                bandwidth::RETRY_INTERVAL,
+                "handle_diffs retry",
+            )))
+            .into();
+        }
     }
 }
 
@@ -498,6 +615,11 @@ impl IntermediateWorldDiff {
         }
     }
 
+    /// Adds back component updates which were taken but not sent
+    pub fn delay(&mut self, changes: Vec<(EntityId, Entity)>) {
+        self.set_changes.extend(changes);
+    }
+
     pub fn take(&mut self) -> WorldDiff {
         self.diffs_merged = 0;
         self.changes_merged = 0;
@@ -0,0 +1,211 @@
+//! Reduced precision encodings of `Vec3`, `Quat` and `Mat4` components, used when a diff is
+//! quantized.
+//!
+//! The encodings are only used for the values of components, so the type of each value is known
+//! from its component when it is deserialized.
+use std::{any::TypeId, f32::consts::SQRT_2};
+
+use ambient_ecs::{ComponentDesc, ComponentEntry, ComponentValue};
+use glam::{Mat4, Quat, Vec3, Vec4};
+use serde::{de::SeqAccess, Deserialize, Serialize};
+
+/// The resolution of quantized `Vec3` values, in steps per unit
+const VEC3_STEPS: f32 = 256.0;
+/// Quantized `Vec3` axes are stored in 24 bits
+const VEC3_MAX: i32 = (1 << 23) - 1;
+/// Quaternion components are stored in 15 bits
+const QUAT_MAX: f32 = ((1 << 15) - 1) as f32;
+/// How far a matrix may be from its decomposition to be sent as a transform
+const MAT4_TOLERANCE: f32 = 1e-4;
+
+/// A `Vec3` rounded to 1/256 of a unit. Values that are out of range are sent in full.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) enum QuantizedVec3 {
+    Fixed([u8; 9]),
+    Full(Vec3),
+}
+
+impl From<Vec3> for QuantizedVec3 {
+    fn from(value: Vec3) -> Self {
+        let fixed = (value * VEC3_STEPS).round();
+        if !fixed.is_finite() || fixed.abs().max_element() > VEC3_MAX as f32 {
+            return Self::Full(value);
+        }
+
+        let mut bytes = [0; 9];
+        for (axis, chunk) in fixed.to_array().into_iter().zip(bytes.chunks_mut(3)) {
+            chunk.copy_from_slice(&(axis as i32).to_le_bytes()[..3]);
+        }
+        Self::Fixed(bytes)
+    }
+}
+
+impl From<QuantizedVec3> for Vec3 {
+    fn from(value: QuantizedVec3) -> Self {
+        match value {
+            QuantizedVec3::Fixed(bytes) => {
+                let mut axes = [0.0; 3];
+                for (axis, chunk) in axes.iter_mut().zip(bytes.chunks(3)) {
+                    // Sign extend from 24 bits
+                    let fixed = i32::from_le_bytes([0, chunk[0], chunk[1], chunk[2]]) >> 8;
+                    *axis = fixed as f32 / VEC3_STEPS;
+                }
+                Vec3::from_array(axes)
+            }
+            QuantizedVec3::Full(value) => value,
+        }
+    }
+}
+
+/// A rotation stored as the index of its largest component and the other three components, which
+/// are at most `1 / sqrt(2)`, in 15 bits each
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) struct QuantizedQuat([u8; 6]);
+
+impl From<Quat> for QuantizedQuat {
+    fn from(value: Quat) -> Self {
+        let mut components = value.normalize().to_array();
+        let largest = (0..4)
+            .max_by(|&a, &b| components[a].abs().total_cmp(&components[b].abs()))
+            .unwrap();
+        // `q` and `-q` are the same rotation, so the largest component is always positive
+        if components[largest] < 0.0 {
+            components = components.map(|v| -v);
+        }
+
+        let mut bits = largest as u64;
+        for (_, v) in components.iter().enumerate().filter(|&(i, _)| i != largest) {
+            let v = ((v * SQRT_2 + 1.0) / 2.0 * QUAT_MAX)
+                .round()
+                .clamp(0.0, QUAT_MAX);
+            bits = (bits << 15) | v as u64;
+        }
+        let mut bytes = [0; 6];
+        bytes.copy_from_slice(&bits.to_le_bytes()[..6]);
+        Self(bytes)
+    }
+}
+
+impl From<QuantizedQuat> for Quat {
+    fn from(value: QuantizedQuat) -> Self {
+        let mut bytes = [0; 8];
+        bytes[..6].copy_from_slice(&value.0);
+        let bits = u64::from_le_bytes(bytes);
+        let largest = (bits >> 45) as usize & 3;
+
+        let mut components = [0.0; 4];
+        let mut shift = 45;
+        for i in (0..4).filter(|&i| i != largest) {
+            shift -= 15;
+            let v = ((bits >> shift) & 0x7fff) as f32;
+            components[i] = (v / QUAT_MAX * 2.0 - 1.0) / SQRT_2;
+        }
+        let sum: f32 = components.iter().map(|v| v * v).sum();
+        components[largest] = (1.0 - sum).max(0.0).sqrt();
+        Quat::from_array(components).normalize()
+    }
+}
+
+/// A matrix sent as its scale, quantized rotation and quantized translation. Matrices which are not
+/// affine transforms, such as projections or sheared matrices, are sent in full.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) enum QuantizedMat4 {
+    Transform {
+        scale: Vec3,
+        rotation: QuantizedQuat,
+        translation: QuantizedVec3,
+    },
+    Full(Mat4),
+}
+
+impl From<Mat4> for QuantizedMat4 {
+    fn from(value: Mat4) -> Self {
+        if value.row(3) == Vec4::W {
+            let (scale, rotation, translation) = value.to_scale_rotation_translation();
+            let largest = value
+                .to_cols_array()
+                .iter()
+                .fold(1.0f32, |a, b| a.max(b.abs()));
+            let tolerance = MAT4_TOLERANCE * largest;
+            if Mat4::from_scale_rotation_translation(scale, rotation, translation)
+                .abs_diff_eq(value, tolerance)
+            {
+                return Self::Transform {
+                    scale,
+                    rotation: rotation.into(),
+                    translation: translation.into(),
+                };
+            }
+        }
+        Self::Full(value)
+    }
+}
+
+impl From<QuantizedMat4> for Mat4 {
+    fn from(value: QuantizedMat4) -> Self {
+        match value {
+            QuantizedMat4::Transform {
+                scale,
+                rotation,
+                translation,
+            } => Mat4::from_scale_rotation_translation(scale, rotation.into(), translation.into()),
+            QuantizedMat4::Full(value) => value,
+        }
+    }
+}
+
+/// The quantized value of a component
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(untagged)]
+pub(crate) enum QuantizedValue {
+    Vec3(QuantizedVec3),
+    Quat(QuantizedQuat),
+    Mat4(QuantizedMat4),
+}
+
+impl QuantizedValue {
+    /// Quantizes the value of `entry`, if its type has a quantized encoding
+    pub(crate) fn from_entry(entry: &ComponentEntry) -> Option<Self> {
+        if let Some(&v) = entry.try_downcast_ref::<Vec3>() {
+            Some(Self::Vec3(v.into()))
+        } else if let Some(&v) = entry.try_downcast_ref::<Quat>() {
+            Some(Self::Quat(v.into()))
+        } else {
+            entry
+                .try_downcast_ref::<Mat4>()
+                .map(|&v| Self::Mat4(v.into()))
+        }
+    }
+}
+
+/// Reads the next value of a sequence as the quantized value of `desc`. Returns `None` if the type
+/// of the component has no quantized encoding.
+pub(crate) fn next_quantized_entry<'de, A: SeqAccess<'de>>(
+    seq: &mut A,
+    desc: ComponentDesc,
+) -> Option<Result<Option<ComponentEntry>, A::Error>> {
+    let type_id = desc.type_id();
+    if type_id == TypeId::of::<Vec3>() {
+        Some(next_entry::<_, QuantizedVec3, Vec3>(seq, desc))
+    } else if type_id == TypeId::of::<Quat>() {
+        Some(next_entry::<_, QuantizedQuat, Quat>(seq, desc))
+    } else if type_id == TypeId::of::<Mat4>() {
+        Some(next_entry::<_, QuantizedMat4, Mat4>(seq, desc))
+    } else {
+        None
+    }
+}
+
+fn next_entry<'de, A, Q, T>(
+    seq: &mut A,
+    desc: ComponentDesc,
+) -> Result<Option<ComponentEntry>, A::Error>
+where
+    A: SeqAccess<'de>,
+    Q: Deserialize<'de> + Into<T>,
+    T: ComponentValue,
+{
+    Ok(seq
+        .next_element::<Q>()?
+        .map(|v| ComponentEntry::from_raw_parts(desc, v.into())))
+}
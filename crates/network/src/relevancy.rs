@@ -0,0 +1,322 @@
+//! Per-player relevancy filtering, which decides which entities are synchronized to each player.
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use ambient_core::transform::{local_to_world, translation};
+use ambient_ecs::{
+    generated::network::components::{
+        always_relevant, relevancy_origin, relevancy_radius, relevancy_team, visible_to,
+    },
+    ArchetypeFilter, ComponentDesc, EntityId, FrozenWorldDiff, Query, QueryState, World, WorldDiff,
+    WorldStreamFilter,
+};
+use glam::Vec3;
+
+use crate::server::relevancy_filters;
+
+/// Decides whether an entity is relevant to a player. The arguments are the world, the player
+/// entity and the entity.
+pub type RelevancyFilter = Arc<dyn Fn(&World, EntityId, EntityId) -> bool + Sync + Send>;
+
+/// Filters which are combined with the built-in filter of a [Relevancy]. An entity is only
+/// relevant to a player if every filter says so.
+pub type RelevancyFilters = Vec<RelevancyFilter>;
+
+/// Tracks the entities that have been sent to each player of a world instance
+#[derive(Clone)]
+pub struct Relevancy {
+    filter: RelevancyFilter,
+    all_relevant: bool,
+    /// The entities that have been sent to each player, which are the entities that were relevant
+    /// to them at the last update
+    known: HashMap<EntityId, HashSet<EntityId>>,
+    /// Finds changes to the relevancy components and the positions, which may not be synchronized
+    /// and so may not show up in the diffs
+    component_changes: Vec<(Query, QueryState)>,
+    /// Finds changes to the `relevancy_filters` resource
+    filter_changes: Vec<(Query, QueryState)>,
+    /// The entities whose relevancy may have changed since the last update
+    changed: HashSet<EntityId>,
+    needs_update_all: bool,
+    update_all: bool,
+}
+
+impl Relevancy {
+    /// Creates relevancy tracking with the built-in `filter`, which is combined with the filters
+    /// of the `relevancy_filters` resource
+    pub fn new(filter: RelevancyFilter) -> Self {
+        let components: Vec<ComponentDesc> = vec![
+            relevancy_radius().desc(),
+            relevancy_origin().desc(),
+            always_relevant().desc(),
+            visible_to().desc(),
+            relevancy_team().desc(),
+        ];
+        // Removed components are found by the entities leaving the archetypes with them
+        let removed = components
+            .iter()
+            .map(|&component| Query::new(ArchetypeFilter::new().incl(component)).despawned());
+        let component_changes = [
+            Query::any_changed(components.clone()),
+            Query::any_changed(vec![translation().desc(), local_to_world().desc()]),
+        ]
+        .into_iter()
+        .chain(removed)
+        .map(|query| (query, QueryState::new()))
+        .collect();
+        let filter_changes = [
+            Query::any_changed(vec![relevancy_filters().desc()]),
+            Query::new(ArchetypeFilter::new().incl(relevancy_filters())).despawned(),
+        ]
+        .into_iter()
+        .map(|query| (query, QueryState::new()))
+        .collect();
+
+        Self {
+            filter,
+            all_relevant: false,
+            known: HashMap::new(),
+            component_changes,
+            filter_changes,
+            changed: HashSet::new(),
+            needs_update_all: true,
+            update_all: false,
+        }
+    }
+
+    pub fn filter(&self) -> &RelevancyFilter {
+        &self.filter
+    }
+
+    /// The entities that have been sent to the player
+    pub fn known_entities(&self, player: EntityId) -> impl Iterator<Item = EntityId> + '_ {
+        self.known.get(&player).into_iter().flatten().copied()
+    }
+
+    /// Forgets the entities that have been sent to the player, so that all relevant entities are
+    /// spawned by the next diff
+    pub fn reset_player(&mut self, player: EntityId) {
+        self.known.remove(&player);
+    }
+
+    /// Records that `entities` have been sent to the player, such as when relevancy is turned on
+    /// for players who have been sent every entity
+    pub fn set_known_entities(&mut self, player: EntityId, entities: HashSet<EntityId>) {
+        self.known.insert(player, entities);
+        self.needs_update_all = true;
+    }
+
+    /// Makes every entity relevant to every player, so that the next diffs spawn the entities the
+    /// players have not been sent
+    pub fn make_all_relevant(&mut self) {
+        self.all_relevant = true;
+        self.needs_update_all = true;
+    }
+
+    /// Whether the diffs of the `players` may not be empty, given the next diff of the world stream.
+    /// This is not the case if the diff is empty and the relevant entities can not have changed
+    /// since the last call, and then filtering can be skipped.
+    ///
+    /// This also finds the entities whose relevancy has to be decided again by
+    /// [Self::player_diff]. Those are the entities in the diff, the entities which moved or whose
+    /// relevancy components changed, and every entity for the players which are new, moved or
+    /// whose relevancy components changed. Every entity is decided again when the
+    /// `relevancy_filters` resource changes.
+    pub fn needs_update(&mut self, world: &World, diff: &WorldDiff, players: &[EntityId]) -> bool {
+        self.update_all = std::mem::take(&mut self.needs_update_all);
+        // All of the queries are read, so that they skip past the changes that have been seen
+        for (query, state) in &mut self.filter_changes {
+            self.update_all |= query.iter(world, Some(state)).next().is_some();
+        }
+        self.changed.clear();
+        self.changed
+            .extend(diff.changes.iter().map(|change| change.entity_id()));
+        for (query, state) in &mut self.component_changes {
+            self.changed
+                .extend(query.iter(world, Some(state)).map(|entity| entity.id()));
+        }
+        self.update_all
+            || !self.changed.is_empty()
+            || players.iter().any(|id| !self.known.contains_key(id))
+    }
+
+    /// Creates the diff for the player from the next diff of the world stream. `entities` are all
+    /// the entities of the world stream, which are only all filtered if the relevancy of the
+    /// player may have changed. Otherwise only the entities found by [Self::needs_update] are.
+    pub fn player_diff(
+        &mut self,
+        world: &World,
+        stream_filter: &WorldStreamFilter,
+        entities: &[EntityId],
+        player: EntityId,
+        diff: &FrozenWorldDiff,
+    ) -> WorldDiff {
+        let filters = world.resource_opt(relevancy_filters());
+        let is_relevant = |id: EntityId| {
+            id == player
+                || self.all_relevant
+                || ((self.filter)(world, player, id)
+                    && filters
+                        .into_iter()
+                        .flatten()
+                        .all(|filter| filter(world, player, id)))
+        };
+
+        let origin = world.get(player, relevancy_origin()).unwrap_or(player);
+        let relevant = match self.known.get(&player) {
+            Some(known)
+                if !self.update_all
+                    && !self.changed.contains(&player)
+                    && !self.changed.contains(&origin) =>
+            {
+                let mut relevant = known.clone();
+                for &id in &self.changed {
+                    if stream_filter.contains(world, id) && is_relevant(id) {
+                        relevant.insert(id);
+                    } else {
+                        relevant.remove(&id);
+                    }
+                }
+                relevant
+            }
+            _ => entities
+                .iter()
+                .copied()
+                .filter(|&id| is_relevant(id))
+                .collect(),
+        };
+        let known = self.known.entry(player).or_default();
+        stream_filter.relevant_diff(world, diff, known, relevant)
+    }
+}
+/// Filters entities using the `visible_to`, `relevancy_team` and `relevancy_radius` components. This
+/// is the built-in filter of the server.
+pub fn component_relevancy_filter() -> RelevancyFilter {
+    Arc::new(|world, player, entity| {
+        if let Ok(players) = world.get_ref(entity, visible_to()) {
+            if !players.contains(&player) {
+                return false;
+            }
+        }
+
+        if let Ok(team) = world.get_ref(entity, relevancy_team()) {
+            if world.get_ref(player, relevancy_team()).ok() != Some(team) {
+                return false;
+            }
+        }
+
+        let Ok(radius) = world.get(player, relevancy_radius()) else {
+            return true;
+        };
+        if world.has_component(entity, always_relevant()) {
+            return true;
+        }
+
+        let origin = world.get(player, relevancy_origin()).unwrap_or(player);
+        match (position(world, origin), position(world, entity)) {
+            (Some(origin), Some(position)) => origin.distance_squared(position) <= radius * radius,
+            _ => true,
+        }
+    })
+}
+
+pub(crate) fn position(world: &World, id: EntityId) -> Option<Vec3> {
+    match world.get(id, local_to_world()) {
+        Ok(ltw) => Some(ltw.w_axis.truncate()),
+        Err(_) => world.get(id, translation()).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::{Entity, WorldChange, WorldContext, WorldStream};
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn spawned_and_despawned(diff: &WorldDiff) -> (HashSet<EntityId>, HashSet<EntityId>) {
+        let spawned = diff
+            .changes
+            .iter()
+            .filter_map(|change| match change {
+                WorldChange::Spawn(id, _) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        let despawned = diff
+            .changes
+            .iter()
+            .filter_map(|change| match change {
+                WorldChange::Despawn(id) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        (spawned, despawned)
+    }
+
+    #[test]
+    fn relevancy_is_updated_for_changed_entities_and_filters() {
+        // Arrange
+        ambient_ecs::init_components();
+        crate::init_all_components();
+        let mut world = World::new_with_config("relevancy", WorldContext::Server, true);
+        world.init_shape_change_tracking();
+        let stream_filter = WorldStreamFilter::new(
+            ArchetypeFilter::new().incl(translation()),
+            Arc::new(|_, _| true),
+        );
+        let mut stream = WorldStream::new(stream_filter.clone());
+        let mut relevancy = Relevancy::new(component_relevancy_filter());
+
+        let player = Entity::new()
+            .with(relevancy_radius(), 10.)
+            .with(translation(), Vec3::ZERO)
+            .spawn(&mut world);
+        let near = Entity::new()
+            .with(translation(), Vec3::X * 5.)
+            .spawn(&mut world);
+        let far = Entity::new()
+            .with(translation(), Vec3::X * 20.)
+            .spawn(&mut world);
+
+        let mut update = |world: &mut World, relevancy: &mut Relevancy| {
+            let diff = stream.next_diff(world);
+            let player_diff = relevancy.needs_update(world, &diff, &[player]).then(|| {
+                let entities = stream_filter.all_entities(world).collect_vec();
+                let diff =
+                    relevancy.player_diff(world, &stream_filter, &entities, player, &diff.into());
+                spawned_and_despawned(&diff)
+            });
+            world.next_frame();
+            player_diff
+        };
+
+        // Act & Assert
+        let (spawned, _) = update(&mut world, &mut relevancy).unwrap();
+        assert_eq!(spawned, HashSet::from([player, near]));
+        assert!(update(&mut world, &mut relevancy).is_none());
+
+        // Entities are decided again when they move
+        world.set(far, translation(), Vec3::X * 5.).unwrap();
+        let (spawned, _) = update(&mut world, &mut relevancy).unwrap();
+        assert_eq!(spawned, HashSet::from([far]));
+
+        // Every entity is decided again when the player moves
+        world.set(player, translation(), Vec3::X * 100.).unwrap();
+        let (_, despawned) = update(&mut world, &mut relevancy).unwrap();
+        assert_eq!(despawned, HashSet::from([near, far]));
+        world.set(player, translation(), Vec3::ZERO).unwrap();
+        update(&mut world, &mut relevancy).unwrap();
+
+        // Every entity is decided again when the filters change
+        world.add_resource(
+            relevancy_filters(),
+            vec![Arc::new(move |_: &World, _, id| id != near) as RelevancyFilter],
+        );
+        let (spawned, despawned) = update(&mut world, &mut relevancy).unwrap();
+        assert!(spawned.is_empty());
+        assert_eq!(despawned, HashSet::from([near]));
+    }
+}
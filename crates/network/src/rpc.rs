@@ -6,6 +6,7 @@ use ambient_rpc::RpcRegistry;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    relevancy::Relevancy,
     server::{
         create_player_entity_data, player_connection_id, player_entity_stream, player_transport,
         ForkingEvent, RpcArgs as ServerRpcArgs, WorldInstance, MAIN_INSTANCE_ID,
@@ -78,6 +79,10 @@ pub async fn rpc_fork_instance(
                 systems: (state.create_server_systems)(&mut world),
                 world,
                 world_stream: instance.world_stream.clone(),
+                relevancy: instance
+                    .relevancy
+                    .as_ref()
+                    .map(|relevancy| Relevancy::new(relevancy.filter().clone())),
             }
         };
         state.instances.insert(id.clone(), new_instance);
@@ -106,14 +111,20 @@ pub async fn rpc_join_instance(args: ServerRpcArgs, new_instance_id: String) {
             .get(&old_instance_id)
             .zip(instances.get(&new_instance_id))
             .unwrap();
-        (
-            old_instance.player_count(),
+        let diff = if old_instance.relevancy.is_none() && new_instance.relevancy.is_none() {
             WorldDiff::from_a_to_b(
                 old_instance.world_stream.filter().clone(),
                 &old_instance.world,
                 &new_instance.world,
-            ),
-        )
+            )
+        } else {
+            // The player may only have been sent part of either world, so replace the world
+            // entirely. With relevancy, the new world is sent by the next broadcast.
+            let mut diff = WorldDiff::new().despawn(old_instance.sent_entities(&args.user_id));
+            diff.changes.extend(new_instance.initial_diff().changes);
+            diff
+        };
+        (old_instance.player_count(), diff)
     };
 
     // Borrow the old world mutably to remove the player and their streams.
@@ -1,8 +1,16 @@
-use std::{collections::HashMap, fmt::Debug, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    sync::Arc,
+    time::Duration,
+};
 
 use crate::{
-    client::NetworkTransport, proto::server::Player, DynRecv, DynSend, NetworkError,
-    RPC_BISTREAM_ID,
+    bandwidth::{DiffSettings, PlayerDiff},
+    client::NetworkTransport,
+    proto::server::Player,
+    relevancy::{component_relevancy_filter, Relevancy, RelevancyFilters},
+    DynRecv, DynSend, NetworkError, RPC_BISTREAM_ID,
 };
 use ambient_core::{
     app_start_time, name,
@@ -10,8 +18,13 @@ use ambient_core::{
     FIXED_SERVER_TICK_TIME,
 };
 use ambient_ecs::{
-    components, dont_store, query, ArchetypeFilter, Entity, EntityId, FrameEvent, FrozenWorldDiff,
-    Networked, Resource, System, SystemGroup, World, WorldStream, WorldStreamFilter,
+    components, dont_store,
+    generated::{
+        network::components::{reconnect_grace_period, relevancy_filtering},
+        player::components::disconnected,
+    },
+    query, ArchetypeFilter, Entity, EntityId, FrameEvent, FrozenWorldDiff, Networked, Resource,
+    System, SystemGroup, World, WorldDiff, WorldStream, WorldStreamFilter,
 };
 use ambient_native_std::{
     asset_cache::AssetCache, asset_url::AbsAssetUrl, fps_counter::FpsSample, log_result,
@@ -20,10 +33,14 @@ use ambient_rpc::RpcRegistry;
 use ambient_sys::time::Instant;
 use bytes::Bytes;
 use flume::Sender;
+use itertools::Itertools;
 use parking_lot::Mutex;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use uuid::Uuid;
 
+/// How long the player entity of a lost connection is kept if `reconnect_grace_period` is not set
+pub const DEFAULT_RECONNECT_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
 components!("network::server", {
     @[Resource]
     bi_stream_handlers: BiStreamHandlers,
@@ -31,8 +48,12 @@ components!("network::server", {
     uni_stream_handlers: UniStreamHandlers,
     @[Resource]
     datagram_handlers: DatagramHandlers,
+    /// Filters which decide the entities that are relevant to each player, in addition to the
+    /// built-in `visible_to`, `relevancy_team` and `relevancy_radius` filtering
+    @[Resource]
+    relevancy_filters: RelevancyFilters,
 
-    player_entity_stream: Sender<FrozenWorldDiff>,
+    player_entity_stream: Sender<PlayerDiff>,
     player_connection_id: Uuid,
     player_transport: Arc<dyn NetworkTransport>,
     // synced resource
@@ -62,6 +83,9 @@ pub struct WorldInstance {
     pub world: World,
     pub world_stream: WorldStream,
     pub systems: SystemGroup,
+    /// If set, each player is only sent the entities that are relevant to them. This is turned on
+    /// and off by `broadcast_diffs` to follow the `relevancy_filtering` resource.
+    pub relevancy: Option<Relevancy>,
 }
 
 #[derive(Clone)]
@@ -78,7 +102,7 @@ impl RpcArgs {
 pub fn create_player_entity_data(
     transport: Arc<dyn NetworkTransport>,
     new_user_id: String,
-    entities_tx: Sender<FrozenWorldDiff>,
+    entities_tx: Sender<PlayerDiff>,
     connection_id: Uuid,
 ) -> Entity {
     Entity::new()
@@ -130,10 +154,124 @@ impl WorldInstance {
     }
     pub fn despawn_player(&mut self, user_id: &str) -> Option<Entity> {
         let id = get_by_user_id(&self.world, user_id)?;
+        if let Some(relevancy) = &mut self.relevancy {
+            relevancy.reset_player(id);
+        }
         ambient_core::hierarchy::despawn_recursive(&mut self.world, id)
     }
+    /// Marks the player entity of a lost connection as `disconnected`, and keeps it until the
+    /// player resumes their session
+    pub fn disconnect_player(&mut self, user_id: &str) -> Option<EntityId> {
+        let id = get_by_user_id(&self.world, user_id)?;
+        self.world
+            .remove_components(
+                id,
+                vec![player_entity_stream().desc(), player_transport().desc()],
+            )
+            .ok();
+        self.world.add_component(id, disconnected(), ()).ok();
+        Some(id)
+    }
+    /// How long the player entities of lost connections are kept
+    pub fn reconnect_grace_period(&self) -> Duration {
+        self.world
+            .resource_opt(reconnect_grace_period())
+            .copied()
+            .unwrap_or(DEFAULT_RECONNECT_GRACE_PERIOD)
+    }
+    /// The entities that have been sent to the player
+    pub fn sent_entities(&self, user_id: &str) -> Vec<EntityId> {
+        match (&self.relevancy, get_by_user_id(&self.world, user_id)) {
+            (Some(relevancy), Some(id)) => relevancy.known_entities(id).collect(),
+            _ => self
+                .world_stream
+                .filter()
+                .all_entities(&self.world)
+                .collect(),
+        }
+    }
+    /// The diff that brings a newly connected player up to date
+    pub fn initial_diff(&self) -> WorldDiff {
+        match self.relevancy {
+            // The relevant entities are spawned by the next broadcast, once the player entity exists
+            Some(_) => WorldDiff::new(),
+            None => self.world_stream.filter().initial_diff(&self.world),
+        }
+    }
+    /// The diff that brings a player who takes over an existing player entity up to date. It spawns
+    /// every entity the player should know about, including with relevancy.
+    pub fn resume_diff(&mut self, player: EntityId) -> WorldDiff {
+        let Some(relevancy) = &mut self.relevancy else {
+            return self.world_stream.filter().initial_diff(&self.world);
+        };
+        relevancy.reset_player(player);
+        let filter = self.world_stream.filter();
+        let entities = filter.all_entities(&self.world).collect_vec();
+        relevancy.player_diff(
+            &self.world,
+            filter,
+            &entities,
+            player,
+            &WorldDiff::new().into(),
+        )
+    }
     pub fn broadcast_diffs(&mut self) {
         let diff = self.world_stream.next_diff(&self.world);
+        let settings = DiffSettings::from_world(&self.world);
+        let relevancy_filtering = self.world.resource_opt(relevancy_filtering()).is_some();
+        let Some(relevancy) = &mut self.relevancy else {
+            self.broadcast_diff_to_all(diff, &settings);
+            if relevancy_filtering {
+                // Every entity has been sent to the players, so relevancy starts from there
+                let entities: HashSet<_> = self
+                    .world_stream
+                    .filter()
+                    .all_entities(&self.world)
+                    .collect();
+                let mut relevancy = Relevancy::new(component_relevancy_filter());
+                for (id, _) in query(player_entity_stream()).iter(&self.world, None) {
+                    relevancy.set_known_entities(id, entities.clone());
+                }
+                self.relevancy = Some(relevancy);
+            }
+            return;
+        };
+        if !relevancy_filtering {
+            // Send the players the entities they are missing, after which every entity has been
+            // sent to them and relevancy can be turned off
+            relevancy.make_all_relevant();
+        }
+
+        // Relevancy can change without any synchronized component changing, so the diff being
+        // empty is not enough to skip this
+        let players = query(player_entity_stream())
+            .iter(&self.world, None)
+            .map(|(id, _)| id)
+            .collect_vec();
+        if relevancy.needs_update(&self.world, &diff, &players) {
+            let diff: FrozenWorldDiff = diff.into();
+
+            profiling::scope!("Send relevant MsgEntities");
+
+            let filter = self.world_stream.filter();
+            let entities = filter.all_entities(&self.world).collect_vec();
+            for (id, (entity_stream,)) in query((player_entity_stream(),)).iter(&self.world, None) {
+                let diff = relevancy.player_diff(&self.world, filter, &entities, id, &diff);
+                if diff.is_empty() {
+                    continue;
+                }
+                let diff = PlayerDiff::new(&self.world, &settings, id, diff.into());
+                if let Err(err) = entity_stream.send(diff) {
+                    tracing::warn!("Failed to send diff to player: {err:?}");
+                }
+            }
+        }
+
+        if !relevancy_filtering {
+            self.relevancy = None;
+        }
+    }
+    fn broadcast_diff_to_all(&self, diff: WorldDiff, settings: &DiffSettings) {
         if diff.is_empty() {
             return;
         }
@@ -141,8 +279,9 @@ impl WorldInstance {
 
         profiling::scope!("Send MsgEntities");
 
-        for (_, (entity_stream,)) in query((player_entity_stream(),)).iter(&self.world, None) {
-            if let Err(err) = entity_stream.send(diff.clone()) {
+        for (id, (entity_stream,)) in query((player_entity_stream(),)).iter(&self.world, None) {
+            let diff = PlayerDiff::new(&self.world, settings, id, diff.clone());
+            if let Err(err) = entity_stream.send(diff) {
                 tracing::warn!("Failed to broadcast diff to player: {err:?}");
             }
         }
@@ -191,6 +330,7 @@ impl ServerState {
                     world: World::new("main_server", ambient_ecs::WorldContext::Server),
                     world_stream: WorldStream::new(world_stream_filter),
                     systems: SystemGroup::new("", vec![]),
+                    relevancy: None,
                 },
             )]
             .into(),
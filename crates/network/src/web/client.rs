@@ -385,7 +385,7 @@ async fn handle_connection(
     tracing::debug!("Attempting to connect using {user_id:?}");
 
     request_send
-        .send(ClientRequest::Connect(user_id.clone()))
+        .send(ClientRequest::Connect(user_id.clone(), None))
         .await?;
 
     let mut client = ClientProtoState::Pending(user_id.clone());
@@ -0,0 +1,150 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use ambient_core::{asset_cache, main_package_name, player::get_by_user_id};
+use ambient_ecs::{ComponentDesc, Entity, SystemGroup, World, WorldContext, WorldStreamCompEvent};
+use ambient_native_std::{
+    asset_cache::{AssetCache, SyncAssetKeyExt},
+    asset_url::{AbsAssetUrl, ServerBaseUrlKey},
+};
+use ambient_network::{
+    connection::Connection,
+    is_synced_resources,
+    native::{
+        client::{open_connection, ResolvedAddr},
+        client_connection::ConnectionKind,
+        loopback::LoopbackConnector,
+        server::{Crypto, GameServer},
+    },
+    proto::{ClientRequest, ServerPush},
+    server::SharedServerState,
+    stream::{FramedRecvStream, FramedSendStream, RawFramedRecvStream},
+};
+use futures::{SinkExt, StreamExt};
+
+const CERT: &[u8] = include_bytes!("../../../localhost.crt");
+const CERT_KEY: &[u8] = include_bytes!("../../../localhost.key");
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Starts a server with an empty world, returning its state once it runs
+async fn start_server() -> (LoopbackConnector, SharedServerState) {
+    let assets = AssetCache::new(tokio::runtime::Handle::current());
+    ServerBaseUrlKey.insert(
+        &assets,
+        AbsAssetUrl::from_str("http://localhost/content/").unwrap(),
+    );
+
+    let mut world = World::new("server", WorldContext::Server);
+    world
+        .add_components(
+            world.resource_entity(),
+            Entity::new()
+                .with(asset_cache(), assets)
+                .with(main_package_name(), "loopback_test".to_string())
+                .with_merge(ambient_core::time_resources_start(Duration::ZERO)),
+        )
+        .unwrap();
+    Entity::new()
+        .with(is_synced_resources(), ())
+        .spawn(&mut world);
+
+    let crypto = Crypto {
+        cert_chain: vec![CERT.to_vec()],
+        key: CERT_KEY.to_vec(),
+    };
+    let server = GameServer::new_with_port(([127, 0, 0, 1], 0).into(), None, None, &crypto)
+        .await
+        .unwrap();
+    let connector = server.loopback_connector();
+
+    let (state_tx, state_rx) = flume::bounded(1);
+    tokio::spawn(server.run(
+        world,
+        Arc::new(|_: &mut World| SystemGroup::new("server", vec![])),
+        Arc::new(|| SystemGroup::new("forking", vec![])),
+        Arc::new(|| SystemGroup::new("shutdown", vec![])),
+        Arc::new(|_: ComponentDesc, _: WorldStreamCompEvent| true),
+        Arc::new(move |state: SharedServerState| {
+            state_tx.send(state).ok();
+        }),
+    ));
+
+    (connector, state_rx.recv_async().await.unwrap())
+}
+
+/// Connects as `user_id`, and returns the connection and request stream once the first diff has
+/// been received
+async fn connect(
+    connector: &LoopbackConnector,
+    user_id: &str,
+) -> (
+    ConnectionKind,
+    FramedSendStream<ClientRequest, ambient_network::DynSend>,
+) {
+    let conn = open_connection(ResolvedAddr::localhost_with_port(0), None, Some(connector))
+        .await
+        .unwrap();
+
+    let mut requests = FramedSendStream::new(conn.open_uni().await.unwrap());
+    requests
+        .send(ClientRequest::Connect(user_id.to_string(), None))
+        .await
+        .unwrap();
+
+    let mut push = FramedRecvStream::<ServerPush, _>::new(conn.accept_uni().await.unwrap());
+    match push.next().await.unwrap().unwrap() {
+        ServerPush::ServerInfo(info) => assert_eq!(info.main_package_name, "loopback_test"),
+        other => panic!("Expected the server info, got {other:?}"),
+    }
+
+    let mut diffs = RawFramedRecvStream::new(conn.accept_uni().await.unwrap());
+    diffs.next().await.unwrap().unwrap();
+
+    (conn, requests)
+}
+
+async fn wait_for_player_count(state: &SharedServerState, count: usize) {
+    tokio::time::timeout(TIMEOUT, async {
+        while state.lock().player_count() != count {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap_or_else(|_| panic!("Expected {count} players"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn server_with_several_clients() {
+    let (connector, state) = start_server().await;
+
+    let user_ids = ["alice", "bob", "carol"];
+    let mut clients = futures::future::join_all(
+        user_ids
+            .iter()
+            .map(|user_id| tokio::time::timeout(TIMEOUT, connect(&connector, user_id))),
+    )
+    .await
+    .into_iter()
+    .map(|client| client.expect("Timed out connecting"))
+    .collect::<Vec<_>>();
+
+    wait_for_player_count(&state, user_ids.len()).await;
+    {
+        let state = state.lock();
+        for user_id in user_ids {
+            let world = state.get_player_world(user_id).unwrap();
+            assert!(get_by_user_id(world, user_id).is_some());
+        }
+    }
+
+    // A client which disconnects is removed, and the others stay connected
+    let (_conn, mut requests) = clients.remove(0);
+    requests.send(ClientRequest::Disconnect).await.unwrap();
+
+    wait_for_player_count(&state, user_ids.len() - 1).await;
+    let state = state.lock();
+    assert!(state.get_player_world(user_ids[0]).is_none());
+    for user_id in &user_ids[1..] {
+        assert!(state.get_player_world(user_id).is_some());
+    }
+}
@@ -0,0 +1,72 @@
+use ambient_network::{
+    client::NetworkTransport,
+    connection::Connection,
+    native::{loopback::LoopbackConnection, websocket::WebSocketConnection},
+};
+use bytes::Bytes;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_tungstenite::{tungstenite::protocol::Role, WebSocketStream};
+
+async fn check_transport(client: impl Connection, server: impl Connection) {
+    // Unidirectional streams
+    let mut send = client.open_uni().await.unwrap();
+    send.write_all(b"hello").await.unwrap();
+    drop(send);
+
+    let mut recv = server.accept_uni().await.unwrap();
+    let mut buf = Vec::new();
+    recv.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, b"hello");
+
+    // Bidirectional streams
+    let responder = tokio::spawn({
+        let server = server.clone();
+        async move {
+            let (mut send, mut recv) = server.accept_bi().await.unwrap();
+            let id = recv.read_u32().await.unwrap();
+            let mut buf = Vec::new();
+            recv.read_to_end(&mut buf).await.unwrap();
+            buf.reverse();
+            send.write_u32(id).await.unwrap();
+            send.write_all(&buf).await.unwrap();
+        }
+    });
+    let resp = client
+        .request_bi(7, Bytes::from_static(b"ping"))
+        .await
+        .unwrap();
+    responder.await.unwrap();
+    assert_eq!(&resp[..], b"\0\0\0\x07gnip");
+
+    // Datagrams arrive in order
+    for i in 0..10u8 {
+        Connection::send_datagram(&server, Bytes::from(vec![i])).unwrap();
+    }
+    for i in 0..10u8 {
+        assert_eq!(&client.read_datagram().await.unwrap()[..], [i]);
+    }
+
+    // Closing one side closes the connection for the other
+    drop(server);
+    assert!(client.accept_uni().await.is_err());
+}
+
+#[tokio::test]
+async fn loopback_transport() {
+    let (client, server) = LoopbackConnection::pair();
+    check_transport(client, server).await;
+}
+
+#[tokio::test]
+async fn websocket_transport() {
+    let (client, server) = tokio::io::duplex(64 * 1024);
+    let (client, server) = tokio::join!(
+        WebSocketStream::from_raw_socket(client, Role::Client, None),
+        WebSocketStream::from_raw_socket(server, Role::Server, None),
+    );
+    check_transport(
+        WebSocketConnection::new(client, Role::Client, None),
+        WebSocketConnection::new(server, Role::Server, None),
+    )
+    .await;
+}
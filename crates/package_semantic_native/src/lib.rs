@@ -14,7 +14,7 @@ use ambient_ecs::{
 };
 use ambient_native_std::asset_url::AbsAssetUrl;
 use ambient_network::ServerWorldExt;
-use ambient_package::PackageContent;
+use ambient_package::{Limits, PackageContent};
 use ambient_package_semantic::{
     Item, ItemId, ItemSource, LocalOrRemote, Package, PrimitiveType, RetrievableFile, Semantic,
     TypeInner,
@@ -43,6 +43,8 @@ pub struct WasmSpawnRequest {
     pub package_id: EntityId,
     pub client_modules: Vec<(AbsAssetUrl, bool)>,
     pub server_modules: Vec<(AbsAssetUrl, bool)>,
+    /// The limits from the manifest of the package
+    pub limits: Limits,
 }
 #[derive(Debug, Default)]
 pub struct WasmSpawnResponse {
@@ -274,6 +276,7 @@ fn sync_semantic_to_world(
                         .iter()
                         .map(|m| Ok((asset_url.push(m)?, enabled)))
                         .collect::<Result<Vec<_>, url::ParseError>>()?,
+                    limits: manifest.limits.clone(),
                 },
             )?;
 
@@ -337,6 +340,7 @@ fn all_defined_components(semantic: &Semantic) -> anyhow::Result<Vec<ExternalCom
         let mut type_map = HashMap::new();
 
         // First pass: add all root-level primitive types
+        let mut primitives = vec![];
         for type_id in root_scope.types.values() {
             let type_ = items.get(*type_id);
             if let TypeInner::Primitive(pt) = type_.inner {
@@ -344,18 +348,32 @@ fn all_defined_components(semantic: &Semantic) -> anyhow::Result<Vec<ExternalCom
                 type_map.insert(*type_id, ty);
                 type_map.insert(items.get_vec_id(*type_id), ty.to_vec_type().unwrap());
                 type_map.insert(items.get_option_id(*type_id), ty.to_option_type().unwrap());
+                primitives.push((*type_id, ty));
+            }
+        }
+        for (key_id, key_ty) in &primitives {
+            for (value_id, value_ty) in &primitives {
+                if let Some(map_id) = items.get_map_id(*key_id, *value_id) {
+                    type_map.insert(map_id, value_ty.to_map_type(*key_ty).unwrap());
+                }
             }
         }
 
-        // Second pass: traverse the type graph and add all enums
+        // Second pass: traverse the type graph and add all enums and structs
         for package_id in semantic.packages.values() {
             let package = items.get(*package_id);
             let scope = items.get(package.scope_id);
             scope.visit_recursive(items, |scope| {
                 for type_id in scope.types.values() {
                     let type_ = items.get(*type_id);
-                    if let TypeInner::Enum { .. } = type_.inner {
-                        type_map.insert(*type_id, PrimitiveComponentType::U32);
+                    match type_.inner {
+                        TypeInner::Enum { .. } => {
+                            type_map.insert(*type_id, PrimitiveComponentType::U32);
+                        }
+                        TypeInner::Struct { .. } => {
+                            type_map.insert(*type_id, PrimitiveComponentType::VecU8);
+                        }
+                        _ => {}
                     }
                 }
                 Ok(())
@@ -391,19 +409,37 @@ fn all_defined_components(semantic: &Semantic) -> anyhow::Result<Vec<ExternalCom
                     })
                     .collect::<anyhow::Result<_>>()?;
 
+                let type_id = component.type_.as_resolved().unwrap_or_else(|| {
+                    panic!(
+                        "type id {:?} not resolved in component {:?}",
+                        component.type_, component
+                    )
+                });
+                let struct_fields = items.get(type_id).inner.as_struct().map(|struct_| {
+                    struct_
+                        .fields
+                        .values()
+                        .map(
+                            |field| match items.get(field.as_resolved().unwrap()).inner {
+                                TypeInner::Primitive(pt) => {
+                                    primitive_type_to_primitive_component_type(pt)
+                                }
+                                // Struct fields can only be primitives or enums
+                                _ => PrimitiveComponentType::U32,
+                            },
+                        )
+                        .collect()
+                });
+
                 components.push(ExternalComponentDesc {
                     path: items.fully_qualified_display_path(component, None, None),
-                    ty: type_map[&component.type_.as_resolved().unwrap_or_else(|| {
-                        panic!(
-                            "type id {:?} not resolved in component {:?}",
-                            component.type_, component
-                        )
-                    })],
+                    ty: type_map[&type_id],
                     name: component.name.clone(),
                     description: component.description.clone(),
                     attributes: ExternalComponentAttributes::from_iter(
                         attributes.iter().map(|s| s.as_str()),
                     ),
+                    struct_fields,
                 });
             }
             Ok(())
@@ -20,14 +20,15 @@ use ambient_native_std::{
 use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::try_join_all;
-use glam::{vec3, Mat4, Quat, Vec3};
+use glam::{vec3, Mat3, Mat4, Quat, Vec3};
 use itertools::Itertools;
 use physxx::{
-    AsPxActor, AsPxRigidActor, PxActor, PxActorFlag, PxBase, PxBoxGeometry, PxControllerDesc,
-    PxControllerShapeDesc, PxConvexMeshGeometry, PxGeometry, PxMaterial, PxMeshScale,
-    PxPlaneGeometry, PxRigidActor, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef,
-    PxRigidStaticRef, PxShape, PxShapeFlag, PxSphereGeometry, PxTransform, PxTriangleMeshGeometry,
-    PxUserData,
+    AsPxActor, AsPxRigidActor, PxActor, PxActorFlag, PxBase, PxBoxGeometry, PxCapsuleGeometry,
+    PxControllerDesc, PxControllerShapeDesc, PxConvexFlag, PxConvexMesh, PxConvexMeshDesc,
+    PxConvexMeshGeometry, PxGeometry, PxHeightFieldDesc, PxHeightFieldGeometry, PxMaterial,
+    PxMeshScale, PxPlaneGeometry, PxQuantizedHeightFieldSamples, PxRigidActor, PxRigidBody,
+    PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxShape, PxShapeFlag, PxSphereGeometry,
+    PxTransform, PxTriangleMeshGeometry, PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
@@ -141,6 +142,66 @@ pub fn server_systems() -> SystemGroup {
                         .unwrap();
                 }
             }),
+            query(capsule_collider().changed()).to_system(|q, world, qs, _| {
+                for (id, size) in changed_or_missing(q, world, qs, collider()) {
+                    world
+                        .add_component(
+                            id,
+                            collider(),
+                            ColliderDef::Capsule {
+                                radius: size.x,
+                                height: size.y,
+                                center: Vec3::ZERO,
+                            },
+                        )
+                        .unwrap();
+                }
+            }),
+            query(cylinder_collider().changed()).to_system(|q, world, qs, _| {
+                for (id, size) in changed_or_missing(q, world, qs, collider()) {
+                    world
+                        .add_component(
+                            id,
+                            collider(),
+                            ColliderDef::Cylinder {
+                                radius: size.x,
+                                height: size.y,
+                                center: Vec3::ZERO,
+                            },
+                        )
+                        .unwrap();
+                }
+            }),
+            query(convex_collider().changed()).to_system(|q, world, qs, _| {
+                for (id, points) in changed_or_missing(q, world, qs, collider()) {
+                    world
+                        .add_component(id, collider(), ColliderDef::ConvexMesh { points })
+                        .unwrap();
+                }
+            }),
+            query((
+                heightfield_collider().changed(),
+                heightfield_collider_columns().changed(),
+            ))
+            .optional_changed(heightfield_collider_cell_size())
+            .to_system(|q, world, qs, _| {
+                for (id, (heights, columns)) in changed_or_missing(q, world, qs, collider()) {
+                    let cell_size = world
+                        .get(id, heightfield_collider_cell_size())
+                        .unwrap_or(1.);
+                    world
+                        .add_component(
+                            id,
+                            collider(),
+                            ColliderDef::HeightField {
+                                heights,
+                                columns,
+                                cell_size,
+                            },
+                        )
+                        .unwrap();
+                }
+            }),
             query(collider_from_url().changed()).to_system(|q, world, qs, _| {
                 for (id, url) in changed_or_missing(q, world, qs, collider()) {
                     tracing::debug!("Loading collider: {url:#?}");
@@ -460,8 +521,36 @@ pub enum ColliderDef {
         center: Vec3,
     },
     Plane,
+    /// A capsule standing upright along the Z axis; `height` includes the hemispheres at both ends
+    Capsule {
+        radius: f32,
+        height: f32,
+        #[serde(default = "vec3_zero_value")]
+        center: Vec3,
+    },
+    /// A cylinder standing upright along the Z axis, approximated with a convex mesh
+    Cylinder {
+        radius: f32,
+        height: f32,
+        #[serde(default = "vec3_zero_value")]
+        center: Vec3,
+    },
+    /// The convex hull of `points`
+    ConvexMesh {
+        points: Vec<Vec3>,
+    },
+    /// A grid of `heights`, row by row, with rows along the Y axis and columns along the X axis
+    HeightField {
+        heights: Vec<f32>,
+        columns: u32,
+        #[serde(default = "one_value")]
+        cell_size: f32,
+    },
 }
 
+/// The number of sides of the convex mesh that cylinders are approximated with
+const CYLINDER_SEGMENTS: usize = 16;
+
 type ColliderSpawner = Box<dyn Fn(&Physics, Vec3) -> (Vec<PxShape>, Vec<PxShape>) + Sync + Send>;
 impl ColliderDef {
     #[allow(clippy::single_match)]
@@ -547,6 +636,135 @@ impl ColliderDef {
                 });
                 (vec![shape.clone()], vec![shape])
             })),
+            ColliderDef::Capsule {
+                radius,
+                height,
+                center,
+            } => {
+                if !(radius > 0. && height >= radius * 2.) {
+                    return Err(anyhow::anyhow!(
+                        "Capsule collider radius must be more than zero, and its height at least twice the radius. radius={} height={}",
+                        radius,
+                        height
+                    )
+                    .into());
+                }
+                let half_height = height / 2. - radius;
+                // PhysX capsules extend along the X axis
+                let rotation = Quat::from_rotation_y(-PI / 2.);
+                Ok(Box::new(move |physics, scale| {
+                    let geometry = PxCapsuleGeometry::new(
+                        radius * scale.x.max(scale.y),
+                        half_height * scale.z,
+                    );
+                    let shape =
+                        PxShape::new(physics.physics, &geometry, &[&material], Some(true), None);
+                    shape.set_local_pose(&PxTransform::new(center * scale, rotation));
+                    shape.set_user_data(PxShapeUserData {
+                        entity: EntityId::null(),
+                        density,
+                        base_pose: Mat4::from_scale_rotation_translation(
+                            vec3(half_height, radius, radius),
+                            rotation,
+                            center,
+                        ),
+                    });
+                    (vec![shape.clone()], vec![shape])
+                }))
+            }
+            ColliderDef::Cylinder {
+                radius,
+                height,
+                center,
+            } => {
+                if !(radius > 0. && height > 0.) {
+                    return Err(anyhow::anyhow!(
+                        "Cylinder collider radius and height have to be more than zero. radius={} height={}",
+                        radius,
+                        height
+                    )
+                    .into());
+                }
+                let points = (0..CYLINDER_SEGMENTS)
+                    .flat_map(|i| {
+                        let angle = i as f32 / CYLINDER_SEGMENTS as f32 * PI * 2.;
+                        let (y, x) = angle.sin_cos();
+                        [vec3(x, y, -1.), vec3(x, y, 1.)]
+                    })
+                    .collect_vec();
+                let size = vec3(radius, radius, height / 2.);
+                Ok(Box::new(move |physics, scale| {
+                    convex_mesh_shapes(physics, &points, size, center, scale, &material, density)
+                }))
+            }
+            ColliderDef::ConvexMesh { points } => {
+                if points.len() < 4 {
+                    return Err(anyhow::anyhow!(
+                        "Convex collider mesh must have at least four points. points={}",
+                        points.len()
+                    )
+                    .into());
+                }
+                Ok(Box::new(move |physics, scale| {
+                    convex_mesh_shapes(
+                        physics,
+                        &points,
+                        Vec3::ONE,
+                        Vec3::ZERO,
+                        scale,
+                        &material,
+                        density,
+                    )
+                }))
+            }
+            ColliderDef::HeightField {
+                heights,
+                columns,
+                cell_size,
+            } => {
+                let rows = if columns > 0 {
+                    heights.len() as u32 / columns
+                } else {
+                    0
+                };
+                if columns < 2 || rows < 2 || heights.len() as u32 != rows * columns {
+                    return Err(anyhow::anyhow!(
+                        "Heightfield collider must have at least two rows and two columns, and a height for each of them. heights={} columns={}",
+                        heights.len(),
+                        columns
+                    )
+                    .into());
+                }
+                if cell_size <= 0. {
+                    return Err(anyhow::anyhow!(
+                        "Heightfield collider cell size must be more than zero"
+                    )
+                    .into());
+                }
+                // PhysX heightfields have their rows along X, their columns along Z and their
+                // heights along Y; this maps them to Y, X and Z respectively
+                let rotation = Quat::from_mat3(&Mat3::from_cols(Vec3::Y, Vec3::Z, Vec3::X));
+                Ok(Box::new(move |physics, scale| {
+                    let quantized = PxQuantizedHeightFieldSamples::new_from_f32_array(&heights);
+                    let desc = PxHeightFieldDesc::new(rows, columns, &quantized.samples);
+                    let geometry = PxHeightFieldGeometry::new(
+                        &mut physics.cooking.create_height_field(&physics.physics, &desc),
+                        quantized.height_scale * scale.z,
+                        cell_size * scale.y,
+                        cell_size * scale.x,
+                    );
+                    let translation = vec3(0., 0., quantized.min_height);
+                    let shape =
+                        PxShape::new(physics.physics, &geometry, &[&material], Some(true), None);
+                    shape.set_local_pose(&PxTransform::new(translation * scale, rotation));
+                    shape.set_user_data(PxShapeUserData {
+                        entity: EntityId::null(),
+                        density,
+                        base_pose: Mat4::from_rotation_translation(rotation, translation),
+                    });
+                    (vec![shape.clone()], vec![shape])
+                }))
+            }
             ColliderDef::Asset { collider } => {
                 let collider = collider.unwrap_abs();
                 let collider_from_urls: Arc<ColliderFromUrls> =
@@ -566,6 +784,45 @@ impl ColliderDef {
     }
 }
 
+/// Cooks the convex hull of `points`, scaled by `size`, into a shape
+fn convex_mesh_shapes(
+    physics: &Physics,
+    points: &[Vec3],
+    size: Vec3,
+    center: Vec3,
+    scale: Vec3,
+    material: &PxMaterial,
+    density: f32,
+) -> (Vec<PxShape>, Vec<PxShape>) {
+    let desc = PxConvexMeshDesc {
+        points: points.to_vec(),
+        indices: None,
+        vertex_limit: None,
+        flags: Some(PxConvexFlag::COMPUTE_CONVEX),
+    };
+    let mesh = match PxConvexMesh::from_desc(physics.physics, physics.cooking, desc) {
+        Ok(mesh) => mesh,
+        Err(err) => {
+            tracing::warn!("Failed to cook convex collider mesh: {:?}", err);
+            return (Vec::new(), Vec::new());
+        }
+    };
+    let geometry =
+        PxConvexMeshGeometry::new(&mesh, Some(PxMeshScale::from_scale(size * scale)), None);
+    if !geometry.is_valid() {
+        tracing::warn!("Invalid convex collider geometry. size={size:?} scale={scale:?}");
+        return (Vec::new(), Vec::new());
+    }
+    let shape = PxShape::new(physics.physics, &geometry, &[material], Some(true), None);
+    shape.set_local_pose(&PxTransform::from_translation(center * scale));
+    shape.set_user_data(PxShapeUserData {
+        entity: EntityId::null(),
+        density,
+        base_pose: Mat4::from_scale_rotation_translation(size, Default::default(), center),
+    });
+    (vec![shape.clone()], vec![shape])
+}
+
 impl Default for ColliderDef {
     fn default() -> Self {
         Self::Sphere {
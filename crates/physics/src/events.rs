@@ -0,0 +1,253 @@
+use std::{collections::HashSet, sync::Arc};
+
+use ambient_ecs::{
+    components, query, Debuggable, DynSystem, EntityId, FnSystem, Resource, SystemGroup, World,
+};
+use glam::Vec3;
+use parking_lot::{Mutex, RwLock};
+use physxx::{
+    PxContactPair, PxOverlapCallback, PxPairFlags, PxQueryFilterData, PxQueryFlag, PxUserData,
+};
+
+use crate::{
+    collider::{collider_type, ColliderType},
+    contact_events, main_physics_scene,
+    physx::physics_shape,
+    PxShapeUserData,
+};
+
+components!("physics", {
+    @[Debuggable, Resource]
+    contact_reports: Arc<Mutex<Vec<ContactReport>>>,
+    /// The entities with `contact_events`; shared with the simulation callback, so that contacts
+    /// that nobody is interested in are not reported
+    @[Debuggable, Resource]
+    contact_event_entities: Arc<RwLock<HashSet<EntityId>>>,
+    @[Debuggable, Resource]
+    trigger_events: Vec<TriggerEvent>,
+    /// The entities that were overlapping this trigger area in the last physics step
+    @[Debuggable]
+    trigger_area_overlaps: HashSet<EntityId>,
+});
+
+/// Set in the last word of the simulation filter data of the shapes of entities with
+/// `contact_events`, so that the filter shader only tracks touches for the pairs that are reported
+const CONTACT_EVENTS_FILTER_BIT: u32 = 1;
+
+/// Whether the last word of the simulation filter data of a shape has [`CONTACT_EVENTS_FILTER_BIT`]
+pub(crate) fn has_contact_events(filter_word: u32) -> bool {
+    filter_word & CONTACT_EVENTS_FILTER_BIT != 0
+}
+
+fn set_contact_events_filter(shape: &physxx::PxShape, enabled: bool) {
+    let mut words = shape.get_simulation_filter_data();
+    if has_contact_events(words[3]) != enabled {
+        words[3] ^= CONTACT_EVENTS_FILTER_BIT;
+        // Changing the filter data makes PhysX filter the pairs of the shape again
+        shape.set_simulation_filter_data(words);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactPhase {
+    Begin,
+    Persist,
+    End,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContactReport {
+    pub phase: ContactPhase,
+    pub ids: [EntityId; 2],
+    pub points: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    pub impulse: Vec3,
+}
+impl ContactReport {
+    /// Creates a report for `pair` if it involves an entity in `entities`
+    pub fn from_pair(pair: &PxContactPair, entities: &HashSet<EntityId>) -> Option<Self> {
+        let [Some(a), Some(b)] = &pair.shapes else {
+            return None;
+        };
+        let a = a.get_user_data::<PxShapeUserData>()?.entity;
+        let b = b.get_user_data::<PxShapeUserData>()?.entity;
+        if !entities.contains(&a) && !entities.contains(&b) {
+            return None;
+        }
+
+        let phase = if pair.events.contains(PxPairFlags::NOTIFY_TOUCH_FOUND) {
+            ContactPhase::Begin
+        } else if pair.events.contains(PxPairFlags::NOTIFY_TOUCH_LOST) {
+            ContactPhase::End
+        } else if pair.events.contains(PxPairFlags::NOTIFY_TOUCH_PERSISTS) {
+            ContactPhase::Persist
+        } else {
+            return None;
+        };
+
+        // The contact points are only extracted for the pairs that are reported
+        let contact_points = pair.contact_points();
+        Some(Self {
+            phase,
+            ids: [a, b],
+            points: contact_points.iter().map(|p| p.position).collect(),
+            normals: contact_points.iter().map(|p| p.normal).collect(),
+            impulse: contact_points.iter().map(|p| p.impulse).sum(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerEvent {
+    Enter { trigger: EntityId, id: EntityId },
+    Exit { trigger: EntityId, id: EntityId },
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/events",
+        vec![
+            query(())
+                .incl(contact_events())
+                .spawned()
+                .to_system(|q, world, qs, _| {
+                    let entities = world.resource(contact_event_entities()).clone();
+                    let mut entities = entities.write();
+                    for (id, ()) in q.iter(world, qs) {
+                        entities.insert(id);
+                    }
+                }),
+            query(())
+                .incl(contact_events())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    let entities = world.resource(contact_event_entities()).clone();
+                    let mut entities = entities.write();
+                    for (id, ()) in q.iter(world, qs) {
+                        entities.remove(&id);
+                    }
+                }),
+            // Shapes are created asynchronously, and again when the world is forked, so this
+            // is checked every frame
+            query((physics_shape(),))
+                .incl(contact_events())
+                .to_system(|q, world, qs, _| {
+                    for (_, (shape,)) in q.iter(world, qs) {
+                        set_contact_events_filter(shape, true);
+                    }
+                }),
+            query((physics_shape(),))
+                .incl(contact_events())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (id, _) in q.iter(world, qs) {
+                        // Only entities that still have their shape stopped having `contact_events`
+                        if let Ok(shape) = world.get_ref(id, physics_shape()) {
+                            set_contact_events_filter(shape, false);
+                        }
+                    }
+                }),
+            trigger_areas_system(),
+            // Trigger areas that were despawned, or stopped being trigger areas, are exited by everything
+            query((trigger_area_overlaps(),))
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    let exits = q
+                        .iter(world, qs)
+                        .flat_map(|(trigger, (overlaps,))| {
+                            overlaps
+                                .iter()
+                                .map(move |&id| TriggerEvent::Exit { trigger, id })
+                        })
+                        .collect::<Vec<_>>();
+                    world.resource_mut(trigger_events()).extend(exits);
+                }),
+        ],
+    )
+}
+
+/// The trigger areas live in a scene of their own that isn't simulated, so instead of relying on
+/// PhysX trigger reports, each trigger area is overlapped with the dynamic actors of the main scene
+fn trigger_areas_system() -> DynSystem {
+    Box::new(FnSystem::new(|world, _| {
+        profiling::scope!("trigger_areas_system");
+        let mut events = Vec::new();
+
+        let triggers = query((physics_shape(), collider_type()))
+            .iter(world, None)
+            .filter(|(_, (_, ty))| **ty == ColliderType::TriggerArea)
+            .map(|(id, (shape, _))| (id, shape.clone()))
+            .collect::<Vec<_>>();
+        for (trigger, shape) in triggers {
+            let overlaps = overlapping_entities(world, trigger, &shape);
+            let previous = world
+                .get_ref(trigger, trigger_area_overlaps())
+                .cloned()
+                .unwrap_or_default();
+
+            events.extend(
+                overlaps
+                    .difference(&previous)
+                    .map(|&id| TriggerEvent::Enter { trigger, id }),
+            );
+            events.extend(
+                previous
+                    .difference(&overlaps)
+                    .map(|&id| TriggerEvent::Exit { trigger, id }),
+            );
+            if overlaps != previous {
+                world
+                    .add_component(trigger, trigger_area_overlaps(), overlaps)
+                    .unwrap();
+            }
+        }
+
+        // Removing `trigger_area_overlaps` from these makes the despawned query in
+        // `server_systems` send their exit events
+        for (trigger, _) in query(())
+            .incl(trigger_area_overlaps())
+            .collect_cloned(world, None)
+        {
+            let is_trigger_area = world.has_component(trigger, physics_shape())
+                && world.get(trigger, collider_type()).ok() == Some(ColliderType::TriggerArea);
+            if !is_trigger_area {
+                world
+                    .remove_component(trigger, trigger_area_overlaps())
+                    .unwrap();
+            }
+        }
+
+        *world.resource_mut(trigger_events()) = events;
+    }))
+}
+
+fn overlapping_entities(
+    world: &World,
+    trigger: EntityId,
+    shape: &physxx::PxShape,
+) -> HashSet<EntityId> {
+    let Some(actor) = shape.get_actor() else {
+        return HashSet::new();
+    };
+
+    let mut filter_data = PxQueryFilterData::new();
+    filter_data.set_flags(PxQueryFlag::DYNAMIC | PxQueryFlag::NO_BLOCK);
+    let mut hit_call = PxOverlapCallback::new(1000);
+    let scene = world.resource(main_physics_scene());
+    if !scene.overlap(
+        &shape.get_geometry(),
+        shape.get_global_pose(actor),
+        &mut hit_call,
+        &filter_data,
+    ) {
+        return HashSet::new();
+    }
+
+    hit_call
+        .touches()
+        .into_iter()
+        .filter_map(|hit| hit.shape.get_user_data::<PxShapeUserData>())
+        .map(|ud| ud.entity)
+        .filter(|id| *id != trigger)
+        .collect()
+}
@@ -10,10 +10,10 @@ use glam::{vec3, Mat4, Vec3};
 use itertools::Itertools;
 use physxx::{
     AsPxActor, AsPxRigidActor, PxActor, PxActorRef, PxActorTypeFlag, PxBase, PxBoxGeometry,
-    PxConvexMeshGeometry, PxForceMode, PxJoint, PxMeshScale, PxOverlapCallback, PxPhysicsRef,
-    PxQueryFilterData, PxQueryFlag, PxRevoluteJointRef, PxRigidActor, PxRigidActorRef, PxRigidBody,
-    PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxSceneRef, PxShape, PxSphereGeometry,
-    PxTransform, PxTriangleMeshGeometry, PxUserData,
+    PxCapsuleGeometry, PxConvexMeshGeometry, PxForceMode, PxJoint, PxMeshScale, PxOverlapCallback,
+    PxPhysicsRef, PxQueryFilterData, PxQueryFlag, PxRevoluteJointRef, PxRigidActor,
+    PxRigidActorRef, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxSceneRef,
+    PxShape, PxSphereGeometry, PxTransform, PxTriangleMeshGeometry, PxUserData,
 };
 
 use crate::{
@@ -23,7 +23,7 @@ use crate::{
         physics, physics_controlled, physics_shape, revolute_joint, rigid_actor, rigid_dynamic,
         rigid_static,
     },
-    unit_mass, unit_velocity, ColliderScene, PxActorUserData, PxShapeUserData,
+    recording, unit_mass, unit_velocity, ColliderScene, PxActorUserData, PxShapeUserData,
 };
 
 pub fn convert_rigid_static_to_dynamic(world: &mut World, id: EntityId) {
@@ -227,6 +227,10 @@ pub fn scale_shape(shape: PxShape, scale: Vec3) {
     } else if let Some(_geo) = geo.as_box() {
         let new_geo = PxBoxGeometry::new(size.x, size.y, size.z);
         shape.set_geometry(&new_geo);
+    } else if let Some(_geo) = geo.as_capsule() {
+        // Capsules extend along their local X axis; the base scale is (half height, radius, radius)
+        let new_geo = PxCapsuleGeometry::new(size.y.abs(), size.x.abs());
+        shape.set_geometry(&new_geo);
     } else {
         // TODO
     }
@@ -569,6 +573,7 @@ impl PhysicsObjectCollection {
                     .contains(PxRigidBodyFlag::KINEMATIC)
                 {
                     actor.add_force(force, Some(PxForceMode::Force), Some(true));
+                    recording::record_force(world, actor, force, None, Some(PxForceMode::Force));
                 }
             }
         }
@@ -620,6 +625,7 @@ pub fn add_force(
         bail!("Can't apply force to kinematic actor");
     }
     actor.add_force(force, mode, Some(true));
+    recording::record_force(world, actor, force, None, mode);
     Ok(())
 }
 
@@ -641,6 +647,7 @@ pub fn add_force_at_position(
         bail!("Can't apply force to kinematic actor");
     }
     actor.add_force_at_pos(force, position, mode, Some(true));
+    recording::record_force(world, actor, force, Some(position), mode);
     Ok(())
 }
 
@@ -1,20 +1,23 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, f32::consts::FRAC_PI_2};
 
 use ambient_core::{asset_cache, transform::translation};
 use ambient_ecs::{query, ArchetypeFilter, EntityId, World};
 use ambient_meshes::cuboid::CuboidMesh;
 use ambient_native_std::{asset_cache::SyncAssetKeyExt, mesh::Mesh, shapes::Ray};
 use ambient_network::server;
-use glam::Vec3;
+use glam::{Quat, Vec3};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use physxx::{
-    PxConvexFlag, PxConvexMesh, PxConvexMeshDesc, PxConvexMeshGeometry, PxOverlapCallback,
-    PxQueryFilterData, PxRaycastCallback, PxRigidActor, PxShape, PxTransform, PxUserData,
+    PxBoxGeometry, PxCapsuleGeometry, PxConvexFlag, PxConvexMesh, PxConvexMeshDesc,
+    PxConvexMeshGeometry, PxGeometry, PxOverlapCallback, PxQueryFilterData, PxQueryFlag,
+    PxRaycastCallback, PxRigidActor, PxShape, PxSphereGeometry, PxTransform, PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{main_physics_scene, physx::PhysicsKey, ColliderScene, PxShapeUserData};
+use crate::{
+    collider::ColliderType, main_physics_scene, physx::PhysicsKey, ColliderScene, PxShapeUserData,
+};
 
 pub fn get_entities_in_radius(world: &World, center: Vec3, radius: f32) -> Vec<EntityId> {
     query((translation(),))
@@ -124,6 +127,150 @@ pub fn raycast_collider_type_px(
     Vec::new()
 }
 
+/// A shape that can be swept through the scene with [sweep], or tested for overlaps with [overlap]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum QueryShape {
+    Sphere {
+        radius: f32,
+    },
+    Cuboid {
+        half_extents: Vec3,
+    },
+    /// A capsule along the Z axis. `half_height` does not include the hemispheres at the ends.
+    Capsule {
+        radius: f32,
+        half_height: f32,
+    },
+}
+impl QueryShape {
+    fn geometry(&self) -> Box<dyn PxGeometry> {
+        match *self {
+            QueryShape::Sphere { radius } => Box::new(PxSphereGeometry::new(radius)),
+            QueryShape::Cuboid { half_extents } => Box::new(PxBoxGeometry::new(
+                half_extents.x,
+                half_extents.y,
+                half_extents.z,
+            )),
+            QueryShape::Capsule {
+                radius,
+                half_height,
+            } => Box::new(PxCapsuleGeometry::new(radius, half_height)),
+        }
+    }
+
+    fn pose(&self, position: Vec3, rotation: Quat) -> PxTransform {
+        match self {
+            // PhysX capsules extend along the X axis
+            QueryShape::Capsule { .. } => {
+                PxTransform::new(position, rotation * Quat::from_rotation_y(-FRAC_PI_2))
+            }
+            _ => PxTransform::new(position, rotation),
+        }
+    }
+}
+
+/// Where a [sweep] hit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SweepHit {
+    pub entity: EntityId,
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub distance: f32,
+}
+
+/// Sweeps `shape` from `origin` along `direction` (which must be normalized) for up to `max_distance`,
+/// and returns everything it hits, sorted by distance.
+///
+/// If `filter` is set, only colliders of that type are considered.
+pub fn sweep(
+    world: &World,
+    shape: QueryShape,
+    origin: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    filter: Option<ColliderType>,
+) -> Vec<SweepHit> {
+    let geometry = shape.geometry();
+    let pose = shape.pose(origin, rotation);
+
+    query_scenes(filter)
+        .flat_map(|scene| {
+            let hit = scene.get_scene(world).sweep(
+                geometry.as_ref(),
+                &pose,
+                direction,
+                max_distance,
+                query_filter_data(filter),
+            );
+            hit.touches()
+        })
+        .filter_map(|hit| {
+            let ud = hit.shape?.get_user_data::<PxShapeUserData>()?;
+            Some(SweepHit {
+                entity: ud.entity,
+                position: hit.position,
+                normal: hit.normal,
+                distance: hit.distance,
+            })
+        })
+        .sorted_by_key(|hit| OrderedFloat(hit.distance))
+        .collect()
+}
+
+/// Returns all entities with a collider that overlaps `shape` placed at `position` with `rotation`.
+///
+/// If `filter` is set, only colliders of that type are considered.
+pub fn overlap(
+    world: &World,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    filter: Option<ColliderType>,
+) -> Vec<EntityId> {
+    let geometry = shape.geometry();
+    let pose = shape.pose(position, rotation);
+    let filter_data = query_filter_data(filter);
+
+    let mut res = HashSet::new();
+    for scene in query_scenes(filter) {
+        let mut hit_call = PxOverlapCallback::new(1000);
+        if scene
+            .get_scene(world)
+            .overlap(geometry.as_ref(), pose, &mut hit_call, &filter_data)
+        {
+            for hit in hit_call.touches() {
+                if let Some(ud) = hit.shape.get_user_data::<PxShapeUserData>() {
+                    res.insert(ud.entity);
+                }
+            }
+        }
+    }
+    res.into_iter().sorted().collect()
+}
+
+fn query_scenes(filter: Option<ColliderType>) -> impl Iterator<Item = ColliderScene> {
+    match filter {
+        Some(ty) => vec![ty.scene()],
+        None => (0..3).map(ColliderScene::from_usize).collect(),
+    }
+    .into_iter()
+}
+
+/// Static and dynamic colliders share a scene, so they are told apart by the type of their actor.
+///
+/// Every hit is reported as a touch, as PhysX otherwise only returns the closest blocking hit.
+fn query_filter_data(filter: Option<ColliderType>) -> PxQueryFilterData {
+    let actors = match filter {
+        Some(ColliderType::Static) => PxQueryFlag::STATIC,
+        Some(ColliderType::Dynamic) => PxQueryFlag::DYNAMIC,
+        _ => PxQueryFlag::STATIC | PxQueryFlag::DYNAMIC,
+    };
+    let mut filter_data = PxQueryFilterData::new();
+    filter_data.set_flags(actors | PxQueryFlag::NO_BLOCK);
+    filter_data
+}
+
 pub fn intersect_frustum(world: &World, frustum_corners: &[Vec3; 8]) -> Vec<EntityId> {
     let mut hit_call = PxOverlapCallback::new(1000);
     let filter_data = PxQueryFilterData::new();
@@ -0,0 +1,339 @@
+use std::{collections::HashSet, f32::consts::PI};
+
+use ambient_ecs::{
+    components, query, Component, ComponentValue, DynSystem, EntityId, FnSystem, SystemGroup, World,
+};
+use glam::{Mat4, Vec2, Vec3};
+use physxx::{
+    AsPxJoint, PxBase, PxConstraintFlags, PxD6Axis, PxD6Drive, PxD6JointRef, PxD6Motion,
+    PxDistanceJointFlag, PxDistanceJointRef, PxFixedJointRef, PxJoint, PxJointAngularLimitPair,
+    PxJointLimitCone, PxJointLinearLimitPair, PxJointRef, PxPhysicsRef, PxRevoluteJointFlag,
+    PxRevoluteJointRef, PxRigidActorRef, PxTransform, PxUserData,
+};
+
+use crate::{
+    helpers::get_actor, joint_actor_0, joint_actor_1, joint_break_force, joint_break_torque,
+    joint_broken, joint_cone_limit, joint_drive_force_limit, joint_drive_velocity, joint_limit,
+    joint_local_frame_0, joint_local_frame_1, joint_spring_damping, joint_spring_stiffness,
+    joint_type, physx::rigid_actor,
+};
+
+pub use ambient_ecs::generated::physics::types::JointType;
+
+/// The damping of the velocity drives of D6 joints, which have no stiffness. A high damping makes
+/// the joint reach the drive velocity almost immediately, limited by `joint_drive_force_limit`.
+/// `f32::MAX` would make the drive force overflow to infinity for any velocity error above one,
+/// so a large finite damping is used instead.
+const VELOCITY_DRIVE_DAMPING: f32 = 1e10;
+
+components!("physics", {
+    /// The PhysX joint created for an entity with a `joint_type`
+    px_joint: PxJointRef,
+});
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/joints",
+        vec![
+            // Only despawns release the joint here: `px_joint` is also removed when the world is
+            // forked, in which case the joint is still in use by the original world
+            query((px_joint(),))
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (id, (joint,)) in q.iter(world, qs) {
+                        if !world.exists(id) {
+                            joint.release();
+                        }
+                    }
+                }),
+            release_orphaned_joints_system(),
+            // Joints are created when they are spawned, and otherwise retried when one of their
+            // actors is given a collider, instead of every frame
+            query((joint_type(), joint_actor_0()))
+                .excl(px_joint())
+                .excl(joint_broken())
+                .spawned()
+                .to_system(|q, world, qs, _| {
+                    for (id, (ty, actor0)) in q.collect_cloned(world, qs) {
+                        if let Some(actor) = [Ok(actor0), world.get(id, joint_actor_1())]
+                            .into_iter()
+                            .flatten()
+                            .find(|&actor| !world.exists(actor))
+                        {
+                            tracing::warn!(
+                                "Joint {id} can not be created, as its actor {actor} does not exist"
+                            );
+                        }
+                        create_joint(world, id, ty, actor0);
+                    }
+                }),
+            query(())
+                .incl(rigid_actor())
+                .spawned()
+                .to_system(|q, world, qs, _| {
+                    let actors = q.iter(world, qs).map(|(id, ())| id).collect::<HashSet<_>>();
+                    if actors.is_empty() {
+                        return;
+                    }
+                    let waiting = query((joint_type(), joint_actor_0()))
+                        .excl(px_joint())
+                        .excl(joint_broken())
+                        .collect_cloned(world, None);
+                    for (id, (ty, actor0)) in waiting {
+                        let actor1 = world.get(id, joint_actor_1()).ok();
+                        if actors.contains(&actor0) || actor1.is_some_and(|x| actors.contains(&x)) {
+                            create_joint(world, id, ty, actor0);
+                        }
+                    }
+                }),
+            query(())
+                .incl(px_joint())
+                .optional_changed(joint_limit())
+                .optional_changed(joint_cone_limit())
+                .optional_changed(joint_drive_velocity())
+                .optional_changed(joint_drive_force_limit())
+                .optional_changed(joint_spring_stiffness())
+                .optional_changed(joint_spring_damping())
+                .optional_changed(joint_break_force())
+                .optional_changed(joint_break_torque())
+                .to_system(|q, world, qs, _| {
+                    for (id, ()) in q.collect_cloned(world, qs) {
+                        update_joint(world, id);
+                    }
+                }),
+            update_on_removal_system(joint_limit()),
+            update_on_removal_system(joint_cone_limit()),
+            update_on_removal_system(joint_drive_velocity()),
+            update_on_removal_system(joint_drive_force_limit()),
+            update_on_removal_system(joint_spring_stiffness()),
+            update_on_removal_system(joint_spring_damping()),
+            update_on_removal_system(joint_break_force()),
+            update_on_removal_system(joint_break_torque()),
+            query((px_joint(),))
+                .excl(joint_broken())
+                .to_system(|q, world, qs, _| {
+                    for (id, (joint,)) in q.collect_cloned(world, qs) {
+                        if joint
+                            .get_constraint_flags()
+                            .contains(PxConstraintFlags::BROKEN)
+                        {
+                            world.add_component(id, joint_broken(), ()).unwrap();
+                        }
+                    }
+                }),
+        ],
+    )
+}
+
+/// Releases the joints whose type was removed, or whose actors no longer exist.
+///
+/// This must run before the actors' shapes are released.
+fn release_orphaned_joints_system() -> DynSystem {
+    Box::new(FnSystem::new(|world, _| {
+        for (id, (joint,)) in query((px_joint(),)).collect_cloned(world, None) {
+            let is_orphaned = !world.has_component(id, joint_type())
+                || !world
+                    .get(id, joint_actor_0())
+                    .is_ok_and(|actor| world.exists(actor))
+                || world
+                    .get(id, joint_actor_1())
+                    .is_ok_and(|actor| !world.exists(actor));
+
+            if is_orphaned {
+                world.remove_component(id, px_joint()).unwrap();
+                joint.release();
+            }
+        }
+    }))
+}
+
+fn update_on_removal_system<T: ComponentValue>(component: Component<T>) -> DynSystem {
+    query(())
+        .incl(component)
+        .incl(px_joint())
+        .despawned()
+        .to_system(|q, world, qs, _| {
+            for (id, ()) in q.collect_cloned(world, qs) {
+                if world.has_component(id, px_joint()) {
+                    update_joint(world, id);
+                }
+            }
+        })
+}
+
+fn create_joint(world: &mut World, id: EntityId, ty: JointType, actor0: EntityId) {
+    let get_rigid_actor = |id: EntityId| get_actor(world, id).and_then(|x| x.to_rigid_actor());
+
+    // Wait until the actors have been given colliders
+    let Some(actor0) = get_rigid_actor(actor0) else {
+        return;
+    };
+    let actor1 = match world.get(id, joint_actor_1()) {
+        Ok(actor1) => match get_rigid_actor(actor1) {
+            Some(actor1) => Some(actor1),
+            None => return,
+        },
+        Err(_) => None,
+    };
+
+    let frame0 = to_px_transform(world.get(id, joint_local_frame_0()).unwrap_or_default());
+    let frame1 = to_px_transform(world.get(id, joint_local_frame_1()).unwrap_or_default());
+    let joint = new_px_joint(ty, Some(actor0), &frame0, actor1, &frame1);
+    joint.set_user_data(id);
+    joint.set_constraint_flag(PxConstraintFlags::DRIVE_LIMITS_ARE_FORCES, true);
+
+    world.add_component(id, px_joint(), joint).unwrap();
+    update_joint(world, id);
+}
+
+fn new_px_joint(
+    ty: JointType,
+    actor0: Option<PxRigidActorRef>,
+    frame0: &PxTransform,
+    actor1: Option<PxRigidActorRef>,
+    frame1: &PxTransform,
+) -> PxJointRef {
+    let physics = PxPhysicsRef::get();
+    match ty {
+        JointType::Fixed => {
+            PxFixedJointRef::new(physics, actor0, frame0, actor1, frame1).as_joint()
+        }
+        JointType::Revolute => {
+            PxRevoluteJointRef::new(physics, actor0, frame0, actor1, frame1).as_joint()
+        }
+        // PhysX only drives revolute joints, so these are configured D6 joints, which can be
+        // driven along or around any axis
+        JointType::Spherical | JointType::Prismatic => {
+            PxD6JointRef::new(physics, actor0, frame0, actor1, frame1).as_joint()
+        }
+        JointType::Distance => {
+            PxDistanceJointRef::new(physics, actor0, frame0, actor1, frame1).as_joint()
+        }
+    }
+}
+
+/// Applies the limits, drives and break force of the joint entity `id` to its PhysX joint
+fn update_joint(world: &World, id: EntityId) {
+    let Ok(joint) = world.get(id, px_joint()) else {
+        return;
+    };
+
+    joint.set_break_force(
+        world.get(id, joint_break_force()).unwrap_or(f32::MAX),
+        world.get(id, joint_break_torque()).unwrap_or(f32::MAX),
+    );
+
+    let drive_velocity = world.get(id, joint_drive_velocity()).ok();
+    let drive_force_limit = world.get(id, joint_drive_force_limit()).unwrap_or(f32::MAX);
+    if let Some(joint) = joint.to_revolute_joint() {
+        let limit = get_joint_limit(world, id, false);
+        if let Some(limit) = limit {
+            joint.set_limit(&PxJointAngularLimitPair::new(limit.x, limit.y, -1.));
+        }
+        joint.set_revolute_flag(PxRevoluteJointFlag::LIMIT_ENABLED, limit.is_some());
+
+        joint.set_drive_force_limit(drive_force_limit);
+        joint.set_drive_velocity(drive_velocity.unwrap_or_default(), true);
+        joint.set_revolute_flag(PxRevoluteJointFlag::DRIVE_ENABLED, drive_velocity.is_some());
+    } else if let Some(joint) = joint.to_d6_joint() {
+        // A velocity drive: it has no stiffness, and a very high damping towards the velocity
+        let damping = if drive_velocity.is_some() {
+            VELOCITY_DRIVE_DAMPING
+        } else {
+            0.
+        };
+        let drive_velocity = drive_velocity.unwrap_or_default();
+
+        match world.get(id, joint_type()) {
+            Ok(JointType::Spherical) => {
+                let cone_limit = get_joint_cone_limit(world, id);
+                if let Some(cone_limit) = cone_limit {
+                    joint.set_swing_limit(&PxJointLimitCone::new(cone_limit.x, cone_limit.y, -1.));
+                }
+                let swing = if cone_limit.is_some() {
+                    PxD6Motion::Limited
+                } else {
+                    PxD6Motion::Free
+                };
+                joint.set_motion(PxD6Axis::Twist, PxD6Motion::Free);
+                joint.set_motion(PxD6Axis::Swing1, swing);
+                joint.set_motion(PxD6Axis::Swing2, swing);
+
+                joint.set_drive(PxD6Drive::Twist, 0., damping, drive_force_limit);
+                joint.set_drive_velocity(Vec3::ZERO, Vec3::X * drive_velocity, true);
+            }
+            Ok(JointType::Prismatic) => {
+                let limit = get_joint_limit(world, id, false);
+                if let Some(limit) = limit {
+                    joint.set_linear_limit(
+                        PxD6Axis::X,
+                        &PxJointLinearLimitPair::new(limit.x, limit.y),
+                    );
+                }
+                joint.set_motion(
+                    PxD6Axis::X,
+                    if limit.is_some() {
+                        PxD6Motion::Limited
+                    } else {
+                        PxD6Motion::Free
+                    },
+                );
+
+                joint.set_drive(PxD6Drive::X, 0., damping, drive_force_limit);
+                joint.set_drive_velocity(Vec3::X * drive_velocity, Vec3::ZERO, true);
+            }
+            _ => {}
+        }
+    } else if let Some(joint) = joint.to_distance_joint() {
+        let limit = get_joint_limit(world, id, true);
+        if let Some(limit) = limit {
+            joint.set_min_distance(limit.x);
+            joint.set_max_distance(limit.y);
+        }
+        joint.set_distance_flag(PxDistanceJointFlag::MIN_DISTANCE_ENABLED, limit.is_some());
+        joint.set_distance_flag(PxDistanceJointFlag::MAX_DISTANCE_ENABLED, limit.is_some());
+
+        let stiffness = world.get(id, joint_spring_stiffness()).ok();
+        if let Some(stiffness) = stiffness {
+            joint.set_stiffness(stiffness);
+            joint.set_damping(world.get(id, joint_spring_damping()).unwrap_or_default());
+        }
+        joint.set_distance_flag(PxDistanceJointFlag::SPRING_ENABLED, stiffness.is_some());
+    }
+}
+
+/// The `joint_limit` of the joint, unless PhysX would reject it. Distances can not be negative.
+fn get_joint_limit(world: &World, id: EntityId, is_distance: bool) -> Option<Vec2> {
+    let limit = world.get(id, joint_limit()).ok()?;
+    if !limit.is_finite() || limit.x > limit.y {
+        tracing::warn!(
+            "Ignoring the joint_limit {limit} of joint {id}, as it is not finite or its lower limit is greater than its upper limit"
+        );
+        return None;
+    }
+    if is_distance && limit.x < 0. {
+        tracing::warn!(
+            "Ignoring the joint_limit {limit} of distance joint {id}, as distances can not be negative"
+        );
+        return None;
+    }
+    Some(limit)
+}
+
+/// The `joint_cone_limit` of the joint, unless PhysX would reject it
+fn get_joint_cone_limit(world: &World, id: EntityId) -> Option<Vec2> {
+    let limit = world.get(id, joint_cone_limit()).ok()?;
+    let is_valid = |angle: f32| angle > 0. && angle < PI;
+    if !(is_valid(limit.x) && is_valid(limit.y)) {
+        tracing::warn!(
+            "Ignoring the joint_cone_limit {limit} of joint {id}, as its angles must be between 0 and π"
+        );
+        return None;
+    }
+    Some(limit)
+}
+
+fn to_px_transform(frame: Mat4) -> PxTransform {
+    let (_, rotation, translation) = frame.to_scale_rotation_translation();
+    PxTransform::new(translation, rotation)
+}
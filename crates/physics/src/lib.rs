@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use ambient_core::{asset_cache, FIXED_SERVER_TICK_TIME};
 use ambient_ecs::{
@@ -10,27 +10,33 @@ use ambient_network::server::{ForkingEvent, ShutdownEvent};
 use collider::{collider_shapes, collider_shapes_convex};
 use glam::{vec3, Mat4, Vec3};
 use helpers::release_px_scene;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 use physx::{
     actor_aggregate, articulation_cache, articulation_link, articulation_reduce_coordinate,
     character_controller, fixed_joint, physics_shape, revolute_joint, rigid_actor, rigid_dynamic,
     rigid_static,
 };
 use physxx::{
-    AsPxActor, PxContactPairHeader, PxContactPoint, PxControllerManagerRef, PxMaterial,
+    AsPxActor, PxContactPair, PxContactPairHeader, PxControllerManagerRef, PxMaterial, PxPairFlags,
     PxPvdSceneFlag, PxRigidActor, PxSceneDesc, PxSceneFlags, PxSceneRef, PxSimulationEventCallback,
     PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::physx::PhysicsKey;
+use crate::{
+    events::ContactReport,
+    physx::{Physics, PhysicsKey},
+};
 
 pub mod collider;
+pub mod events;
 pub mod helpers;
 pub mod intersection;
+pub mod joints;
 pub mod mesh;
 pub mod physx;
 pub mod rc_asset;
+pub mod recording;
 pub mod visualization;
 
 pub use ambient_ecs::generated::physics::components::*;
@@ -53,6 +59,9 @@ pub fn init_all_components() {
     init_components();
     physx::init_components();
     collider::init_components();
+    events::init_components();
+    joints::init_components();
+    recording::init_components();
     visualization::init_components();
 }
 
@@ -61,17 +70,17 @@ pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entit
     let physics = PhysicsKey.get(assets);
     server_resources.set(crate::physx::physics(), physics.clone());
 
-    let mut main_scene_desc = PxSceneDesc::new(physics.physics);
-    main_scene_desc.set_cpu_dispatcher(&physics.dispatcher);
-    main_scene_desc.set_gravity(vec3(0., 0., -GRAVITY));
-    main_scene_desc.update_flags(|flags| flags | PxSceneFlags::ENABLE_CCD);
-    main_scene_desc.set_filter_shader(main_physx_scene_filter_shader, true);
+    let mut main_scene_desc = main_scene_desc(&physics);
     let collisions = Arc::new(Mutex::new(Vec::new()));
+    let contact_reports = Arc::new(Mutex::new(Vec::new()));
+    let contact_event_entities = Arc::new(RwLock::new(HashSet::new()));
     {
         let collisions = collisions.clone();
+        let contact_reports = contact_reports.clone();
+        let contact_event_entities = contact_event_entities.clone();
         main_scene_desc.set_simulation_event_callbacks(PxSimulationEventCallback {
             collision_callback: Some(Box::new(
-                move |header: &PxContactPairHeader, contacts: Vec<PxContactPoint>| {
+                move |header: &PxContactPairHeader, pairs: &[PxContactPair]| {
                     if let (Some(a), Some(b)) = (header.actors[0], header.actors[1]) {
                         let a = a
                             .borrow_shapes()
@@ -84,22 +93,44 @@ pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entit
                             .and_then(|s| s.get_user_data::<PxShapeUserData>())
                             .map(|ud| ud.entity);
 
+                        // The `Collision` message is only sent when the actors start touching
+                        let touch_found = pairs
+                            .iter()
+                            .filter(|pair| pair.events.contains(PxPairFlags::NOTIFY_TOUCH_FOUND))
+                            .collect::<Vec<_>>();
+                        let contacts = touch_found
+                            .iter()
+                            .flat_map(|pair| pair.contact_points())
+                            .collect::<Vec<_>>();
+
                         let positions: Vec<Vec3> =
                             contacts.iter().map(|point| point.position).collect();
 
                         let normals: Vec<Vec3> =
                             contacts.iter().map(|point| point.normal).collect();
 
-                        if let (Some(a), Some(b)) = (a, b) {
+                        if let (Some(a), Some(b), false) = (a, b, touch_found.is_empty()) {
                             collisions.lock().push((a, b, positions, normals));
                         }
                     }
+
+                    let contact_event_entities = contact_event_entities.read();
+                    if !contact_event_entities.is_empty() {
+                        contact_reports
+                            .lock()
+                            .extend(pairs.iter().filter_map(|pair| {
+                                ContactReport::from_pair(pair, &contact_event_entities)
+                            }));
+                    }
                 },
             )),
         });
     }
     let main_scene = PxSceneRef::new(&physics.physics, &main_scene_desc);
     server_resources.set(self::collisions(), collisions);
+    server_resources.set(events::contact_reports(), contact_reports);
+    server_resources.set(events::contact_event_entities(), contact_event_entities);
+    server_resources.set(events::trigger_events(), vec![]);
     server_resources.set(self::collider_loads(), vec![]);
 
     main_scene.get_scene_pvd_client().set_scene_pvd_flags(
@@ -128,6 +159,18 @@ pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entit
     );
 }
 
+/// The description of the main physics scene, without the simulation event callbacks.
+///
+/// Shared with [`recording::replay`], which has to simulate in an identical scene.
+pub fn main_scene_desc(physics: &Physics) -> PxSceneDesc {
+    let mut desc = PxSceneDesc::new(physics.physics);
+    desc.set_cpu_dispatcher(&physics.dispatcher);
+    desc.set_gravity(vec3(0., 0., -GRAVITY));
+    desc.update_flags(|flags| flags | PxSceneFlags::ENABLE_CCD);
+    desc.set_filter_shader(main_physx_scene_filter_shader, true);
+    desc
+}
+
 #[derive(Debug, Clone)]
 pub struct PxShapeUserData {
     pub entity: EntityId,
@@ -194,12 +237,21 @@ impl SyncAssetKey<PxMaterial> for PxWoodMaterialKey {
 unsafe extern "C" fn main_physx_scene_filter_shader(
     info: *mut physxx::sys::FilterShaderCallbackInfo,
 ) -> u16 {
-    (*(*info).pairFlags).mBits |= (physxx::sys::PxPairFlag::eSOLVE_CONTACT
+    let info = &*info;
+    let mut flags = physxx::sys::PxPairFlag::eSOLVE_CONTACT
         | physxx::sys::PxPairFlag::eDETECT_DISCRETE_CONTACT
         | physxx::sys::PxPairFlag::eDETECT_CCD_CONTACT
         | physxx::sys::PxPairFlag::eCONTACT_DEFAULT
         | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_FOUND
-        | physxx::sys::PxPairFlag::eNOTIFY_CONTACT_POINTS) as u16;
+        | physxx::sys::PxPairFlag::eNOTIFY_CONTACT_POINTS;
+    // Touches are only tracked after they are found for pairs that report contact events
+    if events::has_contact_events(info.filterData0.word3)
+        || events::has_contact_events(info.filterData1.word3)
+    {
+        flags |= physxx::sys::PxPairFlag::eNOTIFY_TOUCH_PERSISTS
+            | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_LOST;
+    }
+    (*info.pairFlags).mBits |= flags as u16;
     (physxx::sys::PxFilterFlag::eDEFAULT) as u16
 }
 
@@ -207,6 +259,8 @@ pub fn server_systems() -> SystemGroup {
     SystemGroup::new(
         "physics",
         vec![
+            // Joints have to be released before their actors
+            Box::new(joints::server_systems()),
             query((physics_shape(),))
                 .despawned()
                 .to_system(|q, world, qs, _| {
@@ -234,6 +288,7 @@ pub fn server_systems() -> SystemGroup {
                     }
                 }),
             Box::new(collider::server_systems()),
+            Box::new(events::server_systems()),
             Box::new(visualization::server_systems()),
         ],
     )
@@ -245,8 +300,10 @@ pub fn server_systems() -> SystemGroup {
 pub fn run_simulation_system() -> DynSystem {
     Box::new(FnSystem::new(|world, _| {
         profiling::scope!("run_simulation_system");
+        let dt = FIXED_SERVER_TICK_TIME.as_secs_f32();
+        recording::begin_step(world, dt);
         let scene = world.resource(main_physics_scene());
-        scene.simulate(FIXED_SERVER_TICK_TIME.as_secs_f32());
+        scene.simulate(dt);
     }))
 }
 
@@ -258,10 +315,12 @@ pub fn fetch_simulation_system() -> DynSystem {
         profiling::scope!("fetch_simulation_system");
 
         world.resource(collisions()).lock().clear();
+        world.resource(events::contact_reports()).lock().clear();
         world.resource_mut(collider_loads()).clear();
         let scene = world.resource(main_physics_scene());
         // Ensure the previous simulation has completed
         scene.fetch_results(true);
+        recording::end_step(world);
     }))
 }
 
@@ -269,10 +328,23 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
     SystemGroup::new(
         "physics/on_forking_systems",
         vec![Box::new(FnSystem::new(|world, _| {
+            // Only the main instance is recorded
+            if world.has_component(world.resource_entity(), recording::physics_recorder()) {
+                world
+                    .remove_component(world.resource_entity(), recording::physics_recorder())
+                    .unwrap();
+            }
+
             let mut ed = Entity::new();
             create_server_resources(world.resource(asset_cache()), &mut ed);
             world.add_components(world.resource_entity(), ed).unwrap();
 
+            let contact_event_entities = query(()).incl(contact_events()).collect_ids(world, None);
+            world
+                .resource(events::contact_event_entities())
+                .write()
+                .extend(contact_event_entities);
+
             for (id, _) in query(())
                 .incl(actor_aggregate())
                 .collect_cloned(world, None)
@@ -297,6 +369,12 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
             for (id, _) in query(()).incl(revolute_joint()).collect_cloned(world, None) {
                 world.remove_component(id, revolute_joint()).unwrap();
             }
+            for (id, _) in query(())
+                .incl(joints::px_joint())
+                .collect_cloned(world, None)
+            {
+                world.remove_component(id, joints::px_joint()).unwrap();
+            }
             for (id, _) in query(())
                 .incl(articulation_reduce_coordinate())
                 .collect_cloned(world, None)
@@ -0,0 +1,829 @@
+//! Recording of the inputs applied to the main physics scene, and deterministic replay of them.
+//!
+//! A recording starts with a [`PhysicsRecordingHeader`], followed by one [`RecordedStep`] per
+//! simulation step. Actors are stored as PhysX binary collections when they are added to the
+//! scene (or when their shapes, flags or mass change); after that, only the changes made to them
+//! between steps are stored. Joints are stored as collections which refer to the recorded actors,
+//! and are stored again whenever they change. Replaying a recording on the same platform and build reproduces the
+//! simulation bit for bit, which is verified with a checksum of the actor states after every step.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    fs::File,
+    hash::{Hash, Hasher},
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+    ptr::null_mut,
+    sync::Arc,
+};
+
+use ambient_ecs::{components, Resource, World};
+use anyhow::Context;
+use glam::{Quat, Vec3};
+use parking_lot::Mutex;
+use physxx::{
+    AsPxActor, AsPxBase, AsPxJoint, AsPxRigidActor, PxActorTypeFlag, PxAny, PxBase,
+    PxBinaryCollectionData, PxCollectionRef, PxDefaultMemoryOutputStream, PxForceMode, PxJoint,
+    PxJointRef, PxRigidActor, PxRigidActorRef, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef,
+    PxSceneRef, PxSerializationRegistryRef, PxShape, PxTransform, PxUserData,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{helpers::release_px_scene, main_physics_scene, main_scene_desc, physx::Physics};
+
+components!("physics", {
+    /// Records the inputs applied to the main physics scene; see [`start_recording`]
+    @[Resource]
+    physics_recorder: Arc<Mutex<PhysicsRecorder>>,
+});
+
+pub const PHYSICS_RECORDING_VERSION: u32 = 2;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PhysicsRecordingHeader {
+    pub version: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordedForceMode {
+    Force,
+    Impulse,
+    VelocityChange,
+    Acceleration,
+}
+impl From<PxForceMode> for RecordedForceMode {
+    fn from(mode: PxForceMode) -> Self {
+        match mode {
+            PxForceMode::Force => Self::Force,
+            PxForceMode::Impulse => Self::Impulse,
+            PxForceMode::VelocityChange => Self::VelocityChange,
+            PxForceMode::Acceleration => Self::Acceleration,
+        }
+    }
+}
+impl From<RecordedForceMode> for PxForceMode {
+    fn from(mode: RecordedForceMode) -> Self {
+        match mode {
+            RecordedForceMode::Force => Self::Force,
+            RecordedForceMode::Impulse => Self::Impulse,
+            RecordedForceMode::VelocityChange => Self::VelocityChange,
+            RecordedForceMode::Acceleration => Self::Acceleration,
+        }
+    }
+}
+
+/// A single change to the physics scene. Actors and joints are identified by the order they were
+/// recorded in.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum PhysicsInput {
+    SetGravity {
+        gravity: Vec3,
+    },
+    /// Adds an actor, serialized as a PhysX binary collection together with its shapes and materials
+    AddActor {
+        actor: u32,
+        data: Vec<u8>,
+    },
+    RemoveActor {
+        actor: u32,
+    },
+    /// Adds a joint, serialized as a PhysX binary collection which refers to the recorded actors
+    AddJoint {
+        joint: u32,
+        data: Vec<u8>,
+    },
+    RemoveJoint {
+        joint: u32,
+    },
+    SetGlobalPose {
+        actor: u32,
+        translation: Vec3,
+        rotation: Quat,
+    },
+    SetVelocity {
+        actor: u32,
+        linear: Vec3,
+        angular: Vec3,
+    },
+    SetKinematicTarget {
+        actor: u32,
+        translation: Vec3,
+        rotation: Quat,
+    },
+    /// A force applied at the center of mass, or at `position` (in world space) if set
+    AddForce {
+        actor: u32,
+        force: Vec3,
+        position: Option<Vec3>,
+        mode: RecordedForceMode,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordedStep {
+    pub dt: f32,
+    /// The inputs applied before the step was simulated, in order
+    pub inputs: Vec<PhysicsInput>,
+    /// A hash of the state of all recorded actors after the step was simulated
+    pub checksum: u64,
+}
+
+/// The simulated state of an actor
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct RecordedActorState {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub linear_velocity: Vec3,
+    pub angular_velocity: Vec3,
+}
+impl RecordedActorState {
+    fn get(actor: PxRigidActorRef) -> Self {
+        let pose = actor.get_global_pose();
+        let (linear_velocity, angular_velocity) = match actor.to_rigid_dynamic() {
+            Some(body) => (body.get_linear_velocity(), body.get_angular_velocity()),
+            None => (Vec3::ZERO, Vec3::ZERO),
+        };
+        Self {
+            translation: pose.translation(),
+            rotation: pose.rotation(),
+            linear_velocity,
+            angular_velocity,
+        }
+    }
+    fn bits(&self) -> [u32; 13] {
+        let mut bits = [0; 13];
+        let values = self
+            .translation
+            .to_array()
+            .into_iter()
+            .chain(self.rotation.to_array())
+            .chain(self.linear_velocity.to_array())
+            .chain(self.angular_velocity.to_array());
+        for (bits, value) in bits.iter_mut().zip(values) {
+            *bits = value.to_bits();
+        }
+        bits
+    }
+}
+
+fn same_bits(a: &[f32], b: &[f32]) -> bool {
+    a.iter().zip(b).all(|(a, b)| a.to_bits() == b.to_bits())
+}
+
+fn checksum<'a>(actors: impl IntoIterator<Item = (u32, &'a RecordedActorState)>) -> u64 {
+    let mut actors = actors.into_iter().collect::<Vec<_>>();
+    actors.sort_by_key(|(id, _)| *id);
+    let mut hasher = DefaultHasher::new();
+    for (id, state) in actors {
+        id.hash(&mut hasher);
+        state.bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// The properties of an actor that require it to be serialized again when they change.
+///
+/// The recorder keeps references to the shapes of the recorded actors, so that they are not
+/// reallocated while it knows about them. An actor allocated at the address of a released one can
+/// then only have the same signature if it was given the shapes of the released actor, which only
+/// happens when an actor is converted between static and dynamic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ActorSignature {
+    shapes: Vec<usize>,
+    dynamic: bool,
+    rigid_body_flags: u32,
+    mass: u32,
+}
+impl ActorSignature {
+    fn get(actor: PxRigidActorRef) -> Self {
+        let body = actor.to_rigid_body();
+        Self {
+            shapes: actor
+                .borrow_shapes()
+                .iter()
+                .map(|shape| shape.0 as usize)
+                .collect(),
+            dynamic: actor.to_rigid_dynamic().is_some(),
+            rigid_body_flags: body.map_or(0, |body| body.get_rigid_body_flags().bits()),
+            mass: body.map_or(0, |body| body.get_mass().to_bits()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RecordedActor {
+    id: u32,
+    signature: ActorSignature,
+    state: RecordedActorState,
+    /// Keeps the shapes of the actor alive; see [`ActorSignature`]
+    _shapes: Vec<PxShape>,
+}
+
+#[derive(Debug)]
+struct RecordedJoint {
+    joint: usize,
+    id: u32,
+    data: Vec<u8>,
+}
+
+/// The serial id of a recorded actor in the collections that joints are serialized against. Zero
+/// is not a valid serial id.
+fn actor_serial_id(actor: u32) -> usize {
+    actor as usize + 1
+}
+
+#[derive(Debug)]
+struct PendingForce {
+    actor: PxRigidActorRef,
+    force: Vec3,
+    position: Option<Vec3>,
+    mode: RecordedForceMode,
+}
+
+/// Writes the inputs applied to the main physics scene to a recording file
+pub struct PhysicsRecorder {
+    writer: BufWriter<File>,
+    registry: PxSerializationRegistryRef,
+    actors: HashMap<PxRigidActorRef, RecordedActor>,
+    next_actor_id: u32,
+    /// In the order of the constraints of the scene
+    joints: Vec<RecordedJoint>,
+    next_joint_id: u32,
+    gravity: Option<Vec3>,
+    pending_forces: Vec<PendingForce>,
+    step: Option<(f32, Vec<PhysicsInput>)>,
+}
+impl std::fmt::Debug for PhysicsRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhysicsRecorder")
+            .field("actors", &self.actors.len())
+            .finish_non_exhaustive()
+    }
+}
+impl PhysicsRecorder {
+    pub fn new(path: &Path, registry: PxSerializationRegistryRef) -> anyhow::Result<Self> {
+        let mut writer = BufWriter::new(
+            File::create(path).with_context(|| format!("Failed to create {path:?}"))?,
+        );
+        bincode::serialize_into(
+            &mut writer,
+            &PhysicsRecordingHeader {
+                version: PHYSICS_RECORDING_VERSION,
+            },
+        )?;
+        writer.flush()?;
+        Ok(Self {
+            writer,
+            registry,
+            actors: HashMap::new(),
+            next_actor_id: 0,
+            joints: Vec::new(),
+            next_joint_id: 0,
+            gravity: None,
+            pending_forces: Vec::new(),
+            step: None,
+        })
+    }
+
+    /// Records a force applied to `actor` since the last step
+    pub fn add_force(
+        &mut self,
+        actor: PxRigidDynamicRef,
+        force: Vec3,
+        position: Option<Vec3>,
+        mode: Option<PxForceMode>,
+    ) {
+        self.pending_forces.push(PendingForce {
+            actor: actor.as_rigid_actor(),
+            force,
+            position,
+            mode: mode.unwrap_or(PxForceMode::Force).into(),
+        });
+    }
+
+    /// Compares the scene to its state after the last step, and records the differences as the
+    /// inputs of the step about to be simulated
+    fn begin_step(&mut self, scene: PxSceneRef, dt: f32) -> anyhow::Result<()> {
+        let mut inputs = Vec::new();
+
+        let scene_actors = scene
+            .get_actors(PxActorTypeFlag::RIGID_DYNAMIC | PxActorTypeFlag::RIGID_STATIC)
+            .into_iter()
+            .filter_map(|actor| actor.to_rigid_actor())
+            .collect::<Vec<_>>();
+        let present = scene_actors.iter().copied().collect::<HashSet<_>>();
+
+        // Released actors must not be touched, so they are only identified by their address
+        let mut removed = Vec::new();
+        self.actors.retain(|actor, recorded| {
+            let keep = present.contains(actor);
+            if !keep {
+                removed.push(recorded.id);
+            }
+            keep
+        });
+        removed.sort();
+        inputs.extend(
+            removed
+                .into_iter()
+                .map(|actor| PhysicsInput::RemoveActor { actor }),
+        );
+
+        for actor in scene_actors {
+            let signature = ActorSignature::get(actor);
+            let state = RecordedActorState::get(actor);
+            let replaced = match self.actors.get(&actor) {
+                Some(recorded) if recorded.signature == signature => None,
+                previous => Some(previous.map(|previous| previous.id)),
+            };
+            if let Some(previous) = replaced {
+                if let Some(previous) = previous {
+                    inputs.push(PhysicsInput::RemoveActor { actor: previous });
+                }
+                let id = self.next_actor_id;
+                self.next_actor_id += 1;
+                inputs.push(PhysicsInput::AddActor {
+                    actor: id,
+                    data: serialize_actor(self.registry, actor)?,
+                });
+                self.actors.insert(
+                    actor,
+                    RecordedActor {
+                        id,
+                        signature,
+                        state,
+                        _shapes: actor.get_shapes(),
+                    },
+                );
+                continue;
+            }
+
+            let recorded = self.actors.get_mut(&actor).unwrap();
+            let id = recorded.id;
+            let old = recorded.state;
+            if !same_bits(&old.translation.to_array(), &state.translation.to_array())
+                || !same_bits(&old.rotation.to_array(), &state.rotation.to_array())
+            {
+                inputs.push(PhysicsInput::SetGlobalPose {
+                    actor: id,
+                    translation: state.translation,
+                    rotation: state.rotation,
+                });
+            }
+            if let Some(body) = actor.to_rigid_dynamic() {
+                if body
+                    .get_rigid_body_flags()
+                    .contains(PxRigidBodyFlag::KINEMATIC)
+                {
+                    if let Some(target) = body.get_kinematic_target() {
+                        inputs.push(PhysicsInput::SetKinematicTarget {
+                            actor: id,
+                            translation: target.translation(),
+                            rotation: target.rotation(),
+                        });
+                    }
+                } else if !same_bits(
+                    &old.linear_velocity.to_array(),
+                    &state.linear_velocity.to_array(),
+                ) || !same_bits(
+                    &old.angular_velocity.to_array(),
+                    &state.angular_velocity.to_array(),
+                ) {
+                    inputs.push(PhysicsInput::SetVelocity {
+                        actor: id,
+                        linear: state.linear_velocity,
+                        angular: state.angular_velocity,
+                    });
+                }
+            }
+            recorded.state = state;
+        }
+
+        self.record_joints(scene, &mut inputs)?;
+
+        let gravity = scene.get_gravity();
+        if self
+            .gravity
+            .map_or(true, |old| !same_bits(&old.to_array(), &gravity.to_array()))
+        {
+            inputs.push(PhysicsInput::SetGravity { gravity });
+            self.gravity = Some(gravity);
+        }
+
+        for force in self.pending_forces.drain(..) {
+            if let Some(recorded) = self.actors.get(&force.actor) {
+                inputs.push(PhysicsInput::AddForce {
+                    actor: recorded.id,
+                    force: force.force,
+                    position: force.position,
+                    mode: force.mode,
+                });
+            }
+        }
+
+        self.step = Some((dt, inputs));
+        Ok(())
+    }
+
+    /// Records the joints that were added, removed or changed since the last step. Joints have no
+    /// state that changes when simulated, so they are compared by their serialized data.
+    fn record_joints(
+        &mut self,
+        scene: PxSceneRef,
+        inputs: &mut Vec<PhysicsInput>,
+    ) -> anyhow::Result<()> {
+        let mut actor_collection = PxCollectionRef::new();
+        for (actor, recorded) in &self.actors {
+            actor_collection.add_with_serial_id(actor.as_base(), actor_serial_id(recorded.id));
+        }
+        let joints = scene
+            .get_constraints()
+            .into_iter()
+            .filter_map(|constraint| constraint.get_external_reference().to_joint())
+            // Joints to actors which have not been recorded could not be replayed
+            .filter(|joint| {
+                let (actor0, actor1) = joint.get_actors();
+                [actor0, actor1]
+                    .into_iter()
+                    .flatten()
+                    .all(|actor| self.actors.contains_key(&actor))
+            })
+            .map(|joint| {
+                let data = serialize_joint(self.registry, joint, actor_collection)?;
+                Ok((joint.0 as usize, data))
+            })
+            .collect::<anyhow::Result<Vec<_>>>();
+        actor_collection.release();
+        let joints = joints?;
+
+        let mut removed = Vec::new();
+        self.joints.retain(|recorded| {
+            let keep = joints
+                .iter()
+                .any(|(joint, data)| *joint == recorded.joint && *data == recorded.data);
+            if !keep {
+                removed.push(recorded.id);
+            }
+            keep
+        });
+        inputs.extend(
+            removed
+                .into_iter()
+                .map(|joint| PhysicsInput::RemoveJoint { joint }),
+        );
+
+        let mut unchanged = std::mem::take(&mut self.joints)
+            .into_iter()
+            .map(|recorded| (recorded.joint, recorded))
+            .collect::<HashMap<_, _>>();
+        for (joint, data) in joints {
+            if let Some(recorded) = unchanged.remove(&joint) {
+                self.joints.push(recorded);
+                continue;
+            }
+            let id = self.next_joint_id;
+            self.next_joint_id += 1;
+            inputs.push(PhysicsInput::AddJoint {
+                joint: id,
+                data: data.clone(),
+            });
+            self.joints.push(RecordedJoint { joint, id, data });
+        }
+        Ok(())
+    }
+
+    /// Captures the simulated state of the actors, and writes the step to the recording
+    fn end_step(&mut self, scene: PxSceneRef) -> anyhow::Result<()> {
+        for actor in
+            scene.get_actors(PxActorTypeFlag::RIGID_DYNAMIC | PxActorTypeFlag::RIGID_STATIC)
+        {
+            let Some(actor) = actor.to_rigid_actor() else {
+                continue;
+            };
+            if let Some(recorded) = self.actors.get_mut(&actor) {
+                recorded.state = RecordedActorState::get(actor);
+            }
+        }
+        let Some((dt, inputs)) = self.step.take() else {
+            return Ok(());
+        };
+        let step = RecordedStep {
+            dt,
+            inputs,
+            checksum: checksum(
+                self.actors
+                    .values()
+                    .map(|recorded| (recorded.id, &recorded.state)),
+            ),
+        };
+        bincode::serialize_into(&mut self.writer, &step)?;
+        // Flushed every step, so that the recording is usable even if the server crashes
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+fn serialize_actor(
+    registry: PxSerializationRegistryRef,
+    actor: PxRigidActorRef,
+) -> anyhow::Result<Vec<u8>> {
+    let mut collection = PxCollectionRef::new();
+    collection.add(actor.as_base());
+    registry.complete(collection);
+    let stream = PxDefaultMemoryOutputStream::new();
+    let serialized = registry.serialize_collection_to_binary(&stream, collection, None, None);
+    collection.release();
+    anyhow::ensure!(serialized, "Failed to serialize physics actor");
+    Ok(stream.get_data())
+}
+
+fn serialize_joint(
+    registry: PxSerializationRegistryRef,
+    joint: PxJointRef,
+    actors: PxCollectionRef,
+) -> anyhow::Result<Vec<u8>> {
+    let mut collection = PxCollectionRef::new();
+    collection.add(joint.as_base());
+    // The actors are referred to by their serial ids instead of being serialized with the joint
+    registry.complete_full(collection, Some(actors), false);
+    let stream = PxDefaultMemoryOutputStream::new();
+    let serialized =
+        registry.serialize_collection_to_binary(&stream, collection, Some(actors), None);
+    collection.release();
+    anyhow::ensure!(serialized, "Failed to serialize physics joint");
+    Ok(stream.get_data())
+}
+
+/// Starts recording the inputs applied to the main physics scene of `world` to `path`
+pub fn start_recording(world: &mut World, path: &Path) -> anyhow::Result<()> {
+    let registry = world
+        .resource(crate::physx::physics())
+        .serialization_registry;
+    let recorder = PhysicsRecorder::new(path, registry)?;
+    world.add_resource(physics_recorder(), Arc::new(Mutex::new(recorder)));
+    Ok(())
+}
+
+/// Records a force applied to `actor`, if the world is being recorded
+pub fn record_force(
+    world: &World,
+    actor: PxRigidDynamicRef,
+    force: Vec3,
+    position: Option<Vec3>,
+    mode: Option<PxForceMode>,
+) {
+    if let Some(recorder) = world.resource_opt(physics_recorder()) {
+        recorder.lock().add_force(actor, force, position, mode);
+    }
+}
+
+/// Called right before the main physics scene is simulated
+pub(crate) fn begin_step(world: &World, dt: f32) {
+    if let Some(recorder) = world.resource_opt(physics_recorder()) {
+        let scene = *world.resource(main_physics_scene());
+        if let Err(err) = recorder.lock().begin_step(scene, dt) {
+            tracing::error!("Failed to record physics step: {err:?}");
+        }
+    }
+}
+
+/// Called right after the results of the main physics scene have been fetched
+pub(crate) fn end_step(world: &World) {
+    if let Some(recorder) = world.resource_opt(physics_recorder()) {
+        let scene = *world.resource(main_physics_scene());
+        if let Err(err) = recorder.lock().end_step(scene) {
+            tracing::error!("Failed to record physics step: {err:?}");
+        }
+    }
+}
+
+/// The result of [`replay`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PhysicsReplayReport {
+    pub steps: usize,
+    /// The first step whose simulated state differed from the recording, if any
+    pub first_divergent_step: Option<usize>,
+    /// The state of the actors still in the scene after the last step, by recorded actor id
+    pub actors: Vec<(u32, RecordedActorState)>,
+}
+
+/// Reads a recording made with [`start_recording`]
+pub fn read_recording(path: &Path) -> anyhow::Result<Vec<RecordedStep>> {
+    let mut reader =
+        BufReader::new(File::open(path).with_context(|| format!("Failed to open {path:?}"))?);
+    let header: PhysicsRecordingHeader = bincode::deserialize_from(&mut reader)?;
+    anyhow::ensure!(
+        header.version == PHYSICS_RECORDING_VERSION,
+        "Unsupported physics recording version {} (expected {PHYSICS_RECORDING_VERSION})",
+        header.version
+    );
+
+    let mut steps = Vec::new();
+    loop {
+        match bincode::deserialize_from::<_, RecordedStep>(&mut reader) {
+            Ok(step) => steps.push(step),
+            // The last step may be incomplete if the server didn't shut down cleanly
+            Err(err) if is_unexpected_eof(&err) => break,
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(steps)
+}
+
+fn is_unexpected_eof(err: &bincode::Error) -> bool {
+    matches!(&**err, bincode::ErrorKind::Io(err) if err.kind() == std::io::ErrorKind::UnexpectedEof)
+}
+
+/// Simulates the recorded steps in a new scene, without an ECS world
+pub fn replay(physics: &Physics, steps: &[RecordedStep]) -> anyhow::Result<PhysicsReplayReport> {
+    let scene = PxSceneRef::new(&physics.physics, &main_scene_desc(physics));
+    let mut replayed = ReplayedObjects::default();
+
+    let result = (|| {
+        let mut first_divergent_step = None;
+        for (index, step) in steps.iter().enumerate() {
+            for input in &step.inputs {
+                apply_input(physics, scene, &mut replayed, input)
+                    .with_context(|| format!("Failed to apply input of step {index}"))?;
+            }
+            scene.simulate(step.dt);
+            scene.fetch_results(true);
+
+            let states = replayed
+                .actors
+                .iter()
+                .map(|(id, actor)| (*id, RecordedActorState::get(*actor)))
+                .collect::<Vec<_>>();
+            if first_divergent_step.is_none()
+                && checksum(states.iter().map(|(id, state)| (*id, state))) != step.checksum
+            {
+                first_divergent_step = Some(index);
+            }
+        }
+
+        let mut states = replayed
+            .actors
+            .iter()
+            .map(|(id, actor)| (*id, RecordedActorState::get(*actor)))
+            .collect::<Vec<_>>();
+        states.sort_by_key(|(id, _)| *id);
+        Ok(PhysicsReplayReport {
+            steps: steps.len(),
+            first_divergent_step,
+            actors: states,
+        })
+    })();
+
+    release_px_scene(scene);
+    drop(replayed);
+    result
+}
+
+#[derive(Default)]
+struct ReplayedObjects {
+    actors: HashMap<u32, PxRigidActorRef>,
+    joints: HashMap<u32, PxJointRef>,
+    /// Deserialized objects live in these buffers, so they have to outlive the scene
+    buffers: Vec<PxBinaryCollectionData>,
+}
+
+fn apply_input(
+    physics: &Physics,
+    scene: PxSceneRef,
+    replayed: &mut ReplayedObjects,
+    input: &PhysicsInput,
+) -> anyhow::Result<()> {
+    let ReplayedObjects {
+        actors,
+        joints,
+        buffers,
+    } = replayed;
+    let get = |actor: &u32| {
+        actors
+            .get(actor)
+            .copied()
+            .with_context(|| format!("Unknown actor {actor}"))
+    };
+    match input {
+        PhysicsInput::SetGravity { gravity } => scene.set_gravity(*gravity),
+        PhysicsInput::AddActor { actor, data } => {
+            let data = PxBinaryCollectionData::new(data);
+            let mut collection = physics
+                .serialization_registry
+                .create_collection_from_binary(&data, None)
+                .context("Failed to deserialize physics actor")?;
+            let objects = collection.get_objects();
+            let rigid_actor = objects.iter().find_map(|(_, object)| match object {
+                PxAny::PxRigidDynamic(object) => Some(object.as_rigid_actor()),
+                PxAny::PxRigidStatic(object) => Some(object.as_rigid_actor()),
+                _ => None,
+            });
+            // User data points into the recorded process, and is meaningless here
+            for (_, object) in &objects {
+                if let PxAny::PxShape(shape) = object {
+                    *shape.raw_user_data_mut() = null_mut();
+                }
+            }
+            let Some(rigid_actor) = rigid_actor else {
+                collection.release();
+                anyhow::bail!("Recorded collection for actor {actor} has no actor");
+            };
+            *rigid_actor.as_actor().raw_user_data_mut() = null_mut();
+            scene.add_collection(&collection);
+            collection.release();
+            buffers.push(data);
+            actors.insert(*actor, rigid_actor);
+        }
+        PhysicsInput::RemoveActor { actor } => {
+            get(actor)?.release();
+            actors.remove(actor);
+        }
+        PhysicsInput::AddJoint { joint, data } => {
+            let mut actor_collection = PxCollectionRef::new();
+            for (id, actor) in actors.iter() {
+                actor_collection.add_with_serial_id(actor.as_base(), actor_serial_id(*id));
+            }
+            let data = PxBinaryCollectionData::new(data);
+            let collection = physics
+                .serialization_registry
+                .create_collection_from_binary(&data, Some(actor_collection));
+            actor_collection.release();
+            let mut collection = collection.context("Failed to deserialize physics joint")?;
+            let px_joint = collection
+                .get_objects()
+                .iter()
+                .find_map(|(_, object)| match object {
+                    PxAny::PxFixedJoint(object) => Some(object.as_joint()),
+                    PxAny::PxRevoluteJoint(object) => Some(object.as_joint()),
+                    PxAny::PxSphericalJoint(object) => Some(object.as_joint()),
+                    PxAny::PxPrismaticJoint(object) => Some(object.as_joint()),
+                    PxAny::PxDistanceJoint(object) => Some(object.as_joint()),
+                    PxAny::PxD6Joint(object) => Some(object.as_joint()),
+                    _ => None,
+                });
+            let Some(px_joint) = px_joint else {
+                collection.release();
+                anyhow::bail!("Recorded collection for joint {joint} has no joint");
+            };
+            // User data points into the recorded process, and is meaningless here
+            *px_joint.raw_user_data_mut() = null_mut();
+
+            collection.release();
+
+            // The constraints of deserialized joints are only added to a scene together with their
+            // actors, which are already in it. Setting the actors again adds the constraint to the
+            // scene of the actors.
+            let (actor0, actor1) = px_joint.get_actors();
+            px_joint.set_actors(actor0, actor1);
+            buffers.push(data);
+            joints.insert(*joint, px_joint);
+        }
+        PhysicsInput::RemoveJoint { joint } => {
+            joints
+                .remove(joint)
+                .with_context(|| format!("Unknown joint {joint}"))?
+                .release();
+        }
+        PhysicsInput::SetGlobalPose {
+            actor,
+            translation,
+            rotation,
+        } => get(actor)?.set_global_pose(&PxTransform::new(*translation, *rotation), true),
+        PhysicsInput::SetVelocity {
+            actor,
+            linear,
+            angular,
+        } => {
+            let body = get(actor)?
+                .to_rigid_dynamic()
+                .context("Actor is not dynamic")?;
+            body.set_linear_velocity(*linear, true);
+            body.set_angular_velocity(*angular, true);
+        }
+        PhysicsInput::SetKinematicTarget {
+            actor,
+            translation,
+            rotation,
+        } => get(actor)?
+            .to_rigid_dynamic()
+            .context("Actor is not dynamic")?
+            .set_kinematic_target(&PxTransform::new(*translation, *rotation)),
+        PhysicsInput::AddForce {
+            actor,
+            force,
+            position,
+            mode,
+        } => {
+            let body = get(actor)?
+                .to_rigid_dynamic()
+                .context("Actor is not dynamic")?;
+            match position {
+                Some(position) => {
+                    body.add_force_at_pos(*force, *position, Some((*mode).into()), Some(true))
+                }
+                None => body.add_force(*force, Some((*mode).into()), Some(true)),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use ambient_core::transform::translation;
+use ambient_ecs::{Component, ComponentValue, Entity, EntityId, System};
+use ambient_network::server::ForkingEvent;
+use ambient_physics::{
+    collider::{
+        capsule_collider, convex_collider, cylinder_collider, heightfield_collider,
+        heightfield_collider_columns,
+    },
+    physx::{physics_shape, rigid_actor},
+};
+use glam::{vec2, Vec3};
+use physxx::PxGeometryType;
+
+mod common;
+use common::PhysicsWorld;
+
+fn spawn<T: ComponentValue>(
+    physics: &mut PhysicsWorld,
+    component: Component<T>,
+    value: T,
+) -> EntityId {
+    Entity::new()
+        .with(translation(), Vec3::ZERO)
+        .with(component, value)
+        .spawn(&mut physics.world)
+}
+
+fn geometry_type(physics: &PhysicsWorld, id: EntityId) -> PxGeometryType {
+    physics
+        .world
+        .get_ref(id, physics_shape())
+        .unwrap()
+        .get_geometry_type()
+}
+
+#[test]
+fn colliders_get_the_right_geometry() {
+    let mut physics = PhysicsWorld::new();
+    let capsule = spawn(&mut physics, capsule_collider(), vec2(0.5, 2.));
+    let cylinder = spawn(&mut physics, cylinder_collider(), vec2(0.5, 2.));
+    let heightfield = spawn(&mut physics, heightfield_collider(), vec![0., 1., 2., 3.]);
+    physics
+        .world
+        .add_component(heightfield, heightfield_collider_columns(), 2)
+        .unwrap();
+    physics.wait_for_actors(&[capsule, cylinder, heightfield]);
+
+    assert_eq!(geometry_type(&physics, capsule), PxGeometryType::Capsule);
+    assert_eq!(
+        geometry_type(&physics, cylinder),
+        PxGeometryType::ConvexMesh
+    );
+    assert_eq!(
+        geometry_type(&physics, heightfield),
+        PxGeometryType::HEIGHTFIELD
+    );
+}
+
+#[test]
+fn convex_colliders_are_rebuilt_from_their_points_after_forking() {
+    let mut physics = PhysicsWorld::new();
+    let points = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z];
+    let id = spawn(&mut physics, convex_collider(), points);
+    physics.wait_for_actors(&[id]);
+    assert_eq!(geometry_type(&physics, id), PxGeometryType::ConvexMesh);
+
+    // Forking recreates the physics scenes, so the collider has to be built again from what is
+    // stored on the entity
+    ambient_physics::on_forking_systems().run(&mut physics.world, &ForkingEvent);
+    assert!(!physics.world.has_component(id, rigid_actor()));
+    physics.wait_for_actors(&[id]);
+    assert_eq!(geometry_type(&physics, id), PxGeometryType::ConvexMesh);
+}
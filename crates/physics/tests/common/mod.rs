@@ -0,0 +1,96 @@
+//! A server world with physics, which is stepped the way the server steps it
+
+use std::{
+    sync::{Mutex, MutexGuard, OnceLock, PoisonError},
+    time::{Duration, Instant},
+};
+
+use ambient_core::{
+    asset_cache,
+    async_ecs::{async_ecs_resources, async_ecs_systems},
+    runtime, FIXED_SERVER_TICK_TIME,
+};
+use ambient_ecs::{Entity, EntityId, FrameEvent, System, SystemGroup, World, WorldContext};
+use ambient_native_std::asset_cache::{AssetCache, SyncAssetKeyExt};
+use ambient_physics::physx::{rigid_actor, Physics, PhysicsKey};
+use ambient_sys::task::RuntimeHandle;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// PhysX only allows one foundation per process, so all of the tests share it, and run one at a time
+static PHYSICS: OnceLock<Physics> = OnceLock::new();
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub struct PhysicsWorld {
+    pub world: World,
+    systems: SystemGroup,
+    runtime: tokio::runtime::Runtime,
+    _lock: MutexGuard<'static, ()>,
+}
+impl PhysicsWorld {
+    pub fn new() -> Self {
+        let lock = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        ambient_ecs::init_components();
+        ambient_core::init_all_components();
+        ambient_physics::init_all_components();
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let _guard = runtime.enter();
+
+        let assets = AssetCache::new(runtime.handle().clone());
+        PhysicsKey.insert(&assets, PHYSICS.get_or_init(Physics::new).clone());
+
+        let mut resources = Entity::new()
+            .with(asset_cache(), assets.clone())
+            .with(runtime(), RuntimeHandle::current());
+        ambient_physics::create_server_resources(&assets, &mut resources);
+        resources.merge(async_ecs_resources());
+        resources.merge(ambient_core::time_resources_start(FIXED_SERVER_TICK_TIME));
+
+        let mut world = World::new("physics_test", WorldContext::Server);
+        world
+            .add_components(world.resource_entity(), resources)
+            .unwrap();
+
+        let systems = SystemGroup::new(
+            "physics_test",
+            vec![
+                ambient_physics::run_simulation_system(),
+                Box::new(async_ecs_systems()),
+                ambient_physics::fetch_simulation_system(),
+                Box::new(ambient_physics::physx::sync_ecs_physics()),
+                Box::new(ambient_physics::server_systems()),
+            ],
+        );
+
+        Self {
+            world,
+            systems,
+            runtime,
+            _lock: lock,
+        }
+    }
+
+    pub fn step(&mut self) {
+        let _guard = self.runtime.enter();
+        self.systems.run(&mut self.world, &FrameEvent);
+    }
+
+    /// Steps until `ids` have actors, as colliders are created asynchronously
+    pub fn wait_for_actors(&mut self, ids: &[EntityId]) {
+        self.step_until(|world| ids.iter().all(|&id| world.has_component(id, rigid_actor())));
+    }
+
+    /// Steps until `done` returns true, and panics if that takes too long
+    pub fn step_until(&mut self, mut done: impl FnMut(&World) -> bool) {
+        let start = Instant::now();
+        while !done(&self.world) {
+            assert!(start.elapsed() < TIMEOUT, "Timed out stepping the world");
+            self.step();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
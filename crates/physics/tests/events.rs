@@ -0,0 +1,123 @@
+use ambient_core::transform::translation;
+use ambient_ecs::{Entity, EntityId, World};
+use ambient_physics::{
+    collider::{collider_type, contact_events, cube_collider, dynamic, kinematic, ColliderType},
+    collisions,
+    events::{contact_reports, trigger_events, ContactPhase, ContactReport, TriggerEvent},
+};
+use glam::{vec3, Vec3};
+
+mod common;
+use common::PhysicsWorld;
+
+fn spawn_cube(world: &mut World, position: Vec3, size: Vec3, is_dynamic: bool) -> EntityId {
+    Entity::new()
+        .with(translation(), position)
+        .with(cube_collider(), size)
+        .with(dynamic(), is_dynamic)
+        .spawn(world)
+}
+
+fn spawn_trigger_area(world: &mut World) -> EntityId {
+    Entity::new()
+        .with(translation(), Vec3::ZERO)
+        .with(cube_collider(), Vec3::splat(2.))
+        .with(collider_type(), ColliderType::TriggerArea)
+        .spawn(world)
+}
+
+#[test]
+fn trigger_areas_are_entered_and_exited() {
+    let mut physics = PhysicsWorld::new();
+    let trigger = spawn_trigger_area(&mut physics.world);
+    // There is no ground, so this falls through the trigger area
+    let id = spawn_cube(&mut physics.world, Vec3::ZERO, Vec3::splat(0.5), true);
+    physics.wait_for_actors(&[trigger, id]);
+
+    let mut events = Vec::new();
+    physics.step_until(|world| {
+        events.extend(world.resource(trigger_events()).iter().copied());
+        events.contains(&TriggerEvent::Exit { trigger, id })
+    });
+    assert_eq!(
+        events,
+        [
+            TriggerEvent::Enter { trigger, id },
+            TriggerEvent::Exit { trigger, id }
+        ]
+    );
+}
+
+#[test]
+fn despawned_trigger_areas_are_exited() {
+    let mut physics = PhysicsWorld::new();
+    let trigger = spawn_trigger_area(&mut physics.world);
+    let id = spawn_cube(&mut physics.world, Vec3::ZERO, Vec3::splat(0.5), true);
+    physics.world.add_component(id, kinematic(), ()).unwrap();
+    physics.wait_for_actors(&[trigger, id]);
+
+    physics.step_until(|world| {
+        world
+            .resource(trigger_events())
+            .contains(&TriggerEvent::Enter { trigger, id })
+    });
+    physics.world.despawn(trigger);
+    physics.step();
+    assert_eq!(
+        physics.world.resource(trigger_events()),
+        &[TriggerEvent::Exit { trigger, id }]
+    );
+}
+
+#[test]
+fn contact_events_are_only_reported_for_opted_in_entities() {
+    let mut physics = PhysicsWorld::new();
+    let ground = spawn_cube(
+        &mut physics.world,
+        vec3(0., 0., -0.5),
+        vec3(20., 20., 1.),
+        false,
+    );
+    let reported = spawn_cube(&mut physics.world, vec3(0., 0., 1.), Vec3::splat(0.5), true);
+    physics
+        .world
+        .add_component(reported, contact_events(), ())
+        .unwrap();
+    let unreported = spawn_cube(&mut physics.world, vec3(5., 0., 1.), Vec3::splat(0.5), true);
+    physics.wait_for_actors(&[ground, reported, unreported]);
+
+    let mut reports = Vec::<ContactReport>::new();
+    let mut found = Vec::new();
+    physics.step_until(|world| {
+        reports.extend(world.resource(contact_reports()).lock().iter().cloned());
+        found.extend(world.resource(collisions()).lock().iter().cloned());
+        reports.iter().any(|r| r.phase == ContactPhase::Persist)
+    });
+
+    assert!(reports
+        .iter()
+        .all(|r| r.ids.contains(&reported) && r.ids.contains(&ground)));
+    let begin = &reports[0];
+    assert_eq!(begin.phase, ContactPhase::Begin);
+    assert!(!begin.points.is_empty());
+    assert_eq!(begin.points.len(), begin.normals.len());
+
+    // Collisions are still sent for everything when touches are found
+    physics.step_until(|world| {
+        found.extend(world.resource(collisions()).lock().iter().cloned());
+        found.iter().any(|c| c.0 == unreported || c.1 == unreported)
+    });
+    assert!(found.iter().all(|c| !c.2.is_empty()));
+
+    // Moving the body away ends the contact
+    physics
+        .world
+        .set(reported, translation(), vec3(0., 0., 10.))
+        .unwrap();
+    let mut reports = Vec::<ContactReport>::new();
+    physics.step_until(|world| {
+        reports.extend(world.resource(contact_reports()).lock().iter().cloned());
+        reports.iter().any(|r| r.phase == ContactPhase::End)
+    });
+    assert!(reports.iter().all(|r| !r.ids.contains(&unreported)));
+}
@@ -0,0 +1,95 @@
+use ambient_core::transform::translation;
+use ambient_ecs::{Entity, EntityId, World};
+use ambient_physics::{
+    collider::{cube_collider, dynamic, kinematic, ColliderType},
+    intersection::{overlap, sweep, QueryShape},
+};
+use glam::{vec3, Quat, Vec3};
+
+mod common;
+use common::PhysicsWorld;
+
+fn spawn_cube(world: &mut World, position: Vec3, is_dynamic: bool) -> EntityId {
+    let id = Entity::new()
+        .with(translation(), position)
+        .with(cube_collider(), Vec3::ONE)
+        .with(dynamic(), is_dynamic)
+        .spawn(world);
+    // Keeps the dynamic cubes where they are, as there is no ground
+    if is_dynamic {
+        world.add_component(id, kinematic(), ()).unwrap();
+    }
+    id
+}
+
+/// Three cubes in a row along the X axis, with a dynamic one between two static ones
+fn spawn_row(physics: &mut PhysicsWorld) -> [EntityId; 3] {
+    let row = [
+        spawn_cube(&mut physics.world, vec3(5., 0., 0.), false),
+        spawn_cube(&mut physics.world, vec3(10., 0., 0.), true),
+        spawn_cube(&mut physics.world, vec3(15., 0., 0.), false),
+    ];
+    physics.wait_for_actors(&row);
+    physics.step();
+    row
+}
+
+fn sweep_row(world: &World, filter: Option<ColliderType>) -> Vec<EntityId> {
+    let hits = sweep(
+        world,
+        QueryShape::Sphere { radius: 0.25 },
+        Vec3::ZERO,
+        Quat::IDENTITY,
+        Vec3::X,
+        100.,
+        filter,
+    );
+    assert!(hits.windows(2).all(|w| w[0].distance <= w[1].distance));
+    hits.into_iter().map(|hit| hit.entity).collect()
+}
+
+#[test]
+fn sweeps_return_every_hit_in_order() {
+    let mut physics = PhysicsWorld::new();
+    let [near, middle, far] = spawn_row(&mut physics);
+
+    assert_eq!(sweep_row(&physics.world, None), [near, middle, far]);
+}
+
+#[test]
+fn sweeps_are_filtered_by_collider_type() {
+    let mut physics = PhysicsWorld::new();
+    let [near, middle, far] = spawn_row(&mut physics);
+
+    assert_eq!(
+        sweep_row(&physics.world, Some(ColliderType::Static)),
+        [near, far]
+    );
+    assert_eq!(
+        sweep_row(&physics.world, Some(ColliderType::Dynamic)),
+        [middle]
+    );
+}
+
+#[test]
+fn overlaps_are_filtered_by_collider_type() {
+    let mut physics = PhysicsWorld::new();
+    let [near, middle, far] = spawn_row(&mut physics);
+
+    let overlapping = |filter| {
+        overlap(
+            &physics.world,
+            QueryShape::Sphere { radius: 20. },
+            Vec3::ZERO,
+            Quat::IDENTITY,
+            filter,
+        )
+    };
+    let mut all = vec![near, middle, far];
+    all.sort();
+    assert_eq!(overlapping(None), all);
+    let mut statics = vec![near, far];
+    statics.sort();
+    assert_eq!(overlapping(Some(ColliderType::Static)), statics);
+    assert_eq!(overlapping(Some(ColliderType::Dynamic)), [middle]);
+}
@@ -0,0 +1,109 @@
+use ambient_core::transform::{rotation, translation};
+use ambient_ecs::{Entity, EntityId, World};
+use ambient_physics::{
+    collider::{cube_collider, dynamic},
+    joint_actor_0, joint_actor_1, joint_drive_velocity, joint_limit, joint_local_frame_1,
+    joint_type,
+    joints::{px_joint, JointType},
+};
+use glam::{vec2, vec3, Mat4, Vec3};
+
+mod common;
+use common::PhysicsWorld;
+
+fn spawn_cube(world: &mut World, position: Vec3, is_dynamic: bool) -> EntityId {
+    Entity::new()
+        .with(translation(), position)
+        .with(cube_collider(), Vec3::splat(0.2))
+        .with(dynamic(), is_dynamic)
+        .spawn(world)
+}
+
+/// Joins a dynamic cube at `offset` from a static one, with the joint at the static cube
+fn spawn_joint(physics: &mut PhysicsWorld, ty: JointType, offset: Vec3) -> (EntityId, EntityId) {
+    let world = &mut physics.world;
+    let anchor = spawn_cube(world, vec3(0., 0., 5.), false);
+    let body = spawn_cube(world, vec3(0., 0., 5.) + offset, true);
+    let joint = Entity::new()
+        .with(joint_type(), ty)
+        .with(joint_actor_0(), anchor)
+        .with(joint_actor_1(), body)
+        .with(joint_local_frame_1(), Mat4::from_translation(-offset))
+        .spawn(world);
+    physics.wait_for_actors(&[anchor, body]);
+    physics.step_until(|world| world.has_component(joint, px_joint()));
+    (joint, body)
+}
+
+fn step(physics: &mut PhysicsWorld, frames: usize) {
+    for _ in 0..frames {
+        physics.step();
+    }
+}
+
+#[test]
+fn prismatic_joints_are_driven_within_their_limit() {
+    let mut physics = PhysicsWorld::new();
+    let (joint, body) = spawn_joint(&mut physics, JointType::Prismatic, Vec3::X);
+    physics
+        .world
+        .add_component(joint, joint_drive_velocity(), 1.)
+        .unwrap();
+    step(&mut physics, 30);
+
+    // The body slides along the joint's X axis, and does not fall
+    let position = physics.world.get(body, translation()).unwrap();
+    assert!(position.x > 1.3, "{position}");
+    assert!(position.y.abs() < 0.01 && (position.z - 5.).abs() < 0.01);
+
+    physics
+        .world
+        .add_component(joint, joint_limit(), vec2(-2., 2.))
+        .unwrap();
+    // The body started 1m from the anchor, so the limit stops it 2m further along
+    step(&mut physics, 120);
+    let position = physics.world.get(body, translation()).unwrap();
+    assert!((position.x - 3.).abs() < 0.05, "{position}");
+}
+
+#[test]
+fn spherical_joints_are_driven_around_their_x_axis() {
+    let mut physics = PhysicsWorld::new();
+    // The body's center is at the joint, so gravity does not swing it
+    let (joint, body) = spawn_joint(&mut physics, JointType::Spherical, Vec3::ZERO);
+    physics
+        .world
+        .add_component(joint, joint_drive_velocity(), 1.)
+        .unwrap();
+    step(&mut physics, 60);
+
+    let (axis, angle) = physics.world.get(body, rotation()).unwrap().to_axis_angle();
+    assert!(angle > 0.5, "{angle}");
+    assert!(axis.x.abs() > 0.99, "{axis}");
+    let position = physics.world.get(body, translation()).unwrap();
+    assert!(position.distance(vec3(0., 0., 5.)) < 0.01, "{position}");
+}
+
+#[test]
+fn joints_are_created_once_their_actors_have_colliders() {
+    let mut physics = PhysicsWorld::new();
+    let anchor = spawn_cube(&mut physics.world, vec3(0., 0., 5.), false);
+    let body = Entity::new()
+        .with(translation(), vec3(1., 0., 5.))
+        .spawn(&mut physics.world);
+    let joint = Entity::new()
+        .with(joint_type(), JointType::Fixed)
+        .with(joint_actor_0(), anchor)
+        .with(joint_actor_1(), body)
+        .spawn(&mut physics.world);
+    physics.wait_for_actors(&[anchor]);
+    step(&mut physics, 10);
+    assert!(!physics.world.has_component(joint, px_joint()));
+
+    let world = &mut physics.world;
+    world
+        .add_component(body, cube_collider(), Vec3::splat(0.2))
+        .unwrap();
+    world.add_component(body, dynamic(), true).unwrap();
+    physics.step_until(|world| world.has_component(joint, px_joint()));
+}
@@ -0,0 +1,75 @@
+use ambient_core::transform::translation;
+use ambient_ecs::{Entity, EntityId, World};
+use ambient_physics::{
+    collider::{cube_collider, dynamic},
+    joint_actor_0, joint_actor_1, joint_local_frame_1, joint_type,
+    joints::{px_joint, JointType},
+    physx::{physics, rigid_actor},
+    recording::{self, physics_recorder, PhysicsInput},
+};
+use glam::{vec3, Mat4, Vec3};
+use physxx::{AsPxActor, PxActor};
+
+mod common;
+use common::PhysicsWorld;
+
+fn spawn_cube(world: &mut World, position: Vec3, size: Vec3, is_dynamic: bool) -> EntityId {
+    Entity::new()
+        .with(translation(), position)
+        .with(cube_collider(), size)
+        .with(dynamic(), is_dynamic)
+        .spawn(world)
+}
+
+#[test]
+fn recordings_replay_the_same_simulation() {
+    let mut physics = PhysicsWorld::new();
+    let path = std::env::temp_dir().join(format!(
+        "ambient_physics_recording_{}.bin",
+        std::process::id()
+    ));
+    recording::start_recording(&mut physics.world, &path).unwrap();
+
+    let world = &mut physics.world;
+    let ground = spawn_cube(world, vec3(0., 0., -0.5), vec3(20., 20., 1.), false);
+    let falling = spawn_cube(world, vec3(3., 0., 2.), Vec3::splat(0.5), true);
+    // A pendulum, which only swings if its joint is replayed
+    let anchor = spawn_cube(world, vec3(0., 0., 5.), Vec3::splat(0.2), false);
+    let pendulum = spawn_cube(world, vec3(1., 0., 5.), Vec3::splat(0.2), true);
+    let joint = Entity::new()
+        .with(joint_type(), JointType::Spherical)
+        .with(joint_actor_0(), anchor)
+        .with(joint_actor_1(), pendulum)
+        .with(
+            joint_local_frame_1(),
+            Mat4::from_translation(vec3(-1., 0., 0.)),
+        )
+        .spawn(world);
+    physics.wait_for_actors(&[ground, falling, anchor, pendulum]);
+    physics.step_until(|world| world.has_component(joint, px_joint()));
+    for _ in 0..60 {
+        physics.step();
+    }
+    let world = &mut physics.world;
+    world
+        .remove_component(world.resource_entity(), physics_recorder())
+        .unwrap();
+
+    // Recording does not rename the actors
+    let actor = world.get(pendulum, rigid_actor()).unwrap();
+    assert_eq!(actor.as_actor().get_name(), None);
+    let position = world.get(pendulum, translation()).unwrap();
+    assert!(position.distance(vec3(0., 0., 5.)) < 1.1);
+
+    let steps = recording::read_recording(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert!(steps
+        .iter()
+        .flat_map(|step| &step.inputs)
+        .any(|input| matches!(input, PhysicsInput::AddJoint { .. })));
+
+    let report = recording::replay(world.resource(physics()), &steps).unwrap();
+    assert_eq!(report.steps, steps.len());
+    assert_eq!(report.first_divergent_step, None);
+    assert_eq!(report.actors.len(), 4);
+}
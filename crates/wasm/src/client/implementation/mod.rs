@@ -2,7 +2,7 @@
 //!
 //! If implementing a trait that is only available on the client, it should go in [specific].
 
-use crate::shared::{self, wit};
+use crate::shared::{self, conversion::FromBindgen, wit};
 
 use super::Bindings;
 
@@ -19,7 +19,7 @@ impl wit::entity::Host for Bindings {
     ) -> wasm_bridge::Result<wit::types::EntityId> {
         shared::implementation::entity::spawn(
             unsafe { self.world_ref.world_mut() },
-            &mut self.base.spawned_entities,
+            &mut self.base,
             data,
         )
     }
@@ -188,6 +188,53 @@ impl wit::component::Host for Bindings {
             query_index,
         )
     }
+
+    fn index_create(
+        &mut self,
+        kind: wit::component::IndexKind,
+        components: Vec<u32>,
+        includes: Vec<u32>,
+        excludes: Vec<u32>,
+    ) -> wasm_bridge::Result<u64> {
+        let id = self.id;
+        shared::implementation::index::create(
+            self.world_mut(),
+            id,
+            kind,
+            components,
+            includes,
+            excludes,
+        )
+    }
+
+    fn index_get(
+        &mut self,
+        index: u64,
+        values: Vec<u8>,
+    ) -> wasm_bridge::Result<Vec<wit::types::EntityId>> {
+        let id = self.id;
+        shared::implementation::index::get(self.world_mut(), id, index, values)
+    }
+
+    fn index_range(
+        &mut self,
+        index: u64,
+        min: Vec<u8>,
+        max: Vec<u8>,
+    ) -> wasm_bridge::Result<Vec<wit::types::EntityId>> {
+        let id = self.id;
+        shared::implementation::index::range(self.world_mut(), id, index, min, max)
+    }
+
+    fn index_within_radius(
+        &mut self,
+        index: u64,
+        center: wit::types::Vec3,
+        radius: f32,
+    ) -> wasm_bridge::Result<Vec<wit::types::EntityId>> {
+        let id = self.id;
+        shared::implementation::index::within_radius(self.world_mut(), id, index, center, radius)
+    }
 }
 
 impl wit::message::Host for Bindings {
@@ -222,3 +269,26 @@ impl wit::ambient_package::Host for Bindings {
         shared::implementation::package::get_entity_for_package_id(self.world(), package_id)
     }
 }
+
+impl wit::timer::Host for Bindings {
+    fn schedule(
+        &mut self,
+        name: Option<String>,
+        after: wit::types::Duration,
+        repeat: Option<wit::types::Duration>,
+    ) -> anyhow::Result<u64> {
+        let id = self.id;
+        shared::implementation::timer::schedule(
+            self.world_mut(),
+            id,
+            name,
+            after.from_bindgen(),
+            repeat.map(|repeat| repeat.from_bindgen()),
+        )
+    }
+
+    fn cancel(&mut self, timer: u64) -> anyhow::Result<bool> {
+        let id = self.id;
+        shared::implementation::timer::cancel(self.world_mut(), id, timer)
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! If implementing a trait that is also available on the server, it should go in [super].
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use ambient_core::{
     async_ecs::async_run,
@@ -11,7 +11,7 @@ use ambient_core::{
     runtime,
     window::{window_ctl, WindowCtl},
 };
-use ambient_ecs::generated::input::messages::ClipboardGet;
+use ambient_ecs::generated::{input::messages::ClipboardGet, messages::SoundFinished};
 use ambient_gpu::texture::Texture;
 use ambient_input::{player_prev_raw_input, player_raw_input};
 use ambient_native_std::mesh::MeshBuilder;
@@ -21,6 +21,7 @@ use ambient_procedurals::{
     procedural_storage,
 };
 use ambient_renderer::pbr_material::{PbrMaterialConfig, PbrMaterialParams};
+use ambient_world_audio::{play_sound, playback_control, seek_sound, stop_sound, SoundOptions};
 
 use anyhow::Context;
 use glam::Vec4;
@@ -388,3 +389,83 @@ impl wit::client_material::Host for Bindings {
         Ok(())
     }
 }
+
+impl wit::client_audio::Host for Bindings {
+    fn play(
+        &mut self,
+        url: String,
+        options: wit::client_audio::PlayOptions,
+    ) -> anyhow::Result<Result<wit::types::EntityId, String>> {
+        let module_id = self.id;
+        let options = SoundOptions {
+            looping: options.looping,
+            amplitude: options.amplitude,
+            panning: options.panning,
+            start: Duration::from_secs_f32(options.start_seconds.max(0.0)),
+            fade_in: Duration::from_secs_f32(options.fade_in_seconds.max(0.0)),
+            spatial: options.spatial.map(|spatial| {
+                (
+                    spatial.emitter.from_bindgen(),
+                    spatial.listener.from_bindgen(),
+                )
+            }),
+        };
+
+        let result = play_sound(self.world_mut(), &url, options, move |world, sound| {
+            SoundFinished { sound }
+                .send(world, Some(module_id))
+                .unwrap();
+        });
+
+        Ok(result
+            .map(|id| id.into_bindgen())
+            .map_err(|err| err.to_string()))
+    }
+
+    fn stop(&mut self, sound: wit::types::EntityId) -> anyhow::Result<()> {
+        stop_sound(self.world_mut(), sound.from_bindgen());
+        Ok(())
+    }
+
+    fn get_position(&mut self, sound: wit::types::EntityId) -> anyhow::Result<Option<f32>> {
+        Ok(self
+            .world()
+            .get_ref(sound.from_bindgen(), playback_control())
+            .ok()
+            .map(|control| control.position().as_secs_f32()))
+    }
+
+    fn get_duration(&mut self, sound: wit::types::EntityId) -> anyhow::Result<Option<f32>> {
+        Ok(self
+            .world()
+            .get_ref(sound.from_bindgen(), playback_control())
+            .ok()
+            .and_then(|control| control.duration())
+            .map(|duration| duration.as_secs_f32()))
+    }
+
+    fn seek(&mut self, sound: wit::types::EntityId, position_seconds: f32) -> anyhow::Result<()> {
+        seek_sound(
+            self.world(),
+            sound.from_bindgen(),
+            Duration::from_secs_f32(position_seconds.max(0.0)),
+        )
+    }
+
+    fn fade(
+        &mut self,
+        sound: wit::types::EntityId,
+        amplitude: f32,
+        duration_seconds: f32,
+    ) -> anyhow::Result<()> {
+        let control = self
+            .world()
+            .get_ref(sound.from_bindgen(), playback_control())
+            .context("The sound has not started playing")?;
+        control.fade(
+            amplitude,
+            Duration::from_secs_f32(duration_seconds.max(0.0)),
+        );
+        Ok(())
+    }
+}
@@ -105,6 +105,28 @@ impl wit::server_physics::Host for Bindings {
         unsupported()
     }
 
+    fn sweep(
+        &mut self,
+        _shape: wit::server_physics::QueryShape,
+        _origin: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _direction: wit::types::Vec3,
+        _max_distance: f32,
+        _filter: Option<wit::server_physics::ColliderType>,
+    ) -> anyhow::Result<Vec<wit::server_physics::SweepHit>> {
+        unsupported()
+    }
+
+    fn overlap(
+        &mut self,
+        _shape: wit::server_physics::QueryShape,
+        _position: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _filter: Option<wit::server_physics::ColliderType>,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        unsupported()
+    }
+
     fn move_character(
         &mut self,
         _entity: wit::types::EntityId,
@@ -154,6 +176,20 @@ impl wit::server_http::Host for Bindings {
         unsupported()
     }
 }
+impl wit::server_storage::Host for Bindings {
+    fn get(&mut self, _: String, _: String) -> anyhow::Result<Option<Vec<u8>>> {
+        unsupported()
+    }
+    fn set(&mut self, _: String, _: String, _: Vec<u8>) -> anyhow::Result<Result<(), String>> {
+        unsupported()
+    }
+    fn delete(&mut self, _: String, _: String) -> anyhow::Result<bool> {
+        unsupported()
+    }
+    fn list(&mut self, _: String) -> anyhow::Result<Vec<String>> {
+        unsupported()
+    }
+}
 impl wit::server_ambient_package::Host for Bindings {
     fn load(&mut self, _: String) -> anyhow::Result<()> {
         unsupported()
@@ -11,20 +11,21 @@ use ambient_core::{
     runtime,
 };
 use ambient_ecs::{
-    generated::{messages::HttpResponse, types::HttpMethod},
+    generated::{messages::HttpResponse, package::components::id as package_id, types::HttpMethod},
     query, EntityId, World,
 };
 use ambient_native_std::asset_url::AbsAssetUrl;
 use ambient_network::server::player_transport;
 use reqwest::header::{HeaderMap, HeaderName};
 
-use super::super::Bindings;
+use super::super::{storage::PackageStorage, Bindings};
 
 use crate::shared::{
     self,
     conversion::FromBindgen,
     implementation::message,
     message::{MessageExt, Target},
+    package_ref,
 };
 
 mod physics;
@@ -196,6 +197,52 @@ impl Bindings {
     }
 }
 
+impl shared::wit::server_storage::Host for Bindings {
+    fn get(&mut self, namespace: String, key: String) -> anyhow::Result<Option<Vec<u8>>> {
+        self.with_package_storage(|storage| {
+            Ok(storage.get(&namespace, &key).map(|value| value.to_vec()))
+        })
+    }
+
+    fn set(
+        &mut self,
+        namespace: String,
+        key: String,
+        value: Vec<u8>,
+    ) -> anyhow::Result<Result<(), String>> {
+        // Running out of quota is reported to the module instead of stopping it
+        self.with_package_storage(|storage| {
+            Ok(storage
+                .set(namespace, key, value)
+                .map_err(|err| format!("{err:#}")))
+        })
+    }
+
+    fn delete(&mut self, namespace: String, key: String) -> anyhow::Result<bool> {
+        self.with_package_storage(|storage| storage.delete(&namespace, &key))
+    }
+
+    fn list(&mut self, namespace: String) -> anyhow::Result<Vec<String>> {
+        self.with_package_storage(|storage| Ok(storage.list(&namespace)))
+    }
+}
+
+impl Bindings {
+    /// Runs `func` with the storage of the package that this module belongs to
+    fn with_package_storage<R>(
+        &self,
+        func: impl FnOnce(&mut PackageStorage) -> anyhow::Result<R>,
+    ) -> anyhow::Result<R> {
+        let Some(storage) = &self.storage else {
+            anyhow::bail!("Storage is not supported on hosted servers");
+        };
+        let world = self.world();
+        let package = world.get(self.id, package_ref())?;
+        let package_id = world.get_ref(package, package_id())?;
+        func(storage.lock().package(package_id)?)
+    }
+}
+
 impl shared::wit::server_ambient_package::Host for Bindings {
     fn load(&mut self, url: String) -> anyhow::Result<()> {
         ambient_package_semantic_native::add(self.world_mut(), url, false)?;
@@ -5,7 +5,11 @@ use crate::shared::{
     wit,
 };
 use ambient_native_std::shapes::Ray;
-use ambient_physics::physx::character_controller;
+use ambient_physics::{
+    collider::ColliderType,
+    intersection::{QueryShape, SweepHit},
+    physx::character_controller,
+};
 use anyhow::Context;
 use physxx::{PxControllerCollisionFlag, PxControllerFilters};
 
@@ -197,6 +201,52 @@ impl shared::wit::server_physics::Host for Bindings {
         Ok(result)
     }
 
+    fn sweep(
+        &mut self,
+        shape: wit::server_physics::QueryShape,
+        origin: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        direction: wit::types::Vec3,
+        max_distance: f32,
+        filter: Option<wit::server_physics::ColliderType>,
+    ) -> anyhow::Result<Vec<wit::server_physics::SweepHit>> {
+        let shape = get_query_shape(shape)?;
+        let rotation = get_query_rotation(rotation)?;
+        let direction = get_raycast_direction(direction)?;
+        if max_distance <= 0.0 || !max_distance.is_finite() {
+            anyhow::bail!("Sweep distance must be positive and finite");
+        }
+        Ok(ambient_physics::intersection::sweep(
+            self.world(),
+            shape,
+            origin.from_bindgen(),
+            rotation,
+            direction,
+            max_distance,
+            filter.from_bindgen(),
+        )
+        .into_bindgen())
+    }
+
+    fn overlap(
+        &mut self,
+        shape: wit::server_physics::QueryShape,
+        position: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        filter: Option<wit::server_physics::ColliderType>,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        let shape = get_query_shape(shape)?;
+        let rotation = get_query_rotation(rotation)?;
+        Ok(ambient_physics::intersection::overlap(
+            self.world(),
+            shape,
+            position.from_bindgen(),
+            rotation,
+            filter.from_bindgen(),
+        )
+        .into_bindgen())
+    }
+
     fn move_character(
         &mut self,
         entity: wit::types::EntityId,
@@ -253,6 +303,46 @@ impl shared::wit::server_physics::Host for Bindings {
     }
 }
 
+impl FromBindgen for wit::server_physics::QueryShape {
+    type Item = QueryShape;
+    fn from_bindgen(self) -> Self::Item {
+        match self {
+            Self::Sphere(radius) => QueryShape::Sphere { radius },
+            Self::Cuboid(half_extents) => QueryShape::Cuboid {
+                half_extents: half_extents.from_bindgen(),
+            },
+            Self::Capsule(capsule) => QueryShape::Capsule {
+                radius: capsule.radius,
+                half_height: capsule.half_height,
+            },
+        }
+    }
+}
+
+impl FromBindgen for wit::server_physics::ColliderType {
+    type Item = ColliderType;
+    fn from_bindgen(self) -> Self::Item {
+        match self {
+            Self::Static => ColliderType::Static,
+            Self::Dynamic => ColliderType::Dynamic,
+            Self::TriggerArea => ColliderType::TriggerArea,
+            Self::Picking => ColliderType::Picking,
+        }
+    }
+}
+
+impl IntoBindgen for SweepHit {
+    type Item = wit::server_physics::SweepHit;
+    fn into_bindgen(self) -> Self::Item {
+        wit::server_physics::SweepHit {
+            entity: self.entity.into_bindgen(),
+            position: self.position.into_bindgen(),
+            normal: self.normal.into_bindgen(),
+            distance: self.distance,
+        }
+    }
+}
+
 /// Returns an error if the direction is non-normalized.
 fn get_raycast_direction(direction: wit::types::Vec3) -> anyhow::Result<glam::Vec3> {
     let direction = direction.from_bindgen();
@@ -267,3 +357,31 @@ fn get_raycast_direction(direction: wit::types::Vec3) -> anyhow::Result<glam::Ve
     }
     Ok(direction)
 }
+
+fn get_query_shape(shape: wit::server_physics::QueryShape) -> anyhow::Result<QueryShape> {
+    let shape = shape.from_bindgen();
+    let valid = |dim: f32| dim.is_finite() && dim > 0.0;
+    let is_valid = match shape {
+        QueryShape::Sphere { radius } => valid(radius),
+        QueryShape::Cuboid { half_extents } => half_extents.to_array().into_iter().all(valid),
+        QueryShape::Capsule {
+            radius,
+            half_height,
+        } => valid(radius) && valid(half_height),
+    };
+    if !is_valid {
+        anyhow::bail!("Query shape dimensions must be positive and finite");
+    }
+    Ok(shape)
+}
+
+fn get_query_rotation(rotation: wit::types::Quat) -> anyhow::Result<glam::Quat> {
+    let rotation = rotation.from_bindgen();
+    if !rotation.is_finite() {
+        anyhow::bail!("Query rotation must be finite");
+    }
+    if rotation.length_squared() < 0.0001 {
+        anyhow::bail!("Query rotation must be non-zero");
+    }
+    Ok(rotation.normalize())
+}
@@ -134,3 +134,33 @@ impl wit::client_material::Host for Bindings {
         unsupported()
     }
 }
+
+impl wit::client_audio::Host for Bindings {
+    fn play(
+        &mut self,
+        _url: String,
+        _options: wit::client_audio::PlayOptions,
+    ) -> anyhow::Result<Result<wit::types::EntityId, String>> {
+        unsupported()
+    }
+    fn stop(&mut self, _sound: wit::types::EntityId) -> anyhow::Result<()> {
+        unsupported()
+    }
+    fn get_position(&mut self, _sound: wit::types::EntityId) -> anyhow::Result<Option<f32>> {
+        unsupported()
+    }
+    fn get_duration(&mut self, _sound: wit::types::EntityId) -> anyhow::Result<Option<f32>> {
+        unsupported()
+    }
+    fn seek(&mut self, _sound: wit::types::EntityId, _position_seconds: f32) -> anyhow::Result<()> {
+        unsupported()
+    }
+    fn fade(
+        &mut self,
+        _sound: wit::types::EntityId,
+        _amplitude: f32,
+        _duration_seconds: f32,
+    ) -> anyhow::Result<()> {
+        unsupported()
+    }
+}
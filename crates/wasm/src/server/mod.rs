@@ -1,11 +1,21 @@
 use crate::shared::{self, message::MessageExt};
-use ambient_ecs::{generated::messages, query, EntityId, FnSystem, SystemGroup, World};
+use ambient_ecs::{
+    generated::{
+        messages,
+        physics::messages as physics_messages,
+        player::{components::disconnected, messages as player_messages},
+    },
+    query, EntityId, FnSystem, SystemGroup, World,
+};
 use ambient_native_std::asset_cache::AssetCache;
 use ambient_network::server::{ForkingEvent, ShutdownEvent};
+use ambient_physics::events::{ContactPhase, TriggerEvent};
+use parking_lot::Mutex;
 use std::{path::PathBuf, sync::Arc};
 
 mod implementation;
 mod network;
+mod storage;
 
 pub fn initialize(
     world: &mut World,
@@ -14,6 +24,14 @@ pub fn initialize(
     data_path: PathBuf,
     messenger: Arc<dyn Fn(&World, EntityId, shared::MessageType, &str) + Send + Sync>,
 ) -> anyhow::Result<()> {
+    // Hosted servers have no data directory to keep the storage in
+    let storage = (!hosted).then(|| {
+        Arc::new(Mutex::new(storage::Storage::new(
+            data_path.join("storage"),
+            storage::STORAGE_QUOTA,
+        )))
+    });
+
     shared::initialize(
         world,
         assets,
@@ -25,6 +43,7 @@ pub fn initialize(
             reqwest_client: reqwest::Client::new(),
             last_http_request_id: 0,
             hosted,
+            storage: storage.clone(),
         }),
         if hosted {
             None
@@ -55,6 +74,54 @@ pub fn systems() -> SystemGroup {
                         .unwrap();
                 }
             })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module contact events");
+                let reports = match world.resource_opt(ambient_physics::events::contact_reports()) {
+                    Some(reports) => reports.lock().clone(),
+                    None => return,
+                };
+                for report in reports {
+                    let ids = report.ids.to_vec();
+                    match report.phase {
+                        ContactPhase::Begin => physics_messages::ContactBegin::new(
+                            ids,
+                            report.points,
+                            report.normals,
+                            report.impulse,
+                        )
+                        .run(world, None),
+                        ContactPhase::Persist => physics_messages::ContactPersist::new(
+                            ids,
+                            report.points,
+                            report.normals,
+                            report.impulse,
+                        )
+                        .run(world, None),
+                        ContactPhase::End => {
+                            physics_messages::ContactEnd::new(ids).run(world, None)
+                        }
+                    }
+                    .unwrap();
+                }
+            })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module trigger events");
+                let events = match world.resource_opt(ambient_physics::events::trigger_events()) {
+                    Some(events) => events.clone(),
+                    None => return,
+                };
+                for event in events {
+                    match event {
+                        TriggerEvent::Enter { trigger, id } => {
+                            physics_messages::TriggerEnter::new(trigger, id).run(world, None)
+                        }
+                        TriggerEvent::Exit { trigger, id } => {
+                            physics_messages::TriggerExit::new(trigger, id).run(world, None)
+                        }
+                    }
+                    .unwrap();
+                }
+            })),
             Box::new(FnSystem::new(move |world, _| {
                 profiling::scope!("WASM module collider loads");
                 // trigger collider loads
@@ -71,6 +138,50 @@ pub fn systems() -> SystemGroup {
                     .run(world, None)
                     .unwrap();
             })),
+            query(())
+                .incl(ambient_physics::joint_broken())
+                .spawned()
+                .to_system(|q, world, qs, _| {
+                    profiling::scope!("WASM module joint broken event");
+                    for (id, ()) in q.collect_cloned(world, qs) {
+                        let actors = [
+                            ambient_physics::joint_actor_0(),
+                            ambient_physics::joint_actor_1(),
+                        ]
+                        .into_iter()
+                        .filter_map(|component| world.get(id, component).ok())
+                        .collect::<Vec<_>>();
+
+                        physics_messages::JointBroken::new(id, actors)
+                            .run(world, None)
+                            .unwrap();
+                    }
+                }),
+            query(())
+                .incl(disconnected())
+                .spawned()
+                .to_system(|q, world, qs, _| {
+                    profiling::scope!("WASM module player disconnect event");
+                    for (id, ()) in q.collect_cloned(world, qs) {
+                        player_messages::Disconnect::new(id)
+                            .run(world, None)
+                            .unwrap();
+                    }
+                }),
+            query(())
+                .incl(disconnected())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    profiling::scope!("WASM module player reconnect event");
+                    for (id, ()) in q.collect_cloned(world, qs) {
+                        // Players whose grace period ran out are despawned instead
+                        if world.exists(id) {
+                            player_messages::Reconnect::new(id)
+                                .run(world, None)
+                                .unwrap();
+                        }
+                    }
+                }),
             Box::new(shared::systems()),
         ],
     )
@@ -108,6 +219,9 @@ struct Bindings {
     /// Whether or not this server is running in a hosted environment,
     /// and should thus have some of its functionality disabled
     hosted: bool,
+    /// The key-value storage of all of the packages, shared between the modules. This is `None`
+    /// on hosted servers
+    storage: Option<Arc<Mutex<storage::Storage>>>,
 }
 
 impl Bindings {
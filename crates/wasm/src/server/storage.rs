@@ -0,0 +1,528 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread::JoinHandle,
+};
+
+use anyhow::Context;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+/// How many bytes of keys and values each package may store
+pub const STORAGE_QUOTA: usize = 16 << 20;
+
+/// The log of a package is rewritten when it is larger than this, and more than twice the size of
+/// the entries it contains
+const COMPACTION_THRESHOLD: u64 = 1 << 20;
+
+const OP_SET: u8 = 0;
+const OP_DELETE: u8 = 1;
+/// The size of a set record, other than its namespace, key and value
+const SET_RECORD_OVERHEAD: usize = 13;
+
+type Entries = BTreeMap<String, BTreeMap<String, Vec<u8>>>;
+
+/// The key-value storage of all of the packages on the server, backed by one append-only log per
+/// package in a directory.
+///
+/// Changes are applied in memory immediately, and written to the logs on a background thread, so
+/// that modules don't wait for the disk.
+pub struct Storage {
+    path: PathBuf,
+    quota: usize,
+    packages: HashMap<String, PackageStorage>,
+    writer: Option<(mpsc::Sender<StorageWrite>, JoinHandle<()>)>,
+}
+
+impl Storage {
+    pub fn new(path: impl Into<PathBuf>, quota: usize) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let thread = std::thread::Builder::new()
+            .name("package_storage".to_string())
+            .spawn(move || write_logs(receiver))
+            .expect("Failed to spawn the package storage thread");
+
+        Self {
+            path: path.into(),
+            quota,
+            packages: HashMap::new(),
+            writer: Some((sender, thread)),
+        }
+    }
+
+    /// The storage of the package with the ID `package_id`, which is loaded from disk the first
+    /// time it is used
+    pub fn package(&mut self, package_id: &str) -> anyhow::Result<&mut PackageStorage> {
+        anyhow::ensure!(
+            !package_id.is_empty()
+                && package_id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+            "Package ID {package_id:?} can't be used as a storage file name"
+        );
+
+        if !self.packages.contains_key(package_id) {
+            fs::create_dir_all(&self.path)
+                .with_context(|| format!("Failed to create storage directory {:?}", self.path))?;
+            let writer = self.writer.as_ref().unwrap().0.clone();
+            let storage = PackageStorage::open(
+                self.path.join(format!("{package_id}.log")),
+                self.quota,
+                writer,
+            )?;
+            self.packages.insert(package_id.to_string(), storage);
+        }
+        Ok(self.packages.get_mut(package_id).unwrap())
+    }
+}
+impl Drop for Storage {
+    /// Waits for the pending writes, so that they are not lost on shutdown
+    fn drop(&mut self) {
+        // The packages also send to the thread, so it only stops once they are gone
+        self.packages.clear();
+        if let Some((sender, thread)) = self.writer.take() {
+            drop(sender);
+            thread.join().ok();
+        }
+    }
+}
+
+/// The entries of a single package, by namespace and key
+pub struct PackageStorage {
+    path: PathBuf,
+    quota: usize,
+    entries: Entries,
+    /// The number of bytes of namespaces, keys and values in `entries`
+    used: usize,
+    len: usize,
+    /// The length of the log once the writes sent to `writer` have been written
+    log_len: u64,
+    writer: mpsc::Sender<StorageWrite>,
+}
+
+impl PackageStorage {
+    fn open(
+        path: PathBuf,
+        quota: usize,
+        writer: mpsc::Sender<StorageWrite>,
+    ) -> anyhow::Result<Self> {
+        let data = match fs::read(&path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to read storage log {path:?}"))
+            }
+        };
+
+        let mut storage = Self {
+            log_len: 0,
+            path,
+            quota,
+            entries: BTreeMap::new(),
+            used: 0,
+            len: 0,
+            writer,
+        };
+
+        let mut reader = data.as_slice();
+        while !reader.is_empty() {
+            match read_record(&mut reader) {
+                Some(Record::Set(namespace, key, value)) => {
+                    storage.insert(namespace, key, value);
+                }
+                Some(Record::Delete(namespace, key)) => {
+                    storage.remove(&namespace, &key);
+                }
+                None => {
+                    // The server stopped in the middle of writing the last record
+                    tracing::warn!(
+                        "Discarding {} bytes at the end of storage log {:?}",
+                        reader.len(),
+                        storage.path
+                    );
+                    break;
+                }
+            }
+        }
+        storage.log_len = (data.len() - reader.len()) as u64;
+        if !reader.is_empty() || storage.should_compact() {
+            storage.compact()?;
+        }
+
+        Ok(storage)
+    }
+
+    pub fn get(&self, namespace: &str, key: &str) -> Option<&[u8]> {
+        self.entries
+            .get(namespace)
+            .and_then(|entries| entries.get(key))
+            .map(|value| value.as_slice())
+    }
+
+    /// Fails without changing anything if the storage would grow beyond its quota
+    pub fn set(&mut self, namespace: String, key: String, value: Vec<u8>) -> anyhow::Result<()> {
+        let previous = self
+            .get(&namespace, &key)
+            .map_or(0, |value| entry_size(&namespace, &key, value));
+        let used = self.used - previous + entry_size(&namespace, &key, &value);
+        anyhow::ensure!(
+            used <= self.quota,
+            "Setting {namespace}/{key} would use {used} bytes of storage, which is more than the quota of {} bytes",
+            self.quota
+        );
+
+        self.append(&Record::Set(namespace.clone(), key.clone(), value.clone()))?;
+        self.insert(namespace, key, value);
+        self.compact_if_needed()
+    }
+
+    /// Returns whether `key` was set
+    pub fn delete(&mut self, namespace: &str, key: &str) -> anyhow::Result<bool> {
+        if self.get(namespace, key).is_none() {
+            return Ok(false);
+        }
+        self.append(&Record::Delete(namespace.to_string(), key.to_string()))?;
+        self.remove(namespace, key);
+        self.compact_if_needed()?;
+        Ok(true)
+    }
+
+    pub fn list(&self, namespace: &str) -> Vec<String> {
+        self.entries
+            .get(namespace)
+            .map(|entries| entries.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn insert(&mut self, namespace: String, key: String, value: Vec<u8>) {
+        self.remove(&namespace, &key);
+        self.used += entry_size(&namespace, &key, &value);
+        self.len += 1;
+        self.entries
+            .entry(namespace)
+            .or_default()
+            .insert(key, value);
+    }
+
+    fn remove(&mut self, namespace: &str, key: &str) {
+        let Some(entries) = self.entries.get_mut(namespace) else {
+            return;
+        };
+        if let Some(value) = entries.remove(key) {
+            self.used -= entry_size(namespace, key, &value);
+            self.len -= 1;
+        }
+        if entries.is_empty() {
+            self.entries.remove(namespace);
+        }
+    }
+
+    fn append(&mut self, record: &Record) -> anyhow::Result<()> {
+        let mut data = Vec::new();
+        write_record(&mut data, record)?;
+        self.log_len += data.len() as u64;
+        self.send(StorageWrite::Append(self.path.clone(), data))
+    }
+
+    /// The length of the log once it only contains the current entries
+    fn compacted_len(&self) -> u64 {
+        (self.used + self.len * SET_RECORD_OVERHEAD) as u64
+    }
+
+    fn should_compact(&self) -> bool {
+        self.log_len > COMPACTION_THRESHOLD && self.log_len > 2 * self.compacted_len()
+    }
+
+    fn compact_if_needed(&mut self) -> anyhow::Result<()> {
+        if self.should_compact() {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    /// Rewrites the log with only the current entries
+    fn compact(&mut self) -> anyhow::Result<()> {
+        self.log_len = self.compacted_len();
+        self.send(StorageWrite::Compact(
+            self.path.clone(),
+            self.entries.clone(),
+        ))
+    }
+
+    fn send(&self, write: StorageWrite) -> anyhow::Result<()> {
+        self.writer
+            .send(write)
+            .map_err(|_| anyhow::anyhow!("The package storage thread has stopped"))
+    }
+}
+
+enum StorageWrite {
+    /// Appends records to the log at the path
+    Append(PathBuf, Vec<u8>),
+    /// Replaces the log at the path with a set record for each of the entries
+    Compact(PathBuf, Entries),
+}
+
+/// Writes the logs until every sender is dropped. The logs are synced once for each batch of
+/// writes, rather than once for each record.
+fn write_logs(receiver: mpsc::Receiver<StorageWrite>) {
+    let mut logs = HashMap::new();
+    while let Ok(write) = receiver.recv() {
+        let mut appended = HashSet::new();
+        for write in std::iter::once(write).chain(receiver.try_iter()) {
+            let result = match write {
+                StorageWrite::Append(path, data) => {
+                    let result = append_log(&mut logs, &path, &data);
+                    appended.insert(path);
+                    result
+                }
+                StorageWrite::Compact(path, entries) => {
+                    // The compacted log is synced when it is written
+                    appended.remove(&path);
+                    logs.remove(&path);
+                    compact_log(&path, &entries)
+                }
+            };
+            if let Err(err) = result {
+                tracing::error!("{err:?}");
+            }
+        }
+
+        for path in appended {
+            if let Some(log) = logs.get(&path) {
+                if let Err(err) = log.sync_data() {
+                    tracing::error!("Failed to sync storage log {path:?}: {err:?}");
+                }
+            }
+        }
+    }
+}
+
+fn append_log(logs: &mut HashMap<PathBuf, File>, path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    if !logs.contains_key(path) {
+        logs.insert(path.to_path_buf(), open_log(path)?);
+    }
+    logs.get_mut(path)
+        .unwrap()
+        .write_all(data)
+        .with_context(|| format!("Failed to write to storage log {path:?}"))
+}
+
+fn compact_log(path: &Path, entries: &Entries) -> anyhow::Result<()> {
+    let mut data = Vec::new();
+    for (namespace, entries) in entries {
+        for (key, value) in entries {
+            write_record(
+                &mut data,
+                &Record::Set(namespace.clone(), key.clone(), value.clone()),
+            )?;
+        }
+    }
+
+    // Write to another file first, so that the log is never left half-written
+    let tmp_path = path.with_extension("log.tmp");
+    let mut tmp = File::create(&tmp_path)
+        .with_context(|| format!("Failed to create storage log {tmp_path:?}"))?;
+    tmp.write_all(&data)
+        .and_then(|_| tmp.sync_all())
+        .with_context(|| format!("Failed to write storage log {tmp_path:?}"))?;
+    drop(tmp);
+    fs::rename(&tmp_path, path)
+        .with_context(|| format!("Failed to replace storage log {path:?}"))?;
+    sync_dir(path)
+}
+
+fn open_log(path: &Path) -> anyhow::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open storage log {path:?}"))
+}
+
+/// Makes sure that a rename of `path` survives a crash, by syncing the directory which contains it
+fn sync_dir(path: &Path) -> anyhow::Result<()> {
+    // Directories can only be synced like this on Unix
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        File::open(dir)
+            .and_then(|dir| dir.sync_all())
+            .with_context(|| format!("Failed to sync storage directory {dir:?}"))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+fn entry_size(namespace: &str, key: &str, value: &[u8]) -> usize {
+    namespace.len() + key.len() + value.len()
+}
+
+enum Record {
+    Set(String, String, Vec<u8>),
+    Delete(String, String),
+}
+
+fn write_record(writer: &mut Vec<u8>, record: &Record) -> anyhow::Result<()> {
+    fn write_bytes(writer: &mut Vec<u8>, bytes: &[u8]) -> anyhow::Result<()> {
+        writer.write_u32::<LittleEndian>(bytes.len().try_into()?)?;
+        writer.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    match record {
+        Record::Set(namespace, key, value) => {
+            writer.write_u8(OP_SET)?;
+            write_bytes(writer, namespace.as_bytes())?;
+            write_bytes(writer, key.as_bytes())?;
+            write_bytes(writer, value)?;
+        }
+        Record::Delete(namespace, key) => {
+            writer.write_u8(OP_DELETE)?;
+            write_bytes(writer, namespace.as_bytes())?;
+            write_bytes(writer, key.as_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads a record from the start of `reader`, and advances past it. Returns `None` without
+/// advancing if the record is incomplete or invalid.
+fn read_record(reader: &mut &[u8]) -> Option<Record> {
+    fn read_bytes(reader: &mut &[u8]) -> Option<Vec<u8>> {
+        let len = reader.read_u32::<LittleEndian>().ok()? as usize;
+        if reader.len() < len {
+            return None;
+        }
+        let (bytes, rest) = reader.split_at(len);
+        *reader = rest;
+        Some(bytes.to_vec())
+    }
+    fn read_string(reader: &mut &[u8]) -> Option<String> {
+        String::from_utf8(read_bytes(reader)?).ok()
+    }
+
+    let mut cursor = *reader;
+    let record = match cursor.read_u8().ok()? {
+        OP_SET => Record::Set(
+            read_string(&mut cursor)?,
+            read_string(&mut cursor)?,
+            read_bytes(&mut cursor)?,
+        ),
+        OP_DELETE => Record::Delete(read_string(&mut cursor)?, read_string(&mut cursor)?),
+        _ => return None,
+    };
+    *reader = cursor;
+    Some(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage_dir(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join("ambient_wasm_storage").join(name);
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn survives_reopening() {
+        let path = storage_dir("survives_reopening");
+        let mut storage = Storage::new(&path, STORAGE_QUOTA);
+        let package = storage.package("game").unwrap();
+        package
+            .set("scores".into(), "alice".into(), vec![1, 2])
+            .unwrap();
+        package.set("scores".into(), "bob".into(), vec![3]).unwrap();
+        package
+            .set("scores".into(), "alice".into(), vec![4])
+            .unwrap();
+        assert!(package.delete("scores", "bob").unwrap());
+        assert!(!package.delete("scores", "bob").unwrap());
+        storage
+            .package("other")
+            .unwrap()
+            .set("scores".into(), "carol".into(), vec![5])
+            .unwrap();
+        drop(storage);
+
+        let mut storage = Storage::new(&path, STORAGE_QUOTA);
+        let package = storage.package("game").unwrap();
+        assert_eq!(package.get("scores", "alice"), Some([4].as_slice()));
+        assert_eq!(package.get("scores", "bob"), None);
+        assert_eq!(package.list("scores"), vec!["alice".to_string()]);
+        assert_eq!(
+            storage.package("other").unwrap().list("scores"),
+            vec!["carol".to_string()]
+        );
+    }
+
+    #[test]
+    fn truncated_logs_are_recovered() {
+        let path = storage_dir("truncated_logs_are_recovered");
+        let mut storage = Storage::new(&path, STORAGE_QUOTA);
+        let package = storage.package("game").unwrap();
+        package.set("a".into(), "b".into(), vec![1]).unwrap();
+        package.set("a".into(), "c".into(), vec![2; 16]).unwrap();
+        drop(storage);
+
+        let log = path.join("game.log");
+        let len = fs::metadata(&log).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&log)
+            .unwrap()
+            .set_len(len - 4)
+            .unwrap();
+
+        let mut storage = Storage::new(&path, STORAGE_QUOTA);
+        let package = storage.package("game").unwrap();
+        assert_eq!(package.list("a"), vec!["b".to_string()]);
+        package.set("a".into(), "d".into(), vec![3]).unwrap();
+        drop(storage);
+
+        let mut storage = Storage::new(&path, STORAGE_QUOTA);
+        let package = storage.package("game").unwrap();
+        assert_eq!(package.list("a"), vec!["b".to_string(), "d".to_string()]);
+    }
+
+    #[test]
+    fn large_logs_are_compacted() {
+        let path = storage_dir("large_logs_are_compacted");
+        let mut storage = Storage::new(&path, STORAGE_QUOTA);
+        let package = storage.package("game").unwrap();
+        for i in 0..32u8 {
+            package
+                .set("a".into(), "b".into(), vec![i; 64 << 10])
+                .unwrap();
+        }
+        package.set("a".into(), "c".into(), vec![1]).unwrap();
+        drop(storage);
+
+        let log = path.join("game.log");
+        assert!(fs::metadata(&log).unwrap().len() < COMPACTION_THRESHOLD);
+        assert!(!log.with_extension("log.tmp").exists());
+
+        let mut storage = Storage::new(&path, STORAGE_QUOTA);
+        let package = storage.package("game").unwrap();
+        assert_eq!(package.get("a", "b"), Some([31; 64 << 10].as_slice()));
+        assert_eq!(package.get("a", "c"), Some([1].as_slice()));
+    }
+
+    #[test]
+    fn quota() {
+        let path = storage_dir("quota");
+        let mut storage = Storage::new(&path, 8);
+        let package = storage.package("game").unwrap();
+        package.set("a".into(), "b".into(), vec![0; 6]).unwrap();
+        assert!(package.set("a".into(), "c".into(), vec![0]).is_err());
+        // Replacing a value only counts the difference
+        package.set("a".into(), "b".into(), vec![0; 4]).unwrap();
+        package.set("a".into(), "c".into(), vec![]).unwrap();
+        assert_eq!(package.list("a"), vec!["b".to_string(), "c".to_string()]);
+        assert!(storage.package("../game").is_err());
+    }
+}
@@ -8,6 +8,9 @@ pub type QueryStateMap =
 #[derive(Clone, Default)]
 pub struct BindingsBase {
     pub spawned_entities: HashSet<EntityId>,
+    /// The number of entities spawned since the start of the frame
+    pub spawned_this_frame: usize,
+    pub max_spawned_entities_per_frame: Option<usize>,
     pub subscribed_messages: HashSet<String>,
     pub query_states: QueryStateMap,
 }
@@ -24,6 +27,7 @@ pub trait BindingsBound:
     + super::wit::message::Host
     + super::wit::player::Host
     + super::wit::ambient_package::Host
+    + super::wit::timer::Host
     // Client
     + super::wit::client_message::Host
     + super::wit::client_player::Host
@@ -35,11 +39,13 @@ pub trait BindingsBound:
     + super::wit::client_texture::Host
     + super::wit::client_sampler::Host
     + super::wit::client_material::Host
+    + super::wit::client_audio::Host
     // Server
     + super::wit::server_asset::Host
     + super::wit::server_message::Host
     + super::wit::server_physics::Host
     + super::wit::server_http::Host
+    + super::wit::server_storage::Host
     + super::wit::server_ambient_package::Host
     + Clone
     + Sync
@@ -1,6 +1,6 @@
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 
-use ambient_ecs::EntityId;
+use ambient_ecs::{EntityId, MessageSerde};
 use ambient_native_std::shapes::Ray;
 use ambient_shared_types::{
     procedural_storage_handle_definitions, ProceduralMaterialHandle, ProceduralMeshHandle,
@@ -271,7 +271,9 @@ impl IntoBindgen for Duration {
 impl FromBindgen for wit::types::Duration {
     type Item = Duration;
     fn from_bindgen(self) -> Self::Item {
-        Duration::new(self.seconds, self.nanoseconds)
+        // `Duration::new` panics if the nanoseconds carry past the maximum number of seconds
+        Duration::from_secs(self.seconds)
+            .saturating_add(Duration::from_nanos(self.nanoseconds.into()))
     }
 }
 
@@ -377,6 +379,22 @@ where
     }
 }
 
+/// Maps are sent to the guest as their message serialization, as their WIT representation
+/// can't depend on the key and value types.
+impl<K, V> IntoBindgen for BTreeMap<K, V>
+where
+    K: MessageSerde + Ord,
+    V: MessageSerde,
+{
+    type Item = Vec<u8>;
+    fn into_bindgen(self) -> Self::Item {
+        let mut output = vec![];
+        self.serialize_message_part(&mut output)
+            .expect("serializing a map to memory should not fail");
+        output
+    }
+}
+
 impl FromBindgen for wit::types::Ulid {
     type Item = Ulid;
 
@@ -7,6 +7,9 @@ use anyhow::Context;
 #[derive(Clone)]
 pub struct Engine {
     engine: wasm_bridge::Engine,
+    /// The epoch thread stops once every clone of this has been dropped
+    #[cfg(not(target_os = "unknown"))]
+    _epoch_thread: flume::Sender<()>,
 }
 
 impl Engine {
@@ -34,14 +37,38 @@ impl SyncAssetKey<Result<Engine, Arc<anyhow::Error>>> for EngineKey {
         {
             config.debug_info(true);
             config.wasm_backtrace_details(wasm_bridge::WasmBacktraceDetails::Enable);
+            // Used to interrupt modules which run for longer than their limit
+            config.epoch_interruption(true);
         }
 
         config.wasm_component_model(true);
 
+        let engine = wasm_bridge::Engine::new(&config)
+            .context("Failed to create wasm execution engine")
+            .map_err(Arc::new)?;
+
+        #[cfg(not(target_os = "unknown"))]
+        let (epoch_thread, stop) = flume::bounded::<()>(0);
+        #[cfg(not(target_os = "unknown"))]
+        {
+            let engine = engine.clone();
+            std::thread::Builder::new()
+                .name("wasm-epoch".to_string())
+                .spawn(move || {
+                    while let Err(flume::RecvTimeoutError::Timeout) =
+                        stop.recv_timeout(super::EPOCH_INTERVAL)
+                    {
+                        engine.increment_epoch();
+                    }
+                })
+                .context("Failed to start wasm epoch thread")
+                .map_err(Arc::new)?;
+        }
+
         Ok(Engine {
-            engine: wasm_bridge::Engine::new(&config)
-                .context("Failed to create wasm execution engine")
-                .map_err(Arc::new)?,
+            engine,
+            #[cfg(not(target_os = "unknown"))]
+            _epoch_thread: epoch_thread,
         })
     }
 }
@@ -1,7 +1,7 @@
 use ambient_ecs::{
     with_component_registry, Component, ComponentEntry, ComponentSet, ComponentValue, Entity,
-    EntityAccessor, EntityId, Enum, PrimitiveComponent, PrimitiveComponentType as PCT, QueryEvent,
-    QueryState, World,
+    EntityAccessor, EntityId, Enum, MessageSerde, PrimitiveComponent,
+    PrimitiveComponentType as PCT, QueryEvent, QueryState, RelationFilter, Struct, World,
 };
 use ambient_shared_types::primitive_component_definitions;
 use ambient_shared_types::{
@@ -13,7 +13,7 @@ use glam::{IVec2, IVec3, IVec4, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec
 use itertools::Itertools;
 use paste::paste;
 use slotmap::Key;
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 
 use crate::shared::bindings::QueryStateMap;
 
@@ -85,6 +85,27 @@ macro_rules! define_component_types {
                     }
                 }
                 ) *
+                // Structs are converted by `struct_value_to_entry`, as their host type depends on the component
+                V::TypeStruct(_) => {}
+                // Maps are serialized, so the component type is needed to know what to deserialize them as
+                V::TypeMap(bytes) => {
+                    let Some(primitive_component) = with_component_registry(|cr| cr.get_primitive_component(index)) else {
+                        return Ok(());
+                    };
+                    match primitive_component.ty {
+                        $(
+                        PCT::[<MapString $value>] => {
+                            let component = Component::<BTreeMap<String, $type>>::new(primitive_component.desc);
+                            operation.visit(ctx, component, BTreeMap::deserialize_message_part(&mut bytes.as_slice())?)?;
+                        }
+                        PCT::[<MapU32 $value>] => {
+                            let component = Component::<BTreeMap<u32, $type>>::new(primitive_component.desc);
+                            operation.visit(ctx, component, BTreeMap::deserialize_message_part(&mut bytes.as_slice())?)?;
+                        }
+                        )*
+                        _ => {}
+                    }
+                }
             }
 
             Ok(())
@@ -99,6 +120,11 @@ macro_rules! define_component_types {
         ) -> anyhow::Result<Option<wit::component::Value>> {
             use wit::component::{OptionValue as OV, Value as V, VecValue as VV};
 
+            if primitive_component.ty == PCT::VecU8 && primitive_component.desc.has_attribute::<Struct>() {
+                let component = Component::<Vec<u8>>::new(primitive_component.desc);
+                return Ok(operation.visit(ctx, component)?.map(V::TypeStruct));
+            }
+
             Ok(match primitive_component.ty {
                 $(
                 PCT::$value            => {
@@ -113,6 +139,14 @@ macro_rules! define_component_types {
                     let component = Component::<Option<$type>>::new(primitive_component.desc);
                     operation.visit(ctx, component)?.map(|v| V::TypeOption(OV::[<Type $value>](v.into_bindgen())))
                 },
+                PCT::[<MapString $value>] => {
+                    let component = Component::<BTreeMap<String, $type>>::new(primitive_component.desc);
+                    operation.visit(ctx, component)?.map(|v| V::TypeMap(v.into_bindgen()))
+                },
+                PCT::[<MapU32 $value>] => {
+                    let component = Component::<BTreeMap<u32, $type>>::new(primitive_component.desc);
+                    operation.visit(ctx, component)?.map(|v| V::TypeMap(v.into_bindgen()))
+                },
                 )*
             })
         }
@@ -132,13 +166,45 @@ fn enum_value_to_entry(index: u32, value: &wit::component::Value) -> Option<Comp
     })
 }
 
+fn struct_value_to_entry(
+    index: u32,
+    value: &wit::component::Value,
+) -> anyhow::Result<Option<ComponentEntry>> {
+    let wit::component::Value::TypeStruct(bytes) = value else {
+        return Ok(None);
+    };
+    let Some(desc) = with_component_registry(|cr| cr.get_by_index(index)) else {
+        return Ok(None);
+    };
+    let Some(entry) = desc
+        .attribute::<Struct>()
+        .map(|struct_| struct_.entry_from_bytes(desc, bytes))
+    else {
+        return Ok(None);
+    };
+    entry
+        .map(Some)
+        .with_context(|| format!("The value of {} does not match its struct", desc.path()))
+}
+
+/// Converts enum and struct values, whose host type depends on the component
+fn special_value_to_entry(
+    index: u32,
+    value: &wit::component::Value,
+) -> anyhow::Result<Option<ComponentEntry>> {
+    match enum_value_to_entry(index, value) {
+        Some(entry) => Ok(Some(entry)),
+        None => struct_value_to_entry(index, value),
+    }
+}
+
 pub(crate) fn add_component(
     world: &mut World,
     id: wit::entity::EntityId,
     index: u32,
     value: wit::component::Value,
 ) -> anyhow::Result<()> {
-    if let Some(entry) = enum_value_to_entry(index, &value) {
+    if let Some(entry) = special_value_to_entry(index, &value)? {
         world.add_entry(id.from_bindgen(), entry)?;
         return Ok(());
     }
@@ -164,7 +230,7 @@ pub(crate) fn set_component(
     index: u32,
     value: wit::component::Value,
 ) -> anyhow::Result<()> {
-    if let Some(entry) = enum_value_to_entry(index, &value) {
+    if let Some(entry) = special_value_to_entry(index, &value)? {
         world.set_entry(id.from_bindgen(), entry)?;
         return Ok(());
     }
@@ -291,7 +357,7 @@ pub(crate) fn wit_entity_to_host_entity(
 
     let mut entity = Entity::new();
     for (index, value) in wit_entity {
-        if let Some(entry) = enum_value_to_entry(index, &value) {
+        if let Some(entry) = special_value_to_entry(index, &value)? {
             entity.set_entry(entry);
             continue;
         }
@@ -393,12 +459,36 @@ pub fn query(
             .collect()
     }
 
-    let (components, include, exclude, changed) = with_component_registry(|cr| {
+    let (components, include, exclude, changed, relations) = with_component_registry(|cr| {
+        let relations = query
+            .relations
+            .iter()
+            .map(|relation| {
+                let component = get_components(cr, &[relation.component])?.remove(0);
+                anyhow::ensure!(
+                    RelationFilter::is_relation(component.as_component()),
+                    "relations must be entity-id or list<entity-id> components"
+                );
+                let mut filter = RelationFilter::new(component.as_component());
+                for component in get_components(cr, &relation.includes)? {
+                    filter = filter.incl(component.as_component());
+                }
+                for component in get_components(cr, &relation.excludes)? {
+                    filter = filter.excl(component.as_component());
+                }
+                for component in get_components(cr, &relation.changed)? {
+                    filter = filter.when_changed(component.as_component());
+                }
+                Ok(filter)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
         anyhow::Ok((
             get_components(cr, &query.components)?,
             get_components(cr, &query.includes)?,
             get_components(cr, &query.excludes)?,
             get_components(cr, &query.changed)?,
+            relations,
         ))
     })?;
 
@@ -420,6 +510,13 @@ pub fn query(
     for component in changed {
         query = query.optional_changed_ref(component.as_component());
     }
+    for mut relation in relations {
+        // Only change queries are triggered by changes to related entities
+        if !matches!(query.event, QueryEvent::Changed { .. }) {
+            relation.changed.clear();
+        }
+        query = query.related_ref(relation);
+    }
 
     Ok(query_states
         .insert((query, QueryState::new(), components))
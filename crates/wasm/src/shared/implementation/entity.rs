@@ -10,19 +10,27 @@ use glam::Mat4;
 
 use super::{
     super::{
+        bindings::BindingsBase,
         conversion::{FromBindgen, IntoBindgen},
-        wit,
+        wit, BudgetExceeded,
     },
     component::{host_entity_to_wit_entity, wit_entity_to_host_entity},
 };
 
 pub fn spawn(
     world: &mut World,
-    spawned_entities: &mut HashSet<EntityId>,
+    base: &mut BindingsBase,
     data: wit::entity::EntityData,
 ) -> anyhow::Result<wit::types::EntityId> {
+    base.spawned_this_frame += 1;
+    if let Some(max) = base.max_spawned_entities_per_frame {
+        if base.spawned_this_frame > max {
+            return Err(BudgetExceeded::SpawnedEntities(max).into());
+        }
+    }
+
     let id = wit_entity_to_host_entity(data)?.spawn(world);
-    spawned_entities.insert(id);
+    base.spawned_entities.insert(id);
     Ok(id.into_bindgen())
 }
 
@@ -110,3 +118,47 @@ pub fn get_all(world: &mut World, index: u32) -> anyhow::Result<Vec<wit::types::
             .collect(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::WorldContext;
+
+    use super::*;
+
+    #[test]
+    fn spawns_are_limited_per_frame() {
+        ambient_ecs::init_components();
+        let mut world = World::new("spawn_limit", WorldContext::Server);
+        let mut base = BindingsBase {
+            max_spawned_entities_per_frame: Some(2),
+            ..Default::default()
+        };
+
+        assert!(spawn(&mut world, &mut base, Vec::new()).is_ok());
+        assert!(spawn(&mut world, &mut base, Vec::new()).is_ok());
+        let err = spawn(&mut world, &mut base, Vec::new()).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<BudgetExceeded>(),
+            Some(&BudgetExceeded::SpawnedEntities(2))
+        );
+        // The spawn over the limit did not happen
+        assert_eq!(base.spawned_entities.len(), 2);
+
+        // The count starts over at the start of each frame
+        base.spawned_this_frame = 0;
+        assert!(spawn(&mut world, &mut base, Vec::new()).is_ok());
+        assert_eq!(base.spawned_entities.len(), 3);
+    }
+
+    #[test]
+    fn spawns_are_not_limited_by_default() {
+        ambient_ecs::init_components();
+        let mut world = World::new("spawn_limit", WorldContext::Server);
+        let mut base = BindingsBase::default();
+
+        for _ in 0..100 {
+            spawn(&mut world, &mut base, Vec::new()).unwrap();
+        }
+        assert_eq!(base.spawned_entities.len(), 100);
+    }
+}
@@ -0,0 +1,273 @@
+use std::ops::Bound;
+
+use ambient_ecs::{
+    with_component_registry, ArchetypeFilter, Component, ComponentEntry, EntityId, EntityIndex,
+    GridIndex, HashIndex, Index, IndexColumns, IndexKey, IndexUpdater, MessageSerde,
+    PrimitiveComponent, PrimitiveComponentType as PCT, World,
+};
+use ambient_shared_types::primitive_component_definitions;
+use ambient_shared_types::{
+    ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
+    ProceduralTextureHandle,
+};
+use anyhow::Context;
+use glam::{IVec2, IVec3, IVec4, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+use slotmap::Key;
+use std::time::Duration;
+
+use super::super::{
+    conversion::{FromBindgen, IntoBindgen},
+    module_indexes, wit,
+};
+
+pub type ModuleIndexes = slotmap::SlotMap<slotmap::DefaultKey, GuestIndex>;
+
+#[derive(Clone, Debug)]
+pub enum GuestIndexKind {
+    Ordered(Index),
+    Hash(HashIndex),
+    Grid(GridIndex),
+}
+
+/// An index created by a module, which is kept up to date by [`update_all`]
+#[derive(Clone, Debug)]
+pub struct GuestIndex {
+    kind: GuestIndexKind,
+    components: Vec<PrimitiveComponent>,
+    updater: IndexUpdater,
+}
+
+impl GuestIndex {
+    fn update(&mut self, world: &World) {
+        match &mut self.kind {
+            GuestIndexKind::Ordered(index) => self.updater.update(world, index),
+            GuestIndexKind::Hash(index) => self.updater.update(world, index),
+            GuestIndexKind::Grid(index) => self.updater.update(world, index),
+        }
+    }
+
+    /// Reads the values of the columns of the index, which are serialized one after another
+    fn read_values(&self, bytes: &[u8]) -> anyhow::Result<Vec<ComponentEntry>> {
+        let mut input = bytes;
+        let values = self
+            .components
+            .iter()
+            .map(|component| read_value(component, &mut input))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        anyhow::ensure!(input.is_empty(), "too many values for the index");
+        Ok(values)
+    }
+}
+
+macro_rules! define_read_value {
+    ($(($value:ident, $type:ty)),*) => {
+        fn read_value(
+            component: &PrimitiveComponent,
+            input: &mut &[u8],
+        ) -> anyhow::Result<ComponentEntry> {
+            Ok(match component.ty {
+                $(PCT::$value => {
+                    ComponentEntry::from_raw_parts(component.desc, <$type>::deserialize_message_part(input)?)
+                })*
+                _ => anyhow::bail!("indexes can only be made from components with primitive types"),
+            })
+        }
+    };
+}
+
+primitive_component_definitions!(define_read_value);
+
+/// Updates all of the indexes of all of the modules. This must happen every frame, so that the
+/// indexes do not miss any changes.
+pub(crate) fn update_all(world: &mut World) {
+    let modules = ambient_ecs::query(module_indexes())
+        .iter(world, None)
+        .map(|(id, _)| id)
+        .collect::<Vec<_>>();
+
+    for id in modules {
+        let Ok(mut indexes) = world.get_mut(id, module_indexes()).map(std::mem::take) else {
+            continue;
+        };
+        for index in indexes.values_mut() {
+            index.update(world);
+        }
+        if let Ok(module_indexes) = world.get_mut(id, module_indexes()) {
+            *module_indexes = indexes;
+        }
+    }
+}
+
+pub fn create(
+    world: &mut World,
+    module_id: EntityId,
+    kind: wit::component::IndexKind,
+    components: Vec<u32>,
+    includes: Vec<u32>,
+    excludes: Vec<u32>,
+) -> anyhow::Result<u64> {
+    let (components, includes, excludes) = with_component_registry(|cr| {
+        let get = |components: Vec<u32>| {
+            components
+                .into_iter()
+                .map(|c| {
+                    cr.get_primitive_component(c)
+                        .context("no primitive component")
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        };
+        anyhow::Ok((get(components)?, get(includes)?, get(excludes)?))
+    })?;
+    anyhow::ensure!(
+        !components.is_empty(),
+        "indexes need at least one component"
+    );
+
+    let columns = components
+        .iter()
+        .try_fold(IndexColumns::new(), |columns, component| {
+            columns.add_primitive_column(component)
+        })
+        .context("indexes can only be made from components with primitive types")?;
+
+    let kind = match kind {
+        wit::component::IndexKind::Ordered => GuestIndexKind::Ordered(Index::new(columns)),
+        wit::component::IndexKind::Hash => GuestIndexKind::Hash(HashIndex::new(columns)),
+        wit::component::IndexKind::Grid(cell_size) => {
+            anyhow::ensure!(
+                components.len() == 1 && components[0].ty == PCT::Vec3,
+                "grid indexes must be made from a single vec3 component"
+            );
+            anyhow::ensure!(
+                cell_size > 0.,
+                "the cell size of a grid index must be positive"
+            );
+            GuestIndexKind::Grid(GridIndex::new(
+                Component::new(components[0].desc),
+                cell_size,
+            ))
+        }
+    };
+
+    let mut filter = ArchetypeFilter::new();
+    for component in &includes {
+        filter = filter.incl_ref(component.as_component());
+    }
+    for component in &excludes {
+        filter = filter.excl_ref(component.as_component());
+    }
+    let updater = IndexUpdater::new(
+        filter,
+        &components.iter().map(|c| c.desc).collect::<Vec<_>>(),
+    );
+
+    let mut index = GuestIndex {
+        kind,
+        components,
+        updater,
+    };
+    index.update(world);
+
+    if !world.has_component(module_id, module_indexes()) {
+        world.add_component(module_id, module_indexes(), ModuleIndexes::default())?;
+    }
+    Ok(world
+        .get_mut(module_id, module_indexes())?
+        .insert(index)
+        .data()
+        .as_ffi())
+}
+
+/// Brings the index up to date with the changes made since the last frame, and looks it up
+fn with_index<R>(
+    world: &mut World,
+    module_id: EntityId,
+    index: u64,
+    lookup: impl FnOnce(&GuestIndex) -> anyhow::Result<R>,
+) -> anyhow::Result<R> {
+    let key = slotmap::DefaultKey::from(slotmap::KeyData::from_ffi(index));
+    let mut indexes = std::mem::take(world.get_mut(module_id, module_indexes())?);
+    let result = match indexes.get_mut(key) {
+        Some(index) => {
+            index.update(world);
+            lookup(index)
+        }
+        None => Err(anyhow::anyhow!("no index for key")),
+    };
+    *world.get_mut(module_id, module_indexes())? = indexes;
+    result
+}
+
+fn into_bindgen(ids: impl IntoIterator<Item = EntityId>) -> Vec<wit::types::EntityId> {
+    ids.into_iter().map(|id| id.into_bindgen()).collect()
+}
+
+pub fn get(
+    world: &mut World,
+    module_id: EntityId,
+    index: u64,
+    values: Vec<u8>,
+) -> anyhow::Result<Vec<wit::types::EntityId>> {
+    with_index(world, module_id, index, |index| {
+        let values = index.read_values(&values)?;
+        Ok(match &index.kind {
+            GuestIndexKind::Ordered(ordered) => {
+                let fields = ordered.columns().exact_fields(values);
+                let min = IndexKey::min(fields.clone());
+                let max = IndexKey::max(fields);
+                into_bindgen(ordered.range(&min..=&max).filter_map(|key| key.id()))
+            }
+            GuestIndexKind::Hash(hash) => into_bindgen(hash.get(&values)),
+            GuestIndexKind::Grid(grid) => {
+                let position = *values[0].downcast_ref::<Vec3>();
+                into_bindgen(grid.within_box(position, position))
+            }
+        })
+    })
+}
+
+pub fn range(
+    world: &mut World,
+    module_id: EntityId,
+    index: u64,
+    min: Vec<u8>,
+    max: Vec<u8>,
+) -> anyhow::Result<Vec<wit::types::EntityId>> {
+    with_index(world, module_id, index, |index| {
+        let (min, max) = (index.read_values(&min)?, index.read_values(&max)?);
+        Ok(match &index.kind {
+            GuestIndexKind::Ordered(ordered) => {
+                let min = IndexKey::min(ordered.columns().exact_fields(min));
+                let max = IndexKey::max(ordered.columns().exact_fields(max));
+                if min > max {
+                    return Ok(Vec::new());
+                }
+                into_bindgen(
+                    ordered
+                        .range((Bound::Included(&min), Bound::Included(&max)))
+                        .filter_map(|key| key.id()),
+                )
+            }
+            GuestIndexKind::Hash(_) => anyhow::bail!("hash indexes do not support range lookups"),
+            GuestIndexKind::Grid(grid) => into_bindgen(grid.within_box(
+                *min[0].downcast_ref::<Vec3>(),
+                *max[0].downcast_ref::<Vec3>(),
+            )),
+        })
+    })
+}
+
+pub fn within_radius(
+    world: &mut World,
+    module_id: EntityId,
+    index: u64,
+    center: wit::types::Vec3,
+    radius: f32,
+) -> anyhow::Result<Vec<wit::types::EntityId>> {
+    with_index(world, module_id, index, |index| match &index.kind {
+        GuestIndexKind::Grid(grid) => Ok(into_bindgen(
+            grid.within_radius(center.from_bindgen(), radius),
+        )),
+        _ => anyhow::bail!("only grid indexes support radius lookups"),
+    })
+}
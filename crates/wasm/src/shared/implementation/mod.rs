@@ -1,9 +1,11 @@
 pub mod asset;
 pub mod component;
 pub mod entity;
+pub mod index;
 pub mod message;
 pub mod package;
 pub mod player;
+pub mod timer;
 
 pub fn unsupported<T>() -> anyhow::Result<T> {
     anyhow::bail!("This function is not supported on this side of the API. Please report this if you were able to access this function.")
@@ -0,0 +1,242 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use ambient_core::game_time;
+use ambient_ecs::{generated::messages::TimerExpired, EntityId, World};
+
+use super::super::{message::MessageExt, module_state, module_timers};
+
+/// The timers scheduled by a module, which are fired by [`update_all`]. Named timers are kept
+/// across reloads.
+#[derive(Clone, Debug, Default)]
+pub struct ModuleTimers {
+    next_id: u64,
+    timers: BTreeMap<u64, Timer>,
+}
+
+#[derive(Clone, Debug)]
+struct Timer {
+    name: Option<String>,
+    /// The game time at which the timer next expires. Guests can schedule timers up to
+    /// `Duration::MAX` away, so this saturates instead of overflowing.
+    expires_at: Duration,
+    repeat: Option<Duration>,
+}
+
+impl ModuleTimers {
+    fn schedule(
+        &mut self,
+        now: Duration,
+        name: Option<String>,
+        after: Duration,
+        repeat: Option<Duration>,
+    ) -> u64 {
+        if let Some(name) = &name {
+            let existing = self
+                .timers
+                .iter_mut()
+                .find(|(_, timer)| timer.name.as_ref() == Some(name));
+            if let Some((&id, timer)) = existing {
+                timer.repeat = repeat;
+                return id;
+            }
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.timers.insert(
+            id,
+            Timer {
+                name,
+                expires_at: now.saturating_add(after),
+                repeat,
+            },
+        );
+        id
+    }
+
+    fn has_expired(&self, now: Duration) -> bool {
+        self.timers.values().any(|timer| timer.expires_at <= now)
+    }
+
+    /// Returns the timers which have expired by `now`, and schedules the next expiry of the
+    /// repeating ones
+    fn expire(&mut self, now: Duration) -> Vec<u64> {
+        let mut fired = Vec::new();
+        self.timers.retain(|&id, timer| {
+            if timer.expires_at > now {
+                return true;
+            }
+            fired.push(id);
+            match timer.repeat {
+                Some(repeat) => {
+                    // Timers that fell behind, such as while the module was loading, only fire once
+                    timer.expires_at = timer
+                        .expires_at
+                        .saturating_add(repeat)
+                        .max(now.saturating_add(repeat));
+                    true
+                }
+                None => false,
+            }
+        });
+        fired
+    }
+
+    /// Drops the unnamed timers when the module is unloaded, as the next version of the module can
+    /// only find its named timers again
+    pub(crate) fn retain_named(&mut self) {
+        self.timers.retain(|_, timer| timer.name.is_some());
+    }
+}
+
+/// Sends a [`TimerExpired`] message to each module whose timers have expired. Modules which are
+/// being reloaded receive the messages for the timers that expired in the meantime once they have
+/// loaded.
+pub(crate) fn update_all(world: &mut World) {
+    let now = *world.resource(game_time());
+    let expired = ambient_ecs::query(module_timers())
+        .incl(module_state())
+        .iter(world, None)
+        .filter(|(_, timers)| timers.has_expired(now))
+        .map(|(id, _)| id)
+        .collect::<Vec<_>>();
+
+    for module_id in expired {
+        let Ok(timers) = world.get_mut(module_id, module_timers()) else {
+            continue;
+        };
+        for timer in timers.expire(now) {
+            TimerExpired::new(timer).run(world, Some(module_id)).ok();
+        }
+    }
+}
+
+pub fn schedule(
+    world: &mut World,
+    module_id: EntityId,
+    name: Option<String>,
+    after: Duration,
+    repeat: Option<Duration>,
+) -> anyhow::Result<u64> {
+    anyhow::ensure!(
+        repeat.map_or(true, |repeat| !repeat.is_zero()),
+        "timers can't repeat every zero seconds"
+    );
+
+    let now = *world.resource(game_time());
+    if !world.has_component(module_id, module_timers()) {
+        world.add_component(module_id, module_timers(), ModuleTimers::default())?;
+    }
+    Ok(world
+        .get_mut(module_id, module_timers())?
+        .schedule(now, name, after, repeat))
+}
+
+pub fn cancel(world: &mut World, module_id: EntityId, timer: u64) -> anyhow::Result<bool> {
+    Ok(world
+        .get_mut(module_id, module_timers())
+        .map(|timers| timers.timers.remove(&timer).is_some())
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::{Entity, WorldContext};
+
+    use super::*;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    fn world_at(now: Duration) -> World {
+        ambient_ecs::init_components();
+        crate::shared::init_all_components();
+        let mut world = World::new("timers", WorldContext::Server);
+        world.add_resource(game_time(), now);
+        world
+    }
+
+    #[test]
+    fn one_shot_timers_fire_once() {
+        let mut timers = ModuleTimers::default();
+        let id = timers.schedule(Duration::ZERO, None, SECOND, None);
+
+        assert!(!timers.has_expired(SECOND / 2));
+        assert!(timers.expire(SECOND / 2).is_empty());
+        assert!(timers.has_expired(SECOND));
+        assert_eq!(timers.expire(SECOND), [id]);
+        assert!(timers.expire(SECOND * 10).is_empty());
+    }
+
+    #[test]
+    fn repeating_timers_fire_every_interval() {
+        let mut timers = ModuleTimers::default();
+        let id = timers.schedule(Duration::ZERO, None, SECOND, Some(SECOND * 2));
+
+        assert_eq!(timers.expire(SECOND), [id]);
+        assert!(timers.expire(SECOND * 2).is_empty());
+        assert_eq!(timers.expire(SECOND * 3), [id]);
+        assert_eq!(timers.expire(SECOND * 5), [id]);
+    }
+
+    #[test]
+    fn repeating_timers_fire_once_after_a_stall() {
+        let mut timers = ModuleTimers::default();
+        let id = timers.schedule(Duration::ZERO, None, SECOND, Some(SECOND));
+
+        // Ten intervals pass in a single frame
+        assert_eq!(timers.expire(SECOND * 10), [id]);
+        // The timer continues from the end of the stall instead of catching up
+        assert!(timers.expire(SECOND * 10 + SECOND / 2).is_empty());
+        assert_eq!(timers.expire(SECOND * 11), [id]);
+    }
+
+    #[test]
+    fn named_timers_are_reused() {
+        let mut timers = ModuleTimers::default();
+        let named = timers.schedule(Duration::ZERO, Some("tick".into()), SECOND, None);
+        let unnamed = timers.schedule(Duration::ZERO, None, SECOND, None);
+
+        // Scheduling the same name again returns the running timer, with the new repeat
+        let again = timers.schedule(SECOND / 2, Some("tick".into()), SECOND * 5, Some(SECOND));
+        assert_eq!(again, named);
+        assert_ne!(unnamed, named);
+
+        // Only named timers are kept for the next version of a module
+        timers.retain_named();
+        assert_eq!(timers.expire(SECOND), [named]);
+        assert_eq!(timers.expire(SECOND * 2), [named]);
+    }
+
+    #[test]
+    fn timers_at_the_maximum_duration_do_not_overflow() {
+        let mut timers = ModuleTimers::default();
+        timers.schedule(SECOND, None, Duration::MAX, None);
+        let repeating = timers.schedule(Duration::ZERO, None, SECOND, Some(Duration::MAX));
+
+        assert_eq!(timers.expire(SECOND), [repeating]);
+        // Neither timer can expire again, as their expiry saturates at the maximum duration
+        assert!(timers.expire(SECOND * 1000).is_empty());
+        assert!(!timers.has_expired(Duration::MAX - SECOND));
+    }
+
+    #[test]
+    fn timers_are_scheduled_and_cancelled_on_the_module() {
+        let mut world = world_at(SECOND);
+        let module_id = Entity::new().spawn(&mut world);
+
+        assert!(schedule(&mut world, module_id, None, SECOND, Some(Duration::ZERO)).is_err());
+        let first = schedule(&mut world, module_id, None, SECOND, None).unwrap();
+        let second = schedule(&mut world, module_id, None, SECOND, None).unwrap();
+        assert_ne!(first, second);
+
+        assert!(cancel(&mut world, module_id, first).unwrap());
+        assert!(!cancel(&mut world, module_id, first).unwrap());
+
+        let timers = world.get_mut(module_id, module_timers()).unwrap();
+        // The timer expires relative to the game time it was scheduled at
+        assert_eq!(timers.expire(SECOND * 2), [second]);
+        // Modules without timers have nothing to cancel
+        let other = Entity::new().spawn(&mut world);
+        assert!(!cancel(&mut world, other, second).unwrap());
+    }
+}
@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// How often the epoch of the engine is incremented, which is the granularity of
+/// [`ModuleLimits::max_run_time`]
+pub const EPOCH_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The resources a module may use before it is unloaded. Limits that are `None` are not enforced,
+/// which is the default. They are usually set from the `[limits]` section of the package manifest.
+///
+/// The run time and memory limits are only enforced on native.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModuleLimits {
+    /// How long a single call into the module, such as handling a message, may run for
+    pub max_run_time: Option<Duration>,
+    /// How large the linear memory of the module may grow, in bytes
+    pub max_memory: Option<usize>,
+    /// How many entities the module may spawn in a single frame
+    pub max_spawned_entities_per_frame: Option<usize>,
+}
+
+impl ModuleLimits {
+    /// The number of epochs a single call into the module may run for
+    #[cfg(not(target_os = "unknown"))]
+    pub(crate) fn epoch_deadline(&self) -> u64 {
+        match self.max_run_time {
+            Some(time) => (time.as_nanos() / EPOCH_INTERVAL.as_nanos()).max(1) as u64,
+            None => u64::MAX / 2,
+        }
+    }
+}
+
+/// Returned when a module exceeds one of its [`ModuleLimits`]. The module is unloaded when this
+/// happens, as it can't be resumed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BudgetExceeded {
+    RunTime(Duration),
+    Memory(usize),
+    SpawnedEntities(usize),
+}
+
+impl std::fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RunTime(time) => write!(f, "ran for longer than the limit of {time:?}"),
+            Self::Memory(bytes) => write!(f, "used more than the limit of {bytes} bytes of memory"),
+            Self::SpawnedEntities(count) => {
+                write!(
+                    f,
+                    "spawned more than the limit of {count} entities in a frame"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+/// Refuses to grow the memories of a module beyond [`ModuleLimits::max_memory`]
+#[cfg(not(target_os = "unknown"))]
+pub(crate) struct MemoryLimiter {
+    pub max_memory: Option<usize>,
+}
+
+#[cfg(not(target_os = "unknown"))]
+impl wasm_bridge::ResourceLimiter for MemoryLimiter {
+    fn memory_growing(
+        &mut self,
+        _current: usize,
+        desired: usize,
+        _maximum: Option<usize>,
+    ) -> anyhow::Result<bool> {
+        match self.max_memory {
+            // Failing instead of returning false traps, so that the module does not keep running
+            // with an allocation that it can't handle
+            Some(max_memory) if desired > max_memory => {
+                Err(BudgetExceeded::Memory(max_memory).into())
+            }
+            _ => Ok(true),
+        }
+    }
+
+    fn table_growing(
+        &mut self,
+        _current: u32,
+        _desired: u32,
+        _maximum: Option<u32>,
+    ) -> anyhow::Result<bool> {
+        Ok(true)
+    }
+}
+
+#[cfg(all(test, not(target_os = "unknown")))]
+mod tests {
+    use wasm_bridge::ResourceLimiter;
+
+    use super::*;
+
+    fn limits(max_run_time: Option<Duration>) -> ModuleLimits {
+        ModuleLimits {
+            max_run_time,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn epoch_deadlines_are_counted_in_epoch_intervals() {
+        assert_eq!(limits(Some(Duration::from_secs(1))).epoch_deadline(), 100);
+        assert_eq!(limits(Some(Duration::from_millis(25))).epoch_deadline(), 2);
+        // Run times shorter than an epoch can still run for one
+        assert_eq!(limits(Some(Duration::from_millis(1))).epoch_deadline(), 1);
+        // Without a limit, the deadline is never reached
+        assert!(limits(None).epoch_deadline() > u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn memory_can_grow_up_to_the_limit() {
+        let mut limiter = MemoryLimiter {
+            max_memory: Some(1 << 20),
+        };
+        assert!(limiter.memory_growing(0, 1 << 20, None).unwrap());
+        let err = limiter.memory_growing(0, (1 << 20) + 1, None).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<BudgetExceeded>(),
+            Some(&BudgetExceeded::Memory(1 << 20))
+        );
+
+        let mut limiter = MemoryLimiter { max_memory: None };
+        assert!(limiter.memory_growing(0, usize::MAX, None).unwrap());
+    }
+}
@@ -2,7 +2,9 @@ pub(crate) mod bindings;
 pub(crate) mod engine;
 pub(crate) mod implementation;
 
+mod limits;
 mod module;
+mod profile;
 
 pub mod build;
 pub mod conversion;
@@ -12,8 +14,13 @@ pub mod wit;
 
 pub use ambient_ecs::generated::wasm::components::*;
 use ambient_sys::task::PlatformBoxFuture;
-pub use internal::{messenger, module_bytecode, module_errors, module_state, module_state_maker};
+pub use internal::{
+    messenger, module_bytecode, module_errors, module_indexes, module_limits, module_state,
+    module_state_maker, module_timers,
+};
+pub use limits::*;
 pub use module::*;
+pub use profile::*;
 use tracing::{Instrument, Span};
 
 use std::{path::Path, str::FromStr, sync::Arc};
@@ -40,7 +47,10 @@ mod internal {
         components, Debuggable, Description, EntityId, Networked, Resource, Store, World,
     };
 
-    use super::{MessageType, ModuleBytecode, ModuleErrors, ModuleState, ModuleStateMaker};
+    use super::{
+        implementation::{index::ModuleIndexes, timer::ModuleTimers},
+        MessageType, ModuleBytecode, ModuleErrors, ModuleLimits, ModuleState, ModuleStateMaker,
+    };
 
     components!("wasm::shared", {
         module_state: ModuleState,
@@ -48,6 +58,12 @@ mod internal {
         module_bytecode: ModuleBytecode,
         @[Networked, Store, Debuggable]
         module_errors: ModuleErrors,
+        @[Description["The indexes created by a WASM module."]]
+        module_indexes: ModuleIndexes,
+        @[Debuggable, Description["The resources a WASM module may use before it is unloaded. Takes effect when the module is loaded; modules without it are not limited."]]
+        module_limits: ModuleLimits,
+        @[Description["The timers scheduled by a WASM module. Named timers are kept when the module is reloaded, and all of them are removed when it is disabled."]]
+        module_timers: ModuleTimers,
 
         @[Resource, Description["Used to signal messages from the WASM host/runtime."]]
         messenger: Arc<dyn Fn(&World, EntityId, MessageType, &str) + Send + Sync>,
@@ -215,6 +231,42 @@ pub fn systems() -> SystemGroup {
                     );
                 }
             })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module indexes");
+                implementation::index::update_all(world);
+            })),
+            Box::new(FnSystem::new({
+                let mut last_profiled = ambient_sys::time::Instant::now();
+                move |world, _| {
+                    profiling::scope!("WASM module frame start");
+                    // Profiles are only attached to the modules when asked for, as they change
+                    // every frame and are synchronized to the clients
+                    let interval = world.resource_opt(module_profiling_interval()).copied();
+                    let write_profiles =
+                        interval.is_some_and(|interval| last_profiled.elapsed() >= interval);
+                    let profiles = query(module_state())
+                        .iter(world, None)
+                        .filter_map(|(id, state)| {
+                            let mut state = state.clone();
+                            let profile = (interval.is_none() || write_profiles)
+                                .then(|| state.take_profile());
+                            state.start_frame();
+                            Some((id, profile?))
+                        })
+                        .collect_vec();
+
+                    if write_profiles {
+                        last_profiled = ambient_sys::time::Instant::now();
+                        for (id, profile) in profiles {
+                            profile.write_to(world, id);
+                        }
+                    }
+                }
+            })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module timers");
+                implementation::timer::update_all(world);
+            })),
             Box::new(FnSystem::new(move |world, _| {
                 profiling::scope!("WASM module frame event");
                 // trigger frame event
@@ -284,10 +336,14 @@ pub(crate) fn reload_all(world: &mut World) {
 fn reload(world: &mut World, module_id: EntityId, new_bytecode: Option<ModuleBytecode>) {
     unload(world, module_id, "reloading");
 
-    if let Some(new_bytecode) = new_bytecode {
-        if !new_bytecode.0.is_empty() {
+    match new_bytecode {
+        Some(new_bytecode) if !new_bytecode.0.is_empty() => {
             load(world, module_id, &new_bytecode.0);
         }
+        // The named timers are only kept for the new version of the module
+        _ => {
+            world.remove_component(module_id, module_timers()).ok();
+        }
     }
 }
 
@@ -303,6 +359,7 @@ fn load(world: &mut World, id: EntityId, component_bytecode: &[u8]) {
         .get_ref(id, module_name())
         .map(|x| x.clone())
         .unwrap_or_else(|_| "Unknown".to_string());
+    let limits = world.get_cloned(id, module_limits()).unwrap_or_default();
 
     let _span = tracing::info_span!("load_module").entered();
 
@@ -328,6 +385,7 @@ fn load(world: &mut World, id: EntityId, component_bytecode: &[u8]) {
                 messenger(world, id, MessageType::Stderr, msg);
             }),
             id,
+            limits,
             #[cfg(not(target_os = "unknown"))]
             preopened_dir,
         })
@@ -410,12 +468,23 @@ fn run(
         return;
     }
 
-    let result =
-        run_and_catch_panics(|| state.run(world, message_source, message_name, message_data));
+    let mut budget_exceeded = None;
+    let result = run_and_catch_panics(|| {
+        state
+            .run(world, message_source, message_name, message_data)
+            .map_err(|err| {
+                budget_exceeded = err.downcast_ref::<BudgetExceeded>().cloned();
+                err
+            })
+    });
 
     if let Err(message) = result {
         update_errors(world, &[(id, message)]);
     }
+    // The module can't be resumed after it was interrupted
+    if let Some(budget_exceeded) = budget_exceeded {
+        unload(world, id, &format!("the module {budget_exceeded}"));
+    }
 }
 
 pub(crate) fn unload(world: &mut World, module_id: EntityId, reason: &str) {
@@ -446,6 +515,21 @@ pub(crate) fn unload(world: &mut World, module_id: EntityId, reason: &str) {
     }
 
     world.remove_component(module_id, module_state()).unwrap();
+    world.remove_component(module_id, module_indexes()).ok();
+    if let Ok(timers) = world.get_mut(module_id, module_timers()) {
+        timers.retain_named();
+    }
+    world
+        .remove_components(
+            module_id,
+            vec![
+                module_execution_time().desc(),
+                module_message_times().desc(),
+                module_host_call_counts().desc(),
+                module_host_call_times().desc(),
+            ],
+        )
+        .ok();
 
     for id in spawned_entities {
         if !world.has_component(id, dont_despawn_on_unload()) {
@@ -482,3 +566,92 @@ fn run_and_catch_panics<R>(f: impl FnOnce() -> anyhow::Result<R>) -> Result<R, S
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Mutex};
+
+    use ambient_ecs::Entity;
+
+    use super::*;
+
+    /// A module which exceeds its budget whenever it is run
+    struct OverBudget {
+        spawned_entities: HashSet<EntityId>,
+        exceeded: bool,
+    }
+
+    impl ModuleStateBehavior for OverBudget {
+        fn run(
+            &mut self,
+            _world: &mut World,
+            _message_source: &WorldEventSource,
+            _message_name: &str,
+            _message_data: &[u8],
+        ) -> anyhow::Result<()> {
+            self.exceeded = true;
+            Err(BudgetExceeded::SpawnedEntities(1).into())
+        }
+
+        fn drain_spawned_entities(&mut self) -> HashSet<EntityId> {
+            std::mem::take(&mut self.spawned_entities)
+        }
+
+        fn start_frame(&mut self) {}
+
+        fn take_profile(&mut self) -> ModuleProfile {
+            ModuleProfile::default()
+        }
+
+        fn listen_to_message(&mut self, _event_name: String) {}
+
+        fn supports_message(&self, _event_name: &str) -> bool {
+            !self.exceeded
+        }
+    }
+
+    #[test]
+    fn modules_which_exceed_their_budget_are_unloaded() {
+        ambient_ecs::init_components();
+        ambient_core::init_all_components();
+        init_all_components();
+        let mut world = World::new("limits", WorldContext::Server);
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        world.add_resource(
+            messenger(),
+            Arc::new({
+                let messages = messages.clone();
+                move |_: &World, _: EntityId, _: MessageType, message: &str| {
+                    messages.lock().unwrap().push(message.to_string())
+                }
+            }),
+        );
+
+        let spawned = Entity::new().spawn(&mut world);
+        let state = ModuleState::from_behavior(OverBudget {
+            spawned_entities: HashSet::from([spawned]),
+            exceeded: false,
+        });
+        let id = Entity::new()
+            .with(is_module(), ())
+            .with(module_errors(), Default::default())
+            .with(module_state(), state.clone())
+            .spawn(&mut world);
+
+        run(
+            &mut world,
+            id,
+            state,
+            &WorldEventSource::Runtime,
+            "Frame",
+            &[],
+        );
+
+        assert!(!world.has_component(id, module_state()));
+        assert!(!world.exists(spawned));
+        assert!(messages.lock().unwrap().contains(
+            &"Unloaded (reason: the module spawned more than the limit of 1 entities in a frame)"
+                .to_string()
+        ));
+    }
+}
@@ -4,7 +4,7 @@ use super::engine::EngineKey;
 #[cfg(feature = "wit")]
 use super::ModuleStateMaker;
 use super::{bindings::BindingsBound, conversion::IntoBindgen};
-use super::{ModuleStateMaker, WorldEventSource};
+use super::{HostCallProfiler, ModuleLimits, ModuleProfile, ModuleStateMaker, WorldEventSource};
 use ambient_ecs::{EntityId, World};
 use ambient_native_std::asset_cache::{AssetCache, SyncAssetKeyExt};
 use ambient_sys::task::PlatformBoxFuture;
@@ -89,6 +89,9 @@ struct BindingContext<Bindings: BindingsBound> {
     bindings: Bindings,
     wasi: WasiCtx,
     table: Table,
+    #[cfg(not(target_os = "unknown"))]
+    memory_limiter: super::MemoryLimiter,
+    profiler: HostCallProfiler,
 }
 
 impl<B: BindingsBound> preview2::WasiView for BindingContext<B> {
@@ -118,6 +121,10 @@ pub trait ModuleStateBehavior: Send + Sync {
         message_data: &[u8],
     ) -> anyhow::Result<()>;
     fn drain_spawned_entities(&mut self) -> HashSet<EntityId>;
+    /// Resets the budgets which are counted per frame, and counts the frame in the profile
+    fn start_frame(&mut self);
+    /// Returns the profile of the frames since it was last taken
+    fn take_profile(&mut self) -> ModuleProfile;
     fn listen_to_message(&mut self, event_name: String);
     fn supports_message(&self, event_name: &str) -> bool;
 }
@@ -129,6 +136,7 @@ pub struct ModuleStateArgs<'a> {
     pub stdout_output: Messenger,
     pub stderr_output: Messenger,
     pub id: EntityId,
+    pub limits: ModuleLimits,
     #[cfg(not(target_os = "unknown"))]
     /// Makes the `data` directory available during development
     pub preopened_dir: Option<wasi_cap_std_sync::Dir>,
@@ -154,6 +162,13 @@ impl ModuleState {
         })
     }
 
+    #[cfg(test)]
+    pub(crate) fn from_behavior(behavior: impl ModuleStateBehavior + 'static) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(behavior)),
+        }
+    }
+
     pub fn create_state_maker<Bindings: BindingsBound + 'static>(
         assets: &AssetCache,
         bindings: Arc<dyn Fn(EntityId) -> Bindings + Send + Sync>,
@@ -187,6 +202,14 @@ impl ModuleStateBehavior for ModuleState {
         self.inner.write().drain_spawned_entities()
     }
 
+    fn start_frame(&mut self) {
+        self.inner.write().start_frame()
+    }
+
+    fn take_profile(&mut self) -> ModuleProfile {
+        self.inner.write().take_profile()
+    }
+
     fn listen_to_message(&mut self, message_name: String) {
         self.inner.write().listen_to_message(message_name)
     }
@@ -196,6 +219,51 @@ impl ModuleStateBehavior for ModuleState {
     }
 }
 
+/// Adds the host functions to the linker, counting the calls to each interface
+fn add_to_linker<Bindings: BindingsBound>(
+    linker: &mut wasm_bridge::component::Linker<BindingContext<Bindings>>,
+) -> anyhow::Result<()> {
+    macro_rules! add_interfaces {
+        ($($interface:ident: $name:literal),* $(,)?) => {
+            $(
+                shared::wit::$interface::add_to_linker(linker, |x| {
+                    x.profiler.begin($name);
+                    &mut x.bindings
+                })?;
+            )*
+        };
+    }
+
+    add_interfaces!(
+        types: "types",
+        asset: "asset",
+        component: "component",
+        entity: "entity",
+        message: "message",
+        player: "player",
+        ambient_package: "ambient-package",
+        timer: "timer",
+        client_message: "client-message",
+        client_player: "client-player",
+        client_input: "client-input",
+        client_camera: "client-camera",
+        client_clipboard: "client-clipboard",
+        client_window: "client-window",
+        client_mesh: "client-mesh",
+        client_texture: "client-texture",
+        client_sampler: "client-sampler",
+        client_material: "client-material",
+        client_audio: "client-audio",
+        server_asset: "server-asset",
+        server_physics: "server-physics",
+        server_message: "server-message",
+        server_http: "server-http",
+        server_storage: "server-storage",
+        server_ambient_package: "server-ambient-package",
+    );
+    Ok(())
+}
+
 #[cfg(target_os = "unknown")]
 use wasm_bridge_js::component::{self, Instance};
 
@@ -212,6 +280,12 @@ struct InstanceState<Bindings: BindingsBound> {
 
     stdout_consumer: WasiOutputStreamConsumer,
     stderr_consumer: WasiOutputStreamConsumer,
+
+    #[cfg(not(target_os = "unknown"))]
+    limits: ModuleLimits,
+    /// Set when a call was interrupted for exceeding the limits, after which the instance can't be
+    /// entered again
+    budget_exceeded: bool,
 }
 
 impl<Bindings: BindingsBound> std::fmt::Debug for InstanceState<Bindings> {
@@ -226,7 +300,9 @@ impl<Bindings: BindingsBound> InstanceState<Bindings> {
         args: ModuleStateArgs<'_>,
         bindings: Arc<dyn Fn(EntityId) -> Bindings + Send + Sync>,
     ) -> anyhow::Result<Self> {
-        let bindings = bindings(args.id);
+        let mut bindings = bindings(args.id);
+        bindings.base_mut().max_spawned_entities_per_frame =
+            args.limits.max_spawned_entities_per_frame;
 
         let engine = EngineKey
             .get(assets)
@@ -252,8 +328,24 @@ impl<Bindings: BindingsBound> InstanceState<Bindings> {
                 wasi,
                 bindings,
                 table,
+                #[cfg(not(target_os = "unknown"))]
+                memory_limiter: super::MemoryLimiter {
+                    max_memory: args.limits.max_memory,
+                },
+                profiler: HostCallProfiler::default(),
             },
         );
+        #[cfg(not(target_os = "unknown"))]
+        {
+            store.limiter(|x| &mut x.memory_limiter);
+            store.set_epoch_deadline(args.limits.epoch_deadline());
+            store.call_hook(|x, hook| {
+                if let wasm_bridge::CallHook::ReturningFromHost = hook {
+                    x.profiler.end();
+                }
+                Ok(())
+            });
+        }
 
         // let mut store = wasmtime::Store::new(
         //     engine,
@@ -271,7 +363,7 @@ impl<Bindings: BindingsBound> InstanceState<Bindings> {
         let component = {
             preview2::command::add_to_linker(&mut linker)?;
 
-            shared::wit::Bindings::add_to_linker(&mut linker, |x| &mut x.bindings)?;
+            add_to_linker(&mut linker)?;
 
             // Browsers won't compile larger wasm modules synchronously to avoid locking up the browser
             component::Component::new_async(engine.inner(), args.component_bytecode).await?
@@ -281,7 +373,7 @@ impl<Bindings: BindingsBound> InstanceState<Bindings> {
         let component = tokio::task::block_in_place(|| -> anyhow::Result<_> {
             preview2::command::sync::add_to_linker(&mut linker)?;
 
-            shared::wit::Bindings::add_to_linker(&mut linker, |x| &mut x.bindings)?;
+            add_to_linker(&mut linker)?;
 
             component::Component::new(engine.inner(), args.component_bytecode)
         })?;
@@ -305,6 +397,10 @@ impl<Bindings: BindingsBound> InstanceState<Bindings> {
 
             stdout_consumer,
             stderr_consumer,
+
+            #[cfg(not(target_os = "unknown"))]
+            limits: args.limits,
+            budget_exceeded: false,
         })
     }
 }
@@ -350,6 +446,9 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for InstanceState<Bindings> {
         message_data: &[u8],
     ) -> anyhow::Result<()> {
         self.store.data_mut().bindings.set_world(world);
+        #[cfg(not(target_os = "unknown"))]
+        self.store.set_epoch_deadline(self.limits.epoch_deadline());
+        let start = ambient_sys::time::Instant::now();
 
         let guest = &self.guest_bindings.ambient_bindings_guest();
         let result = guest.call_exec(
@@ -368,7 +467,28 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for InstanceState<Bindings> {
             message_data,
         );
 
-        self.store.data_mut().bindings.clear_world();
+        #[cfg(not(target_os = "unknown"))]
+        let result = result.map_err(|err| {
+            match (
+                err.downcast_ref::<wasm_bridge::Trap>(),
+                self.limits.max_run_time,
+            ) {
+                (Some(wasm_bridge::Trap::Interrupt), Some(max_run_time)) => {
+                    super::BudgetExceeded::RunTime(max_run_time).into()
+                }
+                _ => err,
+            }
+        });
+        if let Err(err) = &result {
+            self.budget_exceeded |= err.downcast_ref::<super::BudgetExceeded>().is_some();
+        }
+
+        let context = self.store.data_mut();
+        context.bindings.clear_world();
+        context
+            .profiler
+            .profile
+            .add_message(message_name, start.elapsed());
 
         self.stdout_consumer.process_incoming(world);
         self.stderr_consumer.process_incoming(world);
@@ -380,6 +500,16 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for InstanceState<Bindings> {
         std::mem::take(&mut self.store.data_mut().bindings.base_mut().spawned_entities)
     }
 
+    fn start_frame(&mut self) {
+        let context = self.store.data_mut();
+        context.bindings.base_mut().spawned_this_frame = 0;
+        context.profiler.profile.frames += 1;
+    }
+
+    fn take_profile(&mut self) -> ModuleProfile {
+        std::mem::take(&mut self.store.data_mut().profiler.profile)
+    }
+
     fn listen_to_message(&mut self, event_name: String) {
         self.store
             .data_mut()
@@ -390,12 +520,14 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for InstanceState<Bindings> {
     }
 
     fn supports_message(&self, event_name: &str) -> bool {
-        self.store
-            .data()
-            .bindings
-            .base()
-            .subscribed_messages
-            .contains(event_name)
+        !self.budget_exceeded
+            && self
+                .store
+                .data()
+                .bindings
+                .base()
+                .subscribed_messages
+                .contains(event_name)
     }
 }
 
@@ -0,0 +1,197 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use ambient_ecs::{Entity, EntityId, World};
+use ambient_sys::time::Instant;
+
+use super::{
+    module_execution_time, module_host_call_counts, module_host_call_times, module_message_times,
+};
+
+/// What a module spent its time on during the frames since the profile was last taken
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModuleProfile {
+    pub frames: u32,
+    pub execution_time: Duration,
+    pub message_times: BTreeMap<String, Duration>,
+    pub host_call_counts: BTreeMap<&'static str, u32>,
+    pub host_call_times: BTreeMap<&'static str, Duration>,
+}
+
+impl ModuleProfile {
+    pub(crate) fn add_message(&mut self, name: &str, time: Duration) {
+        self.execution_time += time;
+        match self.message_times.get_mut(name) {
+            Some(total) => *total += time,
+            None => {
+                self.message_times.insert(name.to_string(), time);
+            }
+        }
+    }
+
+    /// Averages the profile over its frames, and attaches it to the module as components so that
+    /// it can be inspected by tools
+    pub(crate) fn write_to(self, world: &mut World, module_id: EntityId) {
+        let frames = self.frames.max(1);
+        let per_frame = |time: Duration| time / frames;
+
+        world
+            .add_components(
+                module_id,
+                Entity::new()
+                    .with(module_execution_time(), per_frame(self.execution_time))
+                    .with(
+                        module_message_times(),
+                        self.message_times
+                            .into_iter()
+                            .map(|(name, time)| (name, per_frame(time)))
+                            .collect(),
+                    )
+                    .with(
+                        module_host_call_counts(),
+                        self.host_call_counts
+                            .into_iter()
+                            .map(|(interface, count)| (interface.to_string(), count / frames))
+                            .collect(),
+                    )
+                    .with(
+                        module_host_call_times(),
+                        self.host_call_times
+                            .into_iter()
+                            .map(|(interface, time)| (interface.to_string(), per_frame(time)))
+                            .collect(),
+                    ),
+            )
+            .ok();
+    }
+}
+
+/// Counts and times the calls a module makes to the host, by the WIT interface that was called
+#[derive(Default)]
+pub(crate) struct HostCallProfiler {
+    pub profile: ModuleProfile,
+    current: Option<HostCall>,
+}
+
+#[cfg_attr(target_os = "unknown", allow(dead_code))]
+struct HostCall {
+    interface: &'static str,
+    start: Instant,
+    scope: Option<usize>,
+}
+
+impl HostCallProfiler {
+    /// Called by the host functions of `interface` when they are entered
+    pub fn begin(&mut self, interface: &'static str) {
+        *self.profile.host_call_counts.entry(interface).or_default() += 1;
+
+        // The end of host calls can only be observed on native
+        #[cfg(not(target_os = "unknown"))]
+        if self.current.is_none() {
+            let scope = profiling::puffin::are_scopes_on().then(|| {
+                profiling::puffin::ThreadProfiler::call(|tp| {
+                    tp.begin_scope(interface, "wasm host call", "")
+                })
+            });
+            self.current = Some(HostCall {
+                interface,
+                start: Instant::now(),
+                scope,
+            });
+        }
+    }
+
+    /// Called when the host returns to the module
+    pub fn end(&mut self) {
+        let Some(call) = self.current.take() else {
+            return;
+        };
+        *self
+            .profile
+            .host_call_times
+            .entry(call.interface)
+            .or_default() += call.start.elapsed();
+        if let Some(scope) = call.scope {
+            profiling::puffin::ThreadProfiler::call(|tp| tp.end_scope(scope));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::WorldContext;
+
+    use super::*;
+
+    #[test]
+    fn host_calls_are_counted_and_timed_by_interface() {
+        let mut profiler = HostCallProfiler::default();
+
+        profiler.begin("entity");
+        // Calls made while handling another call are counted, but only the outermost one is timed
+        profiler.begin("message");
+        profiler.end();
+        // Ending a call that was never begun does nothing
+        profiler.end();
+        profiler.begin("entity");
+        profiler.end();
+
+        let profile = &profiler.profile;
+        assert_eq!(profile.host_call_counts.get("entity"), Some(&2));
+        assert_eq!(profile.host_call_counts.get("message"), Some(&1));
+        assert_eq!(
+            profile.host_call_times.keys().copied().collect::<Vec<_>>(),
+            ["entity"]
+        );
+    }
+
+    #[test]
+    fn messages_add_up_to_the_execution_time() {
+        let mut profile = ModuleProfile::default();
+
+        profile.add_message("Frame", Duration::from_millis(2));
+        profile.add_message("ModuleLoad", Duration::from_millis(5));
+        profile.add_message("Frame", Duration::from_millis(3));
+
+        assert_eq!(profile.execution_time, Duration::from_millis(10));
+        assert_eq!(
+            profile.message_times,
+            BTreeMap::from([
+                ("Frame".to_string(), Duration::from_millis(5)),
+                ("ModuleLoad".to_string(), Duration::from_millis(5)),
+            ])
+        );
+    }
+
+    #[test]
+    fn written_profiles_are_averaged_over_their_frames() {
+        ambient_ecs::init_components();
+        let mut world = World::new("profile", WorldContext::Server);
+        let id = Entity::new().spawn(&mut world);
+
+        let mut profile = ModuleProfile {
+            frames: 4,
+            host_call_counts: BTreeMap::from([("entity", 10)]),
+            host_call_times: BTreeMap::from([("entity", Duration::from_millis(8))]),
+            ..Default::default()
+        };
+        profile.add_message("Frame", Duration::from_millis(20));
+        profile.write_to(&mut world, id);
+
+        assert_eq!(
+            world.get(id, module_execution_time()),
+            Ok(Duration::from_millis(5))
+        );
+        assert_eq!(
+            world.get_cloned(id, module_message_times()).unwrap(),
+            BTreeMap::from([("Frame".to_string(), Duration::from_millis(5))])
+        );
+        assert_eq!(
+            world.get_cloned(id, module_host_call_counts()).unwrap(),
+            BTreeMap::from([("entity".to_string(), 2)])
+        );
+        assert_eq!(
+            world.get_cloned(id, module_host_call_times()).unwrap(),
+            BTreeMap::from([("entity".to_string(), Duration::from_millis(2))])
+        );
+    }
+}
@@ -1,10 +1,14 @@
 mod error;
 mod events;
 mod graph;
+mod playback;
 mod sounds;
+mod spatial_sounds;
 pub mod systems;
 pub use ambient_audio as core;
 pub use error::*;
 pub use events::*;
 pub use graph::*;
+pub use playback::*;
 pub use sounds::*;
+pub use spatial_sounds::*;
@@ -0,0 +1,206 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use ambient_audio::{Attenuation, AudioEmitter, AudioFromUrl, AudioListener, Playback, Source};
+use ambient_core::{
+    asset_cache,
+    async_ecs::async_run,
+    runtime,
+    transform::{local_to_world, translation},
+};
+use ambient_ecs::{generated::audio::components::playing_sound, Entity, EntityId, World};
+use ambient_native_std::{asset_cache::AsyncAssetKeyExt, asset_url::AbsAssetUrl};
+use anyhow::Context;
+use glam::Vec3;
+use parking_lot::Mutex;
+
+use crate::{
+    audio_emitter, audio_listener, audio_mixer, hrtf_lib, playback_control, sound_id,
+    systems::Y_UP_LHS,
+};
+
+/// How a sound started with [`play_sound`] is played
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundOptions {
+    pub looping: bool,
+    pub amplitude: f32,
+    /// Ignored for spatial sounds
+    pub panning: f32,
+    /// The position in the sound to start playing from
+    pub start: Duration,
+    /// Fades the sound in from silence over this duration
+    pub fade_in: Duration,
+    /// Plays the sound from the first entity, as heard by the second entity
+    pub spatial: Option<(EntityId, EntityId)>,
+}
+
+impl Default for SoundOptions {
+    fn default() -> Self {
+        Self {
+            looping: false,
+            amplitude: 1.0,
+            panning: 0.0,
+            start: Duration::ZERO,
+            fade_in: Duration::ZERO,
+            spatial: None,
+        }
+    }
+}
+
+/// Plays the audio at `url`, returning an entity which represents the sound instance.
+///
+/// The entity receives a [`playback_control`] once the audio has loaded, which can be used to
+/// query and change the playback. When the sound finishes, or is stopped, the entity is
+/// despawned and `on_finished` is called.
+pub fn play_sound(
+    world: &mut World,
+    url: &str,
+    options: SoundOptions,
+    on_finished: impl FnOnce(&mut World, EntityId) + Send + Sync + 'static,
+) -> anyhow::Result<EntityId> {
+    // Audio is muted
+    if world.resource_opt(audio_mixer()).is_none() {
+        anyhow::bail!("Audio is disabled");
+    }
+
+    let assets = world.resource(asset_cache()).clone();
+    let runtime = world.resource(runtime()).clone();
+    let async_run = world.resource(async_run()).clone();
+    let url = AbsAssetUrl::from_str(url).and_then(|u| u.to_download_url(&assets))?;
+
+    let id = Entity::new().with(playing_sound(), ()).spawn(world);
+
+    runtime.clone().spawn(async move {
+        let track = match (AudioFromUrl { url: url.clone() }).get(&assets).await {
+            Ok(track) => track,
+            Err(err) => {
+                tracing::warn!("Failed to load audio {url}: {err:?}");
+                async_run.run(move |world| {
+                    world.despawn(id);
+                    on_finished(world, id);
+                });
+                return;
+            }
+        };
+
+        // Seeking decodes up to the start position, so do it here rather than on the main thread
+        let initial_amplitude = if options.fade_in.is_zero() {
+            options.amplitude
+        } else {
+            0.0
+        };
+        let playback = Playback::new(track.decode(), options.looping, initial_amplitude);
+        let control = playback.control();
+        if !options.start.is_zero() {
+            control.seek(options.start);
+        }
+        if !options.fade_in.is_zero() {
+            control.fade(options.amplitude, options.fade_in);
+        }
+        let playback = Mutex::new(playback);
+
+        async_run.run(move |world| {
+            // Stopped before the audio finished loading
+            if !world.exists(id) {
+                on_finished(world, id);
+                return;
+            }
+
+            let playback = playback.into_inner();
+            let source: Box<dyn Source> = match options.spatial {
+                Some((emitter, listener)) => {
+                    let (emitter, listener) = spatial_handles(world, emitter, listener);
+                    let hrtf_lib = world.resource(hrtf_lib());
+                    Box::new(playback.spatial(hrtf_lib, listener, emitter))
+                }
+                None => playback.pan(options.panning),
+            };
+
+            let sound = world.resource(audio_mixer()).play(source);
+            let finished = sound.wait();
+            let _ = world.add_component(id, sound_id(), sound.id);
+            let _ = world.add_component(id, playback_control(), control);
+
+            let finish_run = async_run.clone();
+            runtime.spawn(async move {
+                finished.await;
+                finish_run.run(move |world| {
+                    world.despawn(id);
+                    on_finished(world, id);
+                });
+            });
+        });
+    });
+
+    Ok(id)
+}
+
+/// Stops a sound started with [`play_sound`]
+pub fn stop_sound(world: &mut World, id: EntityId) {
+    match world.get(id, sound_id()) {
+        // The entity is despawned once the mixer has dropped the sound
+        Ok(sound) => world.resource(audio_mixer()).stop(sound),
+        // Still loading
+        Err(_) => {
+            world.despawn(id);
+        }
+    }
+}
+
+/// Moves a sound started with [`play_sound`] to `position`.
+///
+/// The sound has to be decoded up to `position`, so this is done in the background, and the
+/// sound keeps playing from where it was until then.
+pub fn seek_sound(world: &World, id: EntityId, position: Duration) -> anyhow::Result<()> {
+    let control = world
+        .get_ref(id, playback_control())
+        .context("The sound has not started playing")?
+        .clone();
+    world
+        .resource(runtime())
+        .spawn_blocking(move || control.seek(position));
+    Ok(())
+}
+
+/// Returns the emitter and listener of the given entities, adding them if they are not already
+/// emitting or listening
+fn spatial_handles(
+    world: &mut World,
+    emitter_id: EntityId,
+    listener_id: EntityId,
+) -> (Arc<Mutex<AudioEmitter>>, Arc<Mutex<AudioListener>>) {
+    let emitter = match world.get_ref(emitter_id, audio_emitter()) {
+        Ok(emitter) => emitter.clone(),
+        Err(_) => {
+            let pos = match world.get(emitter_id, local_to_world()) {
+                Ok(ltw) => ltw.to_scale_rotation_translation().2,
+                Err(_) => world.get(emitter_id, translation()).unwrap_or(Vec3::ZERO),
+            };
+            let emitter = Arc::new(Mutex::new(AudioEmitter {
+                amplitude: 1.0,
+                attenuation: Attenuation::InversePoly {
+                    quad: 0.1,
+                    lin: 0.0,
+                    constant: 1.0,
+                },
+                pos,
+            }));
+            let _ = world.add_component(emitter_id, audio_emitter(), emitter.clone());
+            emitter
+        }
+    };
+
+    let listener = match world.get_ref(listener_id, audio_listener()) {
+        Ok(listener) => listener.clone(),
+        Err(_) => {
+            let transform = world.get(listener_id, local_to_world()).unwrap_or_default();
+            let listener = Arc::new(Mutex::new(AudioListener::new(
+                Y_UP_LHS * transform,
+                Vec3::X * 0.3,
+            )));
+            let _ = world.add_component(listener_id, audio_listener(), listener.clone());
+            listener
+        }
+    };
+
+    (emitter, listener)
+}
@@ -3,7 +3,8 @@ use std::sync::Arc;
 use ambient_audio::{
     hrtf::HrtfLib,
     track::{Track, TrackDecodeStream},
-    Attenuation, AudioEmitter, AudioListener, AudioMixer, Sound, SoundId, Source, Spatial,
+    Attenuation, AudioEmitter, AudioListener, AudioMixer, PlaybackControl, Sound, SoundId, Source,
+    Spatial,
 };
 use ambient_ecs::{components, query, EntityId, Resource, World};
 use ambient_element::ElementComponentExt;
@@ -20,6 +21,8 @@ use parking_lot::Mutex;
 
 use serde::{Deserialize, Serialize};
 
+use crate::SpatialSoundInstance;
+
 components!("audio", {
     @[Resource]
     hrtf_lib: Arc<HrtfLib>,
@@ -34,6 +37,9 @@ components!("audio", {
     onepole_arc: Arc<Mutex<f32>>,
     looping_arc: Arc<Mutex<bool>>,
     sound_id: SoundId,
+    playback_control: Arc<PlaybackControl>,
+    /// The sound this client plays for the entity's `spatial_sound`
+    spatial_sound_instance: SpatialSoundInstance,
 });
 
 #[derive(Debug, Clone, Copy, PartialEq)]
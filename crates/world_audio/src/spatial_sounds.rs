@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use ambient_core::{camera::get_active_camera, epoch_time, main_scene, player::local_user_id};
+use ambient_ecs::{
+    generated::audio::components::{
+        spatial_sound, spatial_sound_amplitude, spatial_sound_looping, spatial_sound_start_time,
+    },
+    query, EntityId, SystemGroup, World,
+};
+
+use crate::{
+    audio_mixer, play_sound, playback_control, spatial_sound_instance, stop_sound, SoundOptions,
+};
+
+/// How long a change to `spatial_sound_amplitude` is faded over, to avoid clicks
+const AMPLITUDE_FADE: Duration = Duration::from_millis(50);
+
+/// The sound played by this client for an entity's `spatial_sound`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpatialSoundInstance {
+    /// The entity returned by [`play_sound`], or null if the sound could not be played
+    pub sound: EntityId,
+    /// The `spatial_sound_start_time` the sound was played for
+    pub start_time: Option<Duration>,
+}
+
+/// Plays the `spatial_sound`s the server has attached to entities, and stops them once the
+/// server removes them
+pub fn spatial_sound_systems() -> SystemGroup {
+    SystemGroup::new(
+        "audio/spatial_sounds",
+        vec![
+            // There may not be a camera to listen with when the sound arrives, so this waits for one
+            query(spatial_sound())
+                .excl(spatial_sound_instance())
+                .to_system(|q, world, qs, _| {
+                    if world.resource_opt(audio_mixer()).is_none() {
+                        return;
+                    }
+                    let Some(listener) =
+                        get_active_camera(world, main_scene(), world.resource_opt(local_user_id()))
+                    else {
+                        return;
+                    };
+
+                    for (id, url) in q.collect_cloned(world, qs) {
+                        let start_time = world.get(id, spatial_sound_start_time()).ok();
+                        let options = SoundOptions {
+                            looping: world.has_component(id, spatial_sound_looping()),
+                            amplitude: world.get(id, spatial_sound_amplitude()).unwrap_or(1.0),
+                            start: start_time.map_or(Duration::ZERO, |start_time| {
+                                world.resource(epoch_time()).saturating_sub(start_time)
+                            }),
+                            spatial: Some((id, listener)),
+                            ..Default::default()
+                        };
+
+                        // A sound which can't be played is not retried
+                        let sound =
+                            play_sound(world, &url, options, |_, _| {}).unwrap_or_else(|err| {
+                                tracing::warn!("Failed to play spatial sound {url}: {err:?}");
+                                EntityId::null()
+                            });
+                        world
+                            .add_component(
+                                id,
+                                spatial_sound_instance(),
+                                SpatialSoundInstance { sound, start_time },
+                            )
+                            .unwrap();
+                    }
+                }),
+            // The server plays a sound again by changing its start time
+            query((spatial_sound_instance(),))
+                .incl(spatial_sound())
+                .optional_changed(spatial_sound_start_time())
+                .to_system(|q, world, qs, _| {
+                    for (id, (instance,)) in q.collect_cloned(world, qs) {
+                        if world.get(id, spatial_sound_start_time()).ok() != instance.start_time {
+                            stop_instance(world, instance);
+                            world
+                                .remove_component(id, spatial_sound_instance())
+                                .unwrap();
+                        }
+                    }
+                }),
+            query((
+                spatial_sound_instance(),
+                spatial_sound_amplitude().changed(),
+            ))
+            .to_system(|q, world, qs, _| {
+                for (_, (instance, amplitude)) in q.iter(world, qs) {
+                    if let Ok(control) = world.get_ref(instance.sound, playback_control()) {
+                        control.fade(*amplitude, AMPLITUDE_FADE);
+                    }
+                }
+            }),
+            query((spatial_sound_instance(),))
+                .incl(spatial_sound())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (id, (instance,)) in q.collect_cloned(world, qs) {
+                        stop_instance(world, instance);
+                        // Also reached when the instance was removed to restart the sound
+                        if world.has_component(id, spatial_sound_instance()) {
+                            world
+                                .remove_component(id, spatial_sound_instance())
+                                .unwrap();
+                        }
+                    }
+                }),
+        ],
+    )
+}
+
+fn stop_instance(world: &mut World, instance: SpatialSoundInstance) {
+    if !instance.sound.is_null() {
+        stop_sound(world, instance.sound);
+    }
+}
@@ -1,7 +1,10 @@
-use std::{io::Cursor, sync::Arc};
+use std::{io::Cursor, sync::Arc, time::Duration};
 
 use crate::{audio_emitter, audio_listener, hrtf_lib};
-use ambient_audio::{hrtf::HrtfLib, AudioFromUrl, Source};
+use ambient_audio::{
+    hrtf::HrtfLib, AudioFromUrl, ImpulseResponse, ImpulseResponseFromUrl, ReverbParams, SampleRate,
+    Source,
+};
 use ambient_audio::{Attenuation, AudioEmitter, AudioListener};
 use ambient_core::{
     asset_cache,
@@ -14,8 +17,12 @@ use ambient_ecs::{
     generated::audio::components::*, generated::hierarchy::components::children, query,
     SystemGroup, World,
 };
-use ambient_native_std::{asset_cache::AsyncAssetKeyExt, asset_url::AbsAssetUrl, unwrap_log_warn};
-use glam::{vec4, Mat4};
+use ambient_native_std::{
+    asset_cache::{AssetCache, AsyncAssetKeyExt},
+    asset_url::AbsAssetUrl,
+    unwrap_log_warn,
+};
+use glam::{vec4, Mat4, Vec3};
 use parking_lot::Mutex;
 use std::str::FromStr;
 
@@ -43,6 +50,7 @@ pub fn audio_systems() -> SystemGroup {
     SystemGroup::new(
         "audio",
         vec![
+            Box::new(crate::spatial_sound_systems()),
             query(audio_url())
                 .incl(is_spatial_audio_player())
                 .incl(play_now())
@@ -156,6 +164,7 @@ pub fn audio_systems() -> SystemGroup {
                     let pan = world.get(player, panning()).unwrap_or(0.0);
                     let freq = world.get(player, onepole_lpf()).unwrap_or(20000.0);
                     let looping = world.get(player, looping()).unwrap_or(false);
+                    let mut effects = Effects::from_player(world, player);
 
                     world.remove_component(player, play_now()).unwrap();
 
@@ -171,6 +180,7 @@ pub fn audio_systems() -> SystemGroup {
                     runtime.spawn(async move {
                         let track =
                             unwrap_log_warn!(AudioFromUrl { url: url.clone() }.get(&assets).await);
+                        effects.load(&assets, track.decode().sample_rate()).await;
                         let id_arc = Arc::new(Mutex::new(None));
                         let id_arc_clone = id_arc.clone();
                         let count_arc = Arc::new(Mutex::new(None));
@@ -192,14 +202,14 @@ pub fn audio_systems() -> SystemGroup {
                             id_arc.lock().replace(id);
 
                             let mut t: Box<dyn Source> = if looping {
-                                Box::new(track.decode().repeat())
+                                effects.apply(track.decode().repeat())
                             } else {
-                                let decoded = track.decode();
+                                let decoded = effects.apply(track.decode());
                                 let count = decoded.sample_count().unwrap();
                                 let sr = decoded.sample_rate();
                                 *count_arc.lock() = Some(count);
                                 *sr_arc.lock() = Some(sr);
-                                Box::new(decoded)
+                                decoded
                             };
                             let a = Arc::new(Mutex::new(amp));
                             t = t.gain(a.clone());
@@ -250,6 +260,7 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
 
     let amp = world.get(player, amplitude()).unwrap_or(1.0);
     let looping = world.get(player, looping()).unwrap_or(false);
+    let mut effects = Effects::from_player(world, player);
     world.remove_component(player, play_now()).unwrap();
 
     let assets = world.resource(asset_cache()).clone();
@@ -259,6 +270,7 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
 
     runtime.spawn(async move {
         let track = unwrap_log_warn!(AudioFromUrl { url: url.clone() }.get(&assets).await);
+        effects.load(&assets, track.decode().sample_rate()).await;
         async_run.run(move |world| {
             let Ok(listener_id) = world.get(player, spatial_audio_listener()) else {
                 return;
@@ -291,17 +303,89 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
             let hrtf_lib = world.resource(hrtf_lib());
 
             let mixer = world.resource(crate::audio_mixer());
-            let source: Box<dyn Source> = if looping {
-                Box::new(track.decode().repeat().spatial(hrtf_lib, listener, emitter))
+            let source = if looping {
+                effects.apply(track.decode().repeat())
             } else {
-                Box::new(track.decode().spatial(hrtf_lib, listener, emitter))
+                effects.apply(track.decode())
             };
+            let source = source.spatial(hrtf_lib, listener, emitter);
             let sound = mixer.play(source);
             let _ = world.add_component(emitter_id, crate::sound_id(), sound.id);
         });
     });
 }
 
+/// The effects applied to the sounds of an audio player, read from its components
+struct Effects {
+    delay: Option<Vec3>,
+    reverb: Option<Vec3>,
+    convolution_reverb: Option<AbsAssetUrl>,
+    convolution_reverb_mix: f32,
+    impulse_response: Option<Arc<ImpulseResponse>>,
+}
+
+impl Effects {
+    fn from_player(world: &World, player: EntityId) -> Self {
+        let assets = world.resource(asset_cache());
+        Self {
+            delay: world.get(player, delay()).ok(),
+            reverb: world.get(player, reverb()).ok(),
+            convolution_reverb: world
+                .get_ref(player, convolution_reverb())
+                .ok()
+                .and_then(|url| {
+                    match AbsAssetUrl::from_str(url).and_then(|u| u.to_download_url(assets)) {
+                        Ok(url) => Some(url),
+                        Err(err) => {
+                            tracing::warn!("Invalid impulse response url: {err:?}");
+                            None
+                        }
+                    }
+                }),
+            convolution_reverb_mix: world.get(player, convolution_reverb_mix()).unwrap_or(0.5),
+            impulse_response: None,
+        }
+    }
+
+    /// Loads the impulse response for the convolution reverb, if any
+    async fn load(&mut self, assets: &AssetCache, sample_rate: SampleRate) {
+        let Some(url) = self.convolution_reverb.clone() else {
+            return;
+        };
+
+        match (ImpulseResponseFromUrl { url, sample_rate })
+            .get(assets)
+            .await
+        {
+            Ok(ir) => self.impulse_response = Some(ir),
+            Err(err) => tracing::warn!("Failed to load impulse response: {err:?}"),
+        }
+    }
+
+    fn apply(&self, source: impl Source + 'static) -> Box<dyn Source> {
+        let mut source: Box<dyn Source> = Box::new(source);
+        if let Some(delay) = self.delay {
+            source = Box::new(source.feedback_delay(
+                Duration::from_secs_f32(delay.x.max(0.0)),
+                delay.y,
+                delay.z,
+            ));
+        }
+        if let Some(reverb) = self.reverb {
+            source = Box::new(source.reverb(ReverbParams {
+                room_size: reverb.x,
+                damping: reverb.y,
+                mix: reverb.z,
+                ..Default::default()
+            }));
+        }
+        if let Some(ir) = &self.impulse_response {
+            source = Box::new(source.convolution_reverb(ir.clone(), self.convolution_reverb_mix));
+        }
+        source
+    }
+}
+
 pub fn client_systems() -> SystemGroup {
     SystemGroup::new("audio", vec![Box::new(audio_systems())])
 }
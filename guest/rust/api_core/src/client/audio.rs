@@ -6,10 +6,16 @@ use crate::{
         audio::components::*,
         ecs::components::remove_at_game_time,
         hierarchy::components::{children, parent, unmanaged_children},
+        messages::SoundFinished,
         transform::components::translation,
     },
     entity,
-    prelude::{game_time, Entity, EntityId, Vec3},
+    global::{run_async, wait_for_runtime_message},
+    internal::{
+        conversion::{FromBindgen, IntoBindgen},
+        wit,
+    },
+    prelude::{game_time, vec3, Entity, EntityId, Vec3},
 };
 
 /// stop the audio on the given entity
@@ -59,6 +65,30 @@ impl SpatialAudioPlayer {
         entity::add_component(self.player, looping(), val);
     }
 
+    /// Add a feedback delay to the sound. See [`AudioPlayer::set_delay`]
+    pub fn set_delay(&self, delay: Duration, feedback: f32, mix: f32) {
+        entity::add_component(
+            self.player,
+            self::delay(),
+            vec3(delay.as_secs_f32(), feedback, mix),
+        );
+    }
+
+    /// Add room reverb to the sound. See [`AudioPlayer::set_reverb`]
+    pub fn set_reverb(&self, room_size: f32, damping: f32, mix: f32) {
+        entity::add_component(self.player, reverb(), vec3(room_size, damping, mix));
+    }
+
+    /// Reverberate the sound with an impulse response. See [`AudioPlayer::set_convolution_reverb`]
+    pub fn set_convolution_reverb(&self, impulse_response_url: impl Into<String>, mix: f32) {
+        entity::add_component(
+            self.player,
+            convolution_reverb(),
+            impulse_response_url.into(),
+        );
+        entity::add_component(self.player, convolution_reverb_mix(), mix);
+    }
+
     pub fn play_sound_on_entity(&self, url: impl Into<String>, emitter: EntityId) {
         entity::add_component(self.player, spatial_audio_emitter(), emitter);
         entity::add_component(self.player, audio_url(), url.into());
@@ -132,6 +162,30 @@ impl AudioPlayer {
     pub fn set_panning(&self, pan: f32) {
         entity::add_component(self.entity, panning(), pan);
     }
+    /// Add a feedback delay to the sound, which repeats it after `delay`. Each echo is attenuated by
+    /// `feedback`, and `mix` is the balance between the original sound and the echoes.
+    pub fn set_delay(&self, delay: Duration, feedback: f32, mix: f32) {
+        entity::add_component(
+            self.entity,
+            self::delay(),
+            vec3(delay.as_secs_f32(), feedback, mix),
+        );
+    }
+    /// Add room reverb to the sound. `room_size` and `damping` are between 0.0 and 1.0, and `mix`
+    /// is the balance between the original sound and the reverb.
+    pub fn set_reverb(&self, room_size: f32, damping: f32, mix: f32) {
+        entity::add_component(self.entity, reverb(), vec3(room_size, damping, mix));
+    }
+    /// Reverberate the sound with the impulse response at the given URL, such as a recording of a room.
+    /// `mix` is the balance between the original sound and the reverb.
+    pub fn set_convolution_reverb(&self, impulse_response_url: impl Into<String>, mix: f32) {
+        entity::add_component(
+            self.entity,
+            convolution_reverb(),
+            impulse_response_url.into(),
+        );
+        entity::add_component(self.entity, convolution_reverb_mix(), mix);
+    }
     /// Play the sound, this will generate a new entity that represents the playing sound.
     pub fn play(&self, url: String) -> EntityId {
         entity::add_component(self.entity, audio_url(), url);
@@ -147,3 +201,125 @@ impl AudioPlayer {
         id
     }
 }
+
+/// How a [`Sound`] is played
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundOptions {
+    /// Restart the sound when it reaches the end
+    pub looping: bool,
+    /// The amp/volume of the sound. 0.0 is 0%, 1.0 is 100%
+    pub amplitude: f32,
+    /// The panning of the sound. -1.0 is 100% left, 1.0 is 100% right. Ignored for spatial sounds
+    pub panning: f32,
+    /// The position in the sound to start playing from
+    pub start: Duration,
+    /// Fade the sound in from silence over this duration
+    pub fade_in: Duration,
+    /// Play the sound from the `emitter` entity, as heard by the `listener` entity
+    pub spatial: Option<SpatialSound>,
+}
+impl Default for SoundOptions {
+    fn default() -> Self {
+        Self {
+            looping: false,
+            amplitude: 1.0,
+            panning: 0.0,
+            start: Duration::ZERO,
+            fade_in: Duration::ZERO,
+            spatial: None,
+        }
+    }
+}
+
+/// The entities a spatial [`Sound`] is emitted from and heard by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpatialSound {
+    /// The entity the sound is emitted from
+    pub emitter: EntityId,
+    /// The entity the sound is heard by, usually the camera
+    pub listener: EntityId,
+}
+
+/// A sound instance which can be controlled while it plays.
+///
+/// Unlike [`AudioPlayer`], the sound can be seeked and faded, and its position can be queried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sound {
+    /// The entity that represents the playing sound. It is despawned when the sound finishes.
+    pub entity: EntityId,
+}
+impl Sound {
+    /// Start playing the audio file at the given URL.
+    pub fn play(url: impl AsRef<str>, options: SoundOptions) -> Result<Self, String> {
+        let entity = wit::client_audio::play(
+            url.as_ref(),
+            wit::client_audio::PlayOptions {
+                looping: options.looping,
+                amplitude: options.amplitude,
+                panning: options.panning,
+                start_seconds: options.start.as_secs_f32(),
+                fade_in_seconds: options.fade_in.as_secs_f32(),
+                spatial: options.spatial.map(|spatial| wit::client_audio::Spatial {
+                    emitter: spatial.emitter.into_bindgen(),
+                    listener: spatial.listener.into_bindgen(),
+                }),
+            },
+        )?;
+
+        Ok(Self {
+            entity: entity.from_bindgen(),
+        })
+    }
+
+    /// Stop the sound. This will finish the sound.
+    pub fn stop(&self) {
+        wit::client_audio::stop(self.entity.into_bindgen());
+    }
+
+    /// The current position in the sound.
+    ///
+    /// Returns `None` if the sound is still loading, or has finished.
+    pub fn position(&self) -> Option<Duration> {
+        wit::client_audio::get_position(self.entity.into_bindgen()).map(Duration::from_secs_f32)
+    }
+
+    /// The total length of the sound.
+    ///
+    /// Returns `None` if the sound is still loading, or has finished.
+    pub fn duration(&self) -> Option<Duration> {
+        wit::client_audio::get_duration(self.entity.into_bindgen()).map(Duration::from_secs_f32)
+    }
+
+    /// Move the sound to the given position. Has no effect while the sound is still loading.
+    pub fn seek(&self, position: Duration) {
+        wit::client_audio::seek(self.entity.into_bindgen(), position.as_secs_f32());
+    }
+
+    /// Linearly change the amplitude of the sound to `amplitude` over `duration`.
+    /// Has no effect while the sound is still loading.
+    pub fn fade(&self, amplitude: f32, duration: Duration) {
+        wit::client_audio::fade(
+            self.entity.into_bindgen(),
+            amplitude,
+            duration.as_secs_f32(),
+        );
+    }
+
+    /// Wait until the sound has finished playing, or was stopped.
+    ///
+    /// This should be started in the same frame the sound was played in, so that the
+    /// finishing message is not missed.
+    pub async fn finished(&self) {
+        let entity = self.entity;
+        wait_for_runtime_message(move |message: &SoundFinished| message.sound == entity).await;
+    }
+
+    /// Call `callback` once the sound has finished playing, or was stopped.
+    pub fn on_finished(&self, callback: impl FnOnce() + 'static) {
+        let sound = *self;
+        run_async(async move {
+            sound.finished().await;
+            callback();
+        });
+    }
+}
@@ -4,6 +4,9 @@ pub mod audio;
 /// **\[Client-only\]** Input retrieval and manipulation.
 pub mod input;
 
+/// **\[Client-only\]** Prediction of entities synchronized from the server.
+pub mod prediction;
+
 /// **\[Client-only\]** Manipulating the operating system's clipboard.
 pub mod clipboard;
 
@@ -0,0 +1,95 @@
+use std::collections::VecDeque;
+
+use crate::{
+    core::network::components::{acked_input_sequence, is_predicted, prediction_rewound},
+    entity,
+    prelude::EntityId,
+};
+
+/// Predicts an entity synchronized from the server by applying inputs to it locally, before the
+/// server has applied them.
+///
+/// Each input is given a sequence number by [`Predictor::predict`], which should be sent to the
+/// server along with the input. When the server applies an input to the entity, it should set
+/// [`acked_input_sequence`] on the entity to the input's sequence number in the same frame.
+///
+/// Whenever the state of the entity arrives from the server, the runtime rewinds the entity to
+/// that state, and [`Predictor::reconcile`] replays the inputs that the server has not applied yet.
+pub struct Predictor<I> {
+    entity: EntityId,
+    step: Box<dyn FnMut(EntityId, &I)>,
+    next_sequence: u64,
+    pending: VecDeque<(u64, I)>,
+    /// Set once the entity has been rewound to its state from the server
+    synced: bool,
+}
+impl<I> Predictor<I> {
+    /// Starts predicting `entity`. `step` applies an input to the entity, and should match what
+    /// the server does with the input.
+    pub fn new(entity: EntityId, step: impl FnMut(EntityId, &I) + 'static) -> Self {
+        entity::add_component(entity, is_predicted(), ());
+        Self {
+            entity,
+            step: Box::new(step),
+            next_sequence: 1,
+            pending: VecDeque::new(),
+            synced: false,
+        }
+    }
+
+    /// The predicted entity
+    pub fn entity(&self) -> EntityId {
+        self.entity
+    }
+
+    /// Applies `input` to the entity and returns its sequence number, which should be sent to the
+    /// server with the input.
+    ///
+    /// Inputs are only applied locally once the entity has received its first update from the
+    /// server, as the state of the entity is not known before then.
+    pub fn predict(&mut self, input: I) -> u64 {
+        self.reconcile();
+
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        if self.synced {
+            (self.step)(self.entity, &input);
+        }
+        self.pending.push_back((sequence, input));
+        sequence
+    }
+
+    /// If the entity has been rewound to its state from the server, replays the inputs that the
+    /// server has not applied yet.
+    ///
+    /// This is called by [`Predictor::predict`], but should also be called every frame in which
+    /// there is no input.
+    pub fn reconcile(&mut self) {
+        if !entity::has_component(self.entity, prediction_rewound()) {
+            return;
+        }
+        entity::remove_component(self.entity, prediction_rewound());
+        self.synced = true;
+
+        let acked = entity::get_component(self.entity, acked_input_sequence()).unwrap_or_default();
+        while matches!(self.pending.front(), Some((sequence, _)) if *sequence <= acked) {
+            self.pending.pop_front();
+        }
+        for (_, input) in &self.pending {
+            (self.step)(self.entity, input);
+        }
+    }
+
+    /// The number of inputs that the server has not applied yet
+    pub fn pending_inputs(&self) -> usize {
+        self.pending.len()
+    }
+}
+impl<I> Drop for Predictor<I> {
+    fn drop(&mut self) {
+        // The entity may have been despawned by the server
+        if entity::exists(self.entity) {
+            entity::remove_component(self.entity, is_predicted());
+        }
+    }
+}
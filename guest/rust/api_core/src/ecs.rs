@@ -1,12 +1,13 @@
 use std::marker::PhantomData;
 
 pub use crate::internal::component::{
+    index::{index, GridIndex, HashIndex, IndexBuilder, IndexComponents, OrderedIndex},
     query::{
         change_query, despawn_query, query, spawn_query, ChangeQuery, EventQuery, GeneralQuery,
-        GeneralQueryBuilder, QueryEvent, UntrackedChangeQuery,
+        GeneralQueryBuilder, QueryEvent, Relation, RelationComponent, UntrackedChangeQuery,
     },
     Component, ComponentOptionValue, ComponentValue, ComponentVecValue, ComponentsTuple, Entity,
-    EnumComponent, SupportedValue, UntypedComponent, __internal_get_component,
+    EnumComponent, StructComponent, SupportedValue, UntypedComponent, __internal_get_component,
 };
 
 use ambient_shared_types::ComponentIndex;
@@ -1,10 +1,4 @@
-use std::{
-    cell::RefCell,
-    future::Future,
-    rc::Rc,
-    task::Poll,
-    time::{Duration, Instant},
-};
+use std::{cell::RefCell, future::Future, rc::Rc, task::Poll, time::Duration};
 
 use crate::{
     core::{app, messages::Frame},
@@ -13,6 +7,7 @@ use crate::{
     internal::executor::EXECUTOR,
     message::Listener,
     prelude::RuntimeMessage,
+    timer,
 };
 
 /// The time, relative to the start of the game. Guaranteed to be monotonic.
@@ -98,12 +93,13 @@ pub async fn block_until(condition: impl Fn() -> bool) {
     .await
 }
 
-/// Stops execution of this function until `seconds` has passed.
+/// Stops execution of this function until `seconds` of game time have passed.
 ///
 /// This must be used with `.await` in either an `async fn` or an `async` block.
 pub async fn sleep(seconds: f32) {
-    let target_time = Instant::now() + Duration::from_secs_f32(seconds);
-    block_until(|| Instant::now() > target_time).await
+    timer::schedule(Duration::from_secs_f32(seconds), None)
+        .expired()
+        .await
 }
 
 /// Stops execution of this function until the given [`RuntimeMessage`] is received.
@@ -1046,6 +1046,207 @@ pub mod ambient {
       }
       
       
+      #[allow(clippy::all)]
+      pub mod client_audio {
+        #[used]
+        #[doc(hidden)]
+        #[cfg(target_arch = "wasm32")]
+        static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+        
+        pub type EntityId = super::super::super::ambient::bindings::types::EntityId;
+        #[repr(C)]
+        #[derive(Copy, Clone)]
+        pub struct Spatial {
+          pub emitter: EntityId,
+          pub listener: EntityId,
+        }
+        impl ::core::fmt::Debug for Spatial {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct("Spatial").field("emitter", &self.emitter).field("listener", &self.listener).finish()
+          }
+        }
+        #[repr(C)]
+        #[derive(Copy, Clone)]
+        pub struct PlayOptions {
+          pub looping: bool,
+          pub amplitude: f32,
+          pub panning: f32,
+          pub start_seconds: f32,
+          pub fade_in_seconds: f32,
+          pub spatial: Option<Spatial>,
+        }
+        impl ::core::fmt::Debug for PlayOptions {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct("PlayOptions").field("looping", &self.looping).field("amplitude", &self.amplitude).field("panning", &self.panning).field("start-seconds", &self.start_seconds).field("fade-in-seconds", &self.fade_in_seconds).field("spatial", &self.spatial).finish()
+          }
+        }
+        #[allow(clippy::all)]
+        pub fn play(url: &str,options: PlayOptions,) -> Result<EntityId,wit_bindgen::rt::string::String>{
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            
+            #[repr(align(8))]
+            struct RetArea([u8; 24]);
+            let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+            let vec0 = url;
+            let ptr0 = vec0.as_ptr() as i32;
+            let len0 = vec0.len() as i32;
+            let PlayOptions{ looping:looping1, amplitude:amplitude1, panning:panning1, start_seconds:start_seconds1, fade_in_seconds:fade_in_seconds1, spatial:spatial1, } = options;
+            let (result5_0,result5_1,result5_2,result5_3,result5_4,) = match spatial1 {
+              Some(e) => {
+                let Spatial{ emitter:emitter2, listener:listener2, } = e;
+                let super::super::super::ambient::bindings::types::EntityId{ id0:id03, id1:id13, } = emitter2;
+                let super::super::super::ambient::bindings::types::EntityId{ id0:id04, id1:id14, } = listener2;
+                
+                (1i32, wit_bindgen::rt::as_i64(id03), wit_bindgen::rt::as_i64(id13), wit_bindgen::rt::as_i64(id04), wit_bindgen::rt::as_i64(id14))
+              },
+              None => {
+                (0i32, 0i64, 0i64, 0i64, 0i64)
+              },
+            };
+            let ptr6 = ret_area.as_mut_ptr() as i32;
+            #[link(wasm_import_module = "ambient:bindings/client-audio")]
+            extern "C" {
+              #[cfg_attr(target_arch = "wasm32", link_name = "play")]
+              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/client-audio_play")]
+              fn wit_import(
+              _: i32, _: i32, _: i32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i64, _: i64, _: i64, _: i64, _: i32, );
+            }
+            wit_import(ptr0, len0, match looping1 { true => 1, false => 0 }, wit_bindgen::rt::as_f32(amplitude1), wit_bindgen::rt::as_f32(panning1), wit_bindgen::rt::as_f32(start_seconds1), wit_bindgen::rt::as_f32(fade_in_seconds1), result5_0, result5_1, result5_2, result5_3, result5_4, ptr6);
+            match i32::from(*((ptr6 + 0) as *const u8)) {
+              0 => Ok(super::super::super::ambient::bindings::types::EntityId{id0:*((ptr6 + 8) as *const i64) as u64, id1:*((ptr6 + 16) as *const i64) as u64, }),
+              1 => Err({
+                let len7 = *((ptr6 + 12) as *const i32) as usize;
+                
+                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr6 + 8) as *const i32) as *mut _, len7, len7))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr6 + 8) as *const i32) as *mut _, len7, len7)).unwrap()}}
+              }),
+              #[cfg(not(debug_assertions))]
+              _ => ::core::hint::unreachable_unchecked(),
+              #[cfg(debug_assertions)]
+              _ => panic!("invalid enum discriminant"),
+            }
+          }
+        }
+        #[allow(clippy::all)]
+        pub fn stop(sound: EntityId,){
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = sound;
+            
+            #[link(wasm_import_module = "ambient:bindings/client-audio")]
+            extern "C" {
+              #[cfg_attr(target_arch = "wasm32", link_name = "stop")]
+              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/client-audio_stop")]
+              fn wit_import(
+              _: i64, _: i64, );
+            }
+            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10));
+          }
+        }
+        #[allow(clippy::all)]
+        pub fn get_position(sound: EntityId,) -> Option<f32>{
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            
+            #[repr(align(4))]
+            struct RetArea([u8; 8]);
+            let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+            let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = sound;
+            let ptr1 = ret_area.as_mut_ptr() as i32;
+            #[link(wasm_import_module = "ambient:bindings/client-audio")]
+            extern "C" {
+              #[cfg_attr(target_arch = "wasm32", link_name = "get-position")]
+              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/client-audio_get-position")]
+              fn wit_import(
+              _: i64, _: i64, _: i32, );
+            }
+            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), ptr1);
+            match i32::from(*((ptr1 + 0) as *const u8)) {
+              0 => None,
+              1 => Some(*((ptr1 + 4) as *const f32)),
+              #[cfg(not(debug_assertions))]
+              _ => ::core::hint::unreachable_unchecked(),
+              #[cfg(debug_assertions)]
+              _ => panic!("invalid enum discriminant"),
+            }
+          }
+        }
+        #[allow(clippy::all)]
+        pub fn get_duration(sound: EntityId,) -> Option<f32>{
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            
+            #[repr(align(4))]
+            struct RetArea([u8; 8]);
+            let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+            let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = sound;
+            let ptr1 = ret_area.as_mut_ptr() as i32;
+            #[link(wasm_import_module = "ambient:bindings/client-audio")]
+            extern "C" {
+              #[cfg_attr(target_arch = "wasm32", link_name = "get-duration")]
+              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/client-audio_get-duration")]
+              fn wit_import(
+              _: i64, _: i64, _: i32, );
+            }
+            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), ptr1);
+            match i32::from(*((ptr1 + 0) as *const u8)) {
+              0 => None,
+              1 => Some(*((ptr1 + 4) as *const f32)),
+              #[cfg(not(debug_assertions))]
+              _ => ::core::hint::unreachable_unchecked(),
+              #[cfg(debug_assertions)]
+              _ => panic!("invalid enum discriminant"),
+            }
+          }
+        }
+        #[allow(clippy::all)]
+        pub fn seek(sound: EntityId,position_seconds: f32,){
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = sound;
+            
+            #[link(wasm_import_module = "ambient:bindings/client-audio")]
+            extern "C" {
+              #[cfg_attr(target_arch = "wasm32", link_name = "seek")]
+              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/client-audio_seek")]
+              fn wit_import(
+              _: i64, _: i64, _: f32, );
+            }
+            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), wit_bindgen::rt::as_f32(position_seconds));
+          }
+        }
+        #[allow(clippy::all)]
+        pub fn fade(sound: EntityId,amplitude: f32,duration_seconds: f32,){
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = sound;
+            
+            #[link(wasm_import_module = "ambient:bindings/client-audio")]
+            extern "C" {
+              #[cfg_attr(target_arch = "wasm32", link_name = "fade")]
+              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/client-audio_fade")]
+              fn wit_import(
+              _: i64, _: i64, _: f32, _: f32, );
+            }
+            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), wit_bindgen::rt::as_f32(amplitude), wit_bindgen::rt::as_f32(duration_seconds));
+          }
+        }
+        
+      }
+      
+      
       #[allow(clippy::all)]
       pub mod component {
         #[used]
@@ -1363,6 +1564,8 @@ pub mod ambient {
           TypeProceduralMaterialHandle(ProceduralMaterialHandle),
           TypeVec(VecValue),
           TypeOption(OptionValue),
+          TypeStruct(wit_bindgen::rt::vec::Vec::<u8>),
+          TypeMap(wit_bindgen::rt::vec::Vec::<u8>),
         }
         impl ::core::fmt::Debug for Value {
           fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -1463,20 +1666,41 @@ pub mod ambient {
               Value::TypeOption(e) => {
                 f.debug_tuple("Value::TypeOption").field(e).finish()
               }
+              Value::TypeStruct(e) => {
+                f.debug_tuple("Value::TypeStruct").field(e).finish()
+              }
+              Value::TypeMap(e) => {
+                f.debug_tuple("Value::TypeMap").field(e).finish()
+              }
             }
           }
         }
         pub type Entity = wit_bindgen::rt::vec::Vec::<(u32,Value,)>;
+        /// Requires the entities referred to by an `entity-id` or `list<entity-id>` component to
+        /// match a filter
+        #[derive(Clone)]
+        pub struct QueryRelation {
+          pub component: u32,
+          pub includes: wit_bindgen::rt::vec::Vec::<u32>,
+          pub excludes: wit_bindgen::rt::vec::Vec::<u32>,
+          pub changed: wit_bindgen::rt::vec::Vec::<u32>,
+        }
+        impl ::core::fmt::Debug for QueryRelation {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct("QueryRelation").field("component", &self.component).field("includes", &self.includes).field("excludes", &self.excludes).field("changed", &self.changed).finish()
+          }
+        }
         #[derive(Clone)]
         pub struct QueryBuild {
           pub components: wit_bindgen::rt::vec::Vec::<u32>,
           pub includes: wit_bindgen::rt::vec::Vec::<u32>,
           pub excludes: wit_bindgen::rt::vec::Vec::<u32>,
           pub changed: wit_bindgen::rt::vec::Vec::<u32>,
+          pub relations: wit_bindgen::rt::vec::Vec::<QueryRelation>,
         }
         impl ::core::fmt::Debug for QueryBuild {
           fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("QueryBuild").field("components", &self.components).field("includes", &self.includes).field("excludes", &self.excludes).field("changed", &self.changed).finish()
+            f.debug_struct("QueryBuild").field("components", &self.components).field("includes", &self.includes).field("excludes", &self.excludes).field("changed", &self.changed).field("relations", &self.relations).finish()
           }
         }
         #[repr(u8)]
@@ -1501,6 +1725,32 @@ pub mod ambient {
             }
           }
         }
+        /// How an index finds entities
+        #[derive(Clone, Copy)]
+        pub enum IndexKind{
+          /// Sorted by the values of the components, for exact and range lookups
+          Ordered,
+          /// Hashed by the values of the components, for exact lookups
+          Hash,
+          /// Sorted into the cells of a grid with the given cell size by a single `vec3` component,
+          /// for finding the entities in an area
+          Grid(f32),
+        }
+        impl ::core::fmt::Debug for IndexKind {
+          fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+              IndexKind::Ordered => {
+                f.debug_tuple("IndexKind::Ordered").finish()
+              }
+              IndexKind::Hash => {
+                f.debug_tuple("IndexKind::Hash").finish()
+              }
+              IndexKind::Grid(e) => {
+                f.debug_tuple("IndexKind::Grid").field(e).finish()
+              }
+            }
+          }
+        }
         #[allow(clippy::all)]
         pub fn get_index(id: &str,) -> Option<u32>{
           
@@ -1821,7 +2071,7 @@ pub mod ambient {
                   }),
                   #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                 }}}),
-                #[cfg(debug_assertions)]31 => Value::TypeOption({{match i32::from(*((ptr1 + 16) as *const u8)) {
+                31 => Value::TypeOption({{match i32::from(*((ptr1 + 16) as *const u8)) {
                   0 => OptionValue::TypeEmpty(match i32::from(*((ptr1 + 24) as *const u8)) {
                     0 => None,
                     1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((ptr1 + 25) as *const u8)) as u8, }),
@@ -2087,23 +2337,308 @@ pub mod ambient {
                   }),
                   #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                 }}}),
-                #[cfg(not(debug_assertions))]_ => Value::TypeOption({{match i32::from(*((ptr1 + 16) as *const u8)) {
-                  0 => OptionValue::TypeEmpty(match i32::from(*((ptr1 + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((ptr1 + 25) as *const u8)) as u8, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
+                32 => Value::TypeStruct({
+                  let len_struct = *((ptr1 + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((ptr1 + 16) as *const i32) as *mut _, len_struct, len_struct)
+                }),
+                #[cfg(debug_assertions)]33 => Value::TypeMap({
+                  let len_map = *((ptr1 + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((ptr1 + 16) as *const i32) as *mut _, len_map, len_map)
+                }),
+                #[cfg(not(debug_assertions))]_ => Value::TypeMap({
+                  let len_map = *((ptr1 + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((ptr1 + 16) as *const i32) as *mut _, len_map, len_map)
+                }),
+                #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
+              }}}),
+              #[cfg(not(debug_assertions))]
+              _ => ::core::hint::unreachable_unchecked(),
+              #[cfg(debug_assertions)]
+              _ => panic!("invalid enum discriminant"),
+            }
+          }
+        }
+        #[allow(clippy::all)]
+        pub fn get_components(entity: EntityId,indices: &[u32],) -> Entity{
+          
+          #[allow(unused_imports)]
+          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+          unsafe {
+            
+            #[repr(align(4))]
+            struct RetArea([u8; 8]);
+            let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+            let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = entity;
+            let vec1 = indices;
+            let ptr1 = vec1.as_ptr() as i32;
+            let len1 = vec1.len() as i32;
+            let ptr2 = ret_area.as_mut_ptr() as i32;
+            #[link(wasm_import_module = "ambient:bindings/component")]
+            extern "C" {
+              #[cfg_attr(target_arch = "wasm32", link_name = "get-components")]
+              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_get-components")]
+              fn wit_import(
+              _: i64, _: i64, _: i32, _: i32, _: i32, );
+            }
+            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), ptr1, len1, ptr2);
+            let base36 = *((ptr2 + 0) as *const i32);
+            let len36 = *((ptr2 + 4) as *const i32);
+            let mut result36 = Vec::with_capacity(len36 as usize);
+            for i in 0..len36 {
+              let base = base36 + i *96;
+              result36.push((*((base + 0) as *const i32) as u32, {{match i32::from(*((base + 8) as *const u8)) {
+                0 => Value::TypeEmpty(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 16) as *const u8)) as u8, }),
+                1 => Value::TypeBool({
+                  #[cfg(not(debug_assertions))]
+                  { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 16) as *const u8)) as u8) }
+                  #[cfg(debug_assertions)]
+                  {
+                    match i32::from(*((base + 16) as *const u8)) {
+                      0 => false,
+                      1 => true,
+                      _ => panic!("invalid bool discriminant"),
+                    }
+                  }
+                }),
+                2 => Value::TypeEntityId(super::super::super::ambient::bindings::types::EntityId{id0:*((base + 16) as *const i64) as u64, id1:*((base + 24) as *const i64) as u64, }),
+                3 => Value::TypeF32(*((base + 16) as *const f32)),
+                4 => Value::TypeF64(*((base + 16) as *const f64)),
+                5 => Value::TypeMat4(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 32) as *const f32), y:*((base + 36) as *const f32), z:*((base + 40) as *const f32), w:*((base + 44) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 48) as *const f32), y:*((base + 52) as *const f32), z:*((base + 56) as *const f32), w:*((base + 60) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 64) as *const f32), y:*((base + 68) as *const f32), z:*((base + 72) as *const f32), w:*((base + 76) as *const f32), }, }),
+                6 => Value::TypeQuat(super::super::super::ambient::bindings::types::Quat{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
+                7 => Value::TypeString({
+                  let len3 = *((base + 20) as *const i32) as usize;
+                  
+                  {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len3, len3)).unwrap()}}
+                }),
+                8 => Value::TypeU8(i32::from(*((base + 16) as *const u8)) as u8),
+                9 => Value::TypeU16(i32::from(*((base + 16) as *const u16)) as u16),
+                10 => Value::TypeU32(*((base + 16) as *const i32) as u32),
+                11 => Value::TypeU64(*((base + 16) as *const i64) as u64),
+                12 => Value::TypeI8(i32::from(*((base + 16) as *const i8)) as i8),
+                13 => Value::TypeI16(i32::from(*((base + 16) as *const i16)) as i16),
+                14 => Value::TypeI32(*((base + 16) as *const i32)),
+                15 => Value::TypeI64(*((base + 16) as *const i64)),
+                16 => Value::TypeVec2(super::super::super::ambient::bindings::types::Vec2{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), }),
+                17 => Value::TypeVec3(super::super::super::ambient::bindings::types::Vec3{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), }),
+                18 => Value::TypeVec4(super::super::super::ambient::bindings::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
+                19 => Value::TypeUvec2(super::super::super::ambient::bindings::types::Uvec2{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, }),
+                20 => Value::TypeUvec3(super::super::super::ambient::bindings::types::Uvec3{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, z:*((base + 24) as *const i32) as u32, }),
+                21 => Value::TypeUvec4(super::super::super::ambient::bindings::types::Uvec4{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, z:*((base + 24) as *const i32) as u32, w:*((base + 28) as *const i32) as u32, }),
+                22 => Value::TypeIvec2(super::super::super::ambient::bindings::types::Ivec2{x:*((base + 16) as *const i32), y:*((base + 20) as *const i32), }),
+                23 => Value::TypeIvec3(super::super::super::ambient::bindings::types::Ivec3{x:*((base + 16) as *const i32), y:*((base + 20) as *const i32), z:*((base + 24) as *const i32), }),
+                24 => Value::TypeIvec4(super::super::super::ambient::bindings::types::Ivec4{x:*((base + 16) as *const i32), y:*((base + 20) as *const i32), z:*((base + 24) as *const i32), w:*((base + 28) as *const i32), }),
+                25 => Value::TypeDuration(super::super::super::ambient::bindings::types::Duration{seconds:*((base + 16) as *const i64) as u64, nanoseconds:*((base + 24) as *const i32) as u32, }),
+                26 => Value::TypeProceduralMeshHandle(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
+                27 => Value::TypeProceduralTextureHandle(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
+                28 => Value::TypeProceduralSamplerHandle(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
+                29 => Value::TypeProceduralMaterialHandle(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
+                30 => Value::TypeVec({{match i32::from(*((base + 16) as *const u8)) {
+                  0 => VecValue::TypeEmpty({
+                    let len4 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len4, len4)
                   }),
-                  1 => OptionValue::TypeBool(match i32::from(*((ptr1 + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some({
-                      #[cfg(not(debug_assertions))]
-                      { ::core::mem::transmute::<u8, bool>(i32::from(*((ptr1 + 25) as *const u8)) as u8) }
+                  1 => VecValue::TypeBool({
+                    let base5 = *((base + 20) as *const i32);
+                    let len5 = *((base + 24) as *const i32);
+                    let mut result5 = Vec::with_capacity(len5 as usize);
+                    for i in 0..len5 {
+                      let base = base5 + i *1;
+                      result5.push({
+                        #[cfg(not(debug_assertions))]
+                        { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 0) as *const u8)) as u8) }
+                        #[cfg(debug_assertions)]
+                        {
+                          match i32::from(*((base + 0) as *const u8)) {
+                            0 => false,
+                            1 => true,
+                            _ => panic!("invalid bool discriminant"),
+                          }
+                        }
+                      });
+                    }
+                    wit_bindgen::rt::dealloc(base5, (len5 as usize) * 1, 1);
+                    
+                    result5
+                  }),
+                  2 => VecValue::TypeEntityId({
+                    let len6 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len6, len6)
+                  }),
+                  3 => VecValue::TypeF32({
+                    let len7 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len7, len7)
+                  }),
+                  4 => VecValue::TypeF64({
+                    let len8 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len8, len8)
+                  }),
+                  5 => VecValue::TypeMat4({
+                    let len9 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len9, len9)
+                  }),
+                  6 => VecValue::TypeQuat({
+                    let len10 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len10, len10)
+                  }),
+                  7 => VecValue::TypeString({
+                    let base12 = *((base + 20) as *const i32);
+                    let len12 = *((base + 24) as *const i32);
+                    let mut result12 = Vec::with_capacity(len12 as usize);
+                    for i in 0..len12 {
+                      let base = base12 + i *8;
+                      result12.push({
+                        let len11 = *((base + 4) as *const i32) as usize;
+                        
+                        {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len11, len11))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len11, len11)).unwrap()}}
+                      });
+                    }
+                    wit_bindgen::rt::dealloc(base12, (len12 as usize) * 8, 4);
+                    
+                    result12
+                  }),
+                  8 => VecValue::TypeU8({
+                    let len13 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len13, len13)
+                  }),
+                  9 => VecValue::TypeU16({
+                    let len14 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len14, len14)
+                  }),
+                  10 => VecValue::TypeU32({
+                    let len15 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len15, len15)
+                  }),
+                  11 => VecValue::TypeU64({
+                    let len16 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len16, len16)
+                  }),
+                  12 => VecValue::TypeI8({
+                    let len17 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len17, len17)
+                  }),
+                  13 => VecValue::TypeI16({
+                    let len18 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len18, len18)
+                  }),
+                  14 => VecValue::TypeI32({
+                    let len19 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
+                  }),
+                  15 => VecValue::TypeI64({
+                    let len20 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len20, len20)
+                  }),
+                  16 => VecValue::TypeVec2({
+                    let len21 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len21, len21)
+                  }),
+                  17 => VecValue::TypeVec3({
+                    let len22 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len22, len22)
+                  }),
+                  18 => VecValue::TypeVec4({
+                    let len23 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len23, len23)
+                  }),
+                  19 => VecValue::TypeUvec2({
+                    let len24 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len24, len24)
+                  }),
+                  20 => VecValue::TypeUvec3({
+                    let len25 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len25, len25)
+                  }),
+                  21 => VecValue::TypeUvec4({
+                    let len26 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len26, len26)
+                  }),
+                  22 => VecValue::TypeIvec2({
+                    let len27 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len27, len27)
+                  }),
+                  23 => VecValue::TypeIvec3({
+                    let len28 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len28, len28)
+                  }),
+                  24 => VecValue::TypeIvec4({
+                    let len29 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len29, len29)
+                  }),
+                  25 => VecValue::TypeDuration({
+                    let len30 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len30, len30)
+                  }),
+                  26 => VecValue::TypeProceduralMeshHandle({
+                    let len31 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len31, len31)
+                  }),
+                  27 => VecValue::TypeProceduralTextureHandle({
+                    let len32 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len32, len32)
+                  }),
+                  28 => VecValue::TypeProceduralSamplerHandle({
+                    let len33 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
+                  }),
+                  #[cfg(debug_assertions)]29 => VecValue::TypeProceduralMaterialHandle({
+                    let len34 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len34, len34)
+                  }),
+                  #[cfg(not(debug_assertions))]_ => VecValue::TypeProceduralMaterialHandle({
+                    let len34 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len34, len34)
+                  }),
+                  #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
+                }}}),
+                31 => Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
+                  0 => OptionValue::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
+                    0 => None,
+                    1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 25) as *const u8)) as u8, }),
+                    #[cfg(not(debug_assertions))]
+                    _ => ::core::hint::unreachable_unchecked(),
+                    #[cfg(debug_assertions)]
+                    _ => panic!("invalid enum discriminant"),
+                  }),
+                  1 => OptionValue::TypeBool(match i32::from(*((base + 24) as *const u8)) {
+                    0 => None,
+                    1 => Some({
+                      #[cfg(not(debug_assertions))]
+                      { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
                       #[cfg(debug_assertions)]
                       {
-                        match i32::from(*((ptr1 + 25) as *const u8)) {
+                        match i32::from(*((base + 25) as *const u8)) {
                           0 => false,
                           1 => true,
                           _ => panic!("invalid bool discriminant"),
@@ -2115,237 +2650,237 @@ pub mod ambient {
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  2 => OptionValue::TypeEntityId(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  2 => OptionValue::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::EntityId{id0:*((ptr1 + 32) as *const i64) as u64, id1:*((ptr1 + 40) as *const i64) as u64, }),
+                    1 => Some(super::super::super::ambient::bindings::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  3 => OptionValue::TypeF32(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  3 => OptionValue::TypeF32(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(*((ptr1 + 28) as *const f32)),
+                    1 => Some(*((base + 28) as *const f32)),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  4 => OptionValue::TypeF64(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  4 => OptionValue::TypeF64(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(*((ptr1 + 32) as *const f64)),
+                    1 => Some(*((base + 32) as *const f64)),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  5 => OptionValue::TypeMat4(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  5 => OptionValue::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((ptr1 + 28) as *const f32), y:*((ptr1 + 32) as *const f32), z:*((ptr1 + 36) as *const f32), w:*((ptr1 + 40) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((ptr1 + 44) as *const f32), y:*((ptr1 + 48) as *const f32), z:*((ptr1 + 52) as *const f32), w:*((ptr1 + 56) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((ptr1 + 60) as *const f32), y:*((ptr1 + 64) as *const f32), z:*((ptr1 + 68) as *const f32), w:*((ptr1 + 72) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((ptr1 + 76) as *const f32), y:*((ptr1 + 80) as *const f32), z:*((ptr1 + 84) as *const f32), w:*((ptr1 + 88) as *const f32), }, }),
+                    1 => Some(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  6 => OptionValue::TypeQuat(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  6 => OptionValue::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Quat{x:*((ptr1 + 28) as *const f32), y:*((ptr1 + 32) as *const f32), z:*((ptr1 + 36) as *const f32), w:*((ptr1 + 40) as *const f32), }),
+                    1 => Some(super::super::super::ambient::bindings::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  7 => OptionValue::TypeString(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  7 => OptionValue::TypeString(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
                     1 => Some({
-                      let len34 = *((ptr1 + 32) as *const i32) as usize;
+                      let len35 = *((base + 32) as *const i32) as usize;
                       
-                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 28) as *const i32) as *mut _, len34, len34))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 28) as *const i32) as *mut _, len34, len34)).unwrap()}}
+                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len35, len35))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len35, len35)).unwrap()}}
                     }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  8 => OptionValue::TypeU8(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  8 => OptionValue::TypeU8(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(i32::from(*((ptr1 + 25) as *const u8)) as u8),
+                    1 => Some(i32::from(*((base + 25) as *const u8)) as u8),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  9 => OptionValue::TypeU16(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  9 => OptionValue::TypeU16(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(i32::from(*((ptr1 + 26) as *const u16)) as u16),
+                    1 => Some(i32::from(*((base + 26) as *const u16)) as u16),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  10 => OptionValue::TypeU32(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  10 => OptionValue::TypeU32(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(*((ptr1 + 28) as *const i32) as u32),
+                    1 => Some(*((base + 28) as *const i32) as u32),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  11 => OptionValue::TypeU64(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  11 => OptionValue::TypeU64(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(*((ptr1 + 32) as *const i64) as u64),
+                    1 => Some(*((base + 32) as *const i64) as u64),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  12 => OptionValue::TypeI8(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  12 => OptionValue::TypeI8(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(i32::from(*((ptr1 + 25) as *const i8)) as i8),
+                    1 => Some(i32::from(*((base + 25) as *const i8)) as i8),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  13 => OptionValue::TypeI16(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  13 => OptionValue::TypeI16(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(i32::from(*((ptr1 + 26) as *const i16)) as i16),
+                    1 => Some(i32::from(*((base + 26) as *const i16)) as i16),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  14 => OptionValue::TypeI32(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  14 => OptionValue::TypeI32(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(*((ptr1 + 28) as *const i32)),
+                    1 => Some(*((base + 28) as *const i32)),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  15 => OptionValue::TypeI64(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  15 => OptionValue::TypeI64(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(*((ptr1 + 32) as *const i64)),
+                    1 => Some(*((base + 32) as *const i64)),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  16 => OptionValue::TypeVec2(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  16 => OptionValue::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec2{x:*((ptr1 + 28) as *const f32), y:*((ptr1 + 32) as *const f32), }),
+                    1 => Some(super::super::super::ambient::bindings::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  17 => OptionValue::TypeVec3(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  17 => OptionValue::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec3{x:*((ptr1 + 28) as *const f32), y:*((ptr1 + 32) as *const f32), z:*((ptr1 + 36) as *const f32), }),
+                    1 => Some(super::super::super::ambient::bindings::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  18 => OptionValue::TypeVec4(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  18 => OptionValue::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec4{x:*((ptr1 + 28) as *const f32), y:*((ptr1 + 32) as *const f32), z:*((ptr1 + 36) as *const f32), w:*((ptr1 + 40) as *const f32), }),
+                    1 => Some(super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  19 => OptionValue::TypeUvec2(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  19 => OptionValue::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec2{x:*((ptr1 + 28) as *const i32) as u32, y:*((ptr1 + 32) as *const i32) as u32, }),
+                    1 => Some(super::super::super::ambient::bindings::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  20 => OptionValue::TypeUvec3(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  20 => OptionValue::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec3{x:*((ptr1 + 28) as *const i32) as u32, y:*((ptr1 + 32) as *const i32) as u32, z:*((ptr1 + 36) as *const i32) as u32, }),
+                    1 => Some(super::super::super::ambient::bindings::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  21 => OptionValue::TypeUvec4(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  21 => OptionValue::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec4{x:*((ptr1 + 28) as *const i32) as u32, y:*((ptr1 + 32) as *const i32) as u32, z:*((ptr1 + 36) as *const i32) as u32, w:*((ptr1 + 40) as *const i32) as u32, }),
+                    1 => Some(super::super::super::ambient::bindings::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  22 => OptionValue::TypeIvec2(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  22 => OptionValue::TypeIvec2(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec2{x:*((ptr1 + 28) as *const i32), y:*((ptr1 + 32) as *const i32), }),
+                    1 => Some(super::super::super::ambient::bindings::types::Ivec2{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  23 => OptionValue::TypeIvec3(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  23 => OptionValue::TypeIvec3(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec3{x:*((ptr1 + 28) as *const i32), y:*((ptr1 + 32) as *const i32), z:*((ptr1 + 36) as *const i32), }),
+                    1 => Some(super::super::super::ambient::bindings::types::Ivec3{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  24 => OptionValue::TypeIvec4(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  24 => OptionValue::TypeIvec4(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec4{x:*((ptr1 + 28) as *const i32), y:*((ptr1 + 32) as *const i32), z:*((ptr1 + 36) as *const i32), w:*((ptr1 + 40) as *const i32), }),
+                    1 => Some(super::super::super::ambient::bindings::types::Ivec4{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), w:*((base + 40) as *const i32), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  25 => OptionValue::TypeDuration(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  25 => OptionValue::TypeDuration(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Duration{seconds:*((ptr1 + 32) as *const i64) as u64, nanoseconds:*((ptr1 + 40) as *const i32) as u32, }),
+                    1 => Some(super::super::super::ambient::bindings::types::Duration{seconds:*((base + 32) as *const i64) as u64, nanoseconds:*((base + 40) as *const i32) as u32, }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  26 => OptionValue::TypeProceduralMeshHandle(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  26 => OptionValue::TypeProceduralMeshHandle(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((ptr1 + 32) as *const i64) as u64, *((ptr1 + 40) as *const i64) as u64), }),
+                    1 => Some(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  27 => OptionValue::TypeProceduralTextureHandle(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  27 => OptionValue::TypeProceduralTextureHandle(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((ptr1 + 32) as *const i64) as u64, *((ptr1 + 40) as *const i64) as u64), }),
+                    1 => Some(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  28 => OptionValue::TypeProceduralSamplerHandle(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  28 => OptionValue::TypeProceduralSamplerHandle(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((ptr1 + 32) as *const i64) as u64, *((ptr1 + 40) as *const i64) as u64), }),
+                    1 => Some(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  #[cfg(debug_assertions)]29 => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  #[cfg(debug_assertions)]29 => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((ptr1 + 32) as *const i64) as u64, *((ptr1 + 40) as *const i64) as u64), }),
+                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
                     _ => panic!("invalid enum discriminant"),
                   }),
-                  #[cfg(not(debug_assertions))]_ => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((ptr1 + 24) as *const u8)) {
+                  #[cfg(not(debug_assertions))]_ => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((ptr1 + 32) as *const i64) as u64, *((ptr1 + 40) as *const i64) as u64), }),
+                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
                     #[cfg(debug_assertions)]
@@ -2353,17 +2888,30 @@ pub mod ambient {
                   }),
                   #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                 }}}),
+                32 => Value::TypeStruct({
+                  let len_struct = *((base + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_struct, len_struct)
+                }),
+                #[cfg(debug_assertions)]33 => Value::TypeMap({
+                  let len_map = *((base + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_map, len_map)
+                }),
+                #[cfg(not(debug_assertions))]_ => Value::TypeMap({
+                  let len_map = *((base + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_map, len_map)
+                }),
                 #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-              }}}),
-              #[cfg(not(debug_assertions))]
-              _ => ::core::hint::unreachable_unchecked(),
-              #[cfg(debug_assertions)]
-              _ => panic!("invalid enum discriminant"),
+              }}}));
             }
+            wit_bindgen::rt::dealloc(base36, (len36 as usize) * 96, 8);
+            result36
           }
         }
         #[allow(clippy::all)]
-        pub fn get_components(entity: EntityId,indices: &[u32],) -> Entity{
+        pub fn get_all_components(entity: EntityId,) -> Entity{
           
           #[allow(unused_imports)]
           use wit_bindgen::rt::{alloc, vec::Vec, string::String};
@@ -2373,24 +2921,21 @@ pub mod ambient {
             struct RetArea([u8; 8]);
             let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
             let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = entity;
-            let vec1 = indices;
-            let ptr1 = vec1.as_ptr() as i32;
-            let len1 = vec1.len() as i32;
-            let ptr2 = ret_area.as_mut_ptr() as i32;
+            let ptr1 = ret_area.as_mut_ptr() as i32;
             #[link(wasm_import_module = "ambient:bindings/component")]
             extern "C" {
-              #[cfg_attr(target_arch = "wasm32", link_name = "get-components")]
-              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_get-components")]
+              #[cfg_attr(target_arch = "wasm32", link_name = "get-all-components")]
+              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_get-all-components")]
               fn wit_import(
-              _: i64, _: i64, _: i32, _: i32, _: i32, );
+              _: i64, _: i64, _: i32, );
             }
-            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), ptr1, len1, ptr2);
-            let base36 = *((ptr2 + 0) as *const i32);
-            let len36 = *((ptr2 + 4) as *const i32);
-            let mut result36 = Vec::with_capacity(len36 as usize);
-            for i in 0..len36 {
-              let base = base36 + i *96;
-              result36.push((*((base + 0) as *const i32) as u32, {{match i32::from(*((base + 8) as *const u8)) {
+            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), ptr1);
+            let base35 = *((ptr1 + 0) as *const i32);
+            let len35 = *((ptr1 + 4) as *const i32);
+            let mut result35 = Vec::with_capacity(len35 as usize);
+            for i in 0..len35 {
+              let base = base35 + i *96;
+              result35.push((*((base + 0) as *const i32) as u32, {{match i32::from(*((base + 8) as *const u8)) {
                 0 => Value::TypeEmpty(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 16) as *const u8)) as u8, }),
                 1 => Value::TypeBool({
                   #[cfg(not(debug_assertions))]
@@ -2410,9 +2955,9 @@ pub mod ambient {
                 5 => Value::TypeMat4(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 32) as *const f32), y:*((base + 36) as *const f32), z:*((base + 40) as *const f32), w:*((base + 44) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 48) as *const f32), y:*((base + 52) as *const f32), z:*((base + 56) as *const f32), w:*((base + 60) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 64) as *const f32), y:*((base + 68) as *const f32), z:*((base + 72) as *const f32), w:*((base + 76) as *const f32), }, }),
                 6 => Value::TypeQuat(super::super::super::ambient::bindings::types::Quat{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
                 7 => Value::TypeString({
-                  let len3 = *((base + 20) as *const i32) as usize;
+                  let len2 = *((base + 20) as *const i32) as usize;
                   
-                  {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len3, len3)).unwrap()}}
+                  {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len2, len2)).unwrap()}}
                 }),
                 8 => Value::TypeU8(i32::from(*((base + 16) as *const u8)) as u8),
                 9 => Value::TypeU16(i32::from(*((base + 16) as *const u16)) as u16),
@@ -2438,17 +2983,17 @@ pub mod ambient {
                 29 => Value::TypeProceduralMaterialHandle(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
                 30 => Value::TypeVec({{match i32::from(*((base + 16) as *const u8)) {
                   0 => VecValue::TypeEmpty({
-                    let len4 = *((base + 24) as *const i32) as usize;
+                    let len3 = *((base + 24) as *const i32) as usize;
                     
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len4, len4)
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len3, len3)
                   }),
                   1 => VecValue::TypeBool({
-                    let base5 = *((base + 20) as *const i32);
-                    let len5 = *((base + 24) as *const i32);
-                    let mut result5 = Vec::with_capacity(len5 as usize);
-                    for i in 0..len5 {
-                      let base = base5 + i *1;
-                      result5.push({
+                    let base4 = *((base + 20) as *const i32);
+                    let len4 = *((base + 24) as *const i32);
+                    let mut result4 = Vec::with_capacity(len4 as usize);
+                    for i in 0..len4 {
+                      let base = base4 + i *1;
+                      result4.push({
                         #[cfg(not(debug_assertions))]
                         { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 0) as *const u8)) as u8) }
                         #[cfg(debug_assertions)]
@@ -2461,169 +3006,169 @@ pub mod ambient {
                         }
                       });
                     }
-                    wit_bindgen::rt::dealloc(base5, (len5 as usize) * 1, 1);
+                    wit_bindgen::rt::dealloc(base4, (len4 as usize) * 1, 1);
                     
-                    result5
+                    result4
                   }),
                   2 => VecValue::TypeEntityId({
+                    let len5 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len5, len5)
+                  }),
+                  3 => VecValue::TypeF32({
                     let len6 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len6, len6)
                   }),
-                  3 => VecValue::TypeF32({
+                  4 => VecValue::TypeF64({
                     let len7 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len7, len7)
                   }),
-                  4 => VecValue::TypeF64({
+                  5 => VecValue::TypeMat4({
                     let len8 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len8, len8)
                   }),
-                  5 => VecValue::TypeMat4({
+                  6 => VecValue::TypeQuat({
                     let len9 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len9, len9)
                   }),
-                  6 => VecValue::TypeQuat({
-                    let len10 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len10, len10)
-                  }),
                   7 => VecValue::TypeString({
-                    let base12 = *((base + 20) as *const i32);
-                    let len12 = *((base + 24) as *const i32);
-                    let mut result12 = Vec::with_capacity(len12 as usize);
-                    for i in 0..len12 {
-                      let base = base12 + i *8;
-                      result12.push({
-                        let len11 = *((base + 4) as *const i32) as usize;
+                    let base11 = *((base + 20) as *const i32);
+                    let len11 = *((base + 24) as *const i32);
+                    let mut result11 = Vec::with_capacity(len11 as usize);
+                    for i in 0..len11 {
+                      let base = base11 + i *8;
+                      result11.push({
+                        let len10 = *((base + 4) as *const i32) as usize;
                         
-                        {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len11, len11))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len11, len11)).unwrap()}}
+                        {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10)).unwrap()}}
                       });
                     }
-                    wit_bindgen::rt::dealloc(base12, (len12 as usize) * 8, 4);
+                    wit_bindgen::rt::dealloc(base11, (len11 as usize) * 8, 4);
                     
-                    result12
+                    result11
                   }),
                   8 => VecValue::TypeU8({
+                    let len12 = *((base + 24) as *const i32) as usize;
+                    
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len12, len12)
+                  }),
+                  9 => VecValue::TypeU16({
                     let len13 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len13, len13)
                   }),
-                  9 => VecValue::TypeU16({
+                  10 => VecValue::TypeU32({
                     let len14 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len14, len14)
                   }),
-                  10 => VecValue::TypeU32({
+                  11 => VecValue::TypeU64({
                     let len15 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len15, len15)
                   }),
-                  11 => VecValue::TypeU64({
+                  12 => VecValue::TypeI8({
                     let len16 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len16, len16)
                   }),
-                  12 => VecValue::TypeI8({
+                  13 => VecValue::TypeI16({
                     let len17 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len17, len17)
                   }),
-                  13 => VecValue::TypeI16({
+                  14 => VecValue::TypeI32({
                     let len18 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len18, len18)
                   }),
-                  14 => VecValue::TypeI32({
+                  15 => VecValue::TypeI64({
                     let len19 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
                   }),
-                  15 => VecValue::TypeI64({
+                  16 => VecValue::TypeVec2({
                     let len20 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len20, len20)
                   }),
-                  16 => VecValue::TypeVec2({
+                  17 => VecValue::TypeVec3({
                     let len21 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len21, len21)
                   }),
-                  17 => VecValue::TypeVec3({
+                  18 => VecValue::TypeVec4({
                     let len22 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len22, len22)
                   }),
-                  18 => VecValue::TypeVec4({
+                  19 => VecValue::TypeUvec2({
                     let len23 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len23, len23)
                   }),
-                  19 => VecValue::TypeUvec2({
+                  20 => VecValue::TypeUvec3({
                     let len24 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len24, len24)
                   }),
-                  20 => VecValue::TypeUvec3({
+                  21 => VecValue::TypeUvec4({
                     let len25 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len25, len25)
                   }),
-                  21 => VecValue::TypeUvec4({
+                  22 => VecValue::TypeIvec2({
                     let len26 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len26, len26)
                   }),
-                  22 => VecValue::TypeIvec2({
+                  23 => VecValue::TypeIvec3({
                     let len27 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len27, len27)
                   }),
-                  23 => VecValue::TypeIvec3({
+                  24 => VecValue::TypeIvec4({
                     let len28 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len28, len28)
                   }),
-                  24 => VecValue::TypeIvec4({
+                  25 => VecValue::TypeDuration({
                     let len29 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len29, len29)
                   }),
-                  25 => VecValue::TypeDuration({
+                  26 => VecValue::TypeProceduralMeshHandle({
                     let len30 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len30, len30)
                   }),
-                  26 => VecValue::TypeProceduralMeshHandle({
+                  27 => VecValue::TypeProceduralTextureHandle({
                     let len31 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len31, len31)
                   }),
-                  27 => VecValue::TypeProceduralTextureHandle({
+                  28 => VecValue::TypeProceduralSamplerHandle({
                     let len32 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len32, len32)
                   }),
-                  28 => VecValue::TypeProceduralSamplerHandle({
+                  #[cfg(debug_assertions)]29 => VecValue::TypeProceduralMaterialHandle({
                     let len33 = *((base + 24) as *const i32) as usize;
                     
                     Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
                   }),
-                  #[cfg(debug_assertions)]29 => VecValue::TypeProceduralMaterialHandle({
-                    let len34 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len34, len34)
-                  }),
                   #[cfg(not(debug_assertions))]_ => VecValue::TypeProceduralMaterialHandle({
-                    let len34 = *((base + 24) as *const i32) as usize;
+                    let len33 = *((base + 24) as *const i32) as usize;
                     
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len34, len34)
+                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
                   }),
                   #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                 }}}),
-                #[cfg(debug_assertions)]31 => Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
+                31 => Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
                   0 => OptionValue::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
                     1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 25) as *const u8)) as u8, }),
@@ -2694,1072 +3239,9 @@ pub mod ambient {
                   7 => OptionValue::TypeString(match i32::from(*((base + 24) as *const u8)) {
                     0 => None,
                     1 => Some({
-                      let len35 = *((base + 32) as *const i32) as usize;
+                      let len34 = *((base + 32) as *const i32) as usize;
                       
-                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len35, len35))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len35, len35)).unwrap()}}
-                    }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  8 => OptionValue::TypeU8(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 25) as *const u8)) as u8),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  9 => OptionValue::TypeU16(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 26) as *const u16)) as u16),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  10 => OptionValue::TypeU32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const i32) as u32),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  11 => OptionValue::TypeU64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const i64) as u64),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  12 => OptionValue::TypeI8(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 25) as *const i8)) as i8),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  13 => OptionValue::TypeI16(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 26) as *const i16)) as i16),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  14 => OptionValue::TypeI32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const i32)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  15 => OptionValue::TypeI64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const i64)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  16 => OptionValue::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  17 => OptionValue::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  18 => OptionValue::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  19 => OptionValue::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  20 => OptionValue::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  21 => OptionValue::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  22 => OptionValue::TypeIvec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec2{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  23 => OptionValue::TypeIvec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec3{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  24 => OptionValue::TypeIvec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec4{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), w:*((base + 40) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  25 => OptionValue::TypeDuration(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Duration{seconds:*((base + 32) as *const i64) as u64, nanoseconds:*((base + 40) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  26 => OptionValue::TypeProceduralMeshHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  27 => OptionValue::TypeProceduralTextureHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  28 => OptionValue::TypeProceduralSamplerHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(debug_assertions)]29 => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(not(debug_assertions))]_ => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                }}}),
-                #[cfg(not(debug_assertions))]_ => Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
-                  0 => OptionValue::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 25) as *const u8)) as u8, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  1 => OptionValue::TypeBool(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some({
-                      #[cfg(not(debug_assertions))]
-                      { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
-                      #[cfg(debug_assertions)]
-                      {
-                        match i32::from(*((base + 25) as *const u8)) {
-                          0 => false,
-                          1 => true,
-                          _ => panic!("invalid bool discriminant"),
-                        }
-                      }
-                    }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  2 => OptionValue::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  3 => OptionValue::TypeF32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const f32)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  4 => OptionValue::TypeF64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const f64)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  5 => OptionValue::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  6 => OptionValue::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  7 => OptionValue::TypeString(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some({
-                      let len35 = *((base + 32) as *const i32) as usize;
-                      
-                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len35, len35))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len35, len35)).unwrap()}}
-                    }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  8 => OptionValue::TypeU8(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 25) as *const u8)) as u8),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  9 => OptionValue::TypeU16(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 26) as *const u16)) as u16),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  10 => OptionValue::TypeU32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const i32) as u32),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  11 => OptionValue::TypeU64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const i64) as u64),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  12 => OptionValue::TypeI8(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 25) as *const i8)) as i8),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  13 => OptionValue::TypeI16(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 26) as *const i16)) as i16),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  14 => OptionValue::TypeI32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const i32)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  15 => OptionValue::TypeI64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const i64)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  16 => OptionValue::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  17 => OptionValue::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  18 => OptionValue::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  19 => OptionValue::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  20 => OptionValue::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  21 => OptionValue::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  22 => OptionValue::TypeIvec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec2{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  23 => OptionValue::TypeIvec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec3{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  24 => OptionValue::TypeIvec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec4{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), w:*((base + 40) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  25 => OptionValue::TypeDuration(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Duration{seconds:*((base + 32) as *const i64) as u64, nanoseconds:*((base + 40) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  26 => OptionValue::TypeProceduralMeshHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  27 => OptionValue::TypeProceduralTextureHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  28 => OptionValue::TypeProceduralSamplerHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(debug_assertions)]29 => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(not(debug_assertions))]_ => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                }}}),
-                #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-              }}}));
-            }
-            wit_bindgen::rt::dealloc(base36, (len36 as usize) * 96, 8);
-            result36
-          }
-        }
-        #[allow(clippy::all)]
-        pub fn get_all_components(entity: EntityId,) -> Entity{
-          
-          #[allow(unused_imports)]
-          use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-          unsafe {
-            
-            #[repr(align(4))]
-            struct RetArea([u8; 8]);
-            let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
-            let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = entity;
-            let ptr1 = ret_area.as_mut_ptr() as i32;
-            #[link(wasm_import_module = "ambient:bindings/component")]
-            extern "C" {
-              #[cfg_attr(target_arch = "wasm32", link_name = "get-all-components")]
-              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_get-all-components")]
-              fn wit_import(
-              _: i64, _: i64, _: i32, );
-            }
-            wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), ptr1);
-            let base35 = *((ptr1 + 0) as *const i32);
-            let len35 = *((ptr1 + 4) as *const i32);
-            let mut result35 = Vec::with_capacity(len35 as usize);
-            for i in 0..len35 {
-              let base = base35 + i *96;
-              result35.push((*((base + 0) as *const i32) as u32, {{match i32::from(*((base + 8) as *const u8)) {
-                0 => Value::TypeEmpty(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 16) as *const u8)) as u8, }),
-                1 => Value::TypeBool({
-                  #[cfg(not(debug_assertions))]
-                  { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 16) as *const u8)) as u8) }
-                  #[cfg(debug_assertions)]
-                  {
-                    match i32::from(*((base + 16) as *const u8)) {
-                      0 => false,
-                      1 => true,
-                      _ => panic!("invalid bool discriminant"),
-                    }
-                  }
-                }),
-                2 => Value::TypeEntityId(super::super::super::ambient::bindings::types::EntityId{id0:*((base + 16) as *const i64) as u64, id1:*((base + 24) as *const i64) as u64, }),
-                3 => Value::TypeF32(*((base + 16) as *const f32)),
-                4 => Value::TypeF64(*((base + 16) as *const f64)),
-                5 => Value::TypeMat4(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 32) as *const f32), y:*((base + 36) as *const f32), z:*((base + 40) as *const f32), w:*((base + 44) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 48) as *const f32), y:*((base + 52) as *const f32), z:*((base + 56) as *const f32), w:*((base + 60) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 64) as *const f32), y:*((base + 68) as *const f32), z:*((base + 72) as *const f32), w:*((base + 76) as *const f32), }, }),
-                6 => Value::TypeQuat(super::super::super::ambient::bindings::types::Quat{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
-                7 => Value::TypeString({
-                  let len2 = *((base + 20) as *const i32) as usize;
-                  
-                  {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len2, len2)).unwrap()}}
-                }),
-                8 => Value::TypeU8(i32::from(*((base + 16) as *const u8)) as u8),
-                9 => Value::TypeU16(i32::from(*((base + 16) as *const u16)) as u16),
-                10 => Value::TypeU32(*((base + 16) as *const i32) as u32),
-                11 => Value::TypeU64(*((base + 16) as *const i64) as u64),
-                12 => Value::TypeI8(i32::from(*((base + 16) as *const i8)) as i8),
-                13 => Value::TypeI16(i32::from(*((base + 16) as *const i16)) as i16),
-                14 => Value::TypeI32(*((base + 16) as *const i32)),
-                15 => Value::TypeI64(*((base + 16) as *const i64)),
-                16 => Value::TypeVec2(super::super::super::ambient::bindings::types::Vec2{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), }),
-                17 => Value::TypeVec3(super::super::super::ambient::bindings::types::Vec3{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), }),
-                18 => Value::TypeVec4(super::super::super::ambient::bindings::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
-                19 => Value::TypeUvec2(super::super::super::ambient::bindings::types::Uvec2{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, }),
-                20 => Value::TypeUvec3(super::super::super::ambient::bindings::types::Uvec3{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, z:*((base + 24) as *const i32) as u32, }),
-                21 => Value::TypeUvec4(super::super::super::ambient::bindings::types::Uvec4{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, z:*((base + 24) as *const i32) as u32, w:*((base + 28) as *const i32) as u32, }),
-                22 => Value::TypeIvec2(super::super::super::ambient::bindings::types::Ivec2{x:*((base + 16) as *const i32), y:*((base + 20) as *const i32), }),
-                23 => Value::TypeIvec3(super::super::super::ambient::bindings::types::Ivec3{x:*((base + 16) as *const i32), y:*((base + 20) as *const i32), z:*((base + 24) as *const i32), }),
-                24 => Value::TypeIvec4(super::super::super::ambient::bindings::types::Ivec4{x:*((base + 16) as *const i32), y:*((base + 20) as *const i32), z:*((base + 24) as *const i32), w:*((base + 28) as *const i32), }),
-                25 => Value::TypeDuration(super::super::super::ambient::bindings::types::Duration{seconds:*((base + 16) as *const i64) as u64, nanoseconds:*((base + 24) as *const i32) as u32, }),
-                26 => Value::TypeProceduralMeshHandle(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
-                27 => Value::TypeProceduralTextureHandle(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
-                28 => Value::TypeProceduralSamplerHandle(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
-                29 => Value::TypeProceduralMaterialHandle(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 16) as *const i64) as u64, *((base + 24) as *const i64) as u64), }),
-                30 => Value::TypeVec({{match i32::from(*((base + 16) as *const u8)) {
-                  0 => VecValue::TypeEmpty({
-                    let len3 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len3, len3)
-                  }),
-                  1 => VecValue::TypeBool({
-                    let base4 = *((base + 20) as *const i32);
-                    let len4 = *((base + 24) as *const i32);
-                    let mut result4 = Vec::with_capacity(len4 as usize);
-                    for i in 0..len4 {
-                      let base = base4 + i *1;
-                      result4.push({
-                        #[cfg(not(debug_assertions))]
-                        { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 0) as *const u8)) as u8) }
-                        #[cfg(debug_assertions)]
-                        {
-                          match i32::from(*((base + 0) as *const u8)) {
-                            0 => false,
-                            1 => true,
-                            _ => panic!("invalid bool discriminant"),
-                          }
-                        }
-                      });
-                    }
-                    wit_bindgen::rt::dealloc(base4, (len4 as usize) * 1, 1);
-                    
-                    result4
-                  }),
-                  2 => VecValue::TypeEntityId({
-                    let len5 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len5, len5)
-                  }),
-                  3 => VecValue::TypeF32({
-                    let len6 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len6, len6)
-                  }),
-                  4 => VecValue::TypeF64({
-                    let len7 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len7, len7)
-                  }),
-                  5 => VecValue::TypeMat4({
-                    let len8 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len8, len8)
-                  }),
-                  6 => VecValue::TypeQuat({
-                    let len9 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len9, len9)
-                  }),
-                  7 => VecValue::TypeString({
-                    let base11 = *((base + 20) as *const i32);
-                    let len11 = *((base + 24) as *const i32);
-                    let mut result11 = Vec::with_capacity(len11 as usize);
-                    for i in 0..len11 {
-                      let base = base11 + i *8;
-                      result11.push({
-                        let len10 = *((base + 4) as *const i32) as usize;
-                        
-                        {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10)).unwrap()}}
-                      });
-                    }
-                    wit_bindgen::rt::dealloc(base11, (len11 as usize) * 8, 4);
-                    
-                    result11
-                  }),
-                  8 => VecValue::TypeU8({
-                    let len12 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len12, len12)
-                  }),
-                  9 => VecValue::TypeU16({
-                    let len13 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len13, len13)
-                  }),
-                  10 => VecValue::TypeU32({
-                    let len14 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len14, len14)
-                  }),
-                  11 => VecValue::TypeU64({
-                    let len15 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len15, len15)
-                  }),
-                  12 => VecValue::TypeI8({
-                    let len16 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len16, len16)
-                  }),
-                  13 => VecValue::TypeI16({
-                    let len17 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len17, len17)
-                  }),
-                  14 => VecValue::TypeI32({
-                    let len18 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len18, len18)
-                  }),
-                  15 => VecValue::TypeI64({
-                    let len19 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
-                  }),
-                  16 => VecValue::TypeVec2({
-                    let len20 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len20, len20)
-                  }),
-                  17 => VecValue::TypeVec3({
-                    let len21 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len21, len21)
-                  }),
-                  18 => VecValue::TypeVec4({
-                    let len22 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len22, len22)
-                  }),
-                  19 => VecValue::TypeUvec2({
-                    let len23 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len23, len23)
-                  }),
-                  20 => VecValue::TypeUvec3({
-                    let len24 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len24, len24)
-                  }),
-                  21 => VecValue::TypeUvec4({
-                    let len25 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len25, len25)
-                  }),
-                  22 => VecValue::TypeIvec2({
-                    let len26 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len26, len26)
-                  }),
-                  23 => VecValue::TypeIvec3({
-                    let len27 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len27, len27)
-                  }),
-                  24 => VecValue::TypeIvec4({
-                    let len28 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len28, len28)
-                  }),
-                  25 => VecValue::TypeDuration({
-                    let len29 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len29, len29)
-                  }),
-                  26 => VecValue::TypeProceduralMeshHandle({
-                    let len30 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len30, len30)
-                  }),
-                  27 => VecValue::TypeProceduralTextureHandle({
-                    let len31 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len31, len31)
-                  }),
-                  28 => VecValue::TypeProceduralSamplerHandle({
-                    let len32 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len32, len32)
-                  }),
-                  #[cfg(debug_assertions)]29 => VecValue::TypeProceduralMaterialHandle({
-                    let len33 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
-                  }),
-                  #[cfg(not(debug_assertions))]_ => VecValue::TypeProceduralMaterialHandle({
-                    let len33 = *((base + 24) as *const i32) as usize;
-                    
-                    Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
-                  }),
-                  #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                }}}),
-                #[cfg(debug_assertions)]31 => Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
-                  0 => OptionValue::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 25) as *const u8)) as u8, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  1 => OptionValue::TypeBool(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some({
-                      #[cfg(not(debug_assertions))]
-                      { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
-                      #[cfg(debug_assertions)]
-                      {
-                        match i32::from(*((base + 25) as *const u8)) {
-                          0 => false,
-                          1 => true,
-                          _ => panic!("invalid bool discriminant"),
-                        }
-                      }
-                    }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  2 => OptionValue::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  3 => OptionValue::TypeF32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const f32)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  4 => OptionValue::TypeF64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const f64)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  5 => OptionValue::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  6 => OptionValue::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  7 => OptionValue::TypeString(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some({
-                      let len34 = *((base + 32) as *const i32) as usize;
-                      
-                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len34, len34))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len34, len34)).unwrap()}}
-                    }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  8 => OptionValue::TypeU8(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 25) as *const u8)) as u8),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  9 => OptionValue::TypeU16(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 26) as *const u16)) as u16),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  10 => OptionValue::TypeU32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const i32) as u32),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  11 => OptionValue::TypeU64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const i64) as u64),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  12 => OptionValue::TypeI8(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 25) as *const i8)) as i8),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  13 => OptionValue::TypeI16(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(i32::from(*((base + 26) as *const i16)) as i16),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  14 => OptionValue::TypeI32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const i32)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  15 => OptionValue::TypeI64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const i64)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  16 => OptionValue::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  17 => OptionValue::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  18 => OptionValue::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  19 => OptionValue::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  20 => OptionValue::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  21 => OptionValue::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  22 => OptionValue::TypeIvec2(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec2{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  23 => OptionValue::TypeIvec3(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec3{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  24 => OptionValue::TypeIvec4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Ivec4{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), w:*((base + 40) as *const i32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  25 => OptionValue::TypeDuration(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Duration{seconds:*((base + 32) as *const i64) as u64, nanoseconds:*((base + 40) as *const i32) as u32, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  26 => OptionValue::TypeProceduralMeshHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  27 => OptionValue::TypeProceduralTextureHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  28 => OptionValue::TypeProceduralSamplerHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(debug_assertions)]29 => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(not(debug_assertions))]_ => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                }}}),
-                #[cfg(not(debug_assertions))]_ => Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
-                  0 => OptionValue::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 25) as *const u8)) as u8, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  1 => OptionValue::TypeBool(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some({
-                      #[cfg(not(debug_assertions))]
-                      { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
-                      #[cfg(debug_assertions)]
-                      {
-                        match i32::from(*((base + 25) as *const u8)) {
-                          0 => false,
-                          1 => true,
-                          _ => panic!("invalid bool discriminant"),
-                        }
-                      }
-                    }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  2 => OptionValue::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  3 => OptionValue::TypeF32(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 28) as *const f32)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  4 => OptionValue::TypeF64(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(*((base + 32) as *const f64)),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  5 => OptionValue::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  6 => OptionValue::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some(super::super::super::ambient::bindings::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                    #[cfg(not(debug_assertions))]
-                    _ => ::core::hint::unreachable_unchecked(),
-                    #[cfg(debug_assertions)]
-                    _ => panic!("invalid enum discriminant"),
-                  }),
-                  7 => OptionValue::TypeString(match i32::from(*((base + 24) as *const u8)) {
-                    0 => None,
-                    1 => Some({
-                      let len34 = *((base + 32) as *const i32) as usize;
-                      
-                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len34, len34))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len34, len34)).unwrap()}}
+                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len34, len34))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len34, len34)).unwrap()}}
                     }),
                     #[cfg(not(debug_assertions))]
                     _ => ::core::hint::unreachable_unchecked(),
@@ -3952,6 +3434,21 @@ pub mod ambient {
                   }),
                   #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                 }}}),
+                32 => Value::TypeStruct({
+                  let len_struct = *((base + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_struct, len_struct)
+                }),
+                #[cfg(debug_assertions)]33 => Value::TypeMap({
+                  let len_map = *((base + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_map, len_map)
+                }),
+                #[cfg(not(debug_assertions))]_ => Value::TypeMap({
+                  let len_map = *((base + 20) as *const i32) as usize;
+                  
+                  Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_map, len_map)
+                }),
                 #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
               }}}));
             }
@@ -5071,6 +4568,24 @@ pub mod ambient {
                     };
                     
                   },
+                  Value::TypeStruct(e) => {
+                    *((ptr0 + 24) as *mut u8) = (32i32) as u8;
+                    let vec10 = e;
+                    let ptr10 = vec10.as_ptr() as i32;
+                    let len10 = vec10.len() as i32;
+                    *((ptr0 + 36) as *mut i32) = len10;
+                    *((ptr0 + 32) as *mut i32) = ptr10;
+                    
+                  },
+                  Value::TypeMap(e) => {
+                    *((ptr0 + 24) as *mut u8) = (33i32) as u8;
+                    let vec10 = e;
+                    let ptr10 = vec10.as_ptr() as i32;
+                    let len10 = vec10.len() as i32;
+                    *((ptr0 + 36) as *mut i32) = len10;
+                    *((ptr0 + 32) as *mut i32) = ptr10;
+                    
+                  },
                 };
                 
                 #[link(wasm_import_module = "ambient:bindings/component")]
@@ -6216,6 +5731,24 @@ pub mod ambient {
                             };
                             
                           },
+                          Value::TypeStruct(e) => {
+                            *((base + 8) as *mut u8) = (32i32) as u8;
+                            let vec10 = e;
+                            let ptr10 = vec10.as_ptr() as i32;
+                            let len10 = vec10.len() as i32;
+                            *((base + 20) as *mut i32) = len10;
+                            *((base + 16) as *mut i32) = ptr10;
+                            
+                          },
+                          Value::TypeMap(e) => {
+                            *((base + 8) as *mut u8) = (33i32) as u8;
+                            let vec10 = e;
+                            let ptr10 = vec10.as_ptr() as i32;
+                            let len10 = vec10.len() as i32;
+                            *((base + 20) as *mut i32) = len10;
+                            *((base + 16) as *mut i32) = ptr10;
+                            
+                          },
                         };
                         
                       }}
@@ -7354,6 +6887,24 @@ pub mod ambient {
                               };
                               
                             },
+                            Value::TypeStruct(e) => {
+                              *((ptr0 + 24) as *mut u8) = (32i32) as u8;
+                              let vec10 = e;
+                              let ptr10 = vec10.as_ptr() as i32;
+                              let len10 = vec10.len() as i32;
+                              *((ptr0 + 36) as *mut i32) = len10;
+                              *((ptr0 + 32) as *mut i32) = ptr10;
+                              
+                            },
+                            Value::TypeMap(e) => {
+                              *((ptr0 + 24) as *mut u8) = (33i32) as u8;
+                              let vec10 = e;
+                              let ptr10 = vec10.as_ptr() as i32;
+                              let len10 = vec10.len() as i32;
+                              *((ptr0 + 36) as *mut i32) = len10;
+                              *((ptr0 + 32) as *mut i32) = ptr10;
+                              
+                            },
                           };
                           
                           #[link(wasm_import_module = "ambient:bindings/component")]
@@ -8499,6 +8050,24 @@ pub mod ambient {
                                       };
                                       
                                     },
+                                    Value::TypeStruct(e) => {
+                                      *((base + 8) as *mut u8) = (32i32) as u8;
+                                      let vec10 = e;
+                                      let ptr10 = vec10.as_ptr() as i32;
+                                      let len10 = vec10.len() as i32;
+                                      *((base + 20) as *mut i32) = len10;
+                                      *((base + 16) as *mut i32) = ptr10;
+                                      
+                                    },
+                                    Value::TypeMap(e) => {
+                                      *((base + 8) as *mut u8) = (33i32) as u8;
+                                      let vec10 = e;
+                                      let ptr10 = vec10.as_ptr() as i32;
+                                      let len10 = vec10.len() as i32;
+                                      *((base + 20) as *mut i32) = len10;
+                                      *((base + 16) as *mut i32) = ptr10;
+                                      
+                                    },
                                   };
                                   
                                 }}
@@ -8633,7 +8202,8 @@ pub mod ambient {
                               #[allow(unused_imports)]
                               use wit_bindgen::rt::{alloc, vec::Vec, string::String};
                               unsafe {
-                                let QueryBuild{ components:components0, includes:includes0, excludes:excludes0, changed:changed0, } = q;
+                                let mut cleanup_list = Vec::new();
+                                let QueryBuild{ components:components0, includes:includes0, excludes:excludes0, changed:changed0, relations:relations0, } = q;
                                 let vec1 = components0;
                                 let ptr1 = vec1.as_ptr() as i32;
                                 let len1 = vec1.len() as i32;
@@ -8646,20 +8216,66 @@ pub mod ambient {
                                 let vec4 = changed0;
                                 let ptr4 = vec4.as_ptr() as i32;
                                 let len4 = vec4.len() as i32;
-                                
-                                #[link(wasm_import_module = "ambient:bindings/component")]
-                                extern "C" {
-                                  #[cfg_attr(target_arch = "wasm32", link_name = "query")]
-                                  #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_query")]
-                                  fn wit_import(
-                                  _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ) -> i64;
-                                }
-                                let ret = wit_import(ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4, match t {
-                                  QueryEvent::Frame => 0,
-                                  QueryEvent::Spawn => 1,
-                                  QueryEvent::Despawn => 2,
-                                });
-                                ret as u64
+                                let vec10 = relations0;
+                                let len10 = vec10.len() as i32;
+                                let layout10 = alloc::Layout::from_size_align_unchecked(vec10.len() * 28, 4);
+                                let result10 = if layout10.size() != 0
+                                {
+                                  let ptr = alloc::alloc(layout10);
+                                  if ptr.is_null()
+                                  {
+                                    alloc::handle_alloc_error(layout10);
+                                  }
+                                  ptr
+                                }else {
+                                  ::core::ptr::null_mut()
+                                };
+                                for (i, e) in vec10.into_iter().enumerate() {
+                                  let base = result10 as i32 + (i as i32) * 28;
+                                  {
+                                    let QueryRelation{ component:component5, includes:includes5, excludes:excludes5, changed:changed5, } = e;
+                                    *((base + 0) as *mut i32) = wit_bindgen::rt::as_i32(component5);
+                                    let vec6 = includes5;
+                                    let ptr6 = vec6.as_ptr() as i32;
+                                    let len6 = vec6.len() as i32;
+                                    *((base + 8) as *mut i32) = len6;
+                                    *((base + 4) as *mut i32) = ptr6;
+                                    let vec7 = excludes5;
+                                    let ptr7 = vec7.as_ptr() as i32;
+                                    let len7 = vec7.len() as i32;
+                                    *((base + 16) as *mut i32) = len7;
+                                    *((base + 12) as *mut i32) = ptr7;
+                                    let vec8 = changed5;
+                                    let ptr8 = vec8.as_ptr() as i32;
+                                    let len8 = vec8.len() as i32;
+                                    *((base + 24) as *mut i32) = len8;
+                                    *((base + 20) as *mut i32) = ptr8;
+                                    
+                                  }}
+                                  
+                                  #[link(wasm_import_module = "ambient:bindings/component")]
+                                  extern "C" {
+                                    #[cfg_attr(target_arch = "wasm32", link_name = "query")]
+                                    #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_query")]
+                                    fn wit_import(
+                                    _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ) -> i64;
+                                  }
+                                  let ret = wit_import(ptr1, len1, ptr2, len2, ptr3, len3, ptr4, len4, result10 as i32, len10, match t {
+                                    QueryEvent::Frame => 0,
+                                    QueryEvent::Spawn => 1,
+                                    QueryEvent::Despawn => 2,
+                                  });
+                                  cleanup_list.extend_from_slice(&[(result10, layout10),]);
+                                  for (ptr, layout) in cleanup_list {
+                                    
+                                    if layout.size() != 0 {
+                                      
+                                      alloc::dealloc(ptr, layout);
+                                      
+                                    }
+                                    
+                                  }
+                                  ret as u64
                               }
                             }
                             #[allow(clippy::all)]
@@ -8828,370 +8444,104 @@ pub mod ambient {
                                             
                                             Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len14, len14)
                                           }),
-                                          12 => VecValue::TypeI8({
-                                            let len15 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len15, len15)
-                                          }),
-                                          13 => VecValue::TypeI16({
-                                            let len16 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len16, len16)
-                                          }),
-                                          14 => VecValue::TypeI32({
-                                            let len17 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len17, len17)
-                                          }),
-                                          15 => VecValue::TypeI64({
-                                            let len18 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len18, len18)
-                                          }),
-                                          16 => VecValue::TypeVec2({
-                                            let len19 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len19, len19)
-                                          }),
-                                          17 => VecValue::TypeVec3({
-                                            let len20 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len20, len20)
-                                          }),
-                                          18 => VecValue::TypeVec4({
-                                            let len21 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len21, len21)
-                                          }),
-                                          19 => VecValue::TypeUvec2({
-                                            let len22 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len22, len22)
-                                          }),
-                                          20 => VecValue::TypeUvec3({
-                                            let len23 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len23, len23)
-                                          }),
-                                          21 => VecValue::TypeUvec4({
-                                            let len24 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len24, len24)
-                                          }),
-                                          22 => VecValue::TypeIvec2({
-                                            let len25 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len25, len25)
-                                          }),
-                                          23 => VecValue::TypeIvec3({
-                                            let len26 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len26, len26)
-                                          }),
-                                          24 => VecValue::TypeIvec4({
-                                            let len27 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len27, len27)
-                                          }),
-                                          25 => VecValue::TypeDuration({
-                                            let len28 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len28, len28)
-                                          }),
-                                          26 => VecValue::TypeProceduralMeshHandle({
-                                            let len29 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len29, len29)
-                                          }),
-                                          27 => VecValue::TypeProceduralTextureHandle({
-                                            let len30 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len30, len30)
-                                          }),
-                                          28 => VecValue::TypeProceduralSamplerHandle({
-                                            let len31 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len31, len31)
-                                          }),
-                                          #[cfg(debug_assertions)]29 => VecValue::TypeProceduralMaterialHandle({
-                                            let len32 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len32, len32)
-                                          }),
-                                          #[cfg(not(debug_assertions))]_ => VecValue::TypeProceduralMaterialHandle({
-                                            let len32 = *((base + 16) as *const i32) as usize;
-                                            
-                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len32, len32)
-                                          }),
-                                          #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                                        }}}),
-                                        #[cfg(debug_assertions)]31 => Value::TypeOption({{match i32::from(*((base + 8) as *const u8)) {
-                                          0 => OptionValue::TypeEmpty(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 17) as *const u8)) as u8, }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          1 => OptionValue::TypeBool(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some({
-                                              #[cfg(not(debug_assertions))]
-                                              { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 17) as *const u8)) as u8) }
-                                              #[cfg(debug_assertions)]
-                                              {
-                                                match i32::from(*((base + 17) as *const u8)) {
-                                                  0 => false,
-                                                  1 => true,
-                                                  _ => panic!("invalid bool discriminant"),
-                                                }
-                                              }
-                                            }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          2 => OptionValue::TypeEntityId(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::EntityId{id0:*((base + 24) as *const i64) as u64, id1:*((base + 32) as *const i64) as u64, }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          3 => OptionValue::TypeF32(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(*((base + 20) as *const f32)),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          4 => OptionValue::TypeF64(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(*((base + 24) as *const f64)),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          5 => OptionValue::TypeMat4(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 20) as *const f32), y:*((base + 24) as *const f32), z:*((base + 28) as *const f32), w:*((base + 32) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 36) as *const f32), y:*((base + 40) as *const f32), z:*((base + 44) as *const f32), w:*((base + 48) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 52) as *const f32), y:*((base + 56) as *const f32), z:*((base + 60) as *const f32), w:*((base + 64) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 68) as *const f32), y:*((base + 72) as *const f32), z:*((base + 76) as *const f32), w:*((base + 80) as *const f32), }, }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          6 => OptionValue::TypeQuat(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Quat{x:*((base + 20) as *const f32), y:*((base + 24) as *const f32), z:*((base + 28) as *const f32), w:*((base + 32) as *const f32), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          7 => OptionValue::TypeString(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some({
-                                              let len33 = *((base + 24) as *const i32) as usize;
-                                              
-                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)).unwrap()}}
-                                            }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          8 => OptionValue::TypeU8(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(i32::from(*((base + 17) as *const u8)) as u8),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          9 => OptionValue::TypeU16(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(i32::from(*((base + 18) as *const u16)) as u16),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          10 => OptionValue::TypeU32(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(*((base + 20) as *const i32) as u32),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          11 => OptionValue::TypeU64(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(*((base + 24) as *const i64) as u64),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
-                                          }),
-                                          12 => OptionValue::TypeI8(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(i32::from(*((base + 17) as *const i8)) as i8),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          12 => VecValue::TypeI8({
+                                            let len15 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len15, len15)
                                           }),
-                                          13 => OptionValue::TypeI16(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(i32::from(*((base + 18) as *const i16)) as i16),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          13 => VecValue::TypeI16({
+                                            let len16 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len16, len16)
                                           }),
-                                          14 => OptionValue::TypeI32(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(*((base + 20) as *const i32)),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          14 => VecValue::TypeI32({
+                                            let len17 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len17, len17)
                                           }),
-                                          15 => OptionValue::TypeI64(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(*((base + 24) as *const i64)),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          15 => VecValue::TypeI64({
+                                            let len18 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len18, len18)
                                           }),
-                                          16 => OptionValue::TypeVec2(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Vec2{x:*((base + 20) as *const f32), y:*((base + 24) as *const f32), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          16 => VecValue::TypeVec2({
+                                            let len19 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len19, len19)
                                           }),
-                                          17 => OptionValue::TypeVec3(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Vec3{x:*((base + 20) as *const f32), y:*((base + 24) as *const f32), z:*((base + 28) as *const f32), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          17 => VecValue::TypeVec3({
+                                            let len20 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len20, len20)
                                           }),
-                                          18 => OptionValue::TypeVec4(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Vec4{x:*((base + 20) as *const f32), y:*((base + 24) as *const f32), z:*((base + 28) as *const f32), w:*((base + 32) as *const f32), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          18 => VecValue::TypeVec4({
+                                            let len21 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len21, len21)
                                           }),
-                                          19 => OptionValue::TypeUvec2(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Uvec2{x:*((base + 20) as *const i32) as u32, y:*((base + 24) as *const i32) as u32, }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          19 => VecValue::TypeUvec2({
+                                            let len22 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len22, len22)
                                           }),
-                                          20 => OptionValue::TypeUvec3(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Uvec3{x:*((base + 20) as *const i32) as u32, y:*((base + 24) as *const i32) as u32, z:*((base + 28) as *const i32) as u32, }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          20 => VecValue::TypeUvec3({
+                                            let len23 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len23, len23)
                                           }),
-                                          21 => OptionValue::TypeUvec4(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Uvec4{x:*((base + 20) as *const i32) as u32, y:*((base + 24) as *const i32) as u32, z:*((base + 28) as *const i32) as u32, w:*((base + 32) as *const i32) as u32, }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          21 => VecValue::TypeUvec4({
+                                            let len24 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len24, len24)
                                           }),
-                                          22 => OptionValue::TypeIvec2(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Ivec2{x:*((base + 20) as *const i32), y:*((base + 24) as *const i32), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          22 => VecValue::TypeIvec2({
+                                            let len25 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len25, len25)
                                           }),
-                                          23 => OptionValue::TypeIvec3(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Ivec3{x:*((base + 20) as *const i32), y:*((base + 24) as *const i32), z:*((base + 28) as *const i32), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          23 => VecValue::TypeIvec3({
+                                            let len26 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len26, len26)
                                           }),
-                                          24 => OptionValue::TypeIvec4(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Ivec4{x:*((base + 20) as *const i32), y:*((base + 24) as *const i32), z:*((base + 28) as *const i32), w:*((base + 32) as *const i32), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          24 => VecValue::TypeIvec4({
+                                            let len27 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len27, len27)
                                           }),
-                                          25 => OptionValue::TypeDuration(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::types::Duration{seconds:*((base + 24) as *const i64) as u64, nanoseconds:*((base + 32) as *const i32) as u32, }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          25 => VecValue::TypeDuration({
+                                            let len28 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len28, len28)
                                           }),
-                                          26 => OptionValue::TypeProceduralMeshHandle(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((base + 24) as *const i64) as u64, *((base + 32) as *const i64) as u64), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          26 => VecValue::TypeProceduralMeshHandle({
+                                            let len29 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len29, len29)
                                           }),
-                                          27 => OptionValue::TypeProceduralTextureHandle(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((base + 24) as *const i64) as u64, *((base + 32) as *const i64) as u64), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          27 => VecValue::TypeProceduralTextureHandle({
+                                            let len30 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len30, len30)
                                           }),
-                                          28 => OptionValue::TypeProceduralSamplerHandle(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((base + 24) as *const i64) as u64, *((base + 32) as *const i64) as u64), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          28 => VecValue::TypeProceduralSamplerHandle({
+                                            let len31 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len31, len31)
                                           }),
-                                          #[cfg(debug_assertions)]29 => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 24) as *const i64) as u64, *((base + 32) as *const i64) as u64), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          #[cfg(debug_assertions)]29 => VecValue::TypeProceduralMaterialHandle({
+                                            let len32 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len32, len32)
                                           }),
-                                          #[cfg(not(debug_assertions))]_ => OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 16) as *const u8)) {
-                                            0 => None,
-                                            1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 24) as *const i64) as u64, *((base + 32) as *const i64) as u64), }),
-                                            #[cfg(not(debug_assertions))]
-                                            _ => ::core::hint::unreachable_unchecked(),
-                                            #[cfg(debug_assertions)]
-                                            _ => panic!("invalid enum discriminant"),
+                                          #[cfg(not(debug_assertions))]_ => VecValue::TypeProceduralMaterialHandle({
+                                            let len32 = *((base + 16) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len32, len32)
                                           }),
                                           #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                                         }}}),
-                                        #[cfg(not(debug_assertions))]_ => Value::TypeOption({{match i32::from(*((base + 8) as *const u8)) {
+                                        31 => Value::TypeOption({{match i32::from(*((base + 8) as *const u8)) {
                                           0 => OptionValue::TypeEmpty(match i32::from(*((base + 16) as *const u8)) {
                                             0 => None,
                                             1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 17) as *const u8)) as u8, }),
@@ -9457,6 +8807,21 @@ pub mod ambient {
                                           }),
                                           #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                                         }}}),
+                                        32 => Value::TypeStruct({
+                                          let len_struct = *((base + 12) as *const i32) as usize;
+                                          
+                                          Vec::from_raw_parts(*((base + 8) as *const i32) as *mut _, len_struct, len_struct)
+                                        }),
+                                        #[cfg(debug_assertions)]33 => Value::TypeMap({
+                                          let len_map = *((base + 12) as *const i32) as usize;
+                                          
+                                          Vec::from_raw_parts(*((base + 8) as *const i32) as *mut _, len_map, len_map)
+                                        }),
+                                        #[cfg(not(debug_assertions))]_ => Value::TypeMap({
+                                          let len_map = *((base + 12) as *const i32) as usize;
+                                          
+                                          Vec::from_raw_parts(*((base + 8) as *const i32) as *mut _, len_map, len_map)
+                                        }),
                                         #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                                       }}});
                                     }
@@ -9465,8 +8830,132 @@ pub mod ambient {
                                     (super::super::super::ambient::bindings::types::EntityId{id0:*((base + 0) as *const i64) as u64, id1:*((base + 8) as *const i64) as u64, }, result34)
                                   });
                                 }
-                                wit_bindgen::rt::dealloc(base35, (len35 as usize) * 24, 8);
-                                result35
+                                wit_bindgen::rt::dealloc(base35, (len35 as usize) * 24, 8);
+                                result35
+                              }
+                            }
+                            /// Creates an index over entities which have all of `components`, the includes and none of
+                            /// the excludes. The index is kept up to date until the module is unloaded.
+                            #[allow(clippy::all)]
+                            pub fn index_create(kind: IndexKind,components: &[u32],includes: &[u32],excludes: &[u32],) -> u64{
+                              
+                              #[allow(unused_imports)]
+                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                              unsafe {
+                                let (result0_0,result0_1,) = match kind {
+                                  IndexKind::Ordered=> {
+                                    (0i32, 0.0f32)
+                                  }
+                                  IndexKind::Hash=> {
+                                    (1i32, 0.0f32)
+                                  }
+                                  IndexKind::Grid(e) => (2i32, wit_bindgen::rt::as_f32(e)),
+                                };
+                                let vec1 = components;
+                                let ptr1 = vec1.as_ptr() as i32;
+                                let len1 = vec1.len() as i32;
+                                let vec2 = includes;
+                                let ptr2 = vec2.as_ptr() as i32;
+                                let len2 = vec2.len() as i32;
+                                let vec3 = excludes;
+                                let ptr3 = vec3.as_ptr() as i32;
+                                let len3 = vec3.len() as i32;
+                                
+                                #[link(wasm_import_module = "ambient:bindings/component")]
+                                extern "C" {
+                                  #[cfg_attr(target_arch = "wasm32", link_name = "index-create")]
+                                  #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_index-create")]
+                                  fn wit_import(
+                                  _: i32, _: f32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ) -> i64;
+                                }
+                                let ret = wit_import(result0_0, result0_1, ptr1, len1, ptr2, len2, ptr3, len3);
+                                ret as u64
+                              }
+                            }
+                            /// The entities whose components are equal to `values`, which are the values of the
+                            /// components of the index serialized one after another
+                            #[allow(clippy::all)]
+                            pub fn index_get(index: u64,values: &[u8],) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                              
+                              #[allow(unused_imports)]
+                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                              unsafe {
+                                
+                                #[repr(align(4))]
+                                struct RetArea([u8; 8]);
+                                let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                let vec0 = values;
+                                let ptr0 = vec0.as_ptr() as i32;
+                                let len0 = vec0.len() as i32;
+                                let ptr1 = ret_area.as_mut_ptr() as i32;
+                                
+                                #[link(wasm_import_module = "ambient:bindings/component")]
+                                extern "C" {
+                                  #[cfg_attr(target_arch = "wasm32", link_name = "index-get")]
+                                  #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_index-get")]
+                                  fn wit_import(
+                                  _: i64, _: i32, _: i32, _: i32, );
+                                }
+                                wit_import(wit_bindgen::rt::as_i64(index), ptr0, len0, ptr1);
+                                let len2 = *((ptr1 + 4) as *const i32) as usize;
+                                Vec::from_raw_parts(*((ptr1 + 0) as *const i32) as *mut _, len2, len2)
+                              }
+                            }
+                            /// The entities whose components are between `min` and `max`, inclusive
+                            #[allow(clippy::all)]
+                            pub fn index_range(index: u64,min: &[u8],max: &[u8],) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                              
+                              #[allow(unused_imports)]
+                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                              unsafe {
+                                
+                                #[repr(align(4))]
+                                struct RetArea([u8; 8]);
+                                let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                let vec0 = min;
+                                let ptr0 = vec0.as_ptr() as i32;
+                                let len0 = vec0.len() as i32;
+                                let vec1 = max;
+                                let ptr1 = vec1.as_ptr() as i32;
+                                let len1 = vec1.len() as i32;
+                                let ptr2 = ret_area.as_mut_ptr() as i32;
+                                
+                                #[link(wasm_import_module = "ambient:bindings/component")]
+                                extern "C" {
+                                  #[cfg_attr(target_arch = "wasm32", link_name = "index-range")]
+                                  #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_index-range")]
+                                  fn wit_import(
+                                  _: i64, _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                }
+                                wit_import(wit_bindgen::rt::as_i64(index), ptr0, len0, ptr1, len1, ptr2);
+                                let len3 = *((ptr2 + 4) as *const i32) as usize;
+                                Vec::from_raw_parts(*((ptr2 + 0) as *const i32) as *mut _, len3, len3)
+                              }
+                            }
+                            /// The entities at most `radius` away from `center`. Only supported by grid indexes.
+                            #[allow(clippy::all)]
+                            pub fn index_within_radius(index: u64,center: Vec3,radius: f32,) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                              
+                              #[allow(unused_imports)]
+                              use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                              unsafe {
+                                
+                                #[repr(align(4))]
+                                struct RetArea([u8; 8]);
+                                let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = center;
+                                let ptr1 = ret_area.as_mut_ptr() as i32;
+                                
+                                #[link(wasm_import_module = "ambient:bindings/component")]
+                                extern "C" {
+                                  #[cfg_attr(target_arch = "wasm32", link_name = "index-within-radius")]
+                                  #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/component_index-within-radius")]
+                                  fn wit_import(
+                                  _: i64, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                }
+                                wit_import(wit_bindgen::rt::as_i64(index), wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(radius), ptr1);
+                                let len2 = *((ptr1 + 4) as *const i32) as usize;
+                                Vec::from_raw_parts(*((ptr1 + 0) as *const i32) as *mut _, len2, len2)
                               }
                             }
                             
@@ -10638,6 +10127,24 @@ pub mod ambient {
                                             };
                                             
                                           },
+                                          super::super::super::ambient::bindings::component::Value::TypeStruct(e) => {
+                                            *((base + 8) as *mut u8) = (32i32) as u8;
+                                            let vec9 = e;
+                                            let ptr9 = vec9.as_ptr() as i32;
+                                            let len9 = vec9.len() as i32;
+                                            *((base + 20) as *mut i32) = len9;
+                                            *((base + 16) as *mut i32) = ptr9;
+                                            
+                                          },
+                                          super::super::super::ambient::bindings::component::Value::TypeMap(e) => {
+                                            *((base + 8) as *mut u8) = (33i32) as u8;
+                                            let vec9 = e;
+                                            let ptr9 = vec9.as_ptr() as i32;
+                                            let len9 = vec9.len() as i32;
+                                            *((base + 20) as *mut i32) = len9;
+                                            *((base + 16) as *mut i32) = ptr9;
+                                            
+                                          },
                                         };
                                         
                                       }}
@@ -10794,405 +10301,139 @@ pub mod ambient {
                                                   Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len9, len9)
                                                 }),
                                                 7 => super::super::super::ambient::bindings::component::VecValue::TypeString({
-                                                  let base11 = *((base + 20) as *const i32);
-                                                  let len11 = *((base + 24) as *const i32);
-                                                  let mut result11 = Vec::with_capacity(len11 as usize);
-                                                  for i in 0..len11 {
-                                                    let base = base11 + i *8;
-                                                    result11.push({
-                                                      let len10 = *((base + 4) as *const i32) as usize;
-                                                      
-                                                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10)).unwrap()}}
-                                                    });
-                                                  }
-                                                  wit_bindgen::rt::dealloc(base11, (len11 as usize) * 8, 4);
-                                                  
-                                                  result11
-                                                }),
-                                                8 => super::super::super::ambient::bindings::component::VecValue::TypeU8({
-                                                  let len12 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len12, len12)
-                                                }),
-                                                9 => super::super::super::ambient::bindings::component::VecValue::TypeU16({
-                                                  let len13 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len13, len13)
-                                                }),
-                                                10 => super::super::super::ambient::bindings::component::VecValue::TypeU32({
-                                                  let len14 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len14, len14)
-                                                }),
-                                                11 => super::super::super::ambient::bindings::component::VecValue::TypeU64({
-                                                  let len15 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len15, len15)
-                                                }),
-                                                12 => super::super::super::ambient::bindings::component::VecValue::TypeI8({
-                                                  let len16 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len16, len16)
-                                                }),
-                                                13 => super::super::super::ambient::bindings::component::VecValue::TypeI16({
-                                                  let len17 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len17, len17)
-                                                }),
-                                                14 => super::super::super::ambient::bindings::component::VecValue::TypeI32({
-                                                  let len18 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len18, len18)
-                                                }),
-                                                15 => super::super::super::ambient::bindings::component::VecValue::TypeI64({
-                                                  let len19 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
-                                                }),
-                                                16 => super::super::super::ambient::bindings::component::VecValue::TypeVec2({
-                                                  let len20 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len20, len20)
-                                                }),
-                                                17 => super::super::super::ambient::bindings::component::VecValue::TypeVec3({
-                                                  let len21 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len21, len21)
-                                                }),
-                                                18 => super::super::super::ambient::bindings::component::VecValue::TypeVec4({
-                                                  let len22 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len22, len22)
-                                                }),
-                                                19 => super::super::super::ambient::bindings::component::VecValue::TypeUvec2({
-                                                  let len23 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len23, len23)
-                                                }),
-                                                20 => super::super::super::ambient::bindings::component::VecValue::TypeUvec3({
-                                                  let len24 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len24, len24)
-                                                }),
-                                                21 => super::super::super::ambient::bindings::component::VecValue::TypeUvec4({
-                                                  let len25 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len25, len25)
-                                                }),
-                                                22 => super::super::super::ambient::bindings::component::VecValue::TypeIvec2({
-                                                  let len26 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len26, len26)
-                                                }),
-                                                23 => super::super::super::ambient::bindings::component::VecValue::TypeIvec3({
-                                                  let len27 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len27, len27)
-                                                }),
-                                                24 => super::super::super::ambient::bindings::component::VecValue::TypeIvec4({
-                                                  let len28 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len28, len28)
-                                                }),
-                                                25 => super::super::super::ambient::bindings::component::VecValue::TypeDuration({
-                                                  let len29 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len29, len29)
-                                                }),
-                                                26 => super::super::super::ambient::bindings::component::VecValue::TypeProceduralMeshHandle({
-                                                  let len30 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len30, len30)
-                                                }),
-                                                27 => super::super::super::ambient::bindings::component::VecValue::TypeProceduralTextureHandle({
-                                                  let len31 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len31, len31)
-                                                }),
-                                                28 => super::super::super::ambient::bindings::component::VecValue::TypeProceduralSamplerHandle({
-                                                  let len32 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len32, len32)
-                                                }),
-                                                #[cfg(debug_assertions)]29 => super::super::super::ambient::bindings::component::VecValue::TypeProceduralMaterialHandle({
-                                                  let len33 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
-                                                }),
-                                                #[cfg(not(debug_assertions))]_ => super::super::super::ambient::bindings::component::VecValue::TypeProceduralMaterialHandle({
-                                                  let len33 = *((base + 24) as *const i32) as usize;
-                                                  
-                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
-                                                }),
-                                                #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                                              }}}),
-                                              #[cfg(debug_assertions)]31 => super::super::super::ambient::bindings::component::Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
-                                                0 => super::super::super::ambient::bindings::component::OptionValue::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 25) as *const u8)) as u8, }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
-                                                }),
-                                                1 => super::super::super::ambient::bindings::component::OptionValue::TypeBool(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some({
-                                                    #[cfg(not(debug_assertions))]
-                                                    { ::core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
-                                                    #[cfg(debug_assertions)]
-                                                    {
-                                                      match i32::from(*((base + 25) as *const u8)) {
-                                                        0 => false,
-                                                        1 => true,
-                                                        _ => panic!("invalid bool discriminant"),
-                                                      }
-                                                    }
-                                                  }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
-                                                }),
-                                                2 => super::super::super::ambient::bindings::component::OptionValue::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
-                                                }),
-                                                3 => super::super::super::ambient::bindings::component::OptionValue::TypeF32(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(*((base + 28) as *const f32)),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
-                                                }),
-                                                4 => super::super::super::ambient::bindings::component::OptionValue::TypeF64(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(*((base + 32) as *const f64)),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
-                                                }),
-                                                5 => super::super::super::ambient::bindings::component::OptionValue::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Mat4{x:super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::super::super::ambient::bindings::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::super::super::ambient::bindings::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::super::super::ambient::bindings::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
-                                                }),
-                                                6 => super::super::super::ambient::bindings::component::OptionValue::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
-                                                }),
-                                                7 => super::super::super::ambient::bindings::component::OptionValue::TypeString(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some({
-                                                    let len34 = *((base + 32) as *const i32) as usize;
-                                                    
-                                                    {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len34, len34))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len34, len34)).unwrap()}}
-                                                  }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                  let base11 = *((base + 20) as *const i32);
+                                                  let len11 = *((base + 24) as *const i32);
+                                                  let mut result11 = Vec::with_capacity(len11 as usize);
+                                                  for i in 0..len11 {
+                                                    let base = base11 + i *8;
+                                                    result11.push({
+                                                      let len10 = *((base + 4) as *const i32) as usize;
+                                                      
+                                                      {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10)).unwrap()}}
+                                                    });
+                                                  }
+                                                  wit_bindgen::rt::dealloc(base11, (len11 as usize) * 8, 4);
+                                                  
+                                                  result11
                                                 }),
-                                                8 => super::super::super::ambient::bindings::component::OptionValue::TypeU8(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(i32::from(*((base + 25) as *const u8)) as u8),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                8 => super::super::super::ambient::bindings::component::VecValue::TypeU8({
+                                                  let len12 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len12, len12)
                                                 }),
-                                                9 => super::super::super::ambient::bindings::component::OptionValue::TypeU16(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(i32::from(*((base + 26) as *const u16)) as u16),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                9 => super::super::super::ambient::bindings::component::VecValue::TypeU16({
+                                                  let len13 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len13, len13)
                                                 }),
-                                                10 => super::super::super::ambient::bindings::component::OptionValue::TypeU32(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(*((base + 28) as *const i32) as u32),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                10 => super::super::super::ambient::bindings::component::VecValue::TypeU32({
+                                                  let len14 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len14, len14)
                                                 }),
-                                                11 => super::super::super::ambient::bindings::component::OptionValue::TypeU64(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(*((base + 32) as *const i64) as u64),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                11 => super::super::super::ambient::bindings::component::VecValue::TypeU64({
+                                                  let len15 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len15, len15)
                                                 }),
-                                                12 => super::super::super::ambient::bindings::component::OptionValue::TypeI8(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(i32::from(*((base + 25) as *const i8)) as i8),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                12 => super::super::super::ambient::bindings::component::VecValue::TypeI8({
+                                                  let len16 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len16, len16)
                                                 }),
-                                                13 => super::super::super::ambient::bindings::component::OptionValue::TypeI16(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(i32::from(*((base + 26) as *const i16)) as i16),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                13 => super::super::super::ambient::bindings::component::VecValue::TypeI16({
+                                                  let len17 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len17, len17)
                                                 }),
-                                                14 => super::super::super::ambient::bindings::component::OptionValue::TypeI32(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(*((base + 28) as *const i32)),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                14 => super::super::super::ambient::bindings::component::VecValue::TypeI32({
+                                                  let len18 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len18, len18)
                                                 }),
-                                                15 => super::super::super::ambient::bindings::component::OptionValue::TypeI64(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(*((base + 32) as *const i64)),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                15 => super::super::super::ambient::bindings::component::VecValue::TypeI64({
+                                                  let len19 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
                                                 }),
-                                                16 => super::super::super::ambient::bindings::component::OptionValue::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                16 => super::super::super::ambient::bindings::component::VecValue::TypeVec2({
+                                                  let len20 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len20, len20)
                                                 }),
-                                                17 => super::super::super::ambient::bindings::component::OptionValue::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                17 => super::super::super::ambient::bindings::component::VecValue::TypeVec3({
+                                                  let len21 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len21, len21)
                                                 }),
-                                                18 => super::super::super::ambient::bindings::component::OptionValue::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                18 => super::super::super::ambient::bindings::component::VecValue::TypeVec4({
+                                                  let len22 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len22, len22)
                                                 }),
-                                                19 => super::super::super::ambient::bindings::component::OptionValue::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                19 => super::super::super::ambient::bindings::component::VecValue::TypeUvec2({
+                                                  let len23 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len23, len23)
                                                 }),
-                                                20 => super::super::super::ambient::bindings::component::OptionValue::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                20 => super::super::super::ambient::bindings::component::VecValue::TypeUvec3({
+                                                  let len24 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len24, len24)
                                                 }),
-                                                21 => super::super::super::ambient::bindings::component::OptionValue::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                21 => super::super::super::ambient::bindings::component::VecValue::TypeUvec4({
+                                                  let len25 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len25, len25)
                                                 }),
-                                                22 => super::super::super::ambient::bindings::component::OptionValue::TypeIvec2(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Ivec2{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                22 => super::super::super::ambient::bindings::component::VecValue::TypeIvec2({
+                                                  let len26 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len26, len26)
                                                 }),
-                                                23 => super::super::super::ambient::bindings::component::OptionValue::TypeIvec3(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Ivec3{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                23 => super::super::super::ambient::bindings::component::VecValue::TypeIvec3({
+                                                  let len27 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len27, len27)
                                                 }),
-                                                24 => super::super::super::ambient::bindings::component::OptionValue::TypeIvec4(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Ivec4{x:*((base + 28) as *const i32), y:*((base + 32) as *const i32), z:*((base + 36) as *const i32), w:*((base + 40) as *const i32), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                24 => super::super::super::ambient::bindings::component::VecValue::TypeIvec4({
+                                                  let len28 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len28, len28)
                                                 }),
-                                                25 => super::super::super::ambient::bindings::component::OptionValue::TypeDuration(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::types::Duration{seconds:*((base + 32) as *const i64) as u64, nanoseconds:*((base + 40) as *const i32) as u32, }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                25 => super::super::super::ambient::bindings::component::VecValue::TypeDuration({
+                                                  let len29 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len29, len29)
                                                 }),
-                                                26 => super::super::super::ambient::bindings::component::OptionValue::TypeProceduralMeshHandle(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::client_mesh::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                26 => super::super::super::ambient::bindings::component::VecValue::TypeProceduralMeshHandle({
+                                                  let len30 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len30, len30)
                                                 }),
-                                                27 => super::super::super::ambient::bindings::component::OptionValue::TypeProceduralTextureHandle(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::client_texture::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                27 => super::super::super::ambient::bindings::component::VecValue::TypeProceduralTextureHandle({
+                                                  let len31 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len31, len31)
                                                 }),
-                                                28 => super::super::super::ambient::bindings::component::OptionValue::TypeProceduralSamplerHandle(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::client_sampler::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                28 => super::super::super::ambient::bindings::component::VecValue::TypeProceduralSamplerHandle({
+                                                  let len32 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len32, len32)
                                                 }),
-                                                #[cfg(debug_assertions)]29 => super::super::super::ambient::bindings::component::OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                #[cfg(debug_assertions)]29 => super::super::super::ambient::bindings::component::VecValue::TypeProceduralMaterialHandle({
+                                                  let len33 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
                                                 }),
-                                                #[cfg(not(debug_assertions))]_ => super::super::super::ambient::bindings::component::OptionValue::TypeProceduralMaterialHandle(match i32::from(*((base + 24) as *const u8)) {
-                                                  0 => None,
-                                                  1 => Some(super::super::super::ambient::bindings::client_material::Handle{ulid:(*((base + 32) as *const i64) as u64, *((base + 40) as *const i64) as u64), }),
-                                                  #[cfg(not(debug_assertions))]
-                                                  _ => ::core::hint::unreachable_unchecked(),
-                                                  #[cfg(debug_assertions)]
-                                                  _ => panic!("invalid enum discriminant"),
+                                                #[cfg(not(debug_assertions))]_ => super::super::super::ambient::bindings::component::VecValue::TypeProceduralMaterialHandle({
+                                                  let len33 = *((base + 24) as *const i32) as usize;
+                                                  
+                                                  Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len33, len33)
                                                 }),
                                                 #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                                               }}}),
-                                              #[cfg(not(debug_assertions))]_ => super::super::super::ambient::bindings::component::Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
+                                              31 => super::super::super::ambient::bindings::component::Value::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
                                                 0 => super::super::super::ambient::bindings::component::OptionValue::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
                                                   0 => None,
                                                   1 => Some(super::super::super::ambient::bindings::types::Empty{dummy:i32::from(*((base + 25) as *const u8)) as u8, }),
@@ -11458,6 +10699,21 @@ pub mod ambient {
                                                 }),
                                                 #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                                               }}}),
+                                              32 => super::super::super::ambient::bindings::component::Value::TypeStruct({
+                                                let len_struct = *((base + 20) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_struct, len_struct)
+                                              }),
+                                              #[cfg(debug_assertions)]33 => super::super::super::ambient::bindings::component::Value::TypeMap({
+                                                let len_map = *((base + 20) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_map, len_map)
+                                              }),
+                                              #[cfg(not(debug_assertions))]_ => super::super::super::ambient::bindings::component::Value::TypeMap({
+                                                let len_map = *((base + 20) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len_map, len_map)
+                                              }),
                                               #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
                                             }}}));
                                           }
@@ -11738,6 +10994,94 @@ pub mod ambient {
                                 }
                                 
                                 
+                                #[allow(clippy::all)]
+                                pub mod timer {
+                                  #[used]
+                                  #[doc(hidden)]
+                                  #[cfg(target_arch = "wasm32")]
+                                  static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                  
+                                  pub type Duration = super::super::super::ambient::bindings::types::Duration;
+                                  /// Schedules a `timer-expired` message to be sent to this module after `after` of game time,
+                                  /// and then every `repeat` if it is set. Timers are kept when the module is reloaded.
+                                  ///
+                                  /// If `name` is set and this module already has a timer with that name, such as one scheduled
+                                  /// before the module was reloaded, that timer is returned instead. It keeps its expiry time,
+                                  /// but repeats every `repeat` from then on.
+                                  #[allow(clippy::all)]
+                                  pub fn schedule(name: Option<&str>,after: Duration,repeat: Option<Duration>,) -> u64{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      let (result1_0,result1_1,result1_2,) = match name {
+                                        Some(e) => {
+                                          let vec0 = e;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          
+                                          (1i32, ptr0, len0)
+                                        },
+                                        None => {
+                                          (0i32, 0i32, 0i32)
+                                        },
+                                      };
+                                      let super::super::super::ambient::bindings::types::Duration{ seconds:seconds2, nanoseconds:nanoseconds2, } = after;
+                                      let (result4_0,result4_1,result4_2,) = match repeat {
+                                        Some(e) => {
+                                          let super::super::super::ambient::bindings::types::Duration{ seconds:seconds3, nanoseconds:nanoseconds3, } = e;
+                                          
+                                          (1i32, wit_bindgen::rt::as_i64(seconds3), wit_bindgen::rt::as_i32(nanoseconds3))
+                                        },
+                                        None => {
+                                          (0i32, 0i64, 0i32)
+                                        },
+                                      };
+                                      #[link(wasm_import_module = "ambient:bindings/timer")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "schedule")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/timer_schedule")]
+                                        fn wit_import(
+                                        _: i32, _: i32, _: i32, _: i64, _: i32, _: i32, _: i64, _: i32, ) -> i64;
+                                      }
+                                      let ret = wit_import(result1_0, result1_1, result1_2, wit_bindgen::rt::as_i64(seconds2), wit_bindgen::rt::as_i32(nanoseconds2), result4_0, result4_1, result4_2);
+                                      ret as u64
+                                    }
+                                  }
+                                  /// Stops the timer, and returns whether it was still scheduled
+                                  #[allow(clippy::all)]
+                                  pub fn cancel(timer: u64,) -> bool{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[link(wasm_import_module = "ambient:bindings/timer")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "cancel")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/timer_cancel")]
+                                        fn wit_import(
+                                        _: i64, ) -> i32;
+                                      }
+                                      let ret = wit_import(wit_bindgen::rt::as_i64(timer));
+                                      {
+                                        #[cfg(not(debug_assertions))]
+                                        { ::core::mem::transmute::<u8, bool>(ret as u8) }
+                                        #[cfg(debug_assertions)]
+                                        {
+                                          match ret {
+                                            0 => false,
+                                            1 => true,
+                                            _ => panic!("invalid bool discriminant"),
+                                          }
+                                        }
+                                      }
+                                    }
+                                  }
+                                  
+                                }
+                                
+                                
                                 #[allow(clippy::all)]
                                 pub mod client_message {
                                   #[used]
@@ -13526,6 +12870,7 @@ pub mod ambient {
                                   
                                   pub type EntityId = super::super::super::ambient::bindings::types::EntityId;
                                   pub type Vec3 = super::super::super::ambient::bindings::types::Vec3;
+                                  pub type Quat = super::super::super::ambient::bindings::types::Quat;
                                   pub type Mat4 = super::super::super::ambient::bindings::types::Mat4;
                                   #[repr(C)]
                                   #[derive(Copy, Clone)]
@@ -13539,6 +12884,77 @@ pub mod ambient {
                                       f.debug_struct("CharacterCollision").field("side", &self.side).field("up", &self.up).field("down", &self.down).finish()
                                     }
                                   }
+                                  #[repr(u8)]
+                                  #[derive(Clone, Copy, PartialEq, Eq)]
+                                  pub enum ColliderType {
+                                    Static,
+                                    Dynamic,
+                                    TriggerArea,
+                                    Picking,
+                                  }
+                                  impl ::core::fmt::Debug for ColliderType {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      match self {
+                                        ColliderType::Static => {
+                                          f.debug_tuple("ColliderType::Static").finish()
+                                        }
+                                        ColliderType::Dynamic => {
+                                          f.debug_tuple("ColliderType::Dynamic").finish()
+                                        }
+                                        ColliderType::TriggerArea => {
+                                          f.debug_tuple("ColliderType::TriggerArea").finish()
+                                        }
+                                        ColliderType::Picking => {
+                                          f.debug_tuple("ColliderType::Picking").finish()
+                                        }
+                                      }
+                                    }
+                                  }
+                                  #[repr(C)]
+                                  #[derive(Copy, Clone)]
+                                  pub struct CapsuleShape {
+                                    pub radius: f32,
+                                    pub half_height: f32,
+                                  }
+                                  impl ::core::fmt::Debug for CapsuleShape {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      f.debug_struct("CapsuleShape").field("radius", &self.radius).field("half-height", &self.half_height).finish()
+                                    }
+                                  }
+                                  #[derive(Clone, Copy)]
+                                  pub enum QueryShape{
+                                    Sphere(f32),
+                                    Cuboid(Vec3),
+                                    Capsule(CapsuleShape),
+                                  }
+                                  impl ::core::fmt::Debug for QueryShape {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      match self {
+                                        QueryShape::Sphere(e) => {
+                                          f.debug_tuple("QueryShape::Sphere").field(e).finish()
+                                        }
+                                        QueryShape::Cuboid(e) => {
+                                          f.debug_tuple("QueryShape::Cuboid").field(e).finish()
+                                        }
+                                        QueryShape::Capsule(e) => {
+                                          f.debug_tuple("QueryShape::Capsule").field(e).finish()
+                                        }
+                                      }
+                                    }
+                                  }
+                                  #[repr(C)]
+                                  #[derive(Copy, Clone)]
+                                  pub struct SweepHit {
+                                    pub entity: EntityId,
+                                    pub position: Vec3,
+                                    pub normal: Vec3,
+                                    pub distance: f32,
+                                  }
+                                  impl ::core::fmt::Debug for SweepHit {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      f.debug_struct("SweepHit").field("entity", &self.entity).field("position", &self.position).field("normal", &self.normal).field("distance", &self.distance).finish()
+                                    }
+                                  }
                                   #[allow(clippy::all)]
                                   pub fn add_force(entity: EntityId,force: Vec3,){
                                     
@@ -13880,6 +13296,130 @@ pub mod ambient {
                                     }
                                   }
                                   #[allow(clippy::all)]
+                                  pub fn sweep(shape: QueryShape,origin: Vec3,rotation: Quat,direction: Vec3,max_distance: f32,filter: Option<ColliderType>,) -> wit_bindgen::rt::vec::Vec::<SweepHit>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct ParamsArea([u8; 64]);
+                                      let mut params_area = ::core::mem::MaybeUninit::<ParamsArea>::uninit();
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let ptr0 = params_area.as_mut_ptr() as i32;
+                                      match shape {
+                                        QueryShape::Sphere(e) => {
+                                          *((ptr0 + 0) as *mut u8) = (0i32) as u8;
+                                          *((ptr0 + 4) as *mut f32) = wit_bindgen::rt::as_f32(e);
+                                        },
+                                        QueryShape::Cuboid(e) => {
+                                          *((ptr0 + 0) as *mut u8) = (1i32) as u8;
+                                          let super::super::super::ambient::bindings::types::Vec3{ x:x1, y:y1, z:z1, } = e;
+                                          *((ptr0 + 4) as *mut f32) = wit_bindgen::rt::as_f32(x1);
+                                          *((ptr0 + 8) as *mut f32) = wit_bindgen::rt::as_f32(y1);
+                                          *((ptr0 + 12) as *mut f32) = wit_bindgen::rt::as_f32(z1);
+                                        },
+                                        QueryShape::Capsule(e) => {
+                                          *((ptr0 + 0) as *mut u8) = (2i32) as u8;
+                                          let CapsuleShape{ radius:radius2, half_height:half_height2, } = e;
+                                          *((ptr0 + 4) as *mut f32) = wit_bindgen::rt::as_f32(radius2);
+                                          *((ptr0 + 8) as *mut f32) = wit_bindgen::rt::as_f32(half_height2);
+                                        },
+                                      }
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x3, y:y3, z:z3, } = origin;
+                                      *((ptr0 + 16) as *mut f32) = wit_bindgen::rt::as_f32(x3);
+                                      *((ptr0 + 20) as *mut f32) = wit_bindgen::rt::as_f32(y3);
+                                      *((ptr0 + 24) as *mut f32) = wit_bindgen::rt::as_f32(z3);
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x4, y:y4, z:z4, w:w4, } = rotation;
+                                      *((ptr0 + 28) as *mut f32) = wit_bindgen::rt::as_f32(x4);
+                                      *((ptr0 + 32) as *mut f32) = wit_bindgen::rt::as_f32(y4);
+                                      *((ptr0 + 36) as *mut f32) = wit_bindgen::rt::as_f32(z4);
+                                      *((ptr0 + 40) as *mut f32) = wit_bindgen::rt::as_f32(w4);
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x5, y:y5, z:z5, } = direction;
+                                      *((ptr0 + 44) as *mut f32) = wit_bindgen::rt::as_f32(x5);
+                                      *((ptr0 + 48) as *mut f32) = wit_bindgen::rt::as_f32(y5);
+                                      *((ptr0 + 52) as *mut f32) = wit_bindgen::rt::as_f32(z5);
+                                      *((ptr0 + 56) as *mut f32) = wit_bindgen::rt::as_f32(max_distance);
+                                      match filter {
+                                        Some(e) => {
+                                          *((ptr0 + 60) as *mut u8) = (1i32) as u8;
+                                          *((ptr0 + 61) as *mut u8) = (match e {
+                                            ColliderType::Static => 0,
+                                            ColliderType::Dynamic => 1,
+                                            ColliderType::TriggerArea => 2,
+                                            ColliderType::Picking => 3,
+                                          }) as u8;
+                                        },
+                                        None => {
+                                          {
+                                            *((ptr0 + 60) as *mut u8) = (0i32) as u8;
+                                          }
+                                        },
+                                      };
+                                      let ptr6 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "sweep")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_sweep")]
+                                        fn wit_import(
+                                        _: i32, _: i32, );
+                                      }
+                                      wit_import(ptr0, ptr6);
+                                      let len7 = *((ptr6 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr6 + 0) as *const i32) as *mut _, len7, len7)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn overlap(shape: QueryShape,position: Vec3,rotation: Quat,filter: Option<ColliderType>,) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let (result2_0,result2_1,result2_2,result2_3,) = match shape {
+                                        QueryShape::Sphere(e) => (0i32, wit_bindgen::rt::as_f32(e), 0.0f32, 0.0f32),
+                                        QueryShape::Cuboid(e) => {
+                                          let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = e;
+                                          (1i32, wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0))
+                                        },
+                                        QueryShape::Capsule(e) => {
+                                          let CapsuleShape{ radius:radius1, half_height:half_height1, } = e;
+                                          (2i32, wit_bindgen::rt::as_f32(radius1), wit_bindgen::rt::as_f32(half_height1), 0.0f32)
+                                        },
+                                      };
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x3, y:y3, z:z3, } = position;
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x4, y:y4, z:z4, w:w4, } = rotation;
+                                      let (result5_0,result5_1,) = match filter {
+                                        Some(e) => (1i32, match e {
+                                          ColliderType::Static => 0,
+                                          ColliderType::Dynamic => 1,
+                                          ColliderType::TriggerArea => 2,
+                                          ColliderType::Picking => 3,
+                                        }),
+                                        None => {
+                                          (0i32, 0i32)
+                                        },
+                                      };
+                                      let ptr6 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "overlap")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_overlap")]
+                                        fn wit_import(
+                                        _: i32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, _: i32, );
+                                      }
+                                      wit_import(result2_0, result2_1, result2_2, result2_3, wit_bindgen::rt::as_f32(x3), wit_bindgen::rt::as_f32(y3), wit_bindgen::rt::as_f32(z3), wit_bindgen::rt::as_f32(x4), wit_bindgen::rt::as_f32(y4), wit_bindgen::rt::as_f32(z4), wit_bindgen::rt::as_f32(w4), result5_0, result5_1, ptr6);
+                                      let len7 = *((ptr6 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr6 + 0) as *const i32) as *mut _, len7, len7)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
                                   pub fn move_character(entity: EntityId,displacement: Vec3,min_dist: f32,elapsed_time: f32,) -> CharacterCollision{
                                     
                                     #[allow(unused_imports)]
@@ -14204,6 +13744,175 @@ pub mod ambient {
                                     }
                                     
                                     
+                                    #[allow(clippy::all)]
+                                    pub mod server_storage {
+                                      #[used]
+                                      #[doc(hidden)]
+                                      #[cfg(target_arch = "wasm32")]
+                                      static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                      
+                                      /// The value of `key` in `namespace`, if it has been set
+                                      #[allow(clippy::all)]
+                                      pub fn get(namespace: &str,key: &str,) -> Option<wit_bindgen::rt::vec::Vec::<u8>>{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+                                          
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 12]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec0 = namespace;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let vec1 = key;
+                                          let ptr1 = vec1.as_ptr() as i32;
+                                          let len1 = vec1.len() as i32;
+                                          let ptr2 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "get")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_get")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(ptr0, len0, ptr1, len1, ptr2);
+                                          match i32::from(*((ptr2 + 0) as *const u8)) {
+                                            0 => None,
+                                            1 => Some({
+                                              let len3 = *((ptr2 + 8) as *const i32) as usize;
+                                              
+                                              Vec::from_raw_parts(*((ptr2 + 4) as *const i32) as *mut _, len3, len3)
+                                            }),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => ::core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      /// Sets the value of `key` in `namespace`. Fails if the storage of the package would grow
+                                      /// beyond its quota.
+                                      #[allow(clippy::all)]
+                                      pub fn set(namespace: &str,key: &str,value: &[u8],) -> Result<(),wit_bindgen::rt::string::String>{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+                                          
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 12]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec0 = namespace;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let vec1 = key;
+                                          let ptr1 = vec1.as_ptr() as i32;
+                                          let len1 = vec1.len() as i32;
+                                          let vec2 = value;
+                                          let ptr2 = vec2.as_ptr() as i32;
+                                          let len2 = vec2.len() as i32;
+                                          let ptr3 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "set")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_set")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(ptr0, len0, ptr1, len1, ptr2, len2, ptr3);
+                                          match i32::from(*((ptr3 + 0) as *const u8)) {
+                                            0 => Ok(()),
+                                            1 => Err({
+                                              let len4 = *((ptr3 + 8) as *const i32) as usize;
+                                              
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr3 + 4) as *const i32) as *mut _, len4, len4))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr3 + 4) as *const i32) as *mut _, len4, len4)).unwrap()}}
+                                            }),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => ::core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      /// Removes `key` from `namespace`, and returns whether it was set
+                                      #[allow(clippy::all)]
+                                      pub fn delete(namespace: &str,key: &str,) -> bool{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+                                          let vec0 = namespace;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let vec1 = key;
+                                          let ptr1 = vec1.as_ptr() as i32;
+                                          let len1 = vec1.len() as i32;
+                                          
+                                          #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "delete")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_delete")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, _: i32, ) -> i32;
+                                          }
+                                          let ret = wit_import(ptr0, len0, ptr1, len1);
+                                          {
+                                            #[cfg(not(debug_assertions))]
+                                            { ::core::mem::transmute::<u8, bool>(ret as u8) }
+                                            #[cfg(debug_assertions)]
+                                            {
+                                              match ret {
+                                                0 => false,
+                                                1 => true,
+                                                _ => panic!("invalid bool discriminant"),
+                                              }
+                                            }
+                                          }
+                                        }
+                                      }
+                                      /// The keys that are set in `namespace`, in order
+                                      #[allow(clippy::all)]
+                                      pub fn list(namespace: &str,) -> wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+                                          
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 8]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec0 = namespace;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let ptr1 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "list")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_list")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(ptr0, len0, ptr1);
+                                          let base3 = *((ptr1 + 0) as *const i32);
+                                          let len3 = *((ptr1 + 4) as *const i32);
+                                          let mut result3 = Vec::with_capacity(len3 as usize);
+                                          for i in 0..len3 {
+                                            let base = base3 + i *8;
+                                            result3.push({
+                                              let len2 = *((base + 4) as *const i32) as usize;
+                                              
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len2, len2)).unwrap()}}
+                                            });
+                                          }
+                                          wit_bindgen::rt::dealloc(base3, (len3 as usize) * 8, 4);
+                                          result3
+                                        }
+                                      }
+                                      
+                                    }
+                                    
+                                    
                                     #[allow(clippy::all)]
                                     pub mod server_ambient_package {
                                       #[used]
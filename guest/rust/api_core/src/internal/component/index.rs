@@ -0,0 +1,170 @@
+use std::marker::PhantomData;
+
+use crate::{
+    global::{EntityId, Vec3},
+    internal::{
+        component::{Component, ComponentsTuple, SupportedValue},
+        conversion::{FromBindgen, IntoBindgen},
+        wit,
+    },
+    message::MessageSerde,
+};
+
+use ambient_shared_types::ComponentIndex;
+
+/// Creates a new [IndexBuilder] for an index over the specified `components`, which can be built
+/// into an [OrderedIndex], a [HashIndex] or, for a single `Vec3` component, a [GridIndex].
+///
+/// Indexes are kept up to date by the runtime, so that looking up entities by the values of their
+/// components does not need to go through all of the entities. Like queries, indexes should be built
+/// once and reused.
+pub fn index<Components: IndexComponents>(components: Components) -> IndexBuilder<Components> {
+    IndexBuilder {
+        components,
+        include: vec![],
+        exclude: vec![],
+    }
+}
+
+/// A tuple of [Component]s whose values can be used to look up entities in an index.
+pub trait IndexComponents: ComponentsTuple + Copy + Clone + 'static {
+    #[doc(hidden)]
+    fn serialize_values(values: &Self::Data) -> Vec<u8>;
+}
+
+macro_rules! tuple_impls {
+    ( $( $name:ident )+ ) => {
+        impl<$($name: SupportedValue + MessageSerde + 'static),+> IndexComponents for ($(Component<$name>,)+) {
+            fn serialize_values(values: &Self::Data) -> Vec<u8> {
+                #[allow(non_snake_case)]
+                let ($($name,)+) = values;
+                let mut output = vec![];
+                $($name.serialize_message_part(&mut output).unwrap();)+
+                output
+            }
+        }
+    };
+}
+tuple_impls! { A }
+tuple_impls! { A B }
+tuple_impls! { A B C }
+tuple_impls! { A B C D }
+tuple_impls! { A B C D E }
+tuple_impls! { A B C D E F }
+tuple_impls! { A B C D E F G }
+tuple_impls! { A B C D E F G H }
+impl<T: SupportedValue + MessageSerde + 'static> IndexComponents for Component<T> {
+    fn serialize_values(values: &Self::Data) -> Vec<u8> {
+        let mut output = vec![];
+        values.serialize_message_part(&mut output).unwrap();
+        output
+    }
+}
+
+/// Builds an index. See [index].
+pub struct IndexBuilder<Components: IndexComponents> {
+    components: Components,
+    include: Vec<ComponentIndex>,
+    exclude: Vec<ComponentIndex>,
+}
+impl<Components: IndexComponents> IndexBuilder<Components> {
+    /// The entities must include the components in `requires`.
+    pub fn requires(mut self, requires: impl ComponentsTuple) -> Self {
+        self.include.extend_from_slice(&requires.as_indices());
+        self
+    }
+
+    /// The entities must not include the components in `excludes`.
+    pub fn excludes(mut self, excludes: impl ComponentsTuple) -> Self {
+        self.exclude.extend_from_slice(&excludes.as_indices());
+        self
+    }
+
+    /// Builds an [OrderedIndex], which supports exact and range lookups.
+    pub fn ordered(self) -> OrderedIndex<Components> {
+        OrderedIndex(self.create(wit::component::IndexKind::Ordered), PhantomData)
+    }
+
+    /// Builds a [HashIndex], which supports exact lookups.
+    pub fn hash(self) -> HashIndex<Components> {
+        HashIndex(self.create(wit::component::IndexKind::Hash), PhantomData)
+    }
+
+    fn create(self, kind: wit::component::IndexKind) -> u64 {
+        wit::component::index_create(
+            kind,
+            &self.components.as_indices(),
+            &self.include,
+            &self.exclude,
+        )
+    }
+}
+impl IndexBuilder<Component<Vec3>> {
+    /// Builds a [GridIndex] with cells of size `cell_size`, which supports finding the entities
+    /// in an area. Lookups are fastest when the cells are about the size of the areas looked up.
+    pub fn grid(self, cell_size: f32) -> GridIndex {
+        GridIndex(self.create(wit::component::IndexKind::Grid(cell_size)))
+    }
+}
+
+/// An index which sorts entities by the values of its components. See [index].
+pub struct OrderedIndex<Components: IndexComponents>(u64, PhantomData<Components>);
+impl<Components: IndexComponents> Clone for OrderedIndex<Components> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Components: IndexComponents> Copy for OrderedIndex<Components> {}
+impl<Components: IndexComponents> OrderedIndex<Components> {
+    /// The entities whose components are equal to `values`.
+    pub fn get(&self, values: Components::Data) -> Vec<EntityId> {
+        wit::component::index_get(self.0, &Components::serialize_values(&values)).from_bindgen()
+    }
+
+    /// The entities whose components are between `min` and `max`, inclusive. Components are
+    /// compared in order, so later components only matter when the earlier ones are equal.
+    pub fn range(&self, min: Components::Data, max: Components::Data) -> Vec<EntityId> {
+        wit::component::index_range(
+            self.0,
+            &Components::serialize_values(&min),
+            &Components::serialize_values(&max),
+        )
+        .from_bindgen()
+    }
+}
+
+/// An index which hashes entities by the values of its components. See [index].
+pub struct HashIndex<Components: IndexComponents>(u64, PhantomData<Components>);
+impl<Components: IndexComponents> Clone for HashIndex<Components> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Components: IndexComponents> Copy for HashIndex<Components> {}
+impl<Components: IndexComponents> HashIndex<Components> {
+    /// The entities whose components are equal to `values`.
+    pub fn get(&self, values: Components::Data) -> Vec<EntityId> {
+        wit::component::index_get(self.0, &Components::serialize_values(&values)).from_bindgen()
+    }
+}
+
+/// An index which sorts entities into the cells of a grid by a position, such as their
+/// `translation`. See [index].
+#[derive(Clone, Copy, Debug)]
+pub struct GridIndex(u64);
+impl GridIndex {
+    /// The entities at most `radius` away from `center`.
+    pub fn within_radius(&self, center: Vec3, radius: f32) -> Vec<EntityId> {
+        wit::component::index_within_radius(self.0, center.into_bindgen(), radius).from_bindgen()
+    }
+
+    /// The entities inside the box from `min` to `max`.
+    pub fn within_box(&self, min: Vec3, max: Vec3) -> Vec<EntityId> {
+        wit::component::index_range(
+            self.0,
+            &Component::<Vec3>::serialize_values(&min),
+            &Component::<Vec3>::serialize_values(&max),
+        )
+        .from_bindgen()
+    }
+}
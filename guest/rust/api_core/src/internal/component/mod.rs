@@ -1,6 +1,7 @@
 use crate::internal::wit;
 use std::marker::PhantomData;
 
+pub(crate) mod index;
 pub(crate) mod query;
 pub(crate) mod traits;
 
@@ -152,3 +153,13 @@ pub trait EnumComponent {
     where
         Self: Sized;
 }
+
+/// Implemented for component values that are a struct of named fields
+pub trait StructComponent {
+    /// Convert this value to the bytes it is stored as
+    fn to_bytes(&self) -> Vec<u8>;
+    /// Convert the bytes a struct is stored as to this value
+    fn from_bytes(bytes: &[u8]) -> Option<Self>
+    where
+        Self: Sized;
+}
@@ -3,7 +3,11 @@ use std::marker::PhantomData;
 use crate::{
     core::messages::Frame,
     global::{CallbackReturn, EntityId, OkEmpty},
-    internal::{component::ComponentsTuple, conversion::FromBindgen, wit},
+    internal::{
+        component::{Component, ComponentsTuple, UntypedComponent},
+        conversion::FromBindgen,
+        wit,
+    },
     message::Listener,
     message::RuntimeMessage,
 };
@@ -48,6 +52,55 @@ pub fn despawn_query<Components: ComponentsTuple + Copy + Clone + 'static>(
     EventQuery::create(QueryEvent::Despawn, components)
 }
 
+/// Implemented by the components which can be followed by a [Relation]: `EntityId` and `Vec<EntityId>`
+/// components.
+pub trait RelationComponent: UntypedComponent {}
+impl RelationComponent for Component<EntityId> {}
+impl RelationComponent for Component<Vec<EntityId>> {}
+
+/// Requires the entities referred to by a component, such as `parent` or `children`, to match a
+/// filter. An entity matches if at least one of the entities it refers to matches.
+///
+/// For example, `query(translation()).related(Relation::new(parent()).requires(is_player()))`
+/// finds the entities whose parent is a player.
+#[derive(Clone, Debug)]
+pub struct Relation {
+    component: ComponentIndex,
+    include: Vec<ComponentIndex>,
+    exclude: Vec<ComponentIndex>,
+    changed: Vec<ComponentIndex>,
+}
+impl Relation {
+    /// Creates a relation that follows `component`.
+    pub fn new(component: impl RelationComponent) -> Self {
+        Self {
+            component: component.index(),
+            include: vec![],
+            exclude: vec![],
+            changed: vec![],
+        }
+    }
+
+    /// The related entities must include the components in `requires`.
+    pub fn requires(mut self, requires: impl ComponentsTuple) -> Self {
+        self.include.extend_from_slice(&requires.as_indices());
+        self
+    }
+
+    /// The related entities must not include the components in `excludes`.
+    pub fn excludes(mut self, excludes: impl ComponentsTuple) -> Self {
+        self.exclude.extend_from_slice(&excludes.as_indices());
+        self
+    }
+
+    /// A [ChangeQuery] using this relation will also return the entities whose related entities had
+    /// these components change. This has no effect on other queries.
+    pub fn track_change(mut self, changes: impl ComponentsTuple) -> Self {
+        self.changed.extend_from_slice(&changes.as_indices());
+        self
+    }
+}
+
 /// When this [EventQuery] should return results.
 pub enum QueryEvent {
     /// When this collection of components is spawned.
@@ -101,6 +154,12 @@ impl<Components: ComponentsTuple + Copy + Clone + 'static> GeneralQueryBuilder<C
         self
     }
 
+    /// The entities must refer to an entity matching `relation`.
+    pub fn related(mut self, relation: Relation) -> Self {
+        self.0.related(relation);
+        self
+    }
+
     /// Builds a [GeneralQuery].
     pub fn build(self) -> GeneralQuery<Components> {
         GeneralQuery(QueryImpl::new(
@@ -164,6 +223,13 @@ impl<Components: ComponentsTuple + Copy + Clone + 'static> ChangeQuery<Component
         self
     }
 
+    /// The entities must refer to an entity matching `relation`. Changes to the components
+    /// [tracked](Relation::track_change) by the relation also trigger the query.
+    pub fn related(mut self, relation: Relation) -> Self {
+        self.0 .0.related(relation);
+        self
+    }
+
     /// The query will return results when these components change values.
     ///
     /// Note that this does *not* implicitly [requires](Self::requires) the components; this allows you to track
@@ -215,6 +281,13 @@ impl<Components: ComponentsTuple + Copy + Clone + 'static> EventQuery<Components
         self
     }
 
+    /// The entities must refer to an entity matching `relation`. The event occurs when an entity
+    /// starts or stops matching the relation, as well as when it gains or loses the components.
+    pub fn related(mut self, relation: Relation) -> Self {
+        self.0.related(relation);
+        self
+    }
+
     /// Each time the entity associated with `components` experiences the event,
     /// the `callback` (`fn`) is called with the result of the query.
     pub fn bind<R: CallbackReturn>(
@@ -276,6 +349,7 @@ struct QueryBuilderImpl<Components: ComponentsTuple + Copy + Clone + 'static> {
     components: Vec<ComponentIndex>,
     include: Vec<ComponentIndex>,
     exclude: Vec<ComponentIndex>,
+    relations: Vec<Relation>,
     _data: PhantomData<Components>,
 }
 impl<Components: ComponentsTuple + Copy + Clone + 'static> QueryBuilderImpl<Components> {
@@ -284,6 +358,7 @@ impl<Components: ComponentsTuple + Copy + Clone + 'static> QueryBuilderImpl<Comp
             components,
             include: vec![],
             exclude: vec![],
+            relations: vec![],
             _data: PhantomData,
         }
     }
@@ -293,6 +368,9 @@ impl<Components: ComponentsTuple + Copy + Clone + 'static> QueryBuilderImpl<Comp
     pub fn excludes(&mut self, exclude: impl ComponentsTuple) {
         self.exclude.extend_from_slice(&exclude.as_indices());
     }
+    pub fn related(&mut self, relation: Relation) {
+        self.relations.push(relation);
+    }
     fn build_impl(self, changed: Vec<ComponentIndex>, event: wit::component::QueryEvent) -> u64 {
         wit::component::query(
             &wit::component::QueryBuild {
@@ -300,6 +378,16 @@ impl<Components: ComponentsTuple + Copy + Clone + 'static> QueryBuilderImpl<Comp
                 includes: self.include,
                 excludes: self.exclude,
                 changed,
+                relations: self
+                    .relations
+                    .into_iter()
+                    .map(|relation| wit::component::QueryRelation {
+                        component: relation.component,
+                        includes: relation.include,
+                        excludes: relation.exclude,
+                        changed: relation.changed,
+                    })
+                    .collect(),
             },
             event,
         )
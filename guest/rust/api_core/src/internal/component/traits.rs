@@ -9,11 +9,12 @@ use crate::{
         conversion::{FromBindgen, IntoBindgen},
         wit,
     },
+    message::{MessageSerde, MessageSerdeError},
 };
 use serde::{Deserialize, Serialize};
 
 use ambient_shared_types::primitive_component_definitions;
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 
 #[doc(hidden)]
 pub fn get_component<T>(id: &str) -> Component<T> {
@@ -62,6 +63,10 @@ macro_rules! define_component_types {
             )*
             Vec(ComponentVecValue),
             Option(ComponentOptionValue),
+            /// The bytes of a struct value; see [StructComponent](crate::ecs::StructComponent).
+            Struct(Vec<u8>),
+            /// The bytes of a map value, serialized with [MessageSerde].
+            Map(Vec<u8>),
         }
 
         /// A vector value that can be stored in a component.
@@ -91,6 +96,8 @@ macro_rules! define_component_types {
                     )*
                     Self::Vec(v) => wit::component::Value::TypeVec(v.into_bindgen()),
                     Self::Option(v) => wit::component::Value::TypeOption(v.into_bindgen()),
+                    Self::Struct(v) => wit::component::Value::TypeStruct(v),
+                    Self::Map(v) => wit::component::Value::TypeMap(v),
                 }
             }
         }
@@ -103,6 +110,8 @@ macro_rules! define_component_types {
                     )*
                     wit::component::Value::TypeVec(v) => ComponentValue::Vec(v.from_bindgen()),
                     wit::component::Value::TypeOption(v) => ComponentValue::Option(v.from_bindgen()),
+                    wit::component::Value::TypeStruct(v) => ComponentValue::Struct(v),
+                    wit::component::Value::TypeMap(v) => ComponentValue::Map(v),
                 }
             }
         }
@@ -243,8 +252,67 @@ macro_rules! define_component_types {
                 }
             }
         }
+        impl SupportedValue for BTreeMap<String, $type> {
+            fn from_result(result: wit::component::Value) -> Option<Self> {
+                match result {
+                    wit::component::Value::TypeMap(bytes) => map_from_bytes(&bytes).ok(),
+                    _ => None,
+                }
+            }
+
+            fn into_result(self) -> wit::component::Value {
+                wit::component::Value::TypeMap(map_to_bytes(&self))
+            }
+
+            fn from_value(value: ComponentValue) -> Option<Self> {
+                match value {
+                    ComponentValue::Map(bytes) => map_from_bytes(&bytes).ok(),
+                    _ => None,
+                }
+            }
+
+            fn into_value(self) -> ComponentValue {
+                ComponentValue::Map(map_to_bytes(&self))
+            }
+        }
+        impl SupportedValue for BTreeMap<u32, $type> {
+            fn from_result(result: wit::component::Value) -> Option<Self> {
+                match result {
+                    wit::component::Value::TypeMap(bytes) => map_from_bytes(&bytes).ok(),
+                    _ => None,
+                }
+            }
+
+            fn into_result(self) -> wit::component::Value {
+                wit::component::Value::TypeMap(map_to_bytes(&self))
+            }
+
+            fn from_value(value: ComponentValue) -> Option<Self> {
+                match value {
+                    ComponentValue::Map(bytes) => map_from_bytes(&bytes).ok(),
+                    _ => None,
+                }
+            }
+
+            fn into_value(self) -> ComponentValue {
+                ComponentValue::Map(map_to_bytes(&self))
+            }
+        }
         ) *
     } }
 }
 
+fn map_to_bytes<K: MessageSerde + Ord, V: MessageSerde>(map: &BTreeMap<K, V>) -> Vec<u8> {
+    let mut output = vec![];
+    map.serialize_message_part(&mut output)
+        .expect("serializing a map to memory should not fail");
+    output
+}
+
+fn map_from_bytes<K: MessageSerde + Ord, V: MessageSerde>(
+    mut bytes: &[u8],
+) -> Result<BTreeMap<K, V>, MessageSerdeError> {
+    BTreeMap::deserialize_message_part(&mut bytes)
+}
+
 primitive_component_definitions!(define_component_types);
@@ -358,6 +358,32 @@ mod raw {
                 pub fn hpf() -> Component<Vec2> {
                     *HPF
                 }
+                static DELAY: Lazy<Component<Vec3>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::delay"));
+                #[doc = "**Delay**: Feedback delay. The first value is the delay time in seconds, the second is the feedback, and the third is the mix between the original sound and the echoes.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn delay() -> Component<Vec3> {
+                    *DELAY
+                }
+                static REVERB: Lazy<Component<Vec3>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::reverb"));
+                #[doc = "**Reverb**: Algorithmic room reverb. The first value is the room size, the second is the damping, and the third is the mix between the original sound and the reverb. All values are between 0 and 1.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn reverb() -> Component<Vec3> {
+                    *REVERB
+                }
+                static CONVOLUTION_REVERB: Lazy<Component<String>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::convolution_reverb")
+                });
+                #[doc = "**Convolution reverb**: The URL of an impulse response to reverberate the audio with, such as a recording of a room.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn convolution_reverb() -> Component<String> {
+                    *CONVOLUTION_REVERB
+                }
+                static CONVOLUTION_REVERB_MIX: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::convolution_reverb_mix")
+                });
+                #[doc = "**Convolution reverb mix**: The mix between the original sound and the convolution reverb. Defaults to 0.5.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn convolution_reverb_mix() -> Component<f32> {
+                    *CONVOLUTION_REVERB_MIX
+                }
                 static AUDIO_URL: Lazy<Component<String>> =
                     Lazy::new(|| __internal_get_component("ambient_core::audio::audio_url"));
                 #[doc = "**Audio URL**: The URL of the assets.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
@@ -376,6 +402,33 @@ mod raw {
                 pub fn stop_now() -> Component<()> {
                     *STOP_NOW
                 }
+                static SPATIAL_SOUND: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::spatial_sound"));
+                #[doc = "**Spatial sound**: The URL of a sound which every client plays from this entity, as heard by their active camera.\n\nThe server is the authority for these sounds: the sound plays on each client while this component is attached, and stops when it is removed or the entity is despawned.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn spatial_sound() -> Component<String> {
+                    *SPATIAL_SOUND
+                }
+                static SPATIAL_SOUND_START_TIME: Lazy<Component<Duration>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::spatial_sound_start_time")
+                });
+                #[doc = "**Spatial sound start time**: The `epoch_time` at which the `spatial_sound` started playing.\n\nClients start the sound from the time that has passed since then, so that clients which connect later hear the same part of it.\n\nIf not attached, the sound plays from the start when it reaches the client.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn spatial_sound_start_time() -> Component<Duration> {
+                    *SPATIAL_SOUND_START_TIME
+                }
+                static SPATIAL_SOUND_LOOPING: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::spatial_sound_looping")
+                });
+                #[doc = "**Spatial sound looping**: If attached, the `spatial_sound` restarts when it reaches the end.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn spatial_sound_looping() -> Component<()> {
+                    *SPATIAL_SOUND_LOOPING
+                }
+                static SPATIAL_SOUND_AMPLITUDE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::spatial_sound_amplitude")
+                });
+                #[doc = "**Spatial sound amplitude**: The amplitude of the `spatial_sound`, which can be changed while it plays. Defaults to 1.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn spatial_sound_amplitude() -> Component<f32> {
+                    *SPATIAL_SOUND_AMPLITUDE
+                }
             }
         }
         pub mod camera {
@@ -1871,6 +1924,12 @@ mod raw {
                 pub fn id() -> Component<EntityId> {
                     *ID
                 }
+                static PERSISTENT: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::ecs::persistent"));
+                #[doc = "**Persistent**: If attached, this entity will be saved to disk by the server and restored when the server restarts. Only components with the `Store` attribute are saved.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn persistent() -> Component<()> {
+                    *PERSISTENT
+                }
                 static REMOVE_AT_GAME_TIME: Lazy<Component<Duration>> = Lazy::new(|| {
                     __internal_get_component("ambient_core::ecs::remove_at_game_time")
                 });
@@ -1957,7 +2016,8 @@ mod raw {
             pub mod messages {
                 use crate::{
                     message::{
-                        Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                        Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                        RuntimeMessage,
                     },
                     prelude::*,
                 };
@@ -2601,7 +2661,7 @@ mod raw {
                 static IS_PERSISTENT_RESOURCES: Lazy<Component<()>> = Lazy::new(|| {
                     __internal_get_component("ambient_core::network::is_persistent_resources")
                 });
-                #[doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked"]
+                #[doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"]
                 pub fn is_persistent_resources() -> Component<()> {
                     *IS_PERSISTENT_RESOURCES
                 }
@@ -2618,6 +2678,125 @@ mod raw {
                 pub fn no_sync() -> Component<()> {
                     *NO_SYNC
                 }
+                static RELEVANCY_RADIUS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::relevancy_radius")
+                });
+                #[doc = "**Relevancy radius**: If attached to a player entity, only entities within this distance of the player's relevancy origin are synchronized to the player.\n\nEntities without a position, and entities with `always_relevant`, are always synchronized.\n\n*Attributes*: Debuggable"]
+                pub fn relevancy_radius() -> Component<f32> {
+                    *RELEVANCY_RADIUS
+                }
+                static RELEVANCY_ORIGIN: Lazy<Component<EntityId>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::relevancy_origin")
+                });
+                #[doc = "**Relevancy origin**: The entity whose position is used as the centre of the `relevancy_radius` of a player, such as the player's body.\n\nIf not attached, the position of the player entity is used.\n\n*Attributes*: Debuggable"]
+                pub fn relevancy_origin() -> Component<EntityId> {
+                    *RELEVANCY_ORIGIN
+                }
+                static ALWAYS_RELEVANT: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::always_relevant")
+                });
+                #[doc = "**Always relevant**: If attached, this entity is synchronized to players regardless of their `relevancy_radius`.\n\n*Attributes*: Debuggable"]
+                pub fn always_relevant() -> Component<()> {
+                    *ALWAYS_RELEVANT
+                }
+                static VISIBLE_TO: Lazy<Component<Vec<EntityId>>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::network::visible_to"));
+                #[doc = "**Visible to**: If attached, this entity is only synchronized to the listed player entities.\n\n*Attributes*: Debuggable"]
+                pub fn visible_to() -> Component<Vec<EntityId>> {
+                    *VISIBLE_TO
+                }
+                static RELEVANCY_TEAM: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::network::relevancy_team"));
+                #[doc = "**Relevancy team**: If attached to an entity, the entity is only synchronized to players with the same team.\n\nPlayers are assigned to a team by attaching this component to the player entity.\n\n*Attributes*: Debuggable"]
+                pub fn relevancy_team() -> Component<String> {
+                    *RELEVANCY_TEAM
+                }
+                static RELEVANCY_FILTERING: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::relevancy_filtering")
+                });
+                #[doc = "**Relevancy filtering**: If attached as a resource on the server, each player is only sent the entities that are relevant to them, as decided by `relevancy_radius`, `visible_to` and `relevancy_team`.\n\nIf not attached, every entity is sent to every player.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn relevancy_filtering() -> Component<()> {
+                    *RELEVANCY_FILTERING
+                }
+                static IS_PREDICTED: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::network::is_predicted"));
+                #[doc = "**Is predicted**: If attached on the client to an entity synchronized from the server, the client predicts the entity ahead of the server.\n\nWhenever the server sends an update for the entity, all of its synchronized components are reset to their last values from the server, and `prediction_rewound` is attached so that unacknowledged inputs can be replayed.\n\n*Attributes*: Debuggable"]
+                pub fn is_predicted() -> Component<()> {
+                    *IS_PREDICTED
+                }
+                static PREDICTION_REWOUND: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::prediction_rewound")
+                });
+                #[doc = "**Prediction rewound**: Attached by the client to a predicted entity when it has been reset to its state from the server.\n\nRemove it after replaying the unacknowledged inputs.\n\n*Attributes*: Debuggable"]
+                pub fn prediction_rewound() -> Component<()> {
+                    *PREDICTION_REWOUND
+                }
+                static ACKED_INPUT_SEQUENCE: Lazy<Component<u64>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::acked_input_sequence")
+                });
+                #[doc = "**Acknowledged input sequence**: The sequence number of the last input from the owning player that the server has applied to this entity.\n\nSet this on the server in the same frame that the input is applied, so that the client knows which inputs to replay.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn acked_input_sequence() -> Component<u64> {
+                    *ACKED_INPUT_SEQUENCE
+                }
+                static IS_INTERPOLATED: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::is_interpolated")
+                });
+                #[doc = "**Is interpolated**: If attached to an entity on the server, the client renders the entity a short delay behind the server, and interpolates its `translation`, `rotation` and `scale` between the states it has received.\n\nPredicted entities are not interpolated.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn is_interpolated() -> Component<()> {
+                    *IS_INTERPOLATED
+                }
+                static INTERPOLATION_DELAY: Lazy<Component<Duration>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::interpolation_delay")
+                });
+                #[doc = "**Interpolation delay**: How far behind the server the client renders entities with `is_interpolated`, so that it can interpolate between the states it has received.\n\nLarger delays hide more network jitter. Defaults to 100 milliseconds.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn interpolation_delay() -> Component<Duration> {
+                    *INTERPOLATION_DELAY
+                }
+                static INTERPOLATED_COMPONENTS: Lazy<Component<Vec<String>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::interpolated_components")
+                });
+                #[doc = "**Interpolated components**: The paths of components to interpolate on entities with `is_interpolated`, in addition to `translation`, `rotation` and `scale`.\n\nThe components must be of type `F32`, `Vec2`, `Vec3`, `Vec4` or `Quat`.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn interpolated_components() -> Component<Vec<String>> {
+                    *INTERPOLATED_COMPONENTS
+                }
+                static BANDWIDTH_BUDGET: Lazy<Component<u32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::bandwidth_budget")
+                });
+                #[doc = "**Bandwidth budget**: If attached to a player entity, the number of bytes per second of world diffs that the server sends to the player.\n\nWhen a diff does not fit, the most important component updates are sent first and the rest are delayed. Spawns, despawns and added or removed components are always sent.\n\n*Attributes*: Debuggable"]
+                pub fn bandwidth_budget() -> Component<u32> {
+                    *BANDWIDTH_BUDGET
+                }
+                static COMPONENT_PRIORITIES: Lazy<
+                    Component<std::collections::BTreeMap<String, f32>>,
+                > = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::component_priorities")
+                });
+                #[doc = "**Component priorities**: The priorities of component updates by component path, used to decide which updates are sent first when a player has a `bandwidth_budget`.\n\nComponents default to a priority of 1. Updates to entities closer to the player's relevancy origin, and updates that have been delayed for longer, are also sent first.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn component_priorities() -> Component<std::collections::BTreeMap<String, f32>>
+                {
+                    *COMPONENT_PRIORITIES
+                }
+                static DIFF_COMPRESSION: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::diff_compression")
+                });
+                #[doc = "**Diff compression**: If attached as a resource on the server, world diffs are compressed with zstd before they are sent to the players.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn diff_compression() -> Component<()> {
+                    *DIFF_COMPRESSION
+                }
+                static DIFF_QUANTIZATION: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::diff_quantization")
+                });
+                #[doc = "**Diff quantization**: If attached as a resource on the server, `Vec3`, `Quat` and `Mat4` components are sent to the players with reduced precision.\n\n`Vec3` values are rounded to 1/256 of a unit, rotations are sent to within about 0.0001, and transform matrices are sent as their quantized scale, rotation and translation.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn diff_quantization() -> Component<()> {
+                    *DIFF_QUANTIZATION
+                }
+                static RECONNECT_GRACE_PERIOD: Lazy<Component<Duration>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::reconnect_grace_period")
+                });
+                #[doc = "**Reconnect grace period**: How long the server keeps the player entity of a player whose connection was lost, so that they can resume their session.\n\nWhile the player is away, their player entity has `disconnected`. Defaults to 30 seconds. If zero, the player entity is despawned as soon as the connection is lost.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn reconnect_grace_period() -> Component<Duration> {
+                    *RECONNECT_GRACE_PERIOD
+                }
             }
         }
         pub mod package {
@@ -2973,7 +3152,8 @@ mod raw {
             pub mod messages {
                 use crate::{
                     message::{
-                        Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                        Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                        RuntimeMessage,
                     },
                     prelude::*,
                 };
@@ -3144,12 +3324,39 @@ mod raw {
                 pub fn angular_velocity() -> Component<Vec3> {
                     *ANGULAR_VELOCITY
                 }
+                static CAPSULE_COLLIDER: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::capsule_collider")
+                });
+                #[doc = "**Capsule collider**: If attached, this entity will have a capsule physics collider, standing upright along the Z axis.\n\n`x` is the radius of the capsule, and `y` is its total height, including the hemispheres at both ends.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn capsule_collider() -> Component<Vec2> {
+                    *CAPSULE_COLLIDER
+                }
+                static CONTACT_EVENTS: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::contact_events"));
+                #[doc = "**Contact events**: If attached, the `ContactBegin`, `ContactPersist` and `ContactEnd` messages will be sent for contacts involving this entity's collider.\n\nThese are opt-in, as `ContactPersist` is sent every physics step for as long as two colliders touch.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn contact_events() -> Component<()> {
+                    *CONTACT_EVENTS
+                }
+                static CONVEX_COLLIDER: Lazy<Component<Vec<Vec3>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::convex_collider")
+                });
+                #[doc = "**Convex collider**: If attached, this entity will have a convex physics collider, built from the convex hull of the given points.\n\nThe points don't need to be in any order.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn convex_collider() -> Component<Vec<Vec3>> {
+                    *CONVEX_COLLIDER
+                }
                 static CUBE_COLLIDER: Lazy<Component<Vec3>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::cube_collider"));
                 #[doc = "**Cube collider**: If attached, this entity will have a cube physics collider.\n\n`x, y, z` is the size of the cube.\n\n*Attributes*: Debuggable, Networked, Store"]
                 pub fn cube_collider() -> Component<Vec3> {
                     *CUBE_COLLIDER
                 }
+                static CYLINDER_COLLIDER: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::cylinder_collider")
+                });
+                #[doc = "**Cylinder collider**: If attached, this entity will have a cylinder physics collider, standing upright along the Z axis.\n\n`x` is the radius of the cylinder, and `y` is its height.\n\nThe cylinder is approximated with a convex mesh.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn cylinder_collider() -> Component<Vec2> {
+                    *CYLINDER_COLLIDER
+                }
                 static CHARACTER_CONTROLLER_HEIGHT: Lazy<Component<f32>> = Lazy::new(|| {
                     __internal_get_component("ambient_core::physics::character_controller_height")
                 });
@@ -3202,6 +3409,122 @@ mod raw {
                 pub fn dynamic() -> Component<bool> {
                     *DYNAMIC
                 }
+                static HEIGHTFIELD_COLLIDER: Lazy<Component<Vec<f32>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::heightfield_collider")
+                });
+                #[doc = "**Heightfield collider**: If attached, this entity will have a heightfield physics collider, such as for terrain.\n\nThe value is a grid of heights, row by row; the number of columns is set by `heightfield_collider_columns`.\n\nRows run along the Y axis and columns along the X axis, starting at the entity's origin.\n\nHeightfields can only be used for static colliders.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn heightfield_collider() -> Component<Vec<f32>> {
+                    *HEIGHTFIELD_COLLIDER
+                }
+                static HEIGHTFIELD_COLLIDER_CELL_SIZE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component(
+                        "ambient_core::physics::heightfield_collider_cell_size",
+                    )
+                });
+                #[doc = "**Heightfield collider cell size**: The distance between two neighboring samples of the `heightfield_collider`.\n\nDefaults to 1.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn heightfield_collider_cell_size() -> Component<f32> {
+                    *HEIGHTFIELD_COLLIDER_CELL_SIZE
+                }
+                static HEIGHTFIELD_COLLIDER_COLUMNS: Lazy<Component<u32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::heightfield_collider_columns")
+                });
+                #[doc = "**Heightfield collider columns**: The number of columns in the `heightfield_collider`.\n\nThere must be at least two rows and two columns.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn heightfield_collider_columns() -> Component<u32> {
+                    *HEIGHTFIELD_COLLIDER_COLUMNS
+                }
+                static JOINT_TYPE: Lazy<Component<crate::ambient_core::physics::types::JointType>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_type"));
+                #[doc = "**Joint type**: If attached, this entity is a physics joint of the given type, connecting `joint_actor_0` to `joint_actor_1`.\n\nThe joint is created once both actors have colliders, and released when this entity is despawned or either actor is.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_type() -> Component<crate::ambient_core::physics::types::JointType> {
+                    *JOINT_TYPE
+                }
+                static JOINT_ACTOR_0: Lazy<Component<EntityId>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_actor_0"));
+                #[doc = "**Joint actor 0**: The first entity connected by this joint. It must have a collider.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_actor_0() -> Component<EntityId> {
+                    *JOINT_ACTOR_0
+                }
+                static JOINT_ACTOR_1: Lazy<Component<EntityId>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_actor_1"));
+                #[doc = "**Joint actor 1**: The second entity connected by this joint. It must have a collider.\n\nIf not attached, the first entity is attached to a fixed point in the world instead.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_actor_1() -> Component<EntityId> {
+                    *JOINT_ACTOR_1
+                }
+                static JOINT_LOCAL_FRAME_0: Lazy<Component<Mat4>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_local_frame_0")
+                });
+                #[doc = "**Joint local frame 0**: The frame of the joint, relative to `joint_actor_0`.\n\nThe X axis of this frame is the axis of revolute and prismatic joints. Defaults to identity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_local_frame_0() -> Component<Mat4> {
+                    *JOINT_LOCAL_FRAME_0
+                }
+                static JOINT_LOCAL_FRAME_1: Lazy<Component<Mat4>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_local_frame_1")
+                });
+                #[doc = "**Joint local frame 1**: The frame of the joint, relative to `joint_actor_1` (or the world, if there is no second actor).\n\nDefaults to identity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_local_frame_1() -> Component<Mat4> {
+                    *JOINT_LOCAL_FRAME_1
+                }
+                static JOINT_LIMIT: Lazy<Component<Vec2>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_limit"));
+                #[doc = "**Joint limit**: The lower (`x`) and upper (`y`) limit of the joint's motion.\n\nFor revolute joints, this is the rotation around the joint's X axis in radians; for prismatic joints, the translation along the X axis in meters; and for distance joints, the minimum and maximum distance between the actors in meters.\n\nThe lower limit must not be greater than the upper limit, and distances must not be negative, or the limit is ignored.\n\nIf not attached, the motion is not limited.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_limit() -> Component<Vec2> {
+                    *JOINT_LIMIT
+                }
+                static JOINT_CONE_LIMIT: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_cone_limit")
+                });
+                #[doc = "**Joint cone limit**: Limits the motion of a spherical joint to an elliptical cone around its X axis.\n\n`x` is the maximum angle (radians) around the Y axis, and `y` is the maximum angle around the Z axis.\n\nBoth angles must be between 0 and π, or the limit is ignored.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_cone_limit() -> Component<Vec2> {
+                    *JOINT_CONE_LIMIT
+                }
+                static JOINT_DRIVE_VELOCITY: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_velocity")
+                });
+                #[doc = "**Joint drive velocity**: The joint will be driven like a motor towards this velocity.\n\nFor revolute and spherical joints, this is the angular velocity around the joint's X axis in radians/second; for prismatic joints, the velocity along the X axis in meters/second.\n\nRemove this component to let the joint move freely again.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_velocity() -> Component<f32> {
+                    *JOINT_DRIVE_VELOCITY
+                }
+                static JOINT_DRIVE_FORCE_LIMIT: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_force_limit")
+                });
+                #[doc = "**Joint drive force limit**: The maximum torque (revolute and spherical joints) or force (prismatic joints) the joint's drive can apply. If not attached, it is unlimited.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_force_limit() -> Component<f32> {
+                    *JOINT_DRIVE_FORCE_LIMIT
+                }
+                static JOINT_SPRING_STIFFNESS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_spring_stiffness")
+                });
+                #[doc = "**Joint spring stiffness**: If attached to a distance joint, the distance limit is enforced by a spring with this stiffness instead of being rigid.\n\n`joint_spring_damping` controls the damping of the spring.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_spring_stiffness() -> Component<f32> {
+                    *JOINT_SPRING_STIFFNESS
+                }
+                static JOINT_SPRING_DAMPING: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_spring_damping")
+                });
+                #[doc = "**Joint spring damping**: The damping of the spring of a distance joint. See `joint_spring_stiffness`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_spring_damping() -> Component<f32> {
+                    *JOINT_SPRING_DAMPING
+                }
+                static JOINT_BREAK_FORCE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_break_force")
+                });
+                #[doc = "**Joint break force**: The linear force (in newtons) at which this joint breaks.\n\nIf not attached, the joint can not be broken by linear forces.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_break_force() -> Component<f32> {
+                    *JOINT_BREAK_FORCE
+                }
+                static JOINT_BREAK_TORQUE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_break_torque")
+                });
+                #[doc = "**Joint break torque**: The torque (in newton meters) at which this joint breaks.\n\nIf not attached, the joint can not be broken by torque.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_break_torque() -> Component<f32> {
+                    *JOINT_BREAK_TORQUE
+                }
+                static JOINT_BROKEN: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_broken"));
+                #[doc = "**Joint broken**: This component is automatically attached to a joint once it has broken.\n\nA broken joint no longer constrains its actors; despawn it and spawn a new joint to reconnect them.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_broken() -> Component<()> {
+                    *JOINT_BROKEN
+                }
                 static KINEMATIC: Lazy<Component<()>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::kinematic"));
                 #[doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"]
@@ -3288,6 +3611,195 @@ mod raw {
                     global::serde::{self, Deserialize, Serialize},
                     prelude::*,
                 };
+                #[doc = "**Joint**: A physics joint connecting two entities with colliders.\n\nThe joint can be broken by specifying a `joint_break_force` or `joint_break_torque`; when that happens, `joint_broken` is attached and the `JointBroken` message is sent.\n\n**Required**:\n- `joint_type`: If attached, this entity is a physics joint of the given type, connecting `joint_actor_0` to `joint_actor_1`.\nThe joint is created once both actors have colliders, and released when this entity is despawned or either actor is.\n- `joint_actor_0`: The first entity connected by this joint. It must have a collider.\n\n\n**Optional**:\n- `joint_actor_1`: The second entity connected by this joint. It must have a collider.\nIf not attached, the first entity is attached to a fixed point in the world instead.\n- `joint_local_frame_0`: The frame of the joint, relative to `joint_actor_0`.\nThe X axis of this frame is the axis of revolute and prismatic joints. Defaults to identity.\n- `joint_local_frame_1`: The frame of the joint, relative to `joint_actor_1` (or the world, if there is no second actor).\nDefaults to identity.\n- `joint_limit`: The lower (`x`) and upper (`y`) limit of the joint's motion.\nFor revolute joints, this is the rotation around the joint's X axis in radians; for prismatic joints, the translation along the X axis in meters; and for distance joints, the minimum and maximum distance between the actors in meters.\nThe lower limit must not be greater than the upper limit, and distances must not be negative, or the limit is ignored.\nIf not attached, the motion is not limited.\n- `joint_cone_limit`: Limits the motion of a spherical joint to an elliptical cone around its X axis.\n`x` is the maximum angle (radians) around the Y axis, and `y` is the maximum angle around the Z axis.\nBoth angles must be between 0 and π, or the limit is ignored.\n- `joint_drive_velocity`: The joint will be driven like a motor towards this velocity.\nFor revolute and spherical joints, this is the angular velocity around the joint's X axis in radians/second; for prismatic joints, the velocity along the X axis in meters/second.\nRemove this component to let the joint move freely again.\n- `joint_drive_force_limit`: The maximum torque (revolute and spherical joints) or force (prismatic joints) the joint's drive can apply. If not attached, it is unlimited.\n- `joint_spring_stiffness`: If attached to a distance joint, the distance limit is enforced by a spring with this stiffness instead of being rigid.\n`joint_spring_damping` controls the damping of the spring.\n- `joint_spring_damping`: The damping of the spring of a distance joint. See `joint_spring_stiffness`.\n- `joint_break_force`: The linear force (in newtons) at which this joint breaks.\nIf not attached, the joint can not be broken by linear forces.\n- `joint_break_torque`: The torque (in newton meters) at which this joint breaks.\nIf not attached, the joint can not be broken by torque."]
+                #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+                #[serde(crate = "self::serde")]
+                pub struct Joint {
+                    #[doc = "**Component**: `ambient_core::physics::joint_type`\n\n**Component description**: If attached, this entity is a physics joint of the given type, connecting `joint_actor_0` to `joint_actor_1`.\nThe joint is created once both actors have colliders, and released when this entity is despawned or either actor is.\n\n"]
+                    pub joint_type: crate::ambient_core::physics::types::JointType,
+                    #[doc = "**Component**: `ambient_core::physics::joint_actor_0`\n\n**Component description**: The first entity connected by this joint. It must have a collider.\n\n"]
+                    pub joint_actor_0: EntityId,
+                    #[doc = r" Optional components."]
+                    pub optional: JointOptional,
+                }
+                #[doc = "Optional part of [Joint]."]
+                #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+                #[serde(crate = "self::serde")]
+                pub struct JointOptional {
+                    #[doc = "**Component**: `ambient_core::physics::joint_actor_1`\n\n**Component description**: The second entity connected by this joint. It must have a collider.\nIf not attached, the first entity is attached to a fixed point in the world instead.\n\n"]
+                    pub joint_actor_1: Option<EntityId>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_local_frame_0`\n\n**Suggested value**: `Mat4::from_cols_array(&[1f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 0f32, 1f32, ])`\n\n**Component description**: The frame of the joint, relative to `joint_actor_0`.\nThe X axis of this frame is the axis of revolute and prismatic joints. Defaults to identity.\n\n"]
+                    pub joint_local_frame_0: Option<Mat4>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_local_frame_1`\n\n**Suggested value**: `Mat4::from_cols_array(&[1f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 0f32, 1f32, ])`\n\n**Component description**: The frame of the joint, relative to `joint_actor_1` (or the world, if there is no second actor).\nDefaults to identity.\n\n"]
+                    pub joint_local_frame_1: Option<Mat4>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_limit`\n\n**Component description**: The lower (`x`) and upper (`y`) limit of the joint's motion.\nFor revolute joints, this is the rotation around the joint's X axis in radians; for prismatic joints, the translation along the X axis in meters; and for distance joints, the minimum and maximum distance between the actors in meters.\nThe lower limit must not be greater than the upper limit, and distances must not be negative, or the limit is ignored.\nIf not attached, the motion is not limited.\n\n"]
+                    pub joint_limit: Option<Vec2>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_cone_limit`\n\n**Component description**: Limits the motion of a spherical joint to an elliptical cone around its X axis.\n`x` is the maximum angle (radians) around the Y axis, and `y` is the maximum angle around the Z axis.\nBoth angles must be between 0 and π, or the limit is ignored.\n\n"]
+                    pub joint_cone_limit: Option<Vec2>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_drive_velocity`\n\n**Component description**: The joint will be driven like a motor towards this velocity.\nFor revolute and spherical joints, this is the angular velocity around the joint's X axis in radians/second; for prismatic joints, the velocity along the X axis in meters/second.\nRemove this component to let the joint move freely again.\n\n"]
+                    pub joint_drive_velocity: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_drive_force_limit`\n\n**Component description**: The maximum torque (revolute and spherical joints) or force (prismatic joints) the joint's drive can apply. If not attached, it is unlimited.\n\n"]
+                    pub joint_drive_force_limit: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_spring_stiffness`\n\n**Component description**: If attached to a distance joint, the distance limit is enforced by a spring with this stiffness instead of being rigid.\n`joint_spring_damping` controls the damping of the spring.\n\n"]
+                    pub joint_spring_stiffness: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_spring_damping`\n\n**Component description**: The damping of the spring of a distance joint. See `joint_spring_stiffness`.\n\n"]
+                    pub joint_spring_damping: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_break_force`\n\n**Component description**: The linear force (in newtons) at which this joint breaks.\nIf not attached, the joint can not be broken by linear forces.\n\n"]
+                    pub joint_break_force: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_break_torque`\n\n**Component description**: The torque (in newton meters) at which this joint breaks.\nIf not attached, the joint can not be broken by torque.\n\n"]
+                    pub joint_break_torque: Option<f32>,
+                }
+                impl Concept for Joint {
+                    fn make(self) -> Entity {
+                        let mut entity = Entity::new()
+                            .with(
+                                crate::ambient_core::physics::components::joint_type(),
+                                self.joint_type,
+                            )
+                            .with(
+                                crate::ambient_core::physics::components::joint_actor_0(),
+                                self.joint_actor_0,
+                            );
+                        if let Some(joint_actor_1) = self.optional.joint_actor_1 {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_actor_1(),
+                                joint_actor_1,
+                            );
+                        }
+                        if let Some(joint_local_frame_0) = self.optional.joint_local_frame_0 {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_local_frame_0(),
+                                joint_local_frame_0,
+                            );
+                        }
+                        if let Some(joint_local_frame_1) = self.optional.joint_local_frame_1 {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_local_frame_1(),
+                                joint_local_frame_1,
+                            );
+                        }
+                        if let Some(joint_limit) = self.optional.joint_limit {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_limit(),
+                                joint_limit,
+                            );
+                        }
+                        if let Some(joint_cone_limit) = self.optional.joint_cone_limit {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_cone_limit(),
+                                joint_cone_limit,
+                            );
+                        }
+                        if let Some(joint_drive_velocity) = self.optional.joint_drive_velocity {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_drive_velocity(),
+                                joint_drive_velocity,
+                            );
+                        }
+                        if let Some(joint_drive_force_limit) = self.optional.joint_drive_force_limit
+                        {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_drive_force_limit(),
+                                joint_drive_force_limit,
+                            );
+                        }
+                        if let Some(joint_spring_stiffness) = self.optional.joint_spring_stiffness {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_spring_stiffness(),
+                                joint_spring_stiffness,
+                            );
+                        }
+                        if let Some(joint_spring_damping) = self.optional.joint_spring_damping {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_spring_damping(),
+                                joint_spring_damping,
+                            );
+                        }
+                        if let Some(joint_break_force) = self.optional.joint_break_force {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_break_force(),
+                                joint_break_force,
+                            );
+                        }
+                        if let Some(joint_break_torque) = self.optional.joint_break_torque {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_break_torque(),
+                                joint_break_torque,
+                            );
+                        }
+                        entity
+                    }
+                    fn get_spawned(id: EntityId) -> Option<Self> {
+                        Some (Self { joint_type : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_type ()) ? , joint_actor_0 : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_actor_0 ()) ? , optional : JointOptional { joint_actor_1 : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_actor_1 ()) , joint_local_frame_0 : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_local_frame_0 ()) , joint_local_frame_1 : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_local_frame_1 ()) , joint_limit : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_limit ()) , joint_cone_limit : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_cone_limit ()) , joint_drive_velocity : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_drive_velocity ()) , joint_drive_force_limit : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_drive_force_limit ()) , joint_spring_stiffness : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_spring_stiffness ()) , joint_spring_damping : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_spring_damping ()) , joint_break_force : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_break_force ()) , joint_break_torque : entity :: get_component (id , crate :: ambient_core :: physics :: components :: joint_break_torque ()) , } })
+                    }
+                    fn get_unspawned(entity: &Entity) -> Option<Self> {
+                        Some (Self { joint_type : entity . get (crate :: ambient_core :: physics :: components :: joint_type ()) ? , joint_actor_0 : entity . get (crate :: ambient_core :: physics :: components :: joint_actor_0 ()) ? , optional : JointOptional { joint_actor_1 : entity . get (crate :: ambient_core :: physics :: components :: joint_actor_1 ()) , joint_local_frame_0 : entity . get (crate :: ambient_core :: physics :: components :: joint_local_frame_0 ()) , joint_local_frame_1 : entity . get (crate :: ambient_core :: physics :: components :: joint_local_frame_1 ()) , joint_limit : entity . get (crate :: ambient_core :: physics :: components :: joint_limit ()) , joint_cone_limit : entity . get (crate :: ambient_core :: physics :: components :: joint_cone_limit ()) , joint_drive_velocity : entity . get (crate :: ambient_core :: physics :: components :: joint_drive_velocity ()) , joint_drive_force_limit : entity . get (crate :: ambient_core :: physics :: components :: joint_drive_force_limit ()) , joint_spring_stiffness : entity . get (crate :: ambient_core :: physics :: components :: joint_spring_stiffness ()) , joint_spring_damping : entity . get (crate :: ambient_core :: physics :: components :: joint_spring_damping ()) , joint_break_force : entity . get (crate :: ambient_core :: physics :: components :: joint_break_force ()) , joint_break_torque : entity . get (crate :: ambient_core :: physics :: components :: joint_break_torque ()) , } })
+                    }
+                    fn contained_by_spawned(id: EntityId) -> bool {
+                        entity::has_components(
+                            id,
+                            &[
+                                &crate::ambient_core::physics::components::joint_type(),
+                                &crate::ambient_core::physics::components::joint_actor_0(),
+                            ],
+                        )
+                    }
+                    fn contained_by_unspawned(entity: &Entity) -> bool {
+                        entity.has_components(&[
+                            &crate::ambient_core::physics::components::joint_type(),
+                            &crate::ambient_core::physics::components::joint_actor_0(),
+                        ])
+                    }
+                }
+                impl ConceptComponents for Joint {
+                    type Required = (
+                        Component<crate::ambient_core::physics::types::JointType>,
+                        Component<EntityId>,
+                    );
+                    type Optional = (
+                        Component<EntityId>,
+                        Component<Mat4>,
+                        Component<Mat4>,
+                        Component<Vec2>,
+                        Component<Vec2>,
+                        Component<f32>,
+                        Component<f32>,
+                        Component<f32>,
+                        Component<f32>,
+                        Component<f32>,
+                        Component<f32>,
+                    );
+                    fn required() -> Self::Required {
+                        (
+                            crate::ambient_core::physics::components::joint_type(),
+                            crate::ambient_core::physics::components::joint_actor_0(),
+                        )
+                    }
+                    fn optional() -> Self::Optional {
+                        (
+                            crate::ambient_core::physics::components::joint_actor_1(),
+                            crate::ambient_core::physics::components::joint_local_frame_0(),
+                            crate::ambient_core::physics::components::joint_local_frame_1(),
+                            crate::ambient_core::physics::components::joint_limit(),
+                            crate::ambient_core::physics::components::joint_cone_limit(),
+                            crate::ambient_core::physics::components::joint_drive_velocity(),
+                            crate::ambient_core::physics::components::joint_drive_force_limit(),
+                            crate::ambient_core::physics::components::joint_spring_stiffness(),
+                            crate::ambient_core::physics::components::joint_spring_damping(),
+                            crate::ambient_core::physics::components::joint_break_force(),
+                            crate::ambient_core::physics::components::joint_break_torque(),
+                        )
+                    }
+                    fn from_required_data(
+                        required: <Self::Required as ComponentsTuple>::Data,
+                    ) -> Self {
+                        Self {
+                            joint_type: required.0,
+                            joint_actor_0: required.1,
+                            optional: Default::default(),
+                        }
+                    }
+                }
                 #[doc = "**Character Controller**: A capsule character controller. The capsule is defined as a position, a vertical height, and a radius. The height is the distance between the two sphere centers at the end of the capsule.\n\n**Note**: The character controller is not affected by gravity; you will have to manually simulate gravity by moving the character down in accordance with a vertical velocity you maintain. See the `character_movement` package for an example.\n\n**Required**:\n- `character_controller_height`: The height of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider.\n- `character_controller_radius`: The radius of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider.\n- `physics_controlled`: If attached, this entity will be controlled by physics.\nNote that this requires the entity to have a collider."]
                 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
                 #[serde(crate = "self::serde")]
@@ -3357,6 +3869,328 @@ mod raw {
                     }
                 }
             }
+            #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
+            #[doc = r" and with other modules."]
+            pub mod messages {
+                use crate::{
+                    message::{
+                        Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                        RuntimeMessage,
+                    },
+                    prelude::*,
+                };
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactBegin**: Sent when two colliders start touching, if either of them has `contact_events`.\nThe points and normals are in world space, and `impulse` is the total impulse applied between the colliders by this contact."]
+                pub struct ContactBegin {
+                    pub ids: Vec<EntityId>,
+                    pub points: Vec<Vec3>,
+                    pub normals: Vec<Vec3>,
+                    pub impulse: Vec3,
+                }
+                impl ContactBegin {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        ids: impl Into<Vec<EntityId>>,
+                        points: impl Into<Vec<Vec3>>,
+                        normals: impl Into<Vec<Vec3>>,
+                        impulse: impl Into<Vec3>,
+                    ) -> Self {
+                        Self {
+                            ids: ids.into(),
+                            points: points.into(),
+                            normals: normals.into(),
+                            impulse: impulse.into(),
+                        }
+                    }
+                }
+                impl Message for ContactBegin {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactBegin"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.ids.serialize_message_part(&mut output)?;
+                        self.points.serialize_message_part(&mut output)?;
+                        self.normals.serialize_message_part(&mut output)?;
+                        self.impulse.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            ids: Vec::<EntityId>::deserialize_message_part(&mut input)?,
+                            points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            impulse: Vec3::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactBegin {}
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactPersist**: Sent every physics step for as long as two colliders keep touching, if either of them has `contact_events`.\nThe fields are the same as those of `ContactBegin`."]
+                pub struct ContactPersist {
+                    pub ids: Vec<EntityId>,
+                    pub points: Vec<Vec3>,
+                    pub normals: Vec<Vec3>,
+                    pub impulse: Vec3,
+                }
+                impl ContactPersist {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        ids: impl Into<Vec<EntityId>>,
+                        points: impl Into<Vec<Vec3>>,
+                        normals: impl Into<Vec<Vec3>>,
+                        impulse: impl Into<Vec3>,
+                    ) -> Self {
+                        Self {
+                            ids: ids.into(),
+                            points: points.into(),
+                            normals: normals.into(),
+                            impulse: impulse.into(),
+                        }
+                    }
+                }
+                impl Message for ContactPersist {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactPersist"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.ids.serialize_message_part(&mut output)?;
+                        self.points.serialize_message_part(&mut output)?;
+                        self.normals.serialize_message_part(&mut output)?;
+                        self.impulse.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            ids: Vec::<EntityId>::deserialize_message_part(&mut input)?,
+                            points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            impulse: Vec3::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactPersist {}
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactEnd**: Sent when two colliders stop touching, if either of them has `contact_events`."]
+                pub struct ContactEnd {
+                    pub ids: Vec<EntityId>,
+                }
+                impl ContactEnd {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(ids: impl Into<Vec<EntityId>>) -> Self {
+                        Self { ids: ids.into() }
+                    }
+                }
+                impl Message for ContactEnd {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactEnd"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.ids.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            ids: Vec::<EntityId>::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactEnd {}
+                #[derive(Clone, Debug)]
+                #[doc = "**TriggerEnter**: Sent when the collider of a dynamic entity or character starts overlapping a trigger area collider."]
+                pub struct TriggerEnter {
+                    pub trigger: EntityId,
+                    pub id: EntityId,
+                }
+                impl TriggerEnter {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(trigger: impl Into<EntityId>, id: impl Into<EntityId>) -> Self {
+                        Self {
+                            trigger: trigger.into(),
+                            id: id.into(),
+                        }
+                    }
+                }
+                impl Message for TriggerEnter {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::TriggerEnter"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.trigger.serialize_message_part(&mut output)?;
+                        self.id.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            trigger: EntityId::deserialize_message_part(&mut input)?,
+                            id: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for TriggerEnter {}
+                #[derive(Clone, Debug)]
+                #[doc = "**TriggerExit**: Sent when the collider of a dynamic entity or character stops overlapping a trigger area collider, including when either of them is despawned."]
+                pub struct TriggerExit {
+                    pub trigger: EntityId,
+                    pub id: EntityId,
+                }
+                impl TriggerExit {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(trigger: impl Into<EntityId>, id: impl Into<EntityId>) -> Self {
+                        Self {
+                            trigger: trigger.into(),
+                            id: id.into(),
+                        }
+                    }
+                }
+                impl Message for TriggerExit {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::TriggerExit"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.trigger.serialize_message_part(&mut output)?;
+                        self.id.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            trigger: EntityId::deserialize_message_part(&mut input)?,
+                            id: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for TriggerExit {}
+                #[derive(Clone, Debug)]
+                #[doc = "**JointBroken**: Sent when a joint breaks because the force or torque on it exceeded its `joint_break_force` or `joint_break_torque`."]
+                pub struct JointBroken {
+                    pub joint: EntityId,
+                    pub actors: Vec<EntityId>,
+                }
+                impl JointBroken {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        joint: impl Into<EntityId>,
+                        actors: impl Into<Vec<EntityId>>,
+                    ) -> Self {
+                        Self {
+                            joint: joint.into(),
+                            actors: actors.into(),
+                        }
+                    }
+                }
+                impl Message for JointBroken {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::JointBroken"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.joint.serialize_message_part(&mut output)?;
+                        self.actors.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            joint: EntityId::deserialize_message_part(&mut input)?,
+                            actors: Vec::<EntityId>::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for JointBroken {}
+            }
+            #[doc = r" Auto-generated type definitions."]
+            pub mod types {
+                use crate::{global::serde, message::*};
+                #[derive(
+                    Copy,
+                    Clone,
+                    Debug,
+                    PartialEq,
+                    Eq,
+                    serde :: Serialize,
+                    serde :: Deserialize,
+                    Default,
+                )]
+                #[serde(crate = "self::serde")]
+                #[doc = "**JointType**: The type of a physics joint."]
+                pub enum JointType {
+                    #[default]
+                    #[doc = "Locks the actors together, removing all relative motion."]
+                    Fixed,
+                    #[doc = "Allows the actors to rotate relative to each other around the joint's X axis, like a hinge."]
+                    Revolute,
+                    #[doc = "Allows the actors to rotate freely relative to each other around the joint's origin, like a ball-and-socket."]
+                    Spherical,
+                    #[doc = "Allows the actors to slide relative to each other along the joint's X axis."]
+                    Prismatic,
+                    #[doc = "Keeps the distance between the joint's origins within the `joint_limit`."]
+                    Distance,
+                }
+                impl crate::ecs::EnumComponent for JointType {
+                    fn to_u32(&self) -> u32 {
+                        match self {
+                            Self::Fixed => JointType::Fixed as u32,
+                            Self::Revolute => JointType::Revolute as u32,
+                            Self::Spherical => JointType::Spherical as u32,
+                            Self::Prismatic => JointType::Prismatic as u32,
+                            Self::Distance => JointType::Distance as u32,
+                        }
+                    }
+                    fn from_u32(value: u32) -> Option<Self> {
+                        if value == JointType::Fixed as u32 {
+                            return Some(Self::Fixed);
+                        }
+                        if value == JointType::Revolute as u32 {
+                            return Some(Self::Revolute);
+                        }
+                        if value == JointType::Spherical as u32 {
+                            return Some(Self::Spherical);
+                        }
+                        if value == JointType::Prismatic as u32 {
+                            return Some(Self::Prismatic);
+                        }
+                        if value == JointType::Distance as u32 {
+                            return Some(Self::Distance);
+                        }
+                        None
+                    }
+                }
+                impl crate::ecs::SupportedValue for JointType {
+                    fn from_result(result: crate::ecs::WitComponentValue) -> Option<Self> {
+                        use crate::ecs::EnumComponent;
+                        u32::from_result(result).and_then(Self::from_u32)
+                    }
+                    fn into_result(self) -> crate::ecs::WitComponentValue {
+                        use crate::ecs::EnumComponent;
+                        self.to_u32().into_result()
+                    }
+                    fn from_value(value: crate::ecs::ComponentValue) -> Option<Self> {
+                        use crate::ecs::EnumComponent;
+                        u32::from_value(value).and_then(Self::from_u32)
+                    }
+                    fn into_value(self) -> crate::ecs::ComponentValue {
+                        use crate::ecs::EnumComponent;
+                        self.to_u32().into_value()
+                    }
+                }
+                impl MessageSerde for JointType {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        crate::ecs::EnumComponent::to_u32(self).serialize_message_part(output)
+                    }
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        crate::ecs::EnumComponent::from_u32(u32::deserialize_message_part(input)?)
+                            .ok_or(MessageSerdeError::InvalidValue)
+                    }
+                }
+            }
         }
         pub mod player {
             #[doc = r" Auto-generated component definitions."]
@@ -3384,6 +4218,81 @@ mod raw {
                 pub fn user_id() -> Component<String> {
                     *USER_ID
                 }
+                static DISCONNECTED: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::player::disconnected"));
+                #[doc = "**Disconnected**: This player has lost their connection, and their player entity is kept until they reconnect or the `reconnect_grace_period` runs out.\n\nPlayers who disconnect on purpose are despawned immediately.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn disconnected() -> Component<()> {
+                    *DISCONNECTED
+                }
+            }
+            #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
+            #[doc = r" and with other modules."]
+            pub mod messages {
+                use crate::{
+                    message::{
+                        Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                        RuntimeMessage,
+                    },
+                    prelude::*,
+                };
+                #[derive(Clone, Debug)]
+                #[doc = "**Disconnect**: Sent to server modules when a player loses their connection, and their player entity is marked as `disconnected`."]
+                pub struct Disconnect {
+                    pub player: EntityId,
+                }
+                impl Disconnect {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(player: impl Into<EntityId>) -> Self {
+                        Self {
+                            player: player.into(),
+                        }
+                    }
+                }
+                impl Message for Disconnect {
+                    fn id() -> &'static str {
+                        "ambient_core::player::Disconnect"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.player.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            player: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for Disconnect {}
+                #[derive(Clone, Debug)]
+                #[doc = "**Reconnect**: Sent to server modules when a player whose connection was lost resumes their session."]
+                pub struct Reconnect {
+                    pub player: EntityId,
+                }
+                impl Reconnect {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(player: impl Into<EntityId>) -> Self {
+                        Self {
+                            player: player.into(),
+                        }
+                    }
+                }
+                impl Message for Reconnect {
+                    fn id() -> &'static str {
+                        "ambient_core::player::Reconnect"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.player.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            player: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for Reconnect {}
             }
         }
         pub mod prefab {
@@ -4686,7 +5595,8 @@ mod raw {
             pub mod messages {
                 use crate::{
                     message::{
-                        Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                        Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                        RuntimeMessage,
                     },
                     prelude::*,
                 };
@@ -4770,6 +5680,50 @@ mod raw {
                 pub fn package_ref() -> Component<EntityId> {
                     *PACKAGE_REF
                 }
+                static MODULE_EXECUTION_TIME: Lazy<Component<Duration>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::wasm::module_execution_time")
+                });
+                #[doc = "**Module execution time**: The time spent running this module per frame, including the host calls it made, averaged over the last `module_profiling_interval`.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_execution_time() -> Component<Duration> {
+                    *MODULE_EXECUTION_TIME
+                }
+                static MODULE_MESSAGE_TIMES: Lazy<
+                    Component<std::collections::BTreeMap<String, Duration>>,
+                > = Lazy::new(|| {
+                    __internal_get_component("ambient_core::wasm::module_message_times")
+                });
+                #[doc = "**Module message times**: The time spent running this module per frame, by the name of the message it was handling, averaged over the last `module_profiling_interval`. Frame and lifecycle events are messages too.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_message_times(
+                ) -> Component<std::collections::BTreeMap<String, Duration>> {
+                    *MODULE_MESSAGE_TIMES
+                }
+                static MODULE_HOST_CALL_COUNTS: Lazy<
+                    Component<std::collections::BTreeMap<String, u32>>,
+                > = Lazy::new(|| {
+                    __internal_get_component("ambient_core::wasm::module_host_call_counts")
+                });
+                #[doc = "**Module host call counts**: The number of calls this module made to the host per frame, by the WIT interface that was called, such as `entity` or `server-physics`, averaged over the last `module_profiling_interval`.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_host_call_counts(
+                ) -> Component<std::collections::BTreeMap<String, u32>> {
+                    *MODULE_HOST_CALL_COUNTS
+                }
+                static MODULE_HOST_CALL_TIMES: Lazy<
+                    Component<std::collections::BTreeMap<String, Duration>>,
+                > = Lazy::new(|| {
+                    __internal_get_component("ambient_core::wasm::module_host_call_times")
+                });
+                #[doc = "**Module host call times**: The time spent in calls this module made to the host per frame, by the WIT interface that was called, averaged over the last `module_profiling_interval`. Only measured on native.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_host_call_times(
+                ) -> Component<std::collections::BTreeMap<String, Duration>> {
+                    *MODULE_HOST_CALL_TIMES
+                }
+                static MODULE_PROFILING_INTERVAL: Lazy<Component<Duration>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::wasm::module_profiling_interval")
+                });
+                #[doc = "**Module profiling interval**: If attached as a resource, the profile of each module is attached to it as `module_execution_time`, `module_message_times`, `module_host_call_counts` and `module_host_call_times` at this interval.\n\nThe profile components are not updated unless this is attached on the same side as the module.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn module_profiling_interval() -> Component<Duration> {
+                    *MODULE_PROFILING_INTERVAL
+                }
             }
             #[doc = r" Auto-generated concept definitions. Concepts are collections of components that describe some form of gameplay concept."]
             #[doc = r""]
@@ -4946,7 +5900,8 @@ mod raw {
         pub mod messages {
             use crate::{
                 message::{
-                    Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                    Message, MessageSerde, MessageSerdeError, ModuleMessage, Request,
+                    RuntimeMessage,
                 },
                 prelude::*,
             };
@@ -5421,6 +6376,64 @@ mod raw {
             }
             impl RuntimeMessage for HttpResponse {}
             #[derive(Clone, Debug)]
+            #[doc = "**SoundFinished**: Sent to a module when a sound it played has finished playing, or was stopped."]
+            pub struct SoundFinished {
+                pub sound: EntityId,
+            }
+            impl SoundFinished {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(sound: impl Into<EntityId>) -> Self {
+                    Self {
+                        sound: sound.into(),
+                    }
+                }
+            }
+            impl Message for SoundFinished {
+                fn id() -> &'static str {
+                    "ambient_core::SoundFinished"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.sound.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        sound: EntityId::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for SoundFinished {}
+            #[derive(Clone, Debug)]
+            #[doc = "**TimerExpired**: Sent to a module when a timer it scheduled expires."]
+            pub struct TimerExpired {
+                pub timer: u64,
+            }
+            impl TimerExpired {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(timer: impl Into<u64>) -> Self {
+                    Self {
+                        timer: timer.into(),
+                    }
+                }
+            }
+            impl Message for TimerExpired {
+                fn id() -> &'static str {
+                    "ambient_core::TimerExpired"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.timer.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        timer: u64::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for TimerExpired {}
+            #[derive(Clone, Debug)]
             #[doc = "**WasmRebuild**: Sent when a request for WASM rebuilding is completed."]
             pub struct WasmRebuild {
                 pub error: Option<String>,
@@ -29,6 +29,8 @@ pub mod global;
 pub mod message;
 /// Player-specific functionality.
 pub mod player;
+/// Timers that send a message to the module when they expire.
+pub mod timer;
 
 /// Helpful imports that almost all Ambient packages will use.
 pub mod prelude;
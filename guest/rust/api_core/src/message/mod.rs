@@ -1,13 +1,16 @@
 use std::fmt::Debug;
 
 use crate::{
-    global::{CallbackReturn, EntityId},
+    global::{CallbackReturn, EntityId, ResultEmpty},
     internal::{conversion::FromBindgen, executor::EXECUTOR, wit},
 };
 
 mod serde;
 pub use self::serde::*;
 
+mod request;
+pub use request::{call, serve, Request, RequestError, ResponseFuture, DEFAULT_REQUEST_TIMEOUT};
+
 #[cfg(any(feature = "client", feature = "server"))]
 use crate::internal::conversion::IntoBindgen;
 
@@ -222,23 +225,20 @@ impl<'a> IntoBindgen for &'a Target {
 
 /// Send a message from this package to a specific `target`.
 pub fn send<T: Message>(target: Target, data: &T) {
+    send_raw(target, T::id(), &data.serialize_message().unwrap());
+}
+
+/// Sends already serialized `data` under the message name `id`.
+fn send_raw(target: Target, id: &str, data: &[u8]) {
     #[cfg(all(feature = "client", not(feature = "server")))]
-    wit::client_message::send(
-        target.into_bindgen(),
-        T::id(),
-        &data.serialize_message().unwrap(),
-    );
+    wit::client_message::send(target.into_bindgen(), id, data);
     #[cfg(all(feature = "server", not(feature = "client")))]
-    wit::server_message::send(
-        &(&target).into_bindgen(),
-        T::id(),
-        &data.serialize_message().unwrap(),
-    );
+    wit::server_message::send(&(&target).into_bindgen(), id, data);
     #[cfg(any(
         all(not(feature = "server"), not(feature = "client")),
         all(feature = "server", feature = "client")
     ))]
-    let _ = (target, data);
+    let _ = (target, id, data);
 }
 
 /// Handle to a message listener that can be used to stop listening.
@@ -305,22 +305,29 @@ impl Debug for MessageContext {
 pub fn subscribe<R: CallbackReturn, T: Message>(
     mut callback: impl FnMut(MessageContext, T) -> R + 'static,
 ) -> Listener {
-    let id = T::id();
-    wit::message::subscribe(id);
+    subscribe_raw(T::id().to_string(), move |ctx, data| {
+        callback(ctx, T::deserialize_message(data)?).into_result()
+    })
+}
+
+/// Subscribes to the messages called `id`, without deserializing them.
+fn subscribe_raw(
+    id: String,
+    mut callback: impl FnMut(MessageContext, &[u8]) -> ResultEmpty + 'static,
+) -> Listener {
+    wit::message::subscribe(&id);
     Listener(
-        id.to_string(),
+        id.clone(),
         EXECUTOR.register_callback(
-            id.to_string(),
+            id.clone(),
             Box::new(move |source, listener_id, data| {
                 callback(
                     MessageContext {
                         source: source.clone().from_bindgen(),
-                        listener: Listener(id.to_string(), listener_id),
+                        listener: Listener(id.clone(), listener_id),
                     },
-                    T::deserialize_message(data)?,
+                    data,
                 )
-                .into_result()?;
-                Ok(())
             }),
         ),
     )
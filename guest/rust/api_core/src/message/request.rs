@@ -0,0 +1,178 @@
+use std::{
+    cell::RefCell, fmt::Display, future::Future, pin::Pin, rc::Rc, task::Poll, time::Duration,
+};
+
+use rand::random;
+use thiserror::Error;
+
+use super::{
+    deserialize_response, send_raw, serialize_request, serialize_response, split_call_id,
+    subscribe_raw, Listener, MessageContext, ModuleMessage, Source, Target,
+};
+use crate::timer::{self, Timer};
+
+/// How long [`Request::call`] waits for a response before giving up.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Why a [`Request`] did not get a response.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RequestError {
+    /// No response arrived in time. This is also what happens when nothing serves the request.
+    #[error("the request timed out")]
+    Timeout,
+    /// The package that served the request returned an error.
+    #[error("the request failed: {0}")]
+    Failed(String),
+    /// The response could not be deserialized, which usually means that the two packages
+    /// disagree on the definition of the request.
+    #[error("the response could not be deserialized")]
+    InvalidResponse,
+}
+
+/// The response to a [`Request`], which resolves once it arrives or the request times out.
+pub type ResponseFuture<T> = Pin<Box<dyn Future<Output = Result<T, RequestError>>>>;
+
+/// Implemented by all messages that are requests, which are declared with a `response` in `ambient.toml`.
+///
+/// Requests are served by a single package with [`Request::serve`], and can be called from the same
+/// side or from the other side of the network.
+pub trait Request: ModuleMessage + 'static {
+    /// The message that is sent back in response to this request.
+    type Response: ModuleMessage + 'static;
+
+    /// Sends this request to `target`, and waits up to [`DEFAULT_REQUEST_TIMEOUT`] of game time
+    /// for the response. Wrapper around [self::call].
+    ///
+    /// The response is sent back from the first package that serves the request, so `target`
+    /// should usually be a single package.
+    fn call(&self, target: Target) -> ResponseFuture<Self::Response> {
+        Box::pin(self::call(target, self, DEFAULT_REQUEST_TIMEOUT))
+    }
+
+    /// Sends this request to `target`, and waits up to `timeout` of game time for the response.
+    /// Wrapper around [self::call].
+    fn call_with_timeout(
+        &self,
+        target: Target,
+        timeout: Duration,
+    ) -> ResponseFuture<Self::Response> {
+        Box::pin(self::call(target, self, timeout))
+    }
+
+    /// Serves this request. Wrapper around [self::serve].
+    fn serve<E: Display>(
+        handler: impl FnMut(MessageContext, Self) -> Result<Self::Response, E> + 'static,
+    ) -> Listener {
+        self::serve(handler)
+    }
+}
+
+fn request_id<T: Request>() -> String {
+    format!("{}::request", T::id())
+}
+
+/// Sends `request` to `target`, and waits up to `timeout` of game time for the response.
+///
+/// The request is sent immediately, and the response is only received while the returned future is alive.
+pub fn call<T: Request>(
+    target: Target,
+    request: &T,
+    timeout: Duration,
+) -> impl Future<Output = Result<T::Response, RequestError>> {
+    let call_id = random::<u128>();
+    let result = Rc::new(RefCell::new(None));
+
+    let response_listener = subscribe_raw(T::Response::id().to_string(), {
+        let result = result.clone();
+        move |_ctx, data| {
+            let (id, data) = split_call_id(data)?;
+            if id == call_id {
+                *result.borrow_mut() = Some(match deserialize_response(data) {
+                    Ok(response) => response.map_err(RequestError::Failed),
+                    Err(_) => Err(RequestError::InvalidResponse),
+                });
+            }
+            Ok(())
+        }
+    });
+    let timer = timer::schedule(timeout, None);
+    let timeout_listener = timer.on_expired({
+        let result = result.clone();
+        move || {
+            result
+                .borrow_mut()
+                .get_or_insert(Err(RequestError::Timeout));
+        }
+    });
+    let mut guard = Some(CallGuard {
+        response_listener,
+        timeout_listener,
+        timer,
+    });
+
+    send_raw(
+        target,
+        &request_id::<T>(),
+        &serialize_request(call_id, request).unwrap(),
+    );
+
+    std::future::poll_fn(move |_cx| match result.borrow_mut().take() {
+        Some(r) => {
+            guard.take();
+            Poll::Ready(r)
+        }
+        None => Poll::Pending,
+    })
+}
+
+/// Stops waiting for the response to a call when it is dropped, which happens once the response
+/// arrives or the future of the call is dropped.
+struct CallGuard {
+    response_listener: Listener,
+    timeout_listener: Listener,
+    timer: Timer,
+}
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        self.response_listener.stop();
+        self.timeout_listener.stop();
+        self.timer.cancel();
+    }
+}
+
+/// Serves requests of type `T` by calling `handler`, and sends the response back to where the
+/// request came from. Errors returned by `handler` are passed on to the caller as [`RequestError::Failed`].
+///
+/// To stop serving the request, call [Listener::stop] on the returned [Listener],
+/// or on the [MessageContext] that is passed to the handler.
+pub fn serve<T: Request, E: Display>(
+    mut handler: impl FnMut(MessageContext, T) -> Result<T::Response, E> + 'static,
+) -> Listener {
+    subscribe_raw(request_id::<T>(), move |ctx, data| {
+        let (call_id, data) = split_call_id(data)?;
+        let Some(target) = reply_target(&ctx.source) else {
+            return Ok(());
+        };
+
+        let response = match T::deserialize_message(data) {
+            Ok(request) => handler(ctx, request).map_err(|err| err.to_string()),
+            Err(err) => Err(format!("the request could not be deserialized: {err}")),
+        };
+
+        let output = serialize_response(call_id, response.as_ref().map_err(String::as_str))?;
+        send_raw(target, T::Response::id(), &output);
+        Ok(())
+    })
+}
+
+/// Where to send the response to a request from `source`.
+fn reply_target(source: &Source) -> Option<Target> {
+    match source {
+        Source::Runtime => None,
+        #[cfg(feature = "client")]
+        Source::Server => Some(Target::ServerReliable),
+        #[cfg(feature = "server")]
+        Source::Client { user_id } => Some(Target::ClientTargetedReliable(user_id.clone())),
+        Source::Local(id) => Some(Target::Local(*id)),
+    }
+}
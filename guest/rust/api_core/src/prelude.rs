@@ -2,13 +2,13 @@ pub use crate::{
     asset, camera,
     ecs::{
         change_query, despawn_query, query, spawn_query, Component, ComponentsTuple, Concept,
-        ConceptComponents, ConceptQuery, ConceptSuggested, Entity, QueryEvent,
+        ConceptComponents, ConceptQuery, ConceptSuggested, Entity, QueryEvent, Relation,
     },
     entity,
     global::*,
     main, message,
-    message::{Message, ModuleMessage, RuntimeMessage},
-    player,
+    message::{Message, ModuleMessage, Request, RuntimeMessage},
+    player, timer,
 };
 pub use anyhow::{anyhow, Context as AnyhowContext};
 pub use rand::prelude::*;
@@ -20,4 +20,4 @@ pub use crate::client::{
 };
 
 #[cfg(feature = "server")]
-pub use crate::server::{http, physics};
+pub use crate::server::{http, physics, spatial_audio};
@@ -1,5 +1,15 @@
 /// **\[Server-only\]** Physics-related functionality, including applying forces, changing physical properties, and more.
 pub mod physics;
 
+/// **\[Server-only\]** Spatial sounds, which the server plays from entities on every client.
+pub mod spatial_audio;
+
 /// **\[Server-only\]** HTTP-related functionality, including sending requests and receiving responses.
 pub mod http;
+
+/// **\[Server-only\]** Key-value storage that persists across server restarts, such as high scores or player progress.
+///
+/// Each package has its own storage, which is divided into namespaces of keys and limited to 16 MiB of keys and values.
+///
+/// Storage is not available on hosted servers, where using it will stop the module.
+pub mod storage;
@@ -1,5 +1,5 @@
 use crate::{
-    global::{EntityId, Vec3},
+    global::{EntityId, Quat, Vec3},
     internal::{
         conversion::{FromBindgen, IntoBindgen},
         wit,
@@ -182,6 +182,135 @@ fn raycast_result_to_hit(
     }
 }
 
+/// The type of a collider, used to filter the results of [sweep] and [overlap].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColliderType {
+    /// Colliders that cannot move (e.g. a wall).
+    Static,
+    /// Colliders that can move dynamically in the scene (e.g. a physics object).
+    Dynamic,
+    /// Colliders that are only present in the trigger-area scene.
+    TriggerArea,
+    /// Colliders that are only present in the picking scene.
+    Picking,
+}
+impl IntoBindgen for ColliderType {
+    type Item = wit::server_physics::ColliderType;
+    fn into_bindgen(self) -> Self::Item {
+        match self {
+            ColliderType::Static => Self::Item::Static,
+            ColliderType::Dynamic => Self::Item::Dynamic,
+            ColliderType::TriggerArea => Self::Item::TriggerArea,
+            ColliderType::Picking => Self::Item::Picking,
+        }
+    }
+}
+
+/// A shape that can be used with [sweep] and [overlap].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QueryShape {
+    /// A sphere with the given radius.
+    Sphere {
+        /// The radius of the sphere.
+        radius: f32,
+    },
+    /// A cuboid with the given half-extents.
+    Cuboid {
+        /// Half of the size of the cuboid along each axis.
+        half_extents: Vec3,
+    },
+    /// A capsule along the Z axis.
+    Capsule {
+        /// The radius of the capsule.
+        radius: f32,
+        /// Half of the height of the capsule, not including the hemispheres at the ends.
+        half_height: f32,
+    },
+}
+impl IntoBindgen for QueryShape {
+    type Item = wit::server_physics::QueryShape;
+    fn into_bindgen(self) -> Self::Item {
+        match self {
+            QueryShape::Sphere { radius } => Self::Item::Sphere(radius),
+            QueryShape::Cuboid { half_extents } => Self::Item::Cuboid(half_extents.into_bindgen()),
+            QueryShape::Capsule {
+                radius,
+                half_height,
+            } => Self::Item::Capsule(wit::server_physics::CapsuleShape {
+                radius,
+                half_height,
+            }),
+        }
+    }
+}
+
+/// Where a [sweep] hit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SweepHit {
+    /// The entity that was hit.
+    pub entity: EntityId,
+    /// The position of the hit.
+    pub position: Vec3,
+    /// The normal of the surface that was hit.
+    pub normal: Vec3,
+    /// The distance the shape travelled before the hit.
+    pub distance: f32,
+}
+impl FromBindgen for wit::server_physics::SweepHit {
+    type Item = SweepHit;
+    fn from_bindgen(self) -> Self::Item {
+        SweepHit {
+            entity: self.entity.from_bindgen(),
+            position: self.position.from_bindgen(),
+            normal: self.normal.from_bindgen(),
+            distance: self.distance,
+        }
+    }
+}
+
+/// Sweeps `shape`, rotated by `rotation`, from `origin` in `direction` for up to `max_distance`,
+/// and returns the [SweepHit]s along the way, sorted by distance.
+///
+/// `direction` must be normalized, and the dimensions of `shape` must be positive and finite.
+/// If `filter` is specified, only colliders of that type will be hit.
+pub fn sweep(
+    shape: QueryShape,
+    origin: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    filter: Option<ColliderType>,
+) -> Vec<SweepHit> {
+    wit::server_physics::sweep(
+        shape.into_bindgen(),
+        origin.into_bindgen(),
+        rotation.into_bindgen(),
+        direction.into_bindgen(),
+        max_distance,
+        filter.map(|f| f.into_bindgen()),
+    )
+    .from_bindgen()
+}
+
+/// Returns the entities whose colliders overlap `shape` placed at `position` with `rotation`.
+///
+/// The dimensions of `shape` must be positive and finite. If `filter` is specified, only colliders
+/// of that type will be considered.
+pub fn overlap(
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    filter: Option<ColliderType>,
+) -> Vec<EntityId> {
+    wit::server_physics::overlap(
+        shape.into_bindgen(),
+        position.into_bindgen(),
+        rotation.into_bindgen(),
+        filter.map(|f| f.into_bindgen()),
+    )
+    .from_bindgen()
+}
+
 /// Collision results when using [move_character].
 pub struct CharacterCollision {
     /// Side
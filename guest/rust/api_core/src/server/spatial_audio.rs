@@ -0,0 +1,68 @@
+use crate::{
+    core::audio::components::{
+        spatial_sound, spatial_sound_amplitude, spatial_sound_looping, spatial_sound_start_time,
+    },
+    entity,
+    prelude::{epoch_time, EntityId},
+};
+
+/// How a [`SpatialSound`] is played
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialSoundOptions {
+    /// Restart the sound when it reaches the end
+    pub looping: bool,
+    /// The amp/volume of the sound. 0.0 is 0%, 1.0 is 100%
+    pub amplitude: f32,
+}
+impl Default for SpatialSoundOptions {
+    fn default() -> Self {
+        Self {
+            looping: false,
+            amplitude: 1.0,
+        }
+    }
+}
+
+/// A sound which every client plays from an entity, as heard by their camera.
+///
+/// The server decides which of these sounds are playing: clients which connect while the sound
+/// is playing start it from the same position as everyone else, and stopping it stops it for
+/// every client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpatialSound {
+    /// The entity the sound is played from. It can only play one spatial sound at a time.
+    pub entity: EntityId,
+}
+impl SpatialSound {
+    /// Start playing the audio file at the given URL from `entity`, replacing the spatial sound
+    /// it was already playing.
+    pub fn play(url: impl Into<String>, entity: EntityId, options: SpatialSoundOptions) -> Self {
+        entity::add_component(entity, spatial_sound(), url.into());
+        entity::add_component(entity, spatial_sound_start_time(), epoch_time());
+        entity::add_component(entity, spatial_sound_amplitude(), options.amplitude);
+        if options.looping {
+            entity::add_component(entity, spatial_sound_looping(), ());
+        } else {
+            entity::remove_component(entity, spatial_sound_looping());
+        }
+        Self { entity }
+    }
+
+    /// Stop the sound on every client
+    pub fn stop(&self) {
+        entity::remove_components(
+            self.entity,
+            &[
+                &spatial_sound(),
+                &spatial_sound_start_time(),
+                &spatial_sound_amplitude(),
+                &spatial_sound_looping(),
+            ],
+        );
+    }
+
+    /// Change the amp/volume of the sound while it plays
+    pub fn set_amplitude(&self, amplitude: f32) {
+        entity::add_component(self.entity, spatial_sound_amplitude(), amplitude);
+    }
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+use crate::internal::wit;
+
+#[derive(Error, Debug, Clone)]
+#[error("Storage error: {0}")]
+/// Errors that can occur when writing to storage, such as exceeding the storage quota of the package.
+pub struct StorageError(pub String);
+
+/// Gets the value of `key` in `namespace`, if it has been set.
+pub fn get(namespace: &str, key: &str) -> Option<Vec<u8>> {
+    wit::server_storage::get(namespace, key)
+}
+
+/// Sets the value of `key` in `namespace`, replacing its previous value.
+///
+/// Fails without changing anything if the storage of this package would grow beyond its quota.
+pub fn set(namespace: &str, key: &str, value: &[u8]) -> Result<(), StorageError> {
+    wit::server_storage::set(namespace, key, value).map_err(StorageError)
+}
+
+/// Removes `key` from `namespace`, and returns whether it was set.
+pub fn delete(namespace: &str, key: &str) -> bool {
+    wit::server_storage::delete(namespace, key)
+}
+
+/// Lists the keys that are set in `namespace`, in order.
+pub fn list(namespace: &str) -> Vec<String> {
+    wit::server_storage::list(namespace)
+}
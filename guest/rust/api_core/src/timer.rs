@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use crate::{
+    core::messages::TimerExpired,
+    global::{wait_for_runtime_message, CallbackReturn},
+    internal::{conversion::IntoBindgen, wit},
+    message::{Listener, RuntimeMessage},
+};
+
+/// A timer that sends a [`TimerExpired`] message to this module when it expires.
+///
+/// Timers are kept by the host, so they don't need to be polled every frame. Only named timers keep
+/// running when the module is reloaded; use [`schedule_named`] to get a timer back after a reload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timer {
+    /// The handle of the timer, which is sent in [`TimerExpired`] messages.
+    pub id: u64,
+}
+impl Timer {
+    /// Stops the timer. Returns whether it was still scheduled.
+    pub fn cancel(self) -> bool {
+        wit::timer::cancel(self.id)
+    }
+
+    /// Calls `callback` every time the timer expires, until the returned [`Listener`] is stopped.
+    pub fn on_expired<R: CallbackReturn>(
+        self,
+        mut callback: impl FnMut() -> R + 'static,
+    ) -> Listener {
+        TimerExpired::subscribe(move |message| {
+            if message.timer == self.id {
+                callback().into_result()
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Waits until the timer next expires.
+    ///
+    /// This should be started in the same frame the timer was scheduled in, so that the
+    /// expiry message is not missed.
+    pub async fn expired(self) {
+        let id = self.id;
+        wait_for_runtime_message(move |message: &TimerExpired| message.timer == id).await;
+    }
+}
+
+/// Schedules a timer that expires after `after` of game time, and then every `repeat` if it is set.
+pub fn schedule(after: Duration, repeat: Option<Duration>) -> Timer {
+    Timer {
+        id: wit::timer::schedule(None, after.into_bindgen(), repeat.into_bindgen()),
+    }
+}
+
+/// Schedules a timer like [`schedule`], unless this module already has a timer called `name`.
+///
+/// In that case, the existing timer is returned. It keeps its expiry time, but repeats every
+/// `repeat` from then on. This lets modules pick their timers back up after they are reloaded.
+pub fn schedule_named(name: &str, after: Duration, repeat: Option<Duration>) -> Timer {
+    Timer {
+        id: wit::timer::schedule(Some(name), after.into_bindgen(), repeat.into_bindgen()),
+    }
+}
@@ -1,12 +1,26 @@
 use ambient_api::{
-    element::{use_frame, use_ref_with, use_rerender_signal},
+    core::wasm::components::{
+        is_module_on_server, module_execution_time, module_host_call_counts, module_name,
+        module_profiling_interval,
+    },
+    element::{use_frame, use_ref_with, use_rerender_signal, use_state_with},
     prelude::*,
 };
 
+/// The number of modules shown, starting with the one that took the most time
+const MODULES_SHOWN: usize = 5;
+/// How often the module times are updated
+const PROFILING_INTERVAL: Duration = Duration::from_secs(1);
+
 pub mod packages;
 
 #[main]
 pub fn main() {
+    entity::add_component(
+        entity::resources(),
+        module_profiling_interval(),
+        PROFILING_INTERVAL,
+    );
     PerformanceBar.el().spawn_interactive();
 }
 
@@ -30,5 +44,31 @@ fn PerformanceBar(hooks: &mut Hooks) -> Element {
         let fps = frame_times.len() as f32 / frame_times.iter().sum::<f32>();
         fps
     };
-    Text::el(format!("Fps: {fps}"))
+    let (modules, _) = use_state_with(hooks, |_| {
+        query((module_name(), module_execution_time())).build()
+    });
+    let mut modules = modules.evaluate();
+    modules.sort_by_key(|(_, (_, time))| std::cmp::Reverse(*time));
+
+    FlowColumn::el(
+        std::iter::once(Text::el(format!("Fps: {fps}"))).chain(
+            modules
+                .into_iter()
+                .take(MODULES_SHOWN)
+                .map(|(id, (name, time))| {
+                    let side = if entity::has_component(id, is_module_on_server()) {
+                        "server"
+                    } else {
+                        "client"
+                    };
+                    let host_calls: u32 = entity::get_component(id, module_host_call_counts())
+                        .map(|counts| counts.values().sum())
+                        .unwrap_or_default();
+                    Text::el(format!(
+                        "{name} ({side}): {:.2} ms, {host_calls} host calls",
+                        time.as_secs_f64() * 1000.
+                    ))
+                }),
+        ),
+    )
 }
@@ -0,0 +1,15 @@
+use ambient_api::{core::wasm::components::module_profiling_interval, prelude::*};
+
+/// How often the module times are updated
+const PROFILING_INTERVAL: Duration = Duration::from_secs(1);
+
+pub mod packages;
+
+#[main]
+pub fn main() {
+    entity::add_component(
+        entity::resources(),
+        module_profiling_interval(),
+        PROFILING_INTERVAL,
+    );
+}
@@ -35,7 +35,7 @@ pub trait PxActor {
     fn get_world_bounds(&self, inflation: f32) -> (Vec3, Vec3);
     fn set_actor_flag(&self, flag: PxActorFlag, value: bool);
     fn set_actor_flags(&self, flags: PxActorFlag);
-    fn get_name(&self) -> String;
+    fn get_name(&self) -> Option<String>;
     fn set_name(&self, name: &CString);
 }
 impl<T: AsPxActor + 'static> PxActor for T {
@@ -67,10 +67,14 @@ impl<T: AsPxActor + 'static> PxActor for T {
         let bounds = unsafe { physx_sys::PxActor_getWorldBounds(self.as_actor().0, inflation) };
         (to_glam_vec3(&bounds.minimum), to_glam_vec3(&bounds.maximum))
     }
-    fn get_name(&self) -> String {
+    fn get_name(&self) -> Option<String> {
         unsafe {
             let p = physx_sys::PxActor_getName(self.as_actor().0) as *const std::ffi::c_char;
-            CStr::from_ptr(p).to_str().unwrap().to_string()
+            if p.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(p).to_str().unwrap().to_string())
+            }
         }
     }
     /// Note; physx doesn't copy the string, so the string needs to be kept alive somewhere else
@@ -228,6 +232,7 @@ bitflags! {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u32)]
 pub enum PxForceMode {
     Acceleration = physx_sys::PxForceMode::eACCELERATION,
@@ -454,6 +459,15 @@ impl PxRigidDynamicRef {
             physx_sys::PxRigidDynamic_setKinematicTarget_mut(self.0, &destination.0);
         }
     }
+    /// Returns the target set with [`Self::set_kinematic_target`], if one is pending for the next simulation step
+    pub fn get_kinematic_target(&self) -> Option<PxTransform> {
+        let mut target = PxTransform::identity();
+        if unsafe { physx_sys::PxRigidDynamic_getKinematicTarget(self.0, &mut target.0) } {
+            Some(target)
+        } else {
+            None
+        }
+    }
 }
 impl AsPxBase for PxRigidDynamicRef {
     fn as_base(&self) -> PxBaseRef {
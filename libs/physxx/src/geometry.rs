@@ -248,6 +248,16 @@ impl PxGeometryHolder {
             )))
         }
     }
+    pub fn as_capsule(&self) -> Option<PxCapsuleGeometry> {
+        if self.get_type() != PxGeometryType::Capsule {
+            return None;
+        }
+        unsafe {
+            Some(PxCapsuleGeometry(*physx_sys::PxGeometryHolder_capsule(
+                &self.0,
+            )))
+        }
+    }
     pub fn as_convex_mesh(&self) -> Option<PxConvexMeshGeometry> {
         if self.get_type() != PxGeometryType::ConvexMesh {
             return None;
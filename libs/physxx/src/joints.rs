@@ -4,7 +4,8 @@ use glam::Vec3;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    to_glam_vec3, AsPxBase, PxBaseRef, PxPhysicsRef, PxRigidActorRef, PxTransform, PxUserData,
+    to_glam_vec3, to_physx_vec3, AsPxBase, PxBaseRef, PxPhysicsRef, PxRigidActorRef, PxTransform,
+    PxUserData,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -241,6 +242,9 @@ impl PxRevoluteJointRef {
     pub fn set_drive_velocity(&self, velocity: f32, autoawake: bool) {
         unsafe { physx_sys::PxRevoluteJoint_setDriveVelocity_mut(self.0, velocity, autoawake) }
     }
+    pub fn set_drive_force_limit(&self, limit: f32) {
+        unsafe { physx_sys::PxRevoluteJoint_setDriveForceLimit_mut(self.0, limit) }
+    }
     pub fn get_revolute_flags(&self) -> PxRevoluteJointFlag {
         PxRevoluteJointFlag::from_bits(
             unsafe { physx_sys::PxRevoluteJoint_getRevoluteJointFlags(self.0) }.mBits as u32,
@@ -282,6 +286,273 @@ impl AsPxJoint for PxRevoluteJointRef {
 unsafe impl Sync for PxRevoluteJointRef {}
 unsafe impl Send for PxRevoluteJointRef {}
 
+bitflags! {
+    #[derive(Serialize, Deserialize)]
+    pub struct PxSphericalJointFlag: u32 {
+        const LIMIT_ENABLED = physx_sys::PxSphericalJointFlag::eLIMIT_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxSphericalJointRef(pub(crate) *mut physx_sys::PxSphericalJoint);
+impl PxSphericalJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxSphericalJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn set_spherical_flag(&self, flag: PxSphericalJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxSphericalJoint_setSphericalJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+    pub fn get_limit_cone(&self) -> PxJointLimitCone {
+        PxJointLimitCone::from_physx(unsafe { physx_sys::PxSphericalJoint_getLimitCone(self.0) })
+    }
+    pub fn set_limit_cone(&self, limit: &PxJointLimitCone) {
+        unsafe { physx_sys::PxSphericalJoint_setLimitCone_mut(self.0, &limit.to_physx() as _) }
+    }
+}
+impl AsPxBase for PxSphericalJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxSphericalJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxSphericalJointRef {}
+unsafe impl Send for PxSphericalJointRef {}
+
+bitflags! {
+    #[derive(Serialize, Deserialize)]
+    pub struct PxPrismaticJointFlag: u32 {
+        const LIMIT_ENABLED = physx_sys::PxPrismaticJointFlag::eLIMIT_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxPrismaticJointRef(pub(crate) *mut physx_sys::PxPrismaticJoint);
+impl PxPrismaticJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxPrismaticJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn get_position(&self) -> f32 {
+        unsafe { physx_sys::PxPrismaticJoint_getPosition(self.0) }
+    }
+    pub fn set_prismatic_flag(&self, flag: PxPrismaticJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxPrismaticJoint_setPrismaticJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+    pub fn get_limit(&self) -> PxJointLinearLimitPair {
+        PxJointLinearLimitPair::from_physx(unsafe { physx_sys::PxPrismaticJoint_getLimit(self.0) })
+    }
+    pub fn set_limit(&self, limits: &PxJointLinearLimitPair) {
+        unsafe { physx_sys::PxPrismaticJoint_setLimit_mut(self.0, &limits.to_physx() as _) }
+    }
+}
+impl AsPxBase for PxPrismaticJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxPrismaticJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxPrismaticJointRef {}
+unsafe impl Send for PxPrismaticJointRef {}
+
+bitflags! {
+    #[derive(Serialize, Deserialize)]
+    pub struct PxDistanceJointFlag: u32 {
+        const MAX_DISTANCE_ENABLED = physx_sys::PxDistanceJointFlag::eMAX_DISTANCE_ENABLED;
+        const MIN_DISTANCE_ENABLED = physx_sys::PxDistanceJointFlag::eMIN_DISTANCE_ENABLED;
+        const SPRING_ENABLED = physx_sys::PxDistanceJointFlag::eSPRING_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxDistanceJointRef(pub(crate) *mut physx_sys::PxDistanceJoint);
+impl PxDistanceJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxDistanceJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn get_distance(&self) -> f32 {
+        unsafe { physx_sys::PxDistanceJoint_getDistance(self.0) }
+    }
+    pub fn set_min_distance(&self, distance: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setMinDistance_mut(self.0, distance) }
+    }
+    pub fn set_max_distance(&self, distance: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setMaxDistance_mut(self.0, distance) }
+    }
+    pub fn set_stiffness(&self, stiffness: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setStiffness_mut(self.0, stiffness) }
+    }
+    pub fn set_damping(&self, damping: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setDamping_mut(self.0, damping) }
+    }
+    pub fn set_distance_flag(&self, flag: PxDistanceJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxDistanceJoint_setDistanceJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+}
+impl AsPxBase for PxDistanceJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxDistanceJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxDistanceJointRef {}
+unsafe impl Send for PxDistanceJointRef {}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PxD6Axis {
+    X = physx_sys::PxD6Axis::eX,
+    Y = physx_sys::PxD6Axis::eY,
+    Z = physx_sys::PxD6Axis::eZ,
+    Twist = physx_sys::PxD6Axis::eTWIST,
+    Swing1 = physx_sys::PxD6Axis::eSWING1,
+    Swing2 = physx_sys::PxD6Axis::eSWING2,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PxD6Motion {
+    Locked = physx_sys::PxD6Motion::eLOCKED,
+    Limited = physx_sys::PxD6Motion::eLIMITED,
+    Free = physx_sys::PxD6Motion::eFREE,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PxD6Drive {
+    X = physx_sys::PxD6Drive::eX,
+    Y = physx_sys::PxD6Drive::eY,
+    Z = physx_sys::PxD6Drive::eZ,
+    Swing = physx_sys::PxD6Drive::eSWING,
+    Twist = physx_sys::PxD6Drive::eTWIST,
+    Slerp = physx_sys::PxD6Drive::eSLERP,
+}
+
+/// A configurable joint, where each axis can be locked, limited or free, and driven
+#[derive(Debug, Clone, Copy)]
+pub struct PxD6JointRef(pub(crate) *mut physx_sys::PxD6Joint);
+impl PxD6JointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxD6JointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn set_motion(&self, axis: PxD6Axis, motion: PxD6Motion) {
+        unsafe { physx_sys::PxD6Joint_setMotion_mut(self.0, axis as u32, motion as u32) }
+    }
+    pub fn set_linear_limit(&self, axis: PxD6Axis, limit: &PxJointLinearLimitPair) {
+        unsafe {
+            physx_sys::PxD6Joint_setLinearLimit_mut(self.0, axis as u32, &limit.to_physx() as _)
+        }
+    }
+    pub fn set_twist_limit(&self, limit: &PxJointAngularLimitPair) {
+        unsafe { physx_sys::PxD6Joint_setTwistLimit_mut(self.0, &limit.to_physx() as _) }
+    }
+    pub fn set_swing_limit(&self, limit: &PxJointLimitCone) {
+        unsafe { physx_sys::PxD6Joint_setSwingLimit_mut(self.0, &limit.to_physx() as _) }
+    }
+    /// The force limit is an impulse unless `PxConstraintFlags::DRIVE_LIMITS_ARE_FORCES` is set
+    pub fn set_drive(&self, drive: PxD6Drive, stiffness: f32, damping: f32, force_limit: f32) {
+        unsafe {
+            let drive_data =
+                physx_sys::PxD6JointDrive_new_1(stiffness, damping, force_limit, false);
+            physx_sys::PxD6Joint_setDrive_mut(self.0, drive as u32, &drive_data as _)
+        }
+    }
+    pub fn set_drive_velocity(&self, linear: Vec3, angular: Vec3, autowake: bool) {
+        unsafe {
+            physx_sys::PxD6Joint_setDriveVelocity_mut(
+                self.0,
+                &to_physx_vec3(linear),
+                &to_physx_vec3(angular),
+                autowake,
+            )
+        }
+    }
+}
+impl AsPxBase for PxD6JointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxD6JointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxD6JointRef {}
+unsafe impl Send for PxD6JointRef {}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PxJointAngularLimitPair {
     pub restitution: f32,
@@ -321,3 +592,90 @@ impl PxJointAngularLimitPair {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PxJointLinearLimitPair {
+    pub restitution: f32,
+    pub bounce_threshold: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub contact_distance: f32,
+    pub upper: f32,
+    pub lower: f32,
+}
+impl PxJointLinearLimitPair {
+    /// A hard limit; `contact_distance` is set to a fraction of the range, like PhysX does by default
+    pub fn new(lower_limit: f32, upper_limit: f32) -> Self {
+        Self {
+            restitution: 0.,
+            bounce_threshold: 0.,
+            stiffness: 0.,
+            damping: 0.,
+            contact_distance: ((upper_limit - lower_limit) * 0.49).min(0.01),
+            upper: upper_limit,
+            lower: lower_limit,
+        }
+    }
+    fn from_physx(limit: physx_sys::PxJointLinearLimitPair) -> Self {
+        Self {
+            restitution: limit.restitution,
+            bounce_threshold: limit.bounceThreshold,
+            stiffness: limit.stiffness,
+            damping: limit.damping,
+            contact_distance: limit.contactDistance,
+            upper: limit.upper,
+            lower: limit.lower,
+        }
+    }
+    fn to_physx(&self) -> physx_sys::PxJointLinearLimitPair {
+        physx_sys::PxJointLinearLimitPair {
+            restitution: self.restitution,
+            bounceThreshold: self.bounce_threshold,
+            stiffness: self.stiffness,
+            damping: self.damping,
+            contactDistance: self.contact_distance,
+            upper: self.upper,
+            lower: self.lower,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PxJointLimitCone {
+    pub restitution: f32,
+    pub bounce_threshold: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub contact_distance: f32,
+    pub y_angle: f32,
+    pub z_angle: f32,
+}
+impl PxJointLimitCone {
+    pub fn new(y_limit_angle: f32, z_limit_angle: f32, contact_dist: f32) -> Self {
+        Self::from_physx(unsafe {
+            physx_sys::PxJointLimitCone_new(y_limit_angle, z_limit_angle, contact_dist)
+        })
+    }
+    fn from_physx(limit: physx_sys::PxJointLimitCone) -> Self {
+        Self {
+            restitution: limit.restitution,
+            bounce_threshold: limit.bounceThreshold,
+            stiffness: limit.stiffness,
+            damping: limit.damping,
+            contact_distance: limit.contactDistance,
+            y_angle: limit.yAngle,
+            z_angle: limit.zAngle,
+        }
+    }
+    fn to_physx(&self) -> physx_sys::PxJointLimitCone {
+        physx_sys::PxJointLimitCone {
+            restitution: self.restitution,
+            bounceThreshold: self.bounce_threshold,
+            stiffness: self.stiffness,
+            damping: self.damping,
+            contactDistance: self.contact_distance,
+            yAngle: self.y_angle,
+            zAngle: self.z_angle,
+        }
+    }
+}
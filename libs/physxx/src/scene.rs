@@ -20,6 +20,7 @@ use crate::{
 
 pub fn extract_contact_points(
     iter: &physx_sys::PxContactStreamIterator,
+    impulses: Option<&[f32]>,
 ) -> Result<Vec<PxContactPoint>, &'static str> {
     if iter.contact.is_null() || iter.patch.is_null() {
         return Err("Null pointer detected in PxContactStreamIterator");
@@ -37,12 +38,17 @@ pub fn extract_contact_points(
 
         for patch in patches {
             for _ in 0..patch.nbContacts {
-                let contact = contacts[local_iter.nextContactIndex as usize];
+                let index = local_iter.nextContactIndex as usize;
+                let contact = contacts[index];
+                let normal = to_glam_vec3(&patch.normal);
 
                 contact_points.push(PxContactPoint {
                     position: to_glam_vec3(&contact.contact),
                     separation: contact.separation,
-                    normal: to_glam_vec3(&patch.normal),
+                    normal,
+                    impulse: impulses
+                        .and_then(|impulses| impulses.get(index))
+                        .map_or(Vec3::ZERO, |impulse| normal * *impulse),
                 });
 
                 local_iter.nextContactIndex += 1;
@@ -90,44 +96,49 @@ impl PxSceneDesc {
             );
         }
     }
-    pub fn set_simulation_event_callbacks<C: FnMut(&PxContactPairHeader, Vec<PxContactPoint>)>(
+    pub fn set_simulation_event_callbacks<C: FnMut(&PxContactPairHeader, &[PxContactPair<'_>])>(
         &mut self,
         callbacks: PxSimulationEventCallback<C>,
     ) {
         unsafe {
             unsafe extern "C" fn collision_callback_trampoline<
-                C: FnMut(&PxContactPairHeader, Vec<PxContactPoint>),
+                C: FnMut(&PxContactPairHeader, &[PxContactPair<'_>]),
             >(
                 user_data: *mut std::ffi::c_void,
                 pair_header: *const physx_sys::PxContactPairHeader,
                 pairs: *const physx_sys::PxContactPair,
-                _nb_pairs: u32,
+                nb_pairs: u32,
             ) {
                 let mut cb: Box<C> = Box::from_raw(user_data as _);
                 let pair_header_flags =
                     PxContactPairHeaderFlag::from_bits((*pair_header).flags.mBits).unwrap();
-                let mut contact_points_vec = Vec::new();
-
-                // Check for a valid contact stream
-                if let Some(pair) = pairs.as_ref() {
-                    // Create the PxContactStreamIterator from the contact pair data
-                    let contact_stream_iterator = unsafe {
-                        physx_sys::PxContactStreamIterator_new(
-                            pair.contactPatches,
-                            pair.contactPoints,
-                            std::ptr::null(), // Assuming we don't have the contactFaceIndices, passing a null pointer.
-                            pair.patchCount as u32,
-                            pair.contactCount as u32,
-                        )
-                    };
-
-                    if let Ok(points) = extract_contact_points(&contact_stream_iterator) {
-                        contact_points_vec = points;
-                    } else {
-                        // Handle the error
-                        println!("Error extracting contact points");
-                    }
-                }
+
+                let pairs = if pairs.is_null() {
+                    &[]
+                } else {
+                    std::slice::from_raw_parts(pairs, nb_pairs as usize)
+                };
+                let pairs = pairs
+                    .iter()
+                    .map(|pair| {
+                        let flags = PxContactPairFlag::from_bits_truncate(pair.flags.mBits);
+
+                        // The shapes are only borrowed for the duration of the callback, so
+                        // no reference is acquired
+                        PxContactPair {
+                            shapes: [
+                                (!flags.contains(PxContactPairFlag::REMOVED_SHAPE_0)
+                                    && !pair.shapes[0].is_null())
+                                .then(|| PxShape(pair.shapes[0], 0)),
+                                (!flags.contains(PxContactPairFlag::REMOVED_SHAPE_1)
+                                    && !pair.shapes[1].is_null())
+                                .then(|| PxShape(pair.shapes[1], 0)),
+                            ],
+                            events: PxPairFlags::from_bits_truncate(pair.events.mBits),
+                            raw: pair,
+                        }
+                    })
+                    .collect::<Vec<_>>();
 
                 cb(
                     &PxContactPairHeader {
@@ -146,7 +157,7 @@ impl PxSceneDesc {
                             },
                         ],
                     },
-                    contact_points_vec,
+                    &pairs,
                 );
 
                 Box::into_raw(cb); // Convert the box back into a raw pointer.
@@ -180,16 +191,74 @@ pub struct PxContactPoint {
     pub position: glam::Vec3,
     pub normal: glam::Vec3,
     pub separation: f32,
+    /// The impulse applied by the solver at this point; zero if impulses were not reported
+    pub impulse: glam::Vec3,
 }
 
 pub struct PxContactPairHeader {
     pub actors: [Option<PxRigidActorRef>; 2],
 }
 
-pub struct PxSimulationEventCallback<C: FnMut(&PxContactPairHeader, Vec<PxContactPoint>)> {
+/// A pair of shapes in contact; there can be several of these for each [`PxContactPairHeader`].
+/// Only valid for the duration of the collision callback
+pub struct PxContactPair<'a> {
+    /// `None` if the shape was removed from the scene
+    pub shapes: [Option<PxShape>; 2],
+    /// The events that caused this pair to be reported, i.e. [`PxPairFlags::NOTIFY_TOUCH_FOUND`]
+    pub events: PxPairFlags,
+    raw: &'a physx_sys::PxContactPair,
+}
+impl PxContactPair<'_> {
+    /// Extracts the contact points of this pair, which is empty if the shapes lost touch or
+    /// [`PxPairFlags::NOTIFY_CONTACT_POINTS`] was not requested for the pair
+    pub fn contact_points(&self) -> Vec<PxContactPoint> {
+        let pair = self.raw;
+        if pair.contactCount == 0 {
+            return Vec::new();
+        }
+
+        let flags = PxContactPairFlag::from_bits_truncate(pair.flags.mBits);
+        unsafe {
+            let contact_stream_iterator = physx_sys::PxContactStreamIterator_new(
+                pair.contactPatches,
+                pair.contactPoints,
+                std::ptr::null(), // Assuming we don't have the contactFaceIndices, passing a null pointer.
+                pair.patchCount as u32,
+                pair.contactCount as u32,
+            );
+            let impulses = (flags.contains(PxContactPairFlag::HAS_IMPULSES)
+                && !pair.contactImpulses.is_null())
+            .then(|| std::slice::from_raw_parts(pair.contactImpulses, pair.contactCount as usize));
+
+            extract_contact_points(&contact_stream_iterator, impulses).unwrap_or_else(|err| {
+                tracing::warn!("Error extracting contact points: {err}");
+                Vec::new()
+            })
+        }
+    }
+}
+
+pub struct PxSimulationEventCallback<C: FnMut(&PxContactPairHeader, &[PxContactPair<'_>])> {
     pub collision_callback: Option<Box<C>>,
 }
 
+bitflags! {
+    pub struct PxPairFlags: u16 {
+        const NOTIFY_TOUCH_FOUND = physx_sys::PxPairFlag::eNOTIFY_TOUCH_FOUND as u16;
+        const NOTIFY_TOUCH_PERSISTS = physx_sys::PxPairFlag::eNOTIFY_TOUCH_PERSISTS as u16;
+        const NOTIFY_TOUCH_LOST = physx_sys::PxPairFlag::eNOTIFY_TOUCH_LOST as u16;
+        const NOTIFY_CONTACT_POINTS = physx_sys::PxPairFlag::eNOTIFY_CONTACT_POINTS as u16;
+    }
+}
+
+bitflags! {
+    pub struct PxContactPairFlag: u16 {
+        const REMOVED_SHAPE_0 = physx_sys::PxContactPairFlag::eREMOVED_SHAPE_0 as u16;
+        const REMOVED_SHAPE_1 = physx_sys::PxContactPairFlag::eREMOVED_SHAPE_1 as u16;
+        const HAS_IMPULSES = physx_sys::PxContactPairFlag::eINTERNAL_HAS_IMPULSES as u16;
+    }
+}
+
 bitflags! {
     pub struct PxContactPairHeaderFlag: u16 {
         const REMOVED_ACTOR_0 = physx_sys::PxContactPairHeaderFlag::eREMOVED_ACTOR_0 as u16;
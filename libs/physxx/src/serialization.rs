@@ -1,4 +1,9 @@
-use std::{ffi::CString, path::Path, ptr::null_mut};
+use std::{
+    alloc::{alloc, dealloc, Layout},
+    ffi::CString,
+    path::Path,
+    ptr::null_mut,
+};
 
 use crate::{PxAny, PxBaseRef, PxCookingRef, PxPhysicsRef};
 
@@ -93,6 +98,27 @@ impl PxSerializationRegistryRef {
             }
         }
     }
+    /// Deserializes a collection serialized with [`Self::serialize_collection_to_binary`].
+    ///
+    /// The objects are created in place, so `data` must outlive all objects in the collection.
+    pub fn create_collection_from_binary(
+        &self,
+        data: &PxBinaryCollectionData,
+        external_refs: Option<PxCollectionRef>,
+    ) -> Option<PxCollectionRef> {
+        unsafe {
+            let res = physx_sys::PxSerialization_createCollectionFromBinary_mut(
+                data.ptr as *mut std::ffi::c_void,
+                self.0,
+                external_refs.map_or(null_mut(), |x| x.0),
+            );
+            if res.is_null() {
+                None
+            } else {
+                Some(PxCollectionRef(res))
+            }
+        }
+    }
     pub fn release(self) {
         unsafe { physx_sys::PxSerializationRegistry_release_mut(self.0) }
     }
@@ -102,6 +128,31 @@ unsafe impl Send for PxSerializationRegistryRef {}
 
 pub type PxSerialObjectId = usize;
 
+/// Binary serialized data copied into a buffer with the alignment PhysX requires for deserialization
+pub struct PxBinaryCollectionData {
+    ptr: *mut u8,
+    layout: Layout,
+}
+impl PxBinaryCollectionData {
+    pub const ALIGNMENT: usize = 128;
+    pub fn new(data: &[u8]) -> Self {
+        let layout = Layout::from_size_align(data.len().max(1), Self::ALIGNMENT).unwrap();
+        unsafe {
+            let ptr = alloc(layout);
+            assert!(!ptr.is_null(), "Failed to allocate {} bytes", layout.size());
+            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len());
+            Self { ptr, layout }
+        }
+    }
+}
+impl Drop for PxBinaryCollectionData {
+    fn drop(&mut self) {
+        unsafe { dealloc(self.ptr, self.layout) }
+    }
+}
+unsafe impl Sync for PxBinaryCollectionData {}
+unsafe impl Send for PxBinaryCollectionData {}
+
 #[derive(Clone, Copy)]
 pub struct PxCollectionRef(pub(crate) *mut physx_sys::PxCollection);
 impl PxCollectionRef {
@@ -113,6 +113,18 @@ impl PxShape {
     pub fn set_rest_offset(&self, offset: f32) {
         unsafe { physx_sys::PxShape_setRestOffset_mut(self.0, offset) }
     }
+    /// The words of the filter data that are passed to the simulation filter shader
+    pub fn get_simulation_filter_data(&self) -> [u32; 4] {
+        let data = unsafe { physx_sys::PxShape_getSimulationFilterData(self.0) };
+        [data.word0, data.word1, data.word2, data.word3]
+    }
+    pub fn set_simulation_filter_data(&self, words: [u32; 4]) {
+        unsafe {
+            let mut data = physx_sys::PxShape_getSimulationFilterData(self.0);
+            [data.word0, data.word1, data.word2, data.word3] = words;
+            physx_sys::PxShape_setSimulationFilterData_mut(self.0, &data);
+        }
+    }
 }
 impl AsPxBase for PxShape {
     fn as_base(&self) -> PxBaseRef {
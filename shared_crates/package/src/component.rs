@@ -18,6 +18,8 @@ pub struct Component {
 pub enum ContainerType {
     Vec,
     Option,
+    /// A map from `key_type` to `element_type`. Keys must be `String` or `U32`.
+    Map,
 }
 
 #[derive(Deserialize, Clone, PartialEq, Eq, Serialize)]
@@ -29,6 +31,9 @@ pub enum ComponentType {
         #[serde(alias = "container_type")]
         type_: ContainerType,
         element_type: ItemPathBuf,
+        /// Only used by, and required for, [ContainerType::Map].
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        key_type: Option<ItemPathBuf>,
     },
 }
 impl std::fmt::Debug for ComponentType {
@@ -38,6 +43,12 @@ impl std::fmt::Debug for ComponentType {
             Self::Contained {
                 type_,
                 element_type,
+                key_type: Some(key_type),
+            } => write!(f, "{:?}<{}, {}>", type_, key_type, element_type),
+            Self::Contained {
+                type_,
+                element_type,
+                key_type: None,
             } => write!(f, "{:?}<{}>", type_, element_type),
         }
     }
@@ -9,6 +9,7 @@ use thiserror::Error;
 
 use crate::{
     Component, Concept, Enum, ItemPathBuf, Message, PascalCaseIdentifier, SnakeCaseIdentifier,
+    Struct,
 };
 
 #[derive(Error, Debug, PartialEq)]
@@ -37,11 +38,16 @@ pub struct Manifest {
     #[serde(alias = "enum")]
     pub enums: IndexMap<PascalCaseIdentifier, Enum>,
     #[serde(default)]
+    #[serde(alias = "struct")]
+    pub structs: IndexMap<PascalCaseIdentifier, Struct>,
+    #[serde(default)]
     pub includes: HashMap<SnakeCaseIdentifier, PathBuf>,
     #[serde(default)]
     pub dependencies: IndexMap<SnakeCaseIdentifier, Dependency>,
     #[serde(default)]
     pub hosting: Hosting,
+    #[serde(default)]
+    pub limits: Limits,
 }
 impl Manifest {
     pub fn parse(manifest: &str) -> Result<Self, ManifestParseError> {
@@ -278,6 +284,21 @@ pub struct Hosting {
     pub max_players: usize,
 }
 
+/// The resources each WASM module of the package may use before it is unloaded. Limits that are
+/// not set are not enforced.
+#[derive(Deserialize, Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Limits {
+    /// How long a single call into a module may run for, in milliseconds
+    #[serde(default)]
+    pub max_run_time_ms: Option<u64>,
+    /// How large the memory of a module may grow, in megabytes
+    #[serde(default)]
+    pub max_memory_mb: Option<u64>,
+    /// How many entities a module may spawn in a single frame
+    #[serde(default)]
+    pub max_spawned_entities_per_frame: Option<usize>,
+}
+
 #[derive(Deserialize, Clone, Debug, Default, PartialEq, Serialize)]
 pub enum Region {
     /// Automatically select the best region based on the player's location
@@ -297,8 +318,8 @@ mod tests {
 
     use crate::{
         Build, BuildRust, Component, ComponentType, Components, Concept, ConceptValue,
-        ContainerType, Dependency, Enum, Identifier, ItemPathBuf, Manifest, ManifestParseError,
-        Package, PackageId, PascalCaseIdentifier, SnakeCaseIdentifier,
+        ContainerType, Dependency, Enum, Identifier, ItemPathBuf, Limits, Manifest,
+        ManifestParseError, Package, PackageId, PascalCaseIdentifier, SnakeCaseIdentifier, Struct,
     };
     use semver::Version;
 
@@ -453,6 +474,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_parse_limits() {
+        const TOML: &str = r#"
+        [package]
+        id = "lktsfudbjw2qikhyumt573ozxhadkiwm"
+        name = "Limited"
+        version = "0.0.1"
+        content = { type = "Playable" }
+
+        [limits]
+        max_run_time_ms = 500
+        max_spawned_entities_per_frame = 100
+        "#;
+
+        assert_eq!(
+            Manifest::parse(TOML).unwrap().limits,
+            Limits {
+                max_run_time_ms: Some(500),
+                max_memory_mb: None,
+                max_spawned_entities_per_frame: Some(100),
+            }
+        )
+    }
+
     #[test]
     fn can_parse_concepts_with_documented_namespace_from_manifest() {
         use toml::Value;
@@ -658,6 +703,46 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_parse_structs() {
+        const TOML: &str = r#"
+        [package]
+        id = "lktsfudbjw2qikhyumt573ozxhadkiwm"
+        name = "Tic Tac Toe"
+        version = "0.0.1"
+        content = { type = "Playable" }
+
+        [structs.Move]
+        description = "A move made by a player"
+        [structs.Move.fields]
+        cell = "U32"
+        state = "CellState"
+        "#;
+
+        assert_eq!(
+            Manifest::parse(TOML),
+            Ok(Manifest {
+                package: Package {
+                    id: Some(PackageId("lktsfudbjw2qikhyumt573ozxhadkiwm".to_string())),
+                    name: "Tic Tac Toe".to_string(),
+                    version: Version::parse("0.0.1").unwrap(),
+                    ..Default::default()
+                },
+                structs: IndexMap::from_iter([(
+                    pci("Move"),
+                    Struct {
+                        description: Some("A move made by a player".to_string()),
+                        fields: IndexMap::from_iter([
+                            (sci("cell"), ipb("U32")),
+                            (sci("state"), ipb("CellState")),
+                        ])
+                    }
+                )]),
+                ..Default::default()
+            })
+        )
+    }
+
     #[test]
     fn can_parse_container_types() {
         const TOML: &str = r#"
@@ -671,6 +756,7 @@ mod tests {
         test = { type = "I32", name = "Test", description = "Test" }
         vec_test = { type = { container_type = "Vec", element_type = "I32" }, name = "Test", description = "Test" }
         option_test = { type = { container_type = "Option", element_type = "I32" }, name = "Test", description = "Test" }
+        map_test = { type = { container_type = "Map", key_type = "String", element_type = "I32" }, name = "Test", description = "Test" }
 
         "#;
 
@@ -706,7 +792,8 @@ mod tests {
                             description: Some("Test".to_string()),
                             type_: ComponentType::Contained {
                                 type_: ContainerType::Vec,
-                                element_type: i("I32").into()
+                                element_type: i("I32").into(),
+                                key_type: None,
                             },
                             attributes: vec![],
                             default: None,
@@ -719,7 +806,22 @@ mod tests {
                             description: Some("Test".to_string()),
                             type_: ComponentType::Contained {
                                 type_: ContainerType::Option,
-                                element_type: i("I32").into()
+                                element_type: i("I32").into(),
+                                key_type: None,
+                            },
+                            attributes: vec![],
+                            default: None,
+                        }
+                    ),
+                    (
+                        ipb("map_test"),
+                        Component {
+                            name: Some("Test".to_string()),
+                            description: Some("Test".to_string()),
+                            type_: ComponentType::Contained {
+                                type_: ContainerType::Map,
+                                element_type: i("I32").into(),
+                                key_type: Some(i("String").into()),
                             },
                             attributes: vec![],
                             default: None,
@@ -7,6 +7,9 @@ use crate::{ComponentType, SnakeCaseIdentifier};
 pub struct Message {
     pub description: Option<String>,
     pub fields: IndexMap<SnakeCaseIdentifier, ComponentType>,
+    /// When set, this message is a request, and these are the fields of the response to it.
+    #[serde(default)]
+    pub response: Option<IndexMap<SnakeCaseIdentifier, ComponentType>>,
     /// When set, will generate a `ModuleMessage` instead of a `RuntimeMessage`.
     ///
     /// Only applicable to messages defined in the `ambient_core` schema. Intentionally undocumented.
@@ -38,4 +41,25 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn can_parse_request_response() {
+        let t = r#"
+        [fields]
+        a = "a"
+
+        [response]
+        b = "b"
+        "#;
+
+        let message: Message = toml::from_str(t).unwrap();
+
+        assert_eq!(
+            message
+                .response
+                .as_ref()
+                .map(|response| response.keys().collect::<Vec<_>>()),
+            Some(vec![&SnakeCaseIdentifier::new("b").unwrap()])
+        );
+    }
 }
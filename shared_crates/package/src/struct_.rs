@@ -0,0 +1,12 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{ItemPathBuf, SnakeCaseIdentifier};
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Serialize)]
+pub struct Struct {
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The fields of the struct, in order. Each field must be a primitive type or an enum.
+    pub fields: IndexMap<SnakeCaseIdentifier, ItemPathBuf>,
+}
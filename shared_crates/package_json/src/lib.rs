@@ -29,6 +29,8 @@ pub struct Manifest {
     pub vec_items: HashMap<ItemId<Type>, ItemId<Type>>,
     /// Maps a type ID to its Option type ID
     pub option_items: HashMap<ItemId<Type>, ItemId<Type>>,
+    /// All Map type IDs
+    pub map_items: Vec<ItemId<Type>>,
 }
 impl Manifest {
     pub fn get<T: Item>(&self, id: &ItemId<T>) -> &T {
@@ -110,6 +112,7 @@ pub struct Message {
     pub data: ItemData,
     pub description: Option<String>,
     pub fields: IndexMap<Identifier, ItemId<Type>>,
+    pub response: Option<IndexMap<Identifier, ItemId<Type>>>,
 }
 impl_item_for_type!(Message);
 
@@ -125,13 +128,21 @@ pub enum TypeInner {
     Primitive(PrimitiveType),
     Vec(ItemId<Type>),
     Option(ItemId<Type>),
+    /// A map from the first type (the key) to the second type (the value)
+    Map(ItemId<Type>, ItemId<Type>),
     Enum(Enum),
+    Struct(Struct),
 }
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct Enum {
     pub description: Option<String>,
     pub members: IndexMap<Identifier, String>,
 }
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Struct {
+    pub description: Option<String>,
+    pub fields: IndexMap<Identifier, ItemId<Type>>,
+}
 macro_rules! define_primitive_type {
     ($(($value:ident, $type:ty)),*) => {
         #[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug)]
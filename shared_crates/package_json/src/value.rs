@@ -1,6 +1,7 @@
 use std::{fmt, time::Duration};
 
 use ambient_primitive_component_definitions::primitive_component_definitions;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{Identifier, ItemId, Type};
@@ -11,7 +12,9 @@ pub enum Value {
     Scalar(ScalarValue),
     Vec(Vec<ScalarValue>),
     Option(Option<ScalarValue>),
+    Map(Vec<(ScalarValue, ScalarValue)>),
     Enum(EnumValue),
+    Struct(StructValue),
 }
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -19,7 +22,27 @@ impl fmt::Display for Value {
             Value::Scalar(v) => fmt::Display::fmt(v, f),
             Value::Vec(v) => fmt::Debug::fmt(v, f),
             Value::Option(v) => fmt::Debug::fmt(v, f),
+            Value::Map(v) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in v.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{key}: {value}")?;
+                }
+                write!(f, "}}")
+            }
             Value::Enum(v) => write!(f, "{}", v.member),
+            Value::Struct(v) => {
+                write!(f, "{{ ")?;
+                for (i, (name, value)) in v.fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name}: {value}")?;
+                }
+                write!(f, " }}")
+            }
         }
     }
 }
@@ -61,6 +84,12 @@ pub struct EnumValue {
     pub member: Identifier,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct StructValue {
+    pub ty: ItemId<Type>,
+    pub fields: IndexMap<Identifier, Value>,
+}
+
 pub type EntityId = String;
 pub type Mat4 = [f32; 16];
 pub type Quat = [f32; 4];
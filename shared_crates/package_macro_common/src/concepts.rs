@@ -8,7 +8,7 @@ use ambient_package_semantic::{
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::{Context, TypePrinter};
+use crate::{make_path, Context, TypePrinter};
 
 pub fn generate(
     context: Context,
@@ -307,7 +307,7 @@ fn generate_one(
             .iter()
             .map(|c| {
                 let field_name = &c.id;
-                let suggested = value_to_token_stream(items, c.suggested.unwrap())?;
+                let suggested = value_to_token_stream(context, items, c.suggested.unwrap())?;
 
                 anyhow::Ok(quote! { #field_name: #suggested })
             })
@@ -450,7 +450,7 @@ fn component_to_field<'a>(
         writeln!(
             doc_comment,
             "**Suggested value**: `{}`",
-            SemiprettyTokenStream(value_to_token_stream(items, value)?)
+            SemiprettyTokenStream(value_to_token_stream(context, items, value)?)
         )?;
         writeln!(doc_comment)?;
     }
@@ -525,7 +525,11 @@ impl std::fmt::Display for SemiprettyTokenStream {
     }
 }
 
-fn value_to_token_stream(items: &ItemMap, value: &Value) -> anyhow::Result<TokenStream> {
+fn value_to_token_stream(
+    context: Context,
+    items: &ItemMap,
+    value: &Value,
+) -> anyhow::Result<TokenStream> {
     Ok(match value {
         Value::Scalar(v) => scalar_value_to_token_stream(v),
         Value::Vec(v) => {
@@ -539,6 +543,14 @@ fn value_to_token_stream(items: &ItemMap, value: &Value) -> anyhow::Result<Token
             }
             None => quote! { None },
         },
+        Value::Map(v) => {
+            let entries = v.iter().map(|(k, v)| {
+                let k = scalar_value_to_token_stream(k);
+                let v = scalar_value_to_token_stream(v);
+                quote! { (#k, #v) }
+            });
+            quote! { std::collections::BTreeMap::from([#(#entries,)*]) }
+        }
         Value::Enum(id, member) => {
             let item = items.get(*id);
             let index = item
@@ -551,6 +563,28 @@ fn value_to_token_stream(items: &ItemMap, value: &Value) -> anyhow::Result<Token
 
             quote! { #index }
         }
+        Value::Struct(id, fields) => {
+            let path = context.get_path(items, None, *id)?;
+            let fields = fields
+                .iter()
+                .map(|(name, value)| {
+                    let name = make_path(name.as_str());
+                    let value = match value {
+                        // Unlike enum components, which are stored as their index,
+                        // struct fields are typed as the enum itself
+                        Value::Enum(id, member) => {
+                            let path = context.get_path(items, None, *id)?;
+                            let member = make_path(member.as_str());
+                            quote! { #path::#member }
+                        }
+                        value => value_to_token_stream(context, items, value)?,
+                    };
+                    anyhow::Ok(quote! { #name: #value })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            quote! { #path { #(#fields,)* } }
+        }
     })
 }
 
@@ -628,9 +662,9 @@ fn scalar_value_to_token_stream(v: &ScalarValue) -> TokenStream {
 
 #[cfg(test)]
 mod tests {
-    use ambient_package::PascalCaseIdentifier;
+    use ambient_package::{PascalCaseIdentifier, SnakeCaseIdentifier};
     use ambient_package_semantic::{
-        create_root_scope, Enum, ItemData, ItemSource, Type, TypeInner,
+        create_root_scope, Enum, ItemData, ItemSource, ResolvableItemId, Struct, Type, TypeInner,
     };
 
     use super::*;
@@ -778,7 +812,9 @@ mod tests {
 
         let value = Value::Scalar(ScalarValue::Bool(true));
         assert_eq!(
-            value_to_token_stream(&items, &value).unwrap().to_string(),
+            value_to_token_stream(Context::Host, &items, &value)
+                .unwrap()
+                .to_string(),
             "true"
         );
 
@@ -788,22 +824,39 @@ mod tests {
             ScalarValue::U32(3),
         ]);
         assert_eq!(
-            value_to_token_stream(&items, &value).unwrap().to_string(),
+            value_to_token_stream(Context::Host, &items, &value)
+                .unwrap()
+                .to_string(),
             "vec ! [1u32 , 2u32 , 3u32 ,]"
         );
 
         let value = Value::Option(Some(ScalarValue::String("hello".to_string())));
         assert_eq!(
-            value_to_token_stream(&items, &value).unwrap().to_string(),
+            value_to_token_stream(Context::Host, &items, &value)
+                .unwrap()
+                .to_string(),
             "Some (\"hello\" . to_string ())"
         );
 
         let value = Value::Option(None);
         assert_eq!(
-            value_to_token_stream(&items, &value).unwrap().to_string(),
+            value_to_token_stream(Context::Host, &items, &value)
+                .unwrap()
+                .to_string(),
             "None"
         );
 
+        let value = Value::Map(vec![(
+            ScalarValue::String("a".to_string()),
+            ScalarValue::U32(1),
+        )]);
+        assert_eq!(
+            value_to_token_stream(Context::Host, &items, &value)
+                .unwrap()
+                .to_string(),
+            "std :: collections :: BTreeMap :: from ([(\"a\" . to_string () , 1u32) ,])"
+        );
+
         let id = items.add(Type::new(
             ItemData {
                 parent_id: None,
@@ -820,8 +873,44 @@ mod tests {
         ));
         let value = Value::Enum(id, PascalCaseIdentifier::new("B").unwrap());
         assert_eq!(
-            value_to_token_stream(&items, &value).unwrap().to_string(),
+            value_to_token_stream(Context::Host, &items, &value)
+                .unwrap()
+                .to_string(),
             "1usize"
         );
+
+        let struct_id = items.add(Type::new(
+            ItemData {
+                parent_id: None,
+                id: PascalCaseIdentifier::new("MyStruct").unwrap().into(),
+                source: ItemSource::User,
+            },
+            TypeInner::Struct(Struct {
+                description: None,
+                fields: [("choice", ResolvableItemId::Resolved(id))]
+                    .into_iter()
+                    .map(|(k, v)| (SnakeCaseIdentifier::new(k).unwrap(), v))
+                    .collect(),
+            }),
+        ));
+        let value = Value::Struct(
+            struct_id,
+            [
+                (
+                    SnakeCaseIdentifier::new("choice").unwrap(),
+                    Value::Enum(id, PascalCaseIdentifier::new("C").unwrap()),
+                ),
+                (
+                    SnakeCaseIdentifier::new("weight").unwrap(),
+                    Value::Scalar(ScalarValue::F32(0.5)),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        assert_eq!(
+            value_to_token_stream(Context::GuestUser, &items, &value).unwrap().to_string(),
+            "crate :: packages :: raw :: types :: MyStruct { choice : crate :: packages :: raw :: types :: MyEnum :: C , weight : 0.5f32 , }"
+        );
     }
 }
@@ -4,8 +4,12 @@ use quote::quote;
 
 use crate::{make_path, Context};
 
-pub fn generate(context: Context, items: &ItemMap, scope: &Scope) -> anyhow::Result<TokenStream> {
-    let enums = scope
+pub fn generate(
+    context: Context,
+    items: &ItemMap,
+    scope: &Scope,
+) -> anyhow::Result<Vec<TokenStream>> {
+    scope
         .types
         .values()
         .filter_map(|id| context.extract_item_if_relevant(items, *id))
@@ -141,22 +145,5 @@ pub fn generate(context: Context, items: &ItemMap, scope: &Scope) -> anyhow::Res
                 #message_serde_impl
             })
         })
-        .collect::<anyhow::Result<Vec<_>>>()?;
-
-    if enums.is_empty() {
-        return Ok(quote! {});
-    }
-
-    let includes = context
-        .guest_api_path()
-        .map(|s| quote! { use #s::{global::serde, message::*}; })
-        .unwrap_or(quote! { use serde; use ambient_package_rt::message_serde::*; });
-
-    Ok(quote! {
-        /// Auto-generated type definitions.
-        pub mod types {
-            #includes
-            #(#enums)*
-        }
-    })
+        .collect()
 }
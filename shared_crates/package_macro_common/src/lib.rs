@@ -12,6 +12,7 @@ mod concepts;
 mod context;
 mod enums;
 mod messages;
+mod structs;
 
 pub use context::Context;
 
@@ -33,6 +34,7 @@ pub async fn generate_code(
     let root_scope = semantic.root_scope();
     let type_printer = {
         let mut map = HashMap::new();
+        let mut primitives = vec![];
         for type_id in root_scope.types.values() {
             let type_ = items.get(*type_id);
             if let TypeInner::Primitive(pt) = type_.inner {
@@ -40,6 +42,17 @@ pub async fn generate_code(
                 map.insert(*type_id, ty_tokens.clone());
                 map.insert(items.get_vec_id(*type_id), quote! {Vec::<#ty_tokens>});
                 map.insert(items.get_option_id(*type_id), quote! {Option::<#ty_tokens>});
+                primitives.push((*type_id, ty_tokens));
+            }
+        }
+        for (key_id, key_tokens) in &primitives {
+            for (value_id, value_tokens) in &primitives {
+                if let Some(map_id) = items.get_map_id(*key_id, *value_id) {
+                    map.insert(
+                        map_id,
+                        quote! {std::collections::BTreeMap::<#key_tokens, #value_tokens>},
+                    );
+                }
             }
         }
         TypePrinter(map)
@@ -168,7 +181,7 @@ fn generate_scope(
     let components = components::generate(context, items, type_printer, scope)?;
     let concepts = concepts::generate(context, items, type_printer, scope)?;
     let messages = messages::generate(context, items, type_printer, scope)?;
-    let types = enums::generate(context, items, scope)?;
+    let types = generate_types(context, items, type_printer, scope)?;
 
     Ok(quote! {
         #(#scopes)*
@@ -180,6 +193,46 @@ fn generate_scope(
     })
 }
 
+fn generate_types(
+    context: context::Context,
+    items: &ItemMap,
+    type_printer: &TypePrinter,
+    scope: &Scope,
+) -> anyhow::Result<TokenStream> {
+    let enums = enums::generate(context, items, scope)?;
+    let structs = structs::generate(context, items, type_printer, scope)?;
+    if enums.is_empty() && structs.is_empty() {
+        return Ok(quote! {});
+    }
+
+    let mut includes = context
+        .guest_api_path()
+        .map(|s| quote! { use #s::{global::serde, message::*}; })
+        .unwrap_or(quote! { use serde; use ambient_package_rt::message_serde::*; });
+
+    // Struct fields can use any primitive type, so make sure they are all in scope
+    if !structs.is_empty() {
+        includes.extend(match context.guest_api_path() {
+            Some(s) => quote! { use #s::global::*; },
+            None => quote! {
+                use std::time::Duration;
+                use glam::{Vec2, Vec3, Vec4, UVec2, UVec3, UVec4, IVec2, IVec3, IVec4, Mat4, Quat};
+                use crate::EntityId;
+                use ambient_shared_types::{ProceduralMeshHandle, ProceduralTextureHandle, ProceduralSamplerHandle, ProceduralMaterialHandle};
+            },
+        });
+    }
+
+    Ok(quote! {
+        /// Auto-generated type definitions.
+        pub mod types {
+            #includes
+            #(#enums)*
+            #(#structs)*
+        }
+    })
+}
+
 fn make_path(id: &str) -> syn::Path {
     syn::parse_str(id).unwrap()
 }
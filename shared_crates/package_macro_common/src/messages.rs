@@ -1,4 +1,5 @@
-use ambient_package_semantic::{Item, ItemMap, ItemSource, Scope};
+use ambient_package::SnakeCaseIdentifier;
+use ambient_package_semantic::{Item, ItemMap, ItemSource, ResolvableItemId, Scope, Type};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -24,54 +25,6 @@ pub fn generate(
                 format!("**{}**", id)
             };
 
-            let struct_name = make_path(id);
-
-            let fields = message.fields.iter().map(|f| {
-                let name = make_path(f.0.as_str());
-                let ty = type_printer
-                    .get(context, items, None, f.1.as_resolved().unwrap())
-                    .unwrap();
-                quote! { pub #name: #ty }
-            });
-
-            let new_parameters = message.fields.iter().map(|f| {
-                let name = make_path(f.0.as_str());
-                let ty = type_printer
-                    .get(context, items, None, f.1.as_resolved().unwrap())
-                    .unwrap();
-                quote! { #name: impl Into<#ty> }
-            });
-
-            let new_fields = message.fields.iter().map(|f| {
-                let name = make_path(f.0.as_str());
-                quote! { #name: #name.into() }
-            });
-
-            let serialize_fields = message.fields.iter().map(|f| {
-                let name = make_path(f.0.as_str());
-                quote! { self.#name.serialize_message_part(&mut output)? }
-            });
-
-            let deserialize_fields = message.fields.iter().map(|f| {
-                let name = make_path(f.0.as_str());
-                let ty = type_printer
-                    .get(context, items, None, f.1.as_resolved().unwrap())
-                    .unwrap();
-                quote! { #name: #ty ::deserialize_message_part(&mut input)? }
-            });
-
-            let default_impl = if message.fields.is_empty() {
-                quote! {
-                    impl Default for #struct_name {
-                        fn default() -> Self {
-                            Self::new()
-                        }
-                    }
-                }
-            } else {
-                quote! {}
-            };
-
             let message_impl =
                 if message.data().source != ItemSource::Ambient || message.as_module_message {
                     quote! { ModuleMessage }
@@ -79,50 +32,41 @@ pub fn generate(
                     quote! { RuntimeMessage }
                 };
 
-            let struct_definition = if message.fields.is_empty() {
-                quote! {
-                    pub struct #struct_name;
-                    impl #struct_name {
-                        pub fn new() -> Self { Self }
-                    }
-                }
-            } else {
-                quote! {
-                    pub struct #struct_name {
-                        #(#fields,)*
-                    }
-                    impl #struct_name {
-                        #[allow(clippy::too_many_arguments)]
-                        pub fn new(#(#new_parameters,)*) -> Self {
-                            Self {
-                                #(#new_fields,)*
-                            }
-                        }
-                    }
-                }
+            let struct_name = make_path(id);
+            let definition = generate_message(
+                context,
+                items,
+                type_printer,
+                &struct_name,
+                &doc_comment,
+                &path,
+                &message.fields,
+                &message_impl,
+            );
+
+            let Some(response) = &message.response else {
+                return Ok(definition);
             };
 
+            // The response is always sent between modules, and is identified by the path of the request
+            let response_name = make_path(&format!("{id}Response"));
+            let response_definition = generate_message(
+                context,
+                items,
+                type_printer,
+                &response_name,
+                &format!("**{id}Response**: The response to [{id}]."),
+                &format!("{path}::response"),
+                response,
+                &quote! { ModuleMessage },
+            );
+
             Ok(quote! {
-                #[derive(Clone, Debug)]
-                #[doc = #doc_comment]
-                #struct_definition
-                impl Message for #struct_name {
-                    fn id() -> &'static str {
-                        #path
-                    }
-                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
-                        let mut output = vec![];
-                        #(#serialize_fields;)*
-                        Ok(output)
-                    }
-                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
-                        Ok(Self {
-                            #(#deserialize_fields,)*
-                        })
-                    }
+                #definition
+                #response_definition
+                impl Request for #struct_name {
+                    type Response = #response_name;
                 }
-                impl #message_impl for #struct_name {}
-                #default_impl
             })
         })
         .collect::<anyhow::Result<Vec<_>>>()?;
@@ -133,7 +77,7 @@ pub fn generate(
 
     let inner = match context {
         Context::Host => quote! {
-            use ambient_package_rt::message_serde::{Message, MessageSerde, MessageSerdeError, RuntimeMessage, ModuleMessage};
+            use ambient_package_rt::message_serde::{Message, MessageSerde, MessageSerdeError, RuntimeMessage, ModuleMessage, Request};
             use glam::{Vec2, Vec3, Vec4, UVec2, UVec3, UVec4, Mat4, Quat};
             use crate::{EntityId, Entity};
             #(#messages)*
@@ -142,7 +86,7 @@ pub fn generate(
         Context::GuestApi | Context::GuestUser => {
             let api_path = context.guest_api_path().unwrap();
             quote! {
-                use #api_path::{prelude::*, message::{Message, MessageSerde, MessageSerdeError, RuntimeMessage, ModuleMessage}};
+                use #api_path::{prelude::*, message::{Message, MessageSerde, MessageSerdeError, RuntimeMessage, ModuleMessage, Request}};
                 #(#messages)*
             }
         }
@@ -156,3 +100,104 @@ pub fn generate(
         }
     })
 }
+
+#[allow(clippy::too_many_arguments)]
+fn generate_message<'a>(
+    context: Context,
+    items: &ItemMap,
+    type_printer: &TypePrinter,
+    struct_name: &syn::Path,
+    doc_comment: &str,
+    path: &str,
+    fields: impl IntoIterator<Item = (&'a SnakeCaseIdentifier, &'a ResolvableItemId<Type>)>,
+    message_impl: &TokenStream,
+) -> TokenStream {
+    let field_types = fields
+        .into_iter()
+        .map(|f| {
+            let name = make_path(f.0.as_str());
+            let ty = type_printer
+                .get(context, items, None, f.1.as_resolved().unwrap())
+                .unwrap();
+            (name, ty)
+        })
+        .collect::<Vec<_>>();
+
+    let definition_fields = field_types
+        .iter()
+        .map(|(name, ty)| quote! { pub #name: #ty });
+
+    let new_parameters = field_types
+        .iter()
+        .map(|(name, ty)| quote! { #name: impl Into<#ty> });
+
+    let new_fields = field_types
+        .iter()
+        .map(|(name, _)| quote! { #name: #name.into() });
+
+    let serialize_fields = field_types
+        .iter()
+        .map(|(name, _)| quote! { self.#name.serialize_message_part(&mut output)? });
+
+    let deserialize_fields = field_types
+        .iter()
+        .map(|(name, ty)| quote! { #name: #ty ::deserialize_message_part(&mut input)? });
+
+    let default_impl = if field_types.is_empty() {
+        quote! {
+            impl Default for #struct_name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let struct_definition = if field_types.is_empty() {
+        quote! {
+            pub struct #struct_name;
+            impl #struct_name {
+                pub fn new() -> Self { Self }
+            }
+        }
+    } else {
+        quote! {
+            pub struct #struct_name {
+                #(#definition_fields,)*
+            }
+            impl #struct_name {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(#(#new_parameters,)*) -> Self {
+                    Self {
+                        #(#new_fields,)*
+                    }
+                }
+            }
+        }
+    };
+
+    quote! {
+        #[derive(Clone, Debug)]
+        #[doc = #doc_comment]
+        #struct_definition
+        impl Message for #struct_name {
+            fn id() -> &'static str {
+                #path
+            }
+            fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                let mut output = vec![];
+                #(#serialize_fields;)*
+                Ok(output)
+            }
+            fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                Ok(Self {
+                    #(#deserialize_fields,)*
+                })
+            }
+        }
+        impl #message_impl for #struct_name {}
+        #default_impl
+    }
+}
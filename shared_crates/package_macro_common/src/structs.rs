@@ -0,0 +1,127 @@
+use ambient_package_semantic::{Item, ItemMap, Scope};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{make_path, Context, TypePrinter};
+
+pub fn generate(
+    context: Context,
+    items: &ItemMap,
+    type_printer: &TypePrinter,
+    scope: &Scope,
+) -> anyhow::Result<Vec<TokenStream>> {
+    scope
+        .types
+        .values()
+        .filter_map(|id| context.extract_item_if_relevant(items, *id))
+        .filter(|ty| ty.inner.as_struct().is_some())
+        .map(|ty| {
+            let (data, structure) = (ty.data(), ty.inner.as_struct().unwrap());
+            let id = data.id.as_str();
+            let doc_comment = if let Some(desc) = &structure.description {
+                format!("**{}**: {}", id, desc)
+            } else {
+                format!("**{}**", id)
+            };
+
+            let struct_name = make_path(id);
+            let field_names = structure
+                .fields
+                .keys()
+                .map(|id| make_path(id.as_str()))
+                .collect::<Vec<_>>();
+            let field_types = structure
+                .fields
+                .values()
+                .map(|ty| type_printer.get(context, items, None, ty.as_resolved().unwrap()))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            let ecs_prefix = context
+                .guest_api_path()
+                .map(|path| quote! { #path::ecs:: })
+                .unwrap_or_else(|| quote! { crate:: });
+
+            let main = quote! {
+                #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, Default)]
+                #[serde(crate = "self::serde")]
+                #[doc = #doc_comment]
+                pub struct #struct_name {
+                    #(pub #field_names: #field_types,)*
+                }
+
+                impl #ecs_prefix StructComponent for #struct_name {
+                    fn to_bytes(&self) -> Vec<u8> {
+                        let mut output = vec![];
+                        self.serialize_message_part(&mut output)
+                            .expect("serializing a struct to memory should not fail");
+                        output
+                    }
+
+                    fn from_bytes(mut bytes: &[u8]) -> Option<Self> {
+                        Self::deserialize_message_part(&mut bytes).ok()
+                    }
+                }
+            };
+
+            let supported_value = if let Some(guest_api_path) = context.guest_api_path() {
+                quote! {
+                    impl #guest_api_path::ecs::SupportedValue for #struct_name {
+                        fn from_result(result: #guest_api_path::ecs::WitComponentValue) -> Option<Self> {
+                            use #ecs_prefix StructComponent;
+                            match result {
+                                #guest_api_path::ecs::WitComponentValue::TypeStruct(bytes) => Self::from_bytes(&bytes),
+                                _ => None,
+                            }
+                        }
+
+                        fn into_result(self) -> #guest_api_path::ecs::WitComponentValue {
+                            use #ecs_prefix StructComponent;
+                            #guest_api_path::ecs::WitComponentValue::TypeStruct(self.to_bytes())
+                        }
+
+                        fn from_value(value: #guest_api_path::ecs::ComponentValue) -> Option<Self> {
+                            use #ecs_prefix StructComponent;
+                            match value {
+                                #guest_api_path::ecs::ComponentValue::Struct(bytes) => Self::from_bytes(&bytes),
+                                _ => None,
+                            }
+                        }
+
+                        fn into_value(self) -> #guest_api_path::ecs::ComponentValue {
+                            use #ecs_prefix StructComponent;
+                            #guest_api_path::ecs::ComponentValue::Struct(self.to_bytes())
+                        }
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            let message_serde_impl = quote! {
+                impl MessageSerde for #struct_name {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        #(self.#field_names.serialize_message_part(output)?;)*
+                        Ok(())
+                    }
+
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            #(#field_names: <#field_types as MessageSerde>::deserialize_message_part(input)?,)*
+                        })
+                    }
+                }
+            };
+
+            Ok(quote! {
+                #main
+                #supported_value
+                #message_serde_impl
+            })
+        })
+        .collect()
+}
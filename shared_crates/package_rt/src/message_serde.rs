@@ -1,4 +1,4 @@
-use std::{io::Read, time::Duration};
+use std::{collections::BTreeMap, io::Read, time::Duration};
 
 use ambient_shared_types::{procedural_storage_handle_definitions, ComponentIndex};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
@@ -339,6 +339,26 @@ impl<T: MessageSerde> MessageSerde for Option<T> {
         })
     }
 }
+impl<K: MessageSerde + Ord, V: MessageSerde> MessageSerde for BTreeMap<K, V> {
+    fn serialize_message_part(&self, output: &mut Vec<u8>) -> Result<(), MessageSerdeError> {
+        output.write_u32::<BigEndian>(self.len().try_into()?)?;
+        for (key, value) in self {
+            key.serialize_message_part(output)?;
+            value.serialize_message_part(output)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_message_part(input: &mut dyn Read) -> Result<Self, MessageSerdeError> {
+        let length = input.read_u32::<BigEndian>()?;
+        let mut map = BTreeMap::new();
+        for _ in 0..length {
+            let key = K::deserialize_message_part(input)?;
+            map.insert(key, V::deserialize_message_part(input)?);
+        }
+        Ok(map)
+    }
+}
 impl MessageSerde for Duration {
     fn serialize_message_part(&self, output: &mut Vec<u8>) -> Result<(), MessageSerdeError> {
         output.write_u64::<BigEndian>(self.as_nanos().try_into().unwrap())?;
@@ -406,3 +426,135 @@ pub trait RuntimeMessage: Message {}
 
 /// Implemented on all messages that can be sent between packages.
 pub trait ModuleMessage: Message {}
+
+/// Implemented on all messages that are requests, which are answered with a [`Request::Response`].
+pub trait Request: ModuleMessage {
+    /// The message that is sent back in response to this request.
+    type Response: ModuleMessage;
+}
+
+const RESPONSE_OK: u8 = 0;
+const RESPONSE_ERROR: u8 = 1;
+
+#[doc(hidden)]
+/// Splits the ID of the call that a request or response belongs to off the front of `input`.
+pub fn split_call_id(mut input: &[u8]) -> Result<(u128, &[u8]), MessageSerdeError> {
+    let call_id = input.read_u128::<BigEndian>()?;
+    Ok((call_id, input))
+}
+
+#[doc(hidden)]
+/// Serializes `request` as part of the call `call_id`.
+pub fn serialize_request<T: Message>(
+    call_id: u128,
+    request: &T,
+) -> Result<Vec<u8>, MessageSerdeError> {
+    let mut output = vec![];
+    output.write_u128::<BigEndian>(call_id)?;
+    output.extend(request.serialize_message()?);
+    Ok(output)
+}
+
+#[doc(hidden)]
+/// Serializes the response to the call `call_id`, which is either a message or the error that
+/// serving the request failed with.
+pub fn serialize_response<T: Message>(
+    call_id: u128,
+    response: Result<&T, &str>,
+) -> Result<Vec<u8>, MessageSerdeError> {
+    let mut output = vec![];
+    output.write_u128::<BigEndian>(call_id)?;
+    match response {
+        Ok(response) => {
+            output.write_u8(RESPONSE_OK)?;
+            output.extend(response.serialize_message()?);
+        }
+        Err(error) => {
+            output.write_u8(RESPONSE_ERROR)?;
+            error.to_string().serialize_message_part(&mut output)?;
+        }
+    }
+    Ok(output)
+}
+
+#[doc(hidden)]
+/// Deserializes a response, after its call ID has been split off with [`split_call_id`].
+pub fn deserialize_response<T: Message>(
+    mut input: &[u8],
+) -> Result<Result<T, String>, MessageSerdeError> {
+    match input.read_u8()? {
+        RESPONSE_OK => Ok(Ok(T::deserialize_message(input)?)),
+        RESPONSE_ERROR => Ok(Err(String::deserialize_message_part(&mut input)?)),
+        _ => Err(MessageSerdeError::InvalidValue),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Greeting(String, u32);
+    impl Message for Greeting {
+        fn id() -> &'static str {
+            "greeting"
+        }
+
+        fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+            let mut output = vec![];
+            self.0.serialize_message_part(&mut output)?;
+            self.1.serialize_message_part(&mut output)?;
+            Ok(output)
+        }
+
+        fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+            Ok(Self(
+                String::deserialize_message_part(&mut input)?,
+                u32::deserialize_message_part(&mut input)?,
+            ))
+        }
+    }
+
+    #[test]
+    fn requests_keep_their_call_id() {
+        let request = Greeting("hello".to_string(), 1);
+        let data = serialize_request(42, &request).unwrap();
+
+        let (call_id, data) = split_call_id(&data).unwrap();
+        assert_eq!(call_id, 42);
+        assert_eq!(Greeting::deserialize_message(data).unwrap(), request);
+    }
+
+    #[test]
+    fn responses_round_trip() {
+        let response = Greeting("hi".to_string(), 2);
+        let data = serialize_response(7, Ok(&response)).unwrap();
+        let (call_id, data) = split_call_id(&data).unwrap();
+        assert_eq!(call_id, 7);
+        assert_eq!(
+            deserialize_response::<Greeting>(data).unwrap(),
+            Ok(response)
+        );
+
+        let data = serialize_response::<Greeting>(8, Err("no greetings today")).unwrap();
+        let (call_id, data) = split_call_id(&data).unwrap();
+        assert_eq!(call_id, 8);
+        assert_eq!(
+            deserialize_response::<Greeting>(data).unwrap(),
+            Err("no greetings today".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_responses_are_rejected() {
+        assert!(split_call_id(&[0; 15]).is_err());
+
+        // A response which was cut short
+        let data = serialize_response(1, Ok(&Greeting("hi".to_string(), 2))).unwrap();
+        let (_, data) = split_call_id(&data[..data.len() - 2]).unwrap();
+        assert!(deserialize_response::<Greeting>(data).is_err());
+
+        assert!(deserialize_response::<Greeting>(&[2]).is_err());
+        assert!(deserialize_response::<Greeting>(&[]).is_err());
+    }
+}
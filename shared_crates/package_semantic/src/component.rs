@@ -3,7 +3,7 @@ use anyhow::Context as AnyhowContext;
 
 use crate::{
     Attribute, Item, ItemData, ItemId, ItemType, ItemVariant, ResolvableItemId, ResolvableValue,
-    Resolve, Semantic, Type,
+    Resolve, Semantic, Type, TypeInner,
 };
 
 #[derive(Clone, PartialEq, Debug)]
@@ -80,11 +80,34 @@ impl Resolve for Component {
             });
         }
 
-        // If this is an enum, emit the `Enum` attribute
-        if semantic.items.get(type_id).inner.as_enum().is_some() {
-            attributes.push(ResolvableItemId::Resolved(
+        // Structs need their fields resolved before their default value can be resolved
+        semantic.resolve(type_id)?;
+        match &semantic.items.get(type_id).inner {
+            // If this is an enum, emit the `Enum` attribute
+            TypeInner::Enum(_) => attributes.push(ResolvableItemId::Resolved(
                 semantic.standard_definitions.attributes.enum_,
-            ));
+            )),
+            // Likewise, if this is a struct, emit the `Struct` attribute
+            TypeInner::Struct(_) => attributes.push(ResolvableItemId::Resolved(
+                semantic.standard_definitions.attributes.struct_,
+            )),
+            TypeInner::Vec(id) | TypeInner::Option(id) | TypeInner::Map(_, id)
+                if semantic.items.get(*id).inner.as_struct().is_some() =>
+            {
+                anyhow::bail!(
+                    "Component `{}` has a container of struct type `{}`, which is not supported",
+                    self.data.id,
+                    semantic.items.get(*id).data.id
+                );
+            }
+            TypeInner::Map(_, id) if semantic.items.get(*id).inner.as_primitive().is_none() => {
+                anyhow::bail!(
+                    "Component `{}` has a map of `{}`, but map values must be primitive types",
+                    self.data.id,
+                    semantic.items.get(*id).data.id
+                );
+            }
+            _ => {}
         }
         self.attributes = attributes;
 
@@ -9,7 +9,9 @@ use ambient_std::topological_sort::{topological_sort, TopologicalSortable};
 use thiserror::Error;
 use ulid::Ulid;
 
-use crate::{Attribute, Component, Concept, Message, Package, Scope, Type, TypeInner};
+use crate::{
+    Attribute, Component, Concept, Message, Package, PrimitiveType, Scope, Type, TypeInner,
+};
 
 #[derive(Error, Debug)]
 pub enum GetScopeError {
@@ -28,6 +30,8 @@ pub struct ItemMap {
     items: HashMap<Ulid, ItemVariant>,
     vec_items: HashMap<ItemId<Type>, ItemId<Type>>,
     option_items: HashMap<ItemId<Type>, ItemId<Type>>,
+    map_items: HashMap<(ItemId<Type>, ItemId<Type>), ItemId<Type>>,
+    map_key_items: Vec<ItemId<Type>>,
 }
 impl ItemMap {
     pub fn add<T: Item>(&mut self, item: T) -> ItemId<T> {
@@ -58,12 +62,43 @@ impl ItemMap {
                 TypeInner::Option(new_id),
             ));
             self.option_items.insert(new_id, option_id);
+
+            // Create the maps from every key type to this type, and if this type can be
+            // used as a key, the maps from it to every type added so far
+            for key_id in self.map_key_items.clone() {
+                self.add_map(key_id, new_id);
+            }
+            if matches!(
+                self.get(new_id).inner,
+                TypeInner::Primitive(PrimitiveType::String | PrimitiveType::U32)
+            ) {
+                self.map_key_items.push(new_id);
+                for value_id in self.vec_items.keys().copied().collect::<Vec<_>>() {
+                    self.add_map(new_id, value_id);
+                }
+            }
+
             raw_new_id
         } else {
             self.add_raw(item)
         }
     }
 
+    fn add_map(&mut self, key_id: ItemId<Type>, value_id: ItemId<Type>) {
+        let (key, value) = (self.get(key_id), self.get(value_id));
+        let map = Type::new(
+            ItemData {
+                id: PascalCaseIdentifier::new(&format!("Map{}{}", key.data.id, value.data.id))
+                    .unwrap()
+                    .into(),
+                ..value.data.clone()
+            },
+            TypeInner::Map(key_id, value_id),
+        );
+        let map_id = self.add_raw(map);
+        self.map_items.insert((key_id, value_id), map_id);
+    }
+
     // We use ULID creation as this code is only run on the server, where randomness is available.
     // If this runs on the client, then yes, it should panic!
     #[allow(clippy::disallowed_methods)]
@@ -99,6 +134,11 @@ impl ItemMap {
         self.option_items.get(&id).copied().unwrap()
     }
 
+    /// Returns `None` if `key_id` can't be used as the key of a map.
+    pub fn get_map_id(&self, key_id: ItemId<Type>, value_id: ItemId<Type>) -> Option<ItemId<Type>> {
+        self.map_items.get(&(key_id, value_id)).copied()
+    }
+
     pub fn get_scope_id(
         &self,
         start_scope_id: ItemId<Scope>,
@@ -251,6 +291,10 @@ impl ItemMap {
     pub fn option_items(&self) -> &HashMap<ItemId<Type>, ItemId<Type>> {
         &self.option_items
     }
+
+    pub fn map_items(&self) -> &HashMap<(ItemId<Type>, ItemId<Type>), ItemId<Type>> {
+        &self.map_items
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
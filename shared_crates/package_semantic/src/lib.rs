@@ -40,7 +40,7 @@ pub use primitive_type::PrimitiveType;
 
 mod type_;
 use thiserror::Error;
-pub use type_::{Enum, Type, TypeInner};
+pub use type_::{Enum, Struct, Type, TypeInner};
 
 mod message;
 pub use message::Message;
@@ -420,16 +420,29 @@ impl Semantic {
         start_scope: ItemId<Scope>,
         component_type: &ComponentType,
     ) -> Option<ItemId<Type>> {
-        self.get_contextual(start_scope, |scope_id| match component_type {
-            ComponentType::Item(id) => get_type_id(&self.items, scope_id, id.as_path()),
+        let get_item = |path: &ItemPathBuf| {
+            self.get_contextual(start_scope, |scope_id| {
+                get_type_id(&self.items, scope_id, path.as_path())
+            })
+        };
+
+        match component_type {
+            ComponentType::Item(id) => get_item(id),
             ComponentType::Contained {
                 type_,
                 element_type,
-            } => get_type_id(&self.items, scope_id, element_type.as_path()).map(|id| match type_ {
-                ambient_package::ContainerType::Vec => self.items.get_vec_id(id),
-                ambient_package::ContainerType::Option => self.items.get_option_id(id),
-            }),
-        })
+                key_type,
+            } => {
+                let id = get_item(element_type)?;
+                match type_ {
+                    ambient_package::ContainerType::Vec => Some(self.items.get_vec_id(id)),
+                    ambient_package::ContainerType::Option => Some(self.items.get_option_id(id)),
+                    ambient_package::ContainerType::Map => {
+                        self.items.get_map_id(get_item(key_type.as_ref()?)?, id)
+                    }
+                }
+            }
+        }
     }
 
     /// Walks upwards from `start_scope` to find the first attribute located at `path`.
@@ -628,6 +641,17 @@ impl Semantic {
                 .insert(segment.clone(), enum_id);
         }
 
+        for (segment, struct_ty) in manifest.structs.iter() {
+            let struct_id = items.add(Type::from_package_struct(
+                make_item_data(scope_id, &Identifier::from(segment.clone())),
+                struct_ty,
+            ));
+            items
+                .get_mut(scope_id)
+                .types
+                .insert(segment.clone(), struct_id);
+        }
+
         Ok(scope_id)
     }
 }
@@ -645,6 +669,7 @@ pub struct StandardAttributes {
     pub maybe_resource: ItemId<Attribute>,
     pub store: ItemId<Attribute>,
     pub enum_: ItemId<Attribute>,
+    pub struct_: ItemId<Attribute>,
 }
 
 pub fn create_root_scope(
@@ -707,6 +732,7 @@ pub fn create_root_scope(
         maybe_resource: make_attribute(items, root_scope, "MaybeResource")?,
         store: make_attribute(items, root_scope, "Store")?,
         enum_: make_attribute(items, root_scope, "Enum")?,
+        struct_: make_attribute(items, root_scope, "Struct")?,
     };
 
     let standard_definitions = StandardDefinitions { attributes };
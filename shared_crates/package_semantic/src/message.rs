@@ -12,6 +12,7 @@ pub struct Message {
 
     pub description: Option<String>,
     pub fields: IndexMap<SnakeCaseIdentifier, ResolvableItemId<Type>>,
+    pub response: Option<IndexMap<SnakeCaseIdentifier, ResolvableItemId<Type>>>,
     pub as_module_message: bool,
 
     resolved: bool,
@@ -47,22 +48,33 @@ impl Resolve for Message {
     fn resolve(mut self, semantic: &mut Semantic, _self_id: ItemId<Self>) -> anyhow::Result<Self> {
         let parent_id = self.data.parent_id.unwrap();
 
-        let mut fields = IndexMap::new();
-        for (name, type_) in &self.fields {
-            fields.insert(
-                name.clone(),
-                match type_ {
-                    ResolvableItemId::Unresolved(path) => {
-                        let id = semantic.get_contextual_type_id(parent_id, path).with_context(|| {
-                            format!("Failed to resolve type `{path:?}` for field `{name}` of message `{}`", self.data.id)
-                        })?;
-                        ResolvableItemId::Resolved(id)
-                    }
-                    t => t.clone(),
-                },
-            );
+        let message_id = self.data.id.clone();
+        let resolve_fields = |semantic: &mut Semantic,
+                              fields: &IndexMap<SnakeCaseIdentifier, ResolvableItemId<Type>>,
+                              kind: &str|
+         -> anyhow::Result<_> {
+            let mut resolved = IndexMap::new();
+            for (name, type_) in fields {
+                resolved.insert(
+                    name.clone(),
+                    match type_ {
+                        ResolvableItemId::Unresolved(path) => {
+                            let id = semantic.get_contextual_type_id(parent_id, path).with_context(|| {
+                                format!("Failed to resolve type `{path:?}` for {kind} field `{name}` of message `{message_id}`")
+                            })?;
+                            ResolvableItemId::Resolved(id)
+                        }
+                        t => t.clone(),
+                    },
+                );
+            }
+            Ok(resolved)
+        };
+
+        self.fields = resolve_fields(semantic, &self.fields, "message")?;
+        if let Some(response) = &self.response {
+            self.response = Some(resolve_fields(semantic, response, "response")?);
         }
-        self.fields = fields;
         self.resolved = true;
 
         Ok(self)
@@ -83,6 +95,12 @@ impl Message {
                 .iter()
                 .map(|(k, v)| (k.clone(), ResolvableItemId::Unresolved(v.clone())))
                 .collect(),
+            response: value.response.as_ref().map(|response| {
+                response
+                    .iter()
+                    .map(|(k, v)| (k.clone(), ResolvableItemId::Unresolved(v.clone())))
+                    .collect()
+            }),
             as_module_message: value.as_module_message,
             resolved: false,
         }
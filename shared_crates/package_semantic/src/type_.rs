@@ -1,7 +1,11 @@
-use ambient_package::{ComponentType, PascalCaseIdentifier};
+use ambient_package::{ComponentType, PascalCaseIdentifier, SnakeCaseIdentifier};
+use anyhow::Context as AnyhowContext;
 use indexmap::IndexMap;
 
-use crate::{Item, ItemData, ItemId, ItemType, ItemVariant, PrimitiveType, Resolve, Semantic};
+use crate::{
+    Item, ItemData, ItemId, ItemType, ItemVariant, PrimitiveType, ResolvableItemId, Resolve,
+    Semantic,
+};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Type {
@@ -13,7 +17,10 @@ pub enum TypeInner {
     Primitive(PrimitiveType),
     Vec(ItemId<Type>),
     Option(ItemId<Type>),
+    /// A map from the first type (a `String` or `U32`) to the second type
+    Map(ItemId<Type>, ItemId<Type>),
     Enum(Enum),
+    Struct(Struct),
 }
 impl TypeInner {
     pub fn as_primitive(&self) -> Option<PrimitiveType> {
@@ -30,6 +37,20 @@ impl TypeInner {
         }
     }
 
+    pub fn as_struct(&self) -> Option<&Struct> {
+        match self {
+            Self::Struct(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_map(&self) -> Option<(ItemId<Type>, ItemId<Type>)> {
+        match self {
+            Self::Map(k, v) => Some((*k, *v)),
+            _ => None,
+        }
+    }
+
     pub fn as_vec(&self) -> Option<ItemId<Type>> {
         match self {
             Self::Vec(v) => Some(*v),
@@ -58,6 +79,25 @@ impl Type {
             }),
         )
     }
+
+    pub(crate) fn from_package_struct(data: ItemData, value: &ambient_package::Struct) -> Self {
+        Self::new(
+            data,
+            TypeInner::Struct(Struct {
+                description: value.description.clone(),
+                fields: value
+                    .fields
+                    .iter()
+                    .map(|(k, v)| {
+                        (
+                            k.clone(),
+                            ResolvableItemId::Unresolved(ComponentType::Item(v.clone())),
+                        )
+                    })
+                    .collect(),
+            }),
+        )
+    }
 }
 impl Item for Type {
     const TYPE: ItemType = ItemType::Type;
@@ -86,12 +126,50 @@ impl Item for Type {
     }
 }
 impl Resolve for Type {
-    fn resolve(self, _semantic: &mut Semantic, _self_id: ItemId<Self>) -> anyhow::Result<Self> {
+    fn resolve(mut self, semantic: &mut Semantic, _self_id: ItemId<Self>) -> anyhow::Result<Self> {
+        let TypeInner::Struct(struct_) = &mut self.inner else {
+            return Ok(self);
+        };
+        let parent_id = self.data.parent_id.unwrap();
+
+        let mut fields = IndexMap::new();
+        for (name, type_) in &struct_.fields {
+            let id = match type_ {
+                ResolvableItemId::Unresolved(path) => semantic
+                    .get_contextual_type_id(parent_id, path)
+                    .with_context(|| {
+                        format!(
+                            "Failed to resolve type `{path:?}` for field `{name}` of struct `{}`",
+                            self.data.id
+                        )
+                    })?,
+                ResolvableItemId::Resolved(id) => *id,
+            };
+
+            let field_type = semantic.items.get(id);
+            if !matches!(
+                field_type.inner,
+                TypeInner::Primitive(_) | TypeInner::Enum(_)
+            ) {
+                anyhow::bail!(
+                    "Field `{name}` of struct `{}` has type `{}`, but struct fields must be primitive types or enums",
+                    self.data.id,
+                    field_type.data.id
+                );
+            }
+
+            fields.insert(name.clone(), ResolvableItemId::Resolved(id));
+        }
+        struct_.fields = fields;
+
         Ok(self)
     }
 
     fn already_resolved(&self) -> bool {
-        true
+        match &self.inner {
+            TypeInner::Struct(s) => s.fields.values().all(|f| f.as_resolved().is_some()),
+            _ => true,
+        }
     }
 }
 
@@ -100,3 +178,9 @@ pub struct Enum {
     pub description: Option<String>,
     pub members: IndexMap<PascalCaseIdentifier, String>,
 }
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Struct {
+    pub description: Option<String>,
+    pub fields: IndexMap<SnakeCaseIdentifier, ResolvableItemId<Type>>,
+}